@@ -1,5 +1,38 @@
 use crate::config::{AIConfig, AIProvider};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use tokio::sync::Semaphore;
+
+/// Modo offline (--offline o CI): ninguna consulta llega a crear un cliente HTTP
+static OFFLINE: AtomicBool = AtomicBool::new(false);
+
+/// Activa el modo offline para el resto de la ejecución
+pub fn set_offline(offline: bool) {
+    OFFLINE.store(offline, Ordering::Relaxed);
+}
+
+pub fn is_offline() -> bool {
+    OFFLINE.load(Ordering::Relaxed)
+}
+
+/// Clientes HTTP creados hasta ahora (los tests comprueban que offline no crea ninguno)
+#[cfg(test)]
+static CLIENTES_HTTP: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+/// Único punto donde se crea el cliente HTTP. En modo offline falla antes de
+/// construirlo, así que ninguna ruta (wizard, --fix, listado de modelos) sale a la red.
+fn cliente_http() -> anyhow::Result<reqwest::Client> {
+    if is_offline() {
+        return Err(anyhow::anyhow!(
+            "Modo offline: las consultas a la IA están desactivadas"
+        ));
+    }
+    #[cfg(test)]
+    CLIENTES_HTTP.fetch_add(1, Ordering::SeqCst);
+    Ok(reqwest::Client::new())
+}
 
 /// Extrae el primer objeto JSON válido de un texto, manejando correctamente las llaves anidadas
 /// y eliminando marcadores de markdown (```json, ```, etc.)
@@ -101,119 +134,230 @@ pub struct SuggestedRule {
     pub to: String,
     #[serde(deserialize_with = "deserialize_string_or_array")]
     pub reason: String,
+    /// Confianza de la IA en la regla, de 0 a 1 (puede faltar en la respuesta)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f32>,
+}
+
+impl SuggestedRule {
+    /// Alcanza el umbral de --ai-min-confidence. Sin umbral vale cualquier regla;
+    /// con umbral, una regla sin confianza declarada no lo alcanza.
+    pub fn meets_confidence(&self, min_confidence: Option<f32>) -> bool {
+        match min_confidence {
+            Some(min) => self.confidence.is_some_and(|confidence| confidence >= min),
+            None => true,
+        }
+    }
+}
+
+/// Runtime de tokio compartido por todas las consultas a la IA. Se crea la primera
+/// vez que se usa en lugar de levantar uno nuevo en cada petición.
+pub fn runtime() -> &'static tokio::runtime::Runtime {
+    static RUNTIME: OnceLock<tokio::runtime::Runtime> = OnceLock::new();
+    RUNTIME.get_or_init(|| {
+        tokio::runtime::Runtime::new().expect("No se pudo iniciar el runtime de tokio")
+    })
+}
+
+/// Semáforo que limita las peticiones en curso a un proveedor (`max_concurrent_requests`).
+/// Se comparte entre todas las configuraciones con la misma URL; el límite lo fija la
+/// primera que se consulta.
+fn semaforo_del_proveedor(ai_config: &AIConfig) -> Option<Arc<Semaphore>> {
+    static SEMAFOROS: OnceLock<Mutex<HashMap<String, Arc<Semaphore>>>> = OnceLock::new();
+
+    let limit = ai_config
+        .max_concurrent_requests
+        .filter(|&limit| limit > 0)?;
+    let key = ai_config.api_url.trim_end_matches('/').to_lowercase();
+    let mut semaforos = SEMAFOROS.get_or_init(Default::default).lock().unwrap();
+    Some(Arc::clone(
+        semaforos
+            .entry(key)
+            .or_insert_with(|| Arc::new(Semaphore::new(limit))),
+    ))
+}
+
+/// Obtiene la lista de modelos disponibles de varios proveedores a la vez.
+/// Los resultados se devuelven en el mismo orden que `providers` (proveedor, url, key).
+pub fn obtener_modelos_de_proveedores(
+    providers: &[(AIProvider, String, String)],
+) -> Vec<anyhow::Result<Vec<String>>> {
+    runtime().block_on(async {
+        let mut tasks = tokio::task::JoinSet::new();
+        for (index, (provider, api_url, api_key)) in providers.iter().cloned().enumerate() {
+            tasks.spawn(async move {
+                let models = obtener_modelos_disponibles(&provider, &api_url, &api_key).await;
+                (index, models)
+            });
+        }
+
+        let mut results: Vec<Option<anyhow::Result<Vec<String>>>> =
+            providers.iter().map(|_| None).collect();
+        while let Some(joined) = tasks.join_next().await {
+            if let Ok((index, models)) = joined {
+                results[index] = Some(models);
+            }
+        }
+
+        results
+            .into_iter()
+            .map(|models| {
+                models.unwrap_or_else(|| {
+                    Err(anyhow::anyhow!("La consulta de modelos se interrumpió"))
+                })
+            })
+            .collect()
+    })
 }
 
 /// Obtiene la lista de modelos disponibles para el proveedor configurado
-pub fn obtener_modelos_disponibles(
+async fn obtener_modelos_disponibles(
     provider: &AIProvider,
     api_url: &str,
     api_key: &str,
 ) -> anyhow::Result<Vec<String>> {
-    let runtime = tokio::runtime::Runtime::new()?;
-
-    runtime.block_on(async {
-        let client = reqwest::Client::new();
-        let url = api_url.trim_end_matches('/');
-
-        match provider {
-            AIProvider::Claude => {
-                let response = client
-                    .get(&format!("{}/v1/models", url))
-                    .header("x-api-key", api_key)
-                    .header("anthropic-version", "2023-06-01")
-                    .send()
-                    .await?;
-
-                let json: serde_json::Value = response.json().await?;
-                let models = json["data"]
-                    .as_array()
-                    .ok_or_else(|| anyhow::anyhow!("Respuesta de Claude inválida"))?
-                    .iter()
-                    .filter_map(|m| m["id"].as_str().map(|s| s.to_string()))
-                    .collect();
-                Ok(models)
-            }
-            AIProvider::Gemini => {
-                let response = client
-                    .get(&format!("{}/v1beta/models?key={}", url, api_key))
-                    .send()
-                    .await?;
-
-                let json: serde_json::Value = response.json().await?;
-                let models = json["models"]
-                    .as_array()
-                    .ok_or_else(|| anyhow::anyhow!("Respuesta de Gemini inválida"))?
-                    .iter()
-                    .filter_map(|m| {
-                        m["name"]
-                            .as_str()
-                            .map(|s| s.trim_start_matches("models/").to_string())
-                    })
-                    .collect();
-                Ok(models)
-            }
-            AIProvider::OpenAI
-            | AIProvider::Groq
-            | AIProvider::Ollama
-            | AIProvider::Kimi
-            | AIProvider::DeepSeek => {
-                let mut request = client.get(&format!("{}/models", url));
-                if !api_key.is_empty() {
-                    request = request.header("authorization", format!("Bearer {}", api_key));
-                }
+    let client = cliente_http()?;
+    let url = api_url.trim_end_matches('/');
+
+    match provider {
+        AIProvider::Claude => {
+            let response = client
+                .get(format!("{}/v1/models", url))
+                .header("x-api-key", api_key)
+                .header("anthropic-version", "2023-06-01")
+                .send()
+                .await?;
+
+            let json: serde_json::Value = response.json().await?;
+            let models = json["data"]
+                .as_array()
+                .ok_or_else(|| anyhow::anyhow!("Respuesta de Claude inválida"))?
+                .iter()
+                .filter_map(|m| m["id"].as_str().map(|s| s.to_string()))
+                .collect();
+            Ok(models)
+        }
+        AIProvider::Gemini => {
+            let response = client
+                .get(format!("{}/v1beta/models?key={}", url, api_key))
+                .send()
+                .await?;
 
-                let response = request.send().await?;
-                let json: serde_json::Value = response.json().await?;
-                let models = json["data"]
-                    .as_array()
-                    .ok_or_else(|| anyhow::anyhow!("Respuesta de API compatible inválida"))?
-                    .iter()
-                    .filter_map(|m| m["id"].as_str().map(|s| s.to_string()))
-                    .collect();
-                Ok(models)
+            let json: serde_json::Value = response.json().await?;
+            let models = json["models"]
+                .as_array()
+                .ok_or_else(|| anyhow::anyhow!("Respuesta de Gemini inválida"))?
+                .iter()
+                .filter_map(|m| {
+                    m["name"]
+                        .as_str()
+                        .map(|s| s.trim_start_matches("models/").to_string())
+                })
+                .collect();
+            Ok(models)
+        }
+        AIProvider::OpenAI
+        | AIProvider::Groq
+        | AIProvider::Ollama
+        | AIProvider::Kimi
+        | AIProvider::DeepSeek => {
+            let mut request = client.get(format!("{}/models", url));
+            if !api_key.is_empty() {
+                request = request.header("authorization", format!("Bearer {}", api_key));
             }
+
+            let response = request.send().await?;
+            let json: serde_json::Value = response.json().await?;
+            let models = json["data"]
+                .as_array()
+                .ok_or_else(|| anyhow::anyhow!("Respuesta de API compatible inválida"))?
+                .iter()
+                .filter_map(|m| m["id"].as_str().map(|s| s.to_string()))
+                .collect();
+            Ok(models)
         }
-    })
+    }
 }
 
-/// Función para consultar la IA seleccionada de forma genérica
-pub fn consultar_ia(prompt: String, ai_config: AIConfig) -> anyhow::Result<String> {
-    match ai_config.provider {
-        AIProvider::Claude => consultar_claude(prompt, ai_config),
-        AIProvider::Gemini => consultar_gemini(prompt, ai_config),
-        AIProvider::OpenAI | AIProvider::Groq | AIProvider::Ollama | AIProvider::Kimi => {
-            consultar_openai_compatible(prompt, ai_config)
-        }
-        AIProvider::DeepSeek => consultar_openai_compatible(prompt, ai_config),
+/// Función para consultar la IA seleccionada de forma genérica.
+/// Si el proveedor tiene `max_concurrent_requests`, la petición espera turno en lugar de fallar.
+pub async fn consultar_ia(prompt: String, ai_config: AIConfig) -> anyhow::Result<String> {
+    let _permiso = match semaforo_del_proveedor(&ai_config) {
+        Some(semaforo) => Some(semaforo.acquire_owned().await?),
+        None => None,
+    };
+
+    match api_kind(&ai_config.provider) {
+        ApiKind::Claude => consultar_claude(prompt, ai_config).await,
+        ApiKind::Gemini => consultar_gemini(prompt, ai_config).await,
+        ApiKind::OpenAICompatible => consultar_openai_compatible(prompt, ai_config).await,
     }
 }
 
-/// Orquestador que intenta consultar varias IAs en orden hasta que una funcione
-pub fn consultar_ia_con_fallback(prompt: String, configs: &[AIConfig]) -> anyhow::Result<String> {
+/// Forma de la API de cada proveedor: la que decide qué `consultar_*` se usa
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ApiKind {
+    Claude,
+    Gemini,
+    OpenAICompatible,
+}
+
+fn api_kind(provider: &AIProvider) -> ApiKind {
+    match provider {
+        AIProvider::Claude => ApiKind::Claude,
+        AIProvider::Gemini => ApiKind::Gemini,
+        AIProvider::OpenAI
+        | AIProvider::Groq
+        | AIProvider::Ollama
+        | AIProvider::Kimi
+        | AIProvider::DeepSeek => ApiKind::OpenAICompatible,
+    }
+}
+
+/// Orquestador que intenta consultar varias IAs en orden hasta que una funcione.
+/// Con `race` consulta todas a la vez y se queda con la primera respuesta válida.
+pub fn consultar_ia_con_fallback(
+    prompt: String,
+    configs: &[AIConfig],
+    race: bool,
+) -> anyhow::Result<String> {
+    runtime().block_on(consultar_ia_con_fallback_async(prompt, configs, race))
+}
+
+/// Versión asíncrona de `consultar_ia_con_fallback`
+pub async fn consultar_ia_con_fallback_async(
+    prompt: String,
+    configs: &[AIConfig],
+    race: bool,
+) -> anyhow::Result<String> {
     if configs.is_empty() {
         return Err(anyhow::anyhow!("No hay configuraciones de IA disponibles. Ejecuta el linter sin architect.json para configurar una."));
     }
 
+    if race && configs.len() > 1 {
+        return consultar_ia_en_carrera(prompt, configs).await;
+    }
+
     let mut last_error = anyhow::anyhow!("Error desconocido");
 
     for (i, config) in configs.iter().enumerate() {
         if i > 0 {
-            println!(
+            eprintln!(
                 "\n⚠️  El modelo '{}' falló. Intentando con el siguiente configurado: '{}'...",
                 configs[i - 1].name,
                 config.name
             );
         }
 
-        match consultar_ia(prompt.clone(), config.clone()) {
+        match consultar_ia(prompt.clone(), config.clone()).await {
             Ok(res) => {
                 if i > 0 {
-                    println!("✅ El modelo '{}' respondió correctamente.\n", config.name);
+                    eprintln!("✅ El modelo '{}' respondió correctamente.\n", config.name);
                 }
                 return Ok(res);
             }
             Err(e) => {
-                println!("❌ Error en '{}': {}", config.name, e);
+                eprintln!("❌ Error en '{}': {}", config.name, e);
                 last_error = e;
             }
         }
@@ -225,10 +369,59 @@ pub fn consultar_ia_con_fallback(prompt: String, configs: &[AIConfig]) -> anyhow
     ))
 }
 
+/// La respuesta trae un objeto JSON que se puede parsear: llaves balanceadas no
+/// bastan (`{no es json}` no gana la carrera)
+fn contains_parseable_json(response: &str) -> bool {
+    extract_json_object(response)
+        .is_some_and(|json| serde_json::from_str::<serde_json::Value>(&json).is_ok())
+}
+
+/// Modo carrera (--ai-race): lanza todos los modelos a la vez y devuelve la primera
+/// respuesta que contenga un JSON válido. El resto de peticiones se cancelan.
+async fn consultar_ia_en_carrera(prompt: String, configs: &[AIConfig]) -> anyhow::Result<String> {
+    eprintln!(
+        "🏁 Consultando {} modelos en paralelo (gana la primera respuesta válida)...",
+        configs.len()
+    );
+
+    let mut tasks = tokio::task::JoinSet::new();
+    for config in configs {
+        let prompt = prompt.clone();
+        let config = config.clone();
+        tasks.spawn(async move {
+            let name = config.name.clone();
+            (name, consultar_ia(prompt, config).await)
+        });
+    }
+
+    let mut errors = Vec::new();
+    while let Some(joined) = tasks.join_next().await {
+        match joined {
+            Ok((name, Ok(res))) if contains_parseable_json(&res) => {
+                // Cancela las peticiones que siguen en curso; sus errores ya no importan
+                tasks.abort_all();
+                eprintln!("✅ El modelo '{}' respondió primero.\n", name);
+                return Ok(res);
+            }
+            Ok((name, Ok(_))) => {
+                errors.push(format!("{}: la respuesta no contiene un JSON válido", name));
+            }
+            Ok((name, Err(e))) => errors.push(format!("{}: {}", name, e)),
+            Err(e) => errors.push(e.to_string()),
+        }
+    }
+
+    Err(anyhow::anyhow!(
+        "❌ Todos los modelos configurados fallaron:\n  {}",
+        errors.join("\n  ")
+    ))
+}
+
 /// Función exclusiva para el Linter: Sugiere la arquitectura inicial
 pub fn sugerir_arquitectura_inicial(
     context: crate::discovery::ProjectContext,
     ai_configs: Vec<AIConfig>,
+    race: bool,
 ) -> anyhow::Result<AISuggestionResponse> {
     let prompt = format!(
         "Eres un Arquitecto de Software Senior. Analiza este proyecto {framework} con las siguientes dependencias: {deps:?}
@@ -251,6 +444,7 @@ pub fn sugerir_arquitectura_inicial(
         3. Limita las reglas a máximo 3 para evitar respuestas muy largas
         4. Usa comillas dobles para todos los strings
         5. Cada razón debe ser concisa (máximo 15 palabras)
+        6. Incluye en cada regla \"confidence\": tu confianza en ella, de 0 a 1
 
         FORMATO JSON REQUERIDO:
         {{
@@ -260,7 +454,8 @@ pub fn sugerir_arquitectura_inicial(
             {{
               \"from\": \"src/presentation/**\",
               \"to\": \"src/infrastructure/**\",
-              \"reason\": \"La capa de presentación no debe depender de infraestructura\"
+              \"reason\": \"La capa de presentación no debe depender de infraestructura\",
+              \"confidence\": 0.9
             }}
           ]
         }}
@@ -272,7 +467,7 @@ pub fn sugerir_arquitectura_inicial(
     );
 
     // Obtener respuesta con fallback
-    let response_text = consultar_ia_con_fallback(prompt, &ai_configs)?;
+    let response_text = consultar_ia_con_fallback(prompt, &ai_configs, race)?;
 
     // Extraer el JSON válido usando un contador de llaves
     let clean_json = match extract_json_object(&response_text) {
@@ -284,145 +479,163 @@ pub fn sugerir_arquitectura_inicial(
             eprintln!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
             eprintln!("{}", response_text);
             eprintln!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n");
-            return Err(anyhow::anyhow!("No se encontró un JSON válido en la respuesta"));
+            return Err(anyhow::anyhow!(
+                "No se encontró un JSON válido en la respuesta"
+            ));
         }
     };
 
     // Intentar parsear con mejor manejo de errores
-    let suggestion: AISuggestionResponse = serde_json::from_str(&clean_json)
-        .map_err(|e| {
-            eprintln!("\n❌ Error parseando JSON de la IA:");
-            eprintln!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-            eprintln!("{}", e);
-            eprintln!("\n📄 JSON extraído:");
-            eprintln!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-            eprintln!("{}", clean_json);
-            eprintln!("\n📄 Respuesta completa de la IA:");
-            eprintln!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-            eprintln!("{}", response_text);
-            eprintln!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n");
-            anyhow::anyhow!("{}", e)
-        })?;
+    let suggestion: AISuggestionResponse = serde_json::from_str(&clean_json).map_err(|e| {
+        eprintln!("\n❌ Error parseando JSON de la IA:");
+        eprintln!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        eprintln!("{}", e);
+        eprintln!("\n📄 JSON extraído:");
+        eprintln!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        eprintln!("{}", clean_json);
+        eprintln!("\n📄 Respuesta completa de la IA:");
+        eprintln!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        eprintln!("{}", response_text);
+        eprintln!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n");
+        anyhow::anyhow!("{}", e)
+    })?;
     Ok(suggestion)
 }
 
 /// Consulta la API de Claude (Anthropic)
-fn consultar_claude(prompt: String, ai_config: AIConfig) -> anyhow::Result<String> {
+async fn consultar_claude(prompt: String, ai_config: AIConfig) -> anyhow::Result<String> {
     let url = format!("{}/v1/messages", ai_config.api_url.trim_end_matches('/'));
-    let runtime = tokio::runtime::Runtime::new()?;
-
-    runtime.block_on(async {
-        let client = reqwest::Client::new();
-        let body = serde_json::json!({
-            "model": ai_config.model,
-            "max_tokens": 8192,
-            "messages": [{
-                "role": "user",
-                "content": prompt
-            }]
-        });
+    let client = cliente_http()?;
+    let body = cuerpo_claude(&prompt, &ai_config);
 
-        let response = client
-            .post(&url)
-            .header("x-api-key", &ai_config.api_key)
-            .header("anthropic-version", "2023-06-01")
-            .header("content-type", "application/json")
-            .json(&body)
-            .send()
-            .await?;
+    let response = client
+        .post(&url)
+        .header("x-api-key", &ai_config.api_key)
+        .header("anthropic-version", "2023-06-01")
+        .header("content-type", "application/json")
+        .json(&body)
+        .send()
+        .await?;
 
-        procesar_respuesta(response).await
-    })
+    procesar_respuesta(response).await
+}
+
+/// Cuerpo de la petición a Claude. Sin `temperature` configurada se usa la del proveedor
+fn cuerpo_claude(prompt: &str, ai_config: &AIConfig) -> serde_json::Value {
+    let mut body = serde_json::json!({
+        "model": ai_config.model,
+        "max_tokens": ai_config.max_tokens(),
+        "messages": [{
+            "role": "user",
+            "content": prompt
+        }]
+    });
+    if let Some(temperature) = ai_config.temperature {
+        body["temperature"] = serde_json::json!(temperature);
+    }
+    body
 }
 
 /// Consulta la API de Gemini (Google)
-fn consultar_gemini(prompt: String, ai_config: AIConfig) -> anyhow::Result<String> {
+async fn consultar_gemini(prompt: String, ai_config: AIConfig) -> anyhow::Result<String> {
     let url = format!(
         "{}/v1beta/models/{}:generateContent?key={}",
         ai_config.api_url.trim_end_matches('/'),
         ai_config.model,
         ai_config.api_key
     );
-    let runtime = tokio::runtime::Runtime::new()?;
-
-    runtime.block_on(async {
-        let client = reqwest::Client::new();
-        let body = serde_json::json!({
-            "contents": [{
-                "parts": [{
-                    "text": prompt
-                }]
-            }]
-        });
+    let client = cliente_http()?;
+    let body = cuerpo_gemini(&prompt, &ai_config);
 
-        let response = client
-            .post(&url)
-            .header("content-type", "application/json")
-            .json(&body)
-            .send()
-            .await?;
+    let response = client
+        .post(&url)
+        .header("content-type", "application/json")
+        .json(&body)
+        .send()
+        .await?;
 
-        let status = response.status();
-        let response_text = response.text().await?;
+    let status = response.status();
+    let response_text = response.text().await?;
 
-        if !status.is_success() {
-            return Err(anyhow::anyhow!(
-                "Error Gemini ({}): {}",
-                status,
-                response_text
-            ));
-        }
+    if !status.is_success() {
+        return Err(anyhow::anyhow!(
+            "Error Gemini ({}): {}",
+            status,
+            response_text
+        ));
+    }
 
-        let json: serde_json::Value = serde_json::from_str(&response_text)?;
-        let content = json["candidates"][0]["content"]["parts"][0]["text"]
-            .as_str()
-            .ok_or_else(|| anyhow::anyhow!("No se pudo extraer texto de Gemini"))?;
+    let json: serde_json::Value = serde_json::from_str(&response_text)?;
+    let content = json["candidates"][0]["content"]["parts"][0]["text"]
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("No se pudo extraer texto de Gemini"))?;
 
-        Ok(content.to_string())
-    })
+    Ok(content.to_string())
+}
+
+/// Cuerpo de la petición a Gemini
+fn cuerpo_gemini(prompt: &str, ai_config: &AIConfig) -> serde_json::Value {
+    let mut body = serde_json::json!({
+        "contents": [{
+            "parts": [{
+                "text": prompt
+            }]
+        }],
+        "generationConfig": {
+            "maxOutputTokens": ai_config.max_tokens()
+        }
+    });
+    if let Some(temperature) = ai_config.temperature {
+        body["generationConfig"]["temperature"] = serde_json::json!(temperature);
+    }
+    body
 }
 
 /// Consulta APIs compatibles con OpenAI (OpenAI, Groq, Ollama)
-fn consultar_openai_compatible(prompt: String, ai_config: AIConfig) -> anyhow::Result<String> {
+async fn consultar_openai_compatible(
+    prompt: String,
+    ai_config: AIConfig,
+) -> anyhow::Result<String> {
     let url = format!(
         "{}/chat/completions",
         ai_config.api_url.trim_end_matches('/')
     );
-    let runtime = tokio::runtime::Runtime::new()?;
-
-    runtime.block_on(async {
-        let client = reqwest::Client::new();
-        let body = serde_json::json!({
-            "model": ai_config.model,
-            "messages": [
-                {"role": "system", "content": "Eres un Arquitecto de Software Senior."},
-                {"role": "user", "content": prompt}
-            ],
-            "temperature": 0.1,
-            "max_tokens": 8192
-        });
+    let client = cliente_http()?;
+    let body = cuerpo_openai_compatible(&prompt, &ai_config);
 
-        let mut request = client.post(&url).header("content-type", "application/json");
+    let mut request = client.post(&url).header("content-type", "application/json");
 
-        if !ai_config.api_key.is_empty() {
-            request = request.header("authorization", format!("Bearer {}", ai_config.api_key));
-        }
+    if !ai_config.api_key.is_empty() {
+        request = request.header("authorization", format!("Bearer {}", ai_config.api_key));
+    }
 
-        let response = request.json(&body).send().await?;
+    let response = request.json(&body).send().await?;
 
-        let status = response.status();
-        let response_text = response.text().await?;
+    let status = response.status();
+    let response_text = response.text().await?;
 
-        if !status.is_success() {
-            return Err(anyhow::anyhow!("Error API ({}): {}", status, response_text));
-        }
+    if !status.is_success() {
+        return Err(anyhow::anyhow!("Error API ({}): {}", status, response_text));
+    }
 
-        let json: serde_json::Value = serde_json::from_str(&response_text)?;
-        let content = json["choices"][0]["message"]["content"]
-            .as_str()
-            .ok_or_else(|| anyhow::anyhow!("No se pudo extraer texto de la respuesta"))?;
+    let json: serde_json::Value = serde_json::from_str(&response_text)?;
+    let content = json["choices"][0]["message"]["content"]
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("No se pudo extraer texto de la respuesta"))?;
 
-        Ok(content.to_string())
+    Ok(content.to_string())
+}
+
+/// Cuerpo de la petición a una API compatible con OpenAI
+fn cuerpo_openai_compatible(prompt: &str, ai_config: &AIConfig) -> serde_json::Value {
+    serde_json::json!({
+        "model": ai_config.model,
+        "messages": [
+            {"role": "system", "content": "Eres un Arquitecto de Software Senior."},
+            {"role": "user", "content": prompt}
+        ],
+        "temperature": ai_config.temperature.unwrap_or(0.1),
+        "max_tokens": ai_config.max_tokens()
     })
 }
 
@@ -443,3 +656,400 @@ async fn procesar_respuesta(response: reqwest::Response) -> anyhow::Result<Strin
 
     Ok(response_text)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+    use std::time::{Duration, Instant};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    /// Los tests que hablan con proveedores simulados no deben cruzarse con el que
+    /// activa el modo offline, que es global, ni los que fijan las variables
+    /// ARCHITECT_AI_*, que son del proceso
+    static RED: Mutex<()> = Mutex::new(());
+
+    fn red() -> std::sync::MutexGuard<'static, ()> {
+        let guard = RED.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        set_offline(false);
+        guard
+    }
+
+    /// Proveedor simulado: un servidor HTTP local que responde `body` a cada petición
+    /// tras `delay`. Devuelve su URL
+    fn mock_provider(delay: Duration, body: serde_json::Value) -> String {
+        mock_provider_counting(delay, body, Arc::default())
+    }
+
+    /// Como `mock_provider`, anotando en `max_in_flight` el máximo de peticiones
+    /// atendidas a la vez
+    fn mock_provider_counting(
+        delay: Duration,
+        body: serde_json::Value,
+        max_in_flight: Arc<AtomicUsize>,
+    ) -> String {
+        let listener = runtime()
+            .block_on(tokio::net::TcpListener::bind("127.0.0.1:0"))
+            .unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let body = body.to_string();
+        let in_flight = Arc::new(AtomicUsize::new(0));
+
+        runtime().spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let body = body.clone();
+                let (in_flight, max_in_flight) = (Arc::clone(&in_flight), Arc::clone(&max_in_flight));
+                tokio::spawn(async move {
+                    let mut request = Vec::new();
+                    let mut buf = [0u8; 4096];
+                    while !request_complete(&request) {
+                        match socket.read(&mut buf).await {
+                            Ok(0) | Err(_) => return,
+                            Ok(n) => request.extend_from_slice(&buf[..n]),
+                        }
+                    }
+                    let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_in_flight.fetch_max(current, Ordering::SeqCst);
+                    tokio::time::sleep(delay).await;
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+                    let response = format!(
+                        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                        body.len(),
+                        body
+                    );
+                    let _ = socket.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+
+        url
+    }
+
+    /// Cabeceras completas y tantos bytes de cuerpo como indica `content-length`
+    fn request_complete(request: &[u8]) -> bool {
+        let text = String::from_utf8_lossy(request);
+        let end = match text.find("\r\n\r\n") {
+            Some(end) => end,
+            None => return false,
+        };
+        let length = text[..end]
+            .lines()
+            .find_map(|line| {
+                let line = line.to_lowercase();
+                line.strip_prefix("content-length:")
+                    .and_then(|value| value.trim().parse::<usize>().ok())
+            })
+            .unwrap_or(0);
+        request.len() >= end + 4 + length
+    }
+
+    /// Respuesta de chat completions con `content` como texto del modelo
+    fn chat_response(content: &str) -> serde_json::Value {
+        serde_json::json!({ "choices": [{ "message": { "content": content } }] })
+    }
+
+    fn mock_config(name: &str, url: &str) -> AIConfig {
+        AIConfig {
+            name: name.to_string(),
+            api_url: url.to_string(),
+            ..config_for(AIProvider::Ollama)
+        }
+    }
+
+    fn config_for(provider: AIProvider) -> AIConfig {
+        AIConfig {
+            name: provider.as_str().to_string(),
+            api_url: provider.default_api_url().to_string(),
+            provider,
+            api_key: "key".to_string(),
+            model: "model".to_string(),
+            max_concurrent_requests: None,
+            temperature: None,
+            max_tokens: None,
+        }
+    }
+
+    #[test]
+    fn each_provider_is_routed_to_its_api() {
+        let expected = [
+            (AIProvider::Claude, ApiKind::Claude),
+            (AIProvider::Gemini, ApiKind::Gemini),
+            (AIProvider::OpenAI, ApiKind::OpenAICompatible),
+            (AIProvider::Groq, ApiKind::OpenAICompatible),
+            (AIProvider::Ollama, ApiKind::OpenAICompatible),
+            (AIProvider::Kimi, ApiKind::OpenAICompatible),
+            (AIProvider::DeepSeek, ApiKind::OpenAICompatible),
+        ];
+        assert_eq!(expected.len(), AIProvider::ALL.len());
+        for (provider, kind) in expected {
+            let config = config_for(provider);
+            assert_eq!(api_kind(&config.provider), kind, "{}", config.name);
+        }
+    }
+
+    #[test]
+    fn request_bodies_carry_the_configured_sampling() {
+        let bodies = |config: &AIConfig| {
+            [
+                cuerpo_claude("p", config),
+                cuerpo_gemini("p", config),
+                cuerpo_openai_compatible("p", config),
+            ]
+        };
+
+        let [claude, gemini, openai] = bodies(&config_for(AIProvider::Claude));
+        assert_eq!(claude["max_tokens"], 8192);
+        assert!(claude.get("temperature").is_none());
+        assert_eq!(gemini["generationConfig"]["maxOutputTokens"], 8192);
+        assert!(gemini["generationConfig"].get("temperature").is_none());
+        assert_eq!(openai["max_tokens"], 8192);
+        assert_eq!(openai["temperature"], serde_json::json!(0.1f32));
+
+        let configured = AIConfig {
+            temperature: Some(0.5),
+            max_tokens: Some(1024),
+            ..config_for(AIProvider::Claude)
+        };
+        let [claude, gemini, openai] = bodies(&configured);
+        assert_eq!(
+            (&claude["temperature"], &claude["max_tokens"]),
+            (&0.5.into(), &1024.into())
+        );
+        assert_eq!(gemini["generationConfig"]["temperature"], 0.5);
+        assert_eq!(gemini["generationConfig"]["maxOutputTokens"], 1024);
+        assert_eq!(
+            (&openai["temperature"], &openai["max_tokens"]),
+            (&0.5.into(), &1024.into())
+        );
+    }
+
+    #[test]
+    fn the_fastest_valid_response_wins_the_race() {
+        let _red = red();
+        let slow = mock_provider(
+            Duration::from_secs(3),
+            chat_response(r#"{"model": "slow"}"#),
+        );
+        let fast = mock_provider(
+            Duration::from_millis(20),
+            chat_response(r#"{"model": "fast"}"#),
+        );
+        // Un proveedor caído falla antes que todos: su error no tapa al ganador
+        let configs = [
+            mock_config("slow", &slow),
+            mock_config("down", "http://127.0.0.1:1"),
+            mock_config("fast", &fast),
+        ];
+
+        let started = Instant::now();
+        let response = consultar_ia_con_fallback("prompt".to_string(), &configs, true).unwrap();
+        assert_eq!(response, r#"{"model": "fast"}"#);
+        // No se espera al proveedor lento
+        assert!(started.elapsed() < Duration::from_secs(2));
+    }
+
+    #[test]
+    fn a_fast_malformed_json_loses_the_race() {
+        let _red = red();
+        let valid = mock_provider(
+            Duration::from_millis(300),
+            chat_response(r#"{"model": "valid"}"#),
+        );
+        let malformed = mock_provider(Duration::ZERO, chat_response("{no es json}"));
+        let configs = [
+            mock_config("malformed", &malformed),
+            mock_config("valid", &valid),
+        ];
+
+        let response = consultar_ia_con_fallback("prompt".to_string(), &configs, true).unwrap();
+        assert_eq!(response, r#"{"model": "valid"}"#);
+    }
+
+    #[test]
+    fn two_providers_list_their_models_on_the_shared_runtime() {
+        let _red = red();
+        let models = |ids: &[&str]| {
+            let data: Vec<_> = ids
+                .iter()
+                .map(|id| serde_json::json!({ "id": id }))
+                .collect();
+            serde_json::json!({ "data": data })
+        };
+        let delay = Duration::from_secs(1);
+        let ollama = mock_provider(delay, models(&["llama3", "qwen2"]));
+        let groq = mock_provider(delay, models(&["mixtral"]));
+        let providers = [
+            (AIProvider::Ollama, ollama, String::new()),
+            (AIProvider::Groq, groq, "key".to_string()),
+        ];
+
+        let runtime_before: *const _ = runtime();
+        let started = Instant::now();
+        let results = obtener_modelos_de_proveedores(&providers);
+        // Las dos consultas van a la vez, sobre el mismo runtime que el resto de la IA
+        assert!(started.elapsed() < delay * 2);
+        assert!(std::ptr::eq(runtime_before, runtime()));
+
+        let results: Vec<Vec<String>> = results.into_iter().map(Result::unwrap).collect();
+        assert_eq!(results, vec![vec!["llama3", "qwen2"], vec!["mixtral"]]);
+    }
+
+    #[test]
+    fn a_limit_of_one_serializes_the_requests() {
+        let _red = red();
+        let max_in_flight = Arc::new(AtomicUsize::new(0));
+        let url = mock_provider_counting(
+            Duration::from_millis(50),
+            chat_response("ok"),
+            Arc::clone(&max_in_flight),
+        );
+        let config = AIConfig {
+            max_concurrent_requests: Some(1),
+            ..mock_config("limited", &url)
+        };
+
+        // Las peticiones por encima del límite esperan turno en lugar de fallar
+        let responses = runtime().block_on(async {
+            let mut tasks = tokio::task::JoinSet::new();
+            for _ in 0..3 {
+                tasks.spawn(consultar_ia("prompt".to_string(), config.clone()));
+            }
+            let mut responses = Vec::new();
+            while let Some(joined) = tasks.join_next().await {
+                responses.push(joined.unwrap().unwrap());
+            }
+            responses
+        });
+        assert_eq!(responses, vec!["ok", "ok", "ok"]);
+        assert_eq!(max_in_flight.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn offline_mode_never_builds_an_http_client() {
+        let _red = red();
+        let max_in_flight = Arc::new(AtomicUsize::new(0));
+        let url = mock_provider_counting(
+            Duration::ZERO,
+            chat_response("ok"),
+            Arc::clone(&max_in_flight),
+        );
+        let configs = [mock_config("local", &url)];
+        let before = CLIENTES_HTTP.load(Ordering::SeqCst);
+
+        set_offline(true);
+        let models =
+            obtener_modelos_de_proveedores(&[(AIProvider::Ollama, url.clone(), String::new())]);
+        let fix = consultar_ia_con_fallback("prompt".to_string(), &configs, false);
+        let race = consultar_ia_con_fallback(
+            "prompt".to_string(),
+            &[configs[0].clone(), configs[0].clone()],
+            true,
+        );
+        set_offline(false);
+
+        assert!(models[0].is_err());
+        assert!(fix.is_err());
+        assert!(race.is_err());
+        assert_eq!(CLIENTES_HTTP.load(Ordering::SeqCst), before);
+        // El proveedor no recibió ninguna petición
+        assert_eq!(max_in_flight.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn the_ai_config_can_come_entirely_from_the_environment() {
+        use crate::config::{AI_KEY_ENV, AI_MODEL_ENV, AI_PROVIDER_ENV, AI_URL_ENV};
+
+        let _red = red();
+        std::env::set_var(AI_PROVIDER_ENV, "groq");
+        std::env::set_var(AI_KEY_ENV, "gsk-test");
+        std::env::set_var(AI_MODEL_ENV, " llama-3.3-70b ");
+        std::env::remove_var(AI_URL_ENV);
+
+        let config = AIConfig::from_env().unwrap();
+        assert_eq!(config.name, "Groq (entorno)");
+        assert_eq!(config.provider, AIProvider::Groq);
+        assert_eq!(config.api_url, AIProvider::Groq.default_api_url());
+        assert_eq!(config.api_key, "gsk-test");
+        assert_eq!(config.model, "llama-3.3-70b");
+
+        std::env::set_var(AI_URL_ENV, "http://localhost:8080/v1");
+        assert_eq!(
+            AIConfig::from_env().unwrap().api_url,
+            "http://localhost:8080/v1"
+        );
+
+        // Falta la key: el error nombra la variable en lugar de preguntar
+        std::env::remove_var(AI_KEY_ENV);
+        let error = AIConfig::from_env().unwrap_err().to_string();
+        assert!(
+            error.ends_with(&format!("Faltan: {}", AI_KEY_ENV)),
+            "{}",
+            error
+        );
+
+        std::env::set_var(AI_PROVIDER_ENV, "Watson");
+        let error = AIConfig::from_env().unwrap_err().to_string();
+        assert!(
+            error.contains("'Watson' no es un proveedor válido"),
+            "{}",
+            error
+        );
+
+        for var in [AI_PROVIDER_ENV, AI_MODEL_ENV, AI_URL_ENV] {
+            std::env::remove_var(var);
+        }
+    }
+
+    #[test]
+    fn non_interactive_ai_setup_saves_the_confident_rules() {
+        use crate::config::{AI_KEY_ENV, AI_MODEL_ENV, AI_PROVIDER_ENV, AI_URL_ENV};
+
+        let _red = red();
+        let suggestion = r#"Claro: {"pattern": "Hexagonal", "suggested_max_lines": 45, "rules": [
+            {"from": "src/domain/**", "to": "src/infrastructure/**", "reason": "Dominio puro", "confidence": 0.9},
+            {"from": "src/app/**", "to": "src/ui/**", "reason": "Dudosa", "confidence": 0.2}
+        ]}"#;
+        let good = mock_provider(Duration::ZERO, chat_response(suggestion));
+        let broken = mock_provider(Duration::ZERO, chat_response("sin JSON"));
+        std::env::set_var(AI_PROVIDER_ENV, "Ollama");
+        std::env::set_var(AI_MODEL_ENV, "model");
+        std::env::remove_var(AI_KEY_ENV);
+        let setup = |url: &str| {
+            std::env::set_var(AI_URL_ENV, url);
+            // Sin package.json no se instala husky (necesitaría npm y red)
+            let root =
+                crate::rules::testing::temp_project("ai-setup", &[("src/domain/user.ts", "")]);
+            let options = crate::config::SetupOptions {
+                force_ai: true,
+                non_interactive: true,
+                min_confidence: Some(0.5),
+                ..Default::default()
+            };
+            let ctx = crate::config::setup_or_load_config(&root, options).unwrap();
+            let saved: serde_json::Value = serde_json::from_str(
+                &std::fs::read_to_string(root.join("architect.json")).unwrap(),
+            )
+            .unwrap();
+            // La configuración del entorno no se escribe en disco
+            assert!(!root.join(".architect.ai.json").exists());
+            std::fs::remove_dir_all(&root).unwrap();
+            (ctx, saved)
+        };
+
+        let (ctx, saved) = setup(&good);
+        assert_eq!(ctx.max_lines, 45);
+        assert_eq!(
+            saved["forbidden_imports"],
+            serde_json::json!([{ "from": "src/domain/**", "to": "src/infrastructure/**" }])
+        );
+
+        // Si la IA no da una sugerencia válida, queda la configuración manual
+        let (ctx, saved) = setup(&broken);
+        assert_eq!(ctx.max_lines, 40);
+        assert_eq!(saved["forbidden_imports"], serde_json::json!([]));
+
+        for var in [AI_PROVIDER_ENV, AI_MODEL_ENV, AI_URL_ENV] {
+            std::env::remove_var(var);
+        }
+    }
+}
@@ -1,58 +1,48 @@
 use crate::autofix::Violation;
+use crate::circular::{is_asset_import, resolve_import_path};
 use crate::config::{ArchError, LinterContext};
-use crate::parsers;
+use crate::discovery;
+use crate::hotspots::HotspotCollector;
+use crate::parsers::{self, Import};
+use crate::rules::{self, Finding, Location, RuleContext, Severity};
 use miette::{IntoDiagnostic, Result, SourceSpan};
 use std::fs;
-use std::path::PathBuf;
-use swc_common::SourceMap;
-use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax, TsConfig, EsConfig};
+use std::path::Path;
+use swc_common::sync::Lrc;
+use swc_common::{FileName, SourceFile, SourceMap, Spanned};
+use swc_ecma_ast::{CallExpr, Callee, Expr, ImportDecl, Lit, Module};
+use swc_ecma_parser::{lexer::Lexer, EsConfig, Parser, StringInput, Syntax, TsConfig};
+use swc_ecma_visit::{Visit, VisitWith};
+
+/// Analiza un archivo y devuelve todos los hallazgos (errores y advertencias).
+/// Un `Err` indica que el archivo no pudo analizarse.
+pub fn analyze_file(cm: &SourceMap, path: &Path, ctx: &LinterContext) -> Result<Vec<Finding>> {
+    analyze_file_recording(cm, path, ctx, None)
+}
 
-pub fn analyze_file(cm: &SourceMap, path: &PathBuf, ctx: &LinterContext) -> Result<()> {
+/// Como `analyze_file`, registrando en `hotspots` las métricas del módulo ya
+/// parseado (--hotspots) para no volver a parsearlo
+pub fn analyze_file_recording(
+    cm: &SourceMap,
+    path: &Path,
+    ctx: &LinterContext,
+    hotspots: Option<&HotspotCollector>,
+) -> Result<Vec<Finding>> {
     // Try to use multi-language parser first
-    if let Some(parser) = parsers::get_parser_for_file(path) {
-        let source_code = fs::read_to_string(path).into_diagnostic()?;
-        let violations = parser.find_violations(&source_code, path, ctx)?;
-
-        if let Some(first_violation) = violations.first() {
-            // Return error for the first violation
-            let fm = cm.load_file(path).into_diagnostic()?;
-            return Err(create_error_from_violation(&fm, first_violation));
-        }
-
-        // Validate method length for TypeScript/JavaScript files
-        let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
-        if matches!(extension, "ts" | "tsx" | "js" | "jsx") {
-            validate_method_length(cm, path, ctx)?;
-        }
-
-        return Ok(());
+    if parsers::get_parser_for_file(path).is_some() {
+        let source_code = read_source(path)?;
+        return analyze_source_recording(cm, path, source_code, ctx, hotspots);
     }
 
     // Fallback to old swc parser for unsupported files
-    let fm = cm.load_file(path).into_diagnostic()?;
+    let fm = load_source_file(cm, path)?;
 
     // Detectar si es TypeScript o JavaScript según la extensión
     let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
-    let syntax = match extension {
-        "ts" | "tsx" => Syntax::Typescript(TsConfig {
-            decorators: true,
-            tsx: extension == "tsx",
-            ..Default::default()
-        }),
-        "js" | "jsx" => Syntax::Es(EsConfig {
-            decorators: true,
-            jsx: extension == "jsx",
-            ..Default::default()
-        }),
-        _ => Syntax::Typescript(TsConfig::default()),
-    };
+    let syntax =
+        syntax_for_extension(extension).unwrap_or_else(|| Syntax::Typescript(TsConfig::default()));
 
-    let lexer = Lexer::new(
-        syntax,
-        Default::default(),
-        StringInput::from(&*fm),
-        None,
-    );
+    let lexer = Lexer::new(syntax, Default::default(), StringInput::from(&*fm), None);
 
     let mut parser = Parser::new_from(lexer);
     let module = parser
@@ -61,46 +51,52 @@ pub fn analyze_file(cm: &SourceMap, path: &PathBuf, ctx: &LinterContext) -> Resu
 
     let file_path_str = path.to_string_lossy().to_lowercase();
 
-    for item in &module.body {
-        // --- VALIDACIÓN DE IMPORTACIONES DINÁMICAS ---
-        if let swc_ecma_ast::ModuleItem::ModuleDecl(swc_ecma_ast::ModuleDecl::Import(import)) = item
-        {
-            let source = import.src.value.to_string().to_lowercase();
-
-            // 1. Validamos las reglas dinámicas del JSON
-            for rule in &ctx.forbidden_imports {
-                // Normalizar patrones: quitar '**', '*', y '/' al final para matching flexible
-                let from_pattern = normalize_pattern(&rule.from);
-                let to_pattern = normalize_pattern(&rule.to);
-
-                // Verificar si el archivo coincide con el patrón 'from'
-                let file_matches = matches_pattern(&file_path_str, &from_pattern);
-
-                // Verificar si el import coincide con el patrón 'to'
-                let import_matches = matches_pattern(&source, &to_pattern);
-
-                if file_matches && import_matches {
-                    return Err(create_error(
-                        &fm,
-                        import.span,
-                        &format!(
-                            "Restricción: Archivos en '{}' no pueden importar de '{}'.",
-                            rule.from, rule.to
-                        ),
-                    ));
-                }
-            }
+    // --- VALIDACIÓN DE IMPORTACIONES DINÁMICAS ---
+    // En cualquier nivel del árbol: también `import()` y `require()` dentro de
+    // funciones, `if` o `try`
+    for (source, span) in collect_imports(&module) {
+        let source = source.to_lowercase();
 
-            // 2. Regla extra: Siempre prohibir Repository en Controller (Standard NestJS)
-            if file_path_str.contains("controller") && source.contains(".repository") {
+        // 1. Validamos las reglas dinámicas del JSON
+        for rule in &ctx.forbidden_imports {
+            // Normalizar patrones: quitar '**', '*', y '/' al final para matching flexible
+            let from_pattern = normalize_pattern(&rule.from);
+            let to_pattern = normalize_pattern(&rule.to);
+
+            // Verificar si el archivo coincide con el patrón 'from'
+            let file_matches = matches_pattern(&file_path_str, &from_pattern);
+
+            // Verificar si el import coincide con el patrón 'to'
+            let import_matches = matches_pattern(&source, &to_pattern);
+
+            if file_matches && import_matches {
                 return Err(create_error(
                     &fm,
-                    import.span,
-                    "MVC: Prohibido importar Repositorios en Controladores.",
+                    span,
+                    "forbidden-imports",
+                    &format!(
+                        "Restricción: Archivos en '{}' no pueden importar de '{}'.",
+                        rule.from, rule.to
+                    ),
                 ));
             }
         }
 
+        // 2. Regla extra: Repository en Controller (NestJS/MVC, desactivable en `rules`)
+        if ctx.is_rule_enabled("mvc-controller-repository", path)
+            && file_path_str.contains("controller")
+            && source.contains(".repository")
+        {
+            return Err(create_error(
+                &fm,
+                span,
+                "mvc-controller-repository",
+                "MVC: Prohibido importar Repositorios en Controladores.",
+            ));
+        }
+    }
+
+    for item in &module.body {
         // --- VALIDACIÓN DE LÍNEAS POR MÉTODO ---
         if let swc_ecma_ast::ModuleItem::Stmt(swc_ecma_ast::Stmt::Decl(
             swc_ecma_ast::Decl::Class(c),
@@ -116,6 +112,7 @@ pub fn analyze_file(cm: &SourceMap, path: &PathBuf, ctx: &LinterContext) -> Resu
                         return Err(create_error(
                             &fm,
                             m.span,
+                            "max-lines",
                             &format!(
                                 "Método demasiado largo ({} líneas). Máximo: {}.",
                                 lines, ctx.max_lines
@@ -126,7 +123,45 @@ pub fn analyze_file(cm: &SourceMap, path: &PathBuf, ctx: &LinterContext) -> Resu
             }
         }
     }
-    Ok(())
+    Ok(Vec::new())
+}
+
+/// Imports de un módulo en cualquier nivel del árbol: declaraciones `import` y
+/// llamadas `import('x')` o `require('x')` con un string literal, estén donde estén
+pub(crate) fn collect_imports(module: &Module) -> Vec<(String, swc_common::Span)> {
+    let mut collector = ImportCollector::default();
+    module.visit_with(&mut collector);
+    collector.imports
+}
+
+#[derive(Default)]
+struct ImportCollector {
+    imports: Vec<(String, swc_common::Span)>,
+}
+
+impl Visit for ImportCollector {
+    fn visit_import_decl(&mut self, n: &ImportDecl) {
+        self.imports.push((n.src.value.to_string(), n.span));
+    }
+
+    fn visit_call_expr(&mut self, n: &CallExpr) {
+        let loads_module = match &n.callee {
+            Callee::Import(_) => true,
+            Callee::Expr(callee) => {
+                matches!(&**callee, Expr::Ident(ident) if &*ident.sym == "require")
+            }
+            Callee::Super(_) => false,
+        };
+        // Un especificador calculado en runtime (`import(ruta)`) no se puede validar
+        let source = n.args.first().and_then(|arg| match &*arg.expr {
+            Expr::Lit(Lit::Str(source)) if arg.spread.is_none() => Some(&source.value),
+            _ => None,
+        });
+        if let (true, Some(source)) = (loads_module, source) {
+            self.imports.push((source.to_string(), n.span));
+        }
+        n.visit_children_with(self);
+    }
 }
 
 /// Normaliza un patrón glob para hacer matching simple
@@ -134,12 +169,12 @@ pub fn analyze_file(cm: &SourceMap, path: &PathBuf, ctx: &LinterContext) -> Resu
 /// - "src/components/**" → "src/components/"
 /// - "**/*.tsx" → ".tsx"
 /// - "src/services/**" → "src/services/"
-fn normalize_pattern(pattern: &str) -> String {
+pub(crate) fn normalize_pattern(pattern: &str) -> String {
     let normalized = pattern
         .to_lowercase()
-        .replace("\\", "/")  // Normalizar separadores de Windows
-        .replace("**", "")   // Quitar comodines globales
-        .replace("*", "");   // Quitar comodines simples
+        .replace("\\", "/") // Normalizar separadores de Windows
+        .replace("**", "") // Quitar comodines globales
+        .replace("*", ""); // Quitar comodines simples
 
     // Si el patrón termina en /, dejarlo; si no, mantenerlo como está
     normalized
@@ -147,7 +182,7 @@ fn normalize_pattern(pattern: &str) -> String {
 
 /// Verifica si un path coincide con un patrón normalizado
 /// Usa matching flexible para soportar diferentes formatos de import
-fn matches_pattern(path: &str, pattern: &str) -> bool {
+pub(crate) fn matches_pattern(path: &str, pattern: &str) -> bool {
     let normalized_path = path.to_lowercase().replace("\\", "/");
     let normalized_pattern = pattern.to_lowercase();
 
@@ -181,7 +216,8 @@ fn matches_pattern(path: &str, pattern: &str) -> bool {
             if normalized_path.contains(&with_slash)
                 || normalized_path.contains(&with_relative)
                 || normalized_path.contains(&with_at)
-                || normalized_path.contains(folder_part) {
+                || normalized_path.contains(folder_part)
+            {
                 return true;
             }
         }
@@ -190,27 +226,62 @@ fn matches_pattern(path: &str, pattern: &str) -> bool {
     false
 }
 
-fn create_error(fm: &swc_common::SourceFile, span: swc_common::Span, msg: &str) -> miette::Report {
+fn create_error(
+    fm: &swc_common::SourceFile,
+    span: swc_common::Span,
+    rule_id: &str,
+    msg: &str,
+) -> miette::Report {
     let start = (span.lo.0 - fm.start_pos.0) as usize;
     let end = (span.hi.0 - fm.start_pos.0) as usize;
 
     ArchError {
         src: fm.src.to_string(),
-        span: SourceSpan::new(start.into(), (end - start).into()),
+        span: SourceSpan::new(start.into(), end - start),
         message: msg.to_string(),
+        help: rules::help_for(rule_id),
     }
     .into()
 }
 
-/// Create a miette error from a Violation
-fn create_error_from_violation(fm: &swc_common::SourceFile, violation: &Violation) -> miette::Report {
-    // Try to find the import line in the source
+/// Human-readable message for an import Violation. The allow lists name the
+/// folders the file may import from instead of the offending target
+fn violation_message(violation: &Violation, ctx: &LinterContext) -> String {
+    if violation.rule_id == "allowed-imports" {
+        let allowed: Vec<String> = ctx
+            .settings
+            .allowed_imports
+            .iter()
+            .filter(|rule| rule.from == violation.rule.from)
+            .flat_map(|rule| rule.allow.iter().map(|pattern| format!("'{}'", pattern)))
+            .collect();
+        return format!(
+            "Restricción: Archivos en '{}' solo pueden importar de {}. '{}' no está permitido.",
+            violation.rule.from,
+            allowed.join(", "),
+            violation.rule.to
+        );
+    }
+    format!(
+        "Restricción: Archivos en '{}' no pueden importar de '{}'.",
+        violation.rule.from, violation.rule.to
+    )
+}
+
+/// Create a miette error pointing at a whole line of the source file
+fn create_error_at_line(
+    fm: &swc_common::SourceFile,
+    line_number: usize,
+    rule_id: &str,
+    msg: &str,
+) -> miette::Report {
+    // Try to find the line in the source
     let lines: Vec<&str> = fm.src.lines().collect();
-    let line_idx = violation.line_number.saturating_sub(1);
+    let line_idx = line_number.saturating_sub(1);
 
     if line_idx < lines.len() {
         let line_content = lines[line_idx];
-        // Find the position of the import statement in the file
+        // Find the position of the line in the file
         let mut char_offset = 0;
         for (idx, line) in lines.iter().enumerate() {
             if idx == line_idx {
@@ -221,55 +292,334 @@ fn create_error_from_violation(fm: &swc_common::SourceFile, violation: &Violatio
 
         ArchError {
             src: fm.src.to_string(),
-            span: SourceSpan::new(char_offset.into(), line_content.len().into()),
-            message: format!(
-                "Restricción: Archivos en '{}' no pueden importar de '{}'.",
-                violation.rule.from, violation.rule.to
-            ),
+            span: SourceSpan::new(char_offset.into(), line_content.len()),
+            message: msg.to_string(),
+            help: rules::help_for(rule_id),
         }
         .into()
     } else {
         // Fallback if line number is out of bounds
-        miette::miette!(
-            "Restricción: Archivos en '{}' no pueden importar de '{}' (línea {}).",
-            violation.rule.from,
-            violation.rule.to,
-            violation.line_number
-        )
+        miette::miette!("{} (línea {}).", msg.trim_end_matches('.'), line_number)
     }
 }
 
-/// Validate method length for TypeScript/JavaScript files using swc
-fn validate_method_length(cm: &SourceMap, path: &PathBuf, ctx: &LinterContext) -> Result<()> {
-    let fm = cm.load_file(path).into_diagnostic()?;
+/// Busca un import que acceda a los internos de un directorio sellado desde fuera.
+/// Desde fuera de un directorio sellado solo se permite importar su `index`.
+fn find_sealed_violation<'a>(
+    path: &Path,
+    imports: &'a [Import],
+    ctx: &'a LinterContext,
+) -> Option<(&'a Import, &'a str)> {
+    let file_path_str = path.to_string_lossy().to_lowercase();
 
+    for sealed in &ctx.settings.sealed_paths {
+        let mut sealed_pattern = normalize_pattern(sealed);
+        if sealed_pattern.is_empty() {
+            continue;
+        }
+        if !sealed_pattern.ends_with('/') {
+            sealed_pattern.push('/');
+        }
+
+        // Los archivos dentro del directorio sellado pueden importarse entre sí
+        if matches_pattern(&file_path_str, &sealed_pattern) {
+            continue;
+        }
+
+        for import in imports {
+            let resolved = match resolve_import_path(path, &import.source) {
+                Some(resolved) => resolved,
+                None => continue,
+            };
+
+            let resolved_str = resolved.to_string_lossy().to_lowercase().replace('\\', "/");
+            let inner = match path_inside_sealed(&resolved_str, &sealed_pattern) {
+                Some(inner) => inner,
+                None => continue,
+            };
+
+            // Solo el index que está directamente en el directorio sellado es público:
+            // `core/internal/index.ts` sigue siendo un interno de `core/`
+            let is_own_index = !inner.contains('/')
+                && Path::new(inner)
+                    .file_stem()
+                    .is_some_and(|stem| stem.eq_ignore_ascii_case("index"));
+            if !is_own_index {
+                return Some((import, sealed.as_str()));
+            }
+        }
+    }
+
+    None
+}
+
+/// Parte de `path` que queda dentro del directorio sellado (ej: "internal/helper.ts"),
+/// o `None` si el archivo está fuera. Acepta el patrón completo ("src/core/") o, como
+/// `matches_pattern`, solo la carpeta de después de "src/"
+fn path_inside_sealed<'p>(path: &'p str, sealed_pattern: &str) -> Option<&'p str> {
+    let pattern = sealed_pattern.trim_start_matches('/');
+    let mut candidates = vec![format!("/{}", pattern)];
+    if let Some(folder) = pattern.strip_prefix("src/") {
+        candidates.push(format!("/{}", folder));
+    }
+    candidates.iter().find_map(|candidate| {
+        path.find(candidate.as_str())
+            .map(|start| &path[start + candidate.len()..])
+    })
+}
+
+/// Reporta los imports relativos que no resuelven a ningún archivo (modo --report-unresolved).
+/// Los paquetes y los especificadores absolutos se consideran externos y se omiten.
+pub fn find_unresolved_imports(
+    cm: &SourceMap,
+    path: &Path,
+    ctx: &LinterContext,
+) -> Result<Vec<Finding>> {
     let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
-    let syntax = match extension {
-        "ts" | "tsx" => Syntax::Typescript(TsConfig {
+    if syntax_for_extension(extension).is_none() {
+        return Ok(Vec::new());
+    }
+
+    let parser = match parsers::get_parser_for_file(path) {
+        Some(parser) => parser,
+        None => return Ok(Vec::new()),
+    };
+
+    let source_code = read_source(path)?;
+    let imports = parser.extract_imports(&source_code, path)?;
+    // El archivo se lee y se registra una sola vez para todos sus imports
+    let fm = cm.new_source_file(FileName::Real(path.to_path_buf()), source_code);
+
+    let mut findings = Vec::new();
+    for import in &imports {
+        if !is_relative_specifier(&import.source) || is_asset_import(&import.source) {
+            continue;
+        }
+        if resolve_import_path(path, &import.source).is_some() {
+            continue;
+        }
+
+        let message = format!(
+            "Import no resuelto: '{}' no corresponde a ningún archivo.",
+            import.source
+        );
+        findings.push(Finding::error(
+            "unresolved-import",
+            Location::of_line(&fm, import.line_number),
+            message.clone(),
+            create_error_at_line(&fm, import.line_number, "unresolved-import", &message),
+        ));
+    }
+
+    Ok(rules::apply_rule_levels(findings, ctx))
+}
+
+/// `./x`, `../x`, `.` y `..`; todo lo demás (paquetes, URLs, rutas absolutas) es externo
+fn is_relative_specifier(source: &str) -> bool {
+    source == "." || source == ".." || source.starts_with("./") || source.starts_with("../")
+}
+
+/// Analiza un archivo cuyo contenido ya está en memoria (ej: una entrada de --archive).
+/// `path` identifica al archivo en los diagnósticos y al aplicar los patrones de rutas.
+pub fn analyze_source(
+    cm: &SourceMap,
+    path: &Path,
+    source_code: String,
+    ctx: &LinterContext,
+) -> Result<Vec<Finding>> {
+    analyze_source_recording(cm, path, source_code, ctx, None)
+}
+
+fn analyze_source_recording(
+    cm: &SourceMap,
+    path: &Path,
+    source_code: String,
+    ctx: &LinterContext,
+    hotspots: Option<&HotspotCollector>,
+) -> Result<Vec<Finding>> {
+    let parser = match parsers::get_parser_for_file(path) {
+        Some(parser) => parser,
+        None => return Ok(Vec::new()),
+    };
+    let mut findings = Vec::new();
+    let violations = parser.find_violations(&source_code, path, ctx)?;
+    let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+    let is_js_family = syntax_for_extension(extension).is_some();
+    let imports = if is_js_family && !ctx.settings.sealed_paths.is_empty() {
+        parser.extract_imports(&source_code, path)?
+    } else {
+        Vec::new()
+    };
+    let fm = cm.new_source_file(FileName::Real(path.to_path_buf()), source_code);
+
+    // Report the first violation of an enabled rule
+    let first_violation = violations
+        .iter()
+        .find(|violation| ctx.is_rule_enabled(violation.rule_id, path));
+    if let Some(first_violation) = first_violation {
+        let message = violation_message(first_violation, ctx);
+        findings.push(Finding::error(
+            first_violation.rule_id,
+            Location::of_line(&fm, first_violation.line_number),
+            message.clone(),
+            create_error_at_line(
+                &fm,
+                first_violation.line_number,
+                first_violation.rule_id,
+                &message,
+            ),
+        ));
+    }
+
+    if is_js_family {
+        // Validate that sealed directories are only accessed through their index
+        if let Some((import, sealed)) = find_sealed_violation(path, &imports, ctx) {
+            let message = format!(
+                "Encapsulamiento: '{}' solo expone su index. No importes '{}' desde fuera.",
+                sealed, import.source
+            );
+            findings.push(Finding::error(
+                "sealed-paths",
+                Location::of_line(&fm, import.line_number),
+                message.clone(),
+                create_error_at_line(&fm, import.line_number, "sealed-paths", &message),
+            ));
+        }
+
+        // AST-based rules for TypeScript/JavaScript files (method length, complexity...).
+        // Declaration files (`declaration_files: "structural"`) only get the import rules above
+        if !discovery::is_declaration_file(path) {
+            findings.extend(analyze_module(cm, fm, path, ctx, hotspots));
+        }
+    }
+
+    Ok(rules::apply_rule_levels(findings, ctx))
+}
+
+/// Lee un archivo quitando el BOM UTF-8 inicial, que desplaza los offsets y
+/// confunde a Tree-sitter. El shebang (`#!/usr/bin/env node`) se conserva: tanto swc
+/// (`Module.shebang`) como la gramática de Tree-sitter lo reconocen sin mover las líneas.
+pub(crate) fn read_source(path: &Path) -> Result<String> {
+    let source = fs::read_to_string(path).into_diagnostic()?;
+    Ok(match source.strip_prefix('\u{feff}') {
+        Some(stripped) => stripped.to_string(),
+        None => source,
+    })
+}
+
+/// Carga un archivo en el SourceMap sin BOM, para que los spans de swc coincidan
+/// con el texto que se muestra en los diagnósticos
+pub(crate) fn load_source_file(cm: &SourceMap, path: &Path) -> Result<Lrc<SourceFile>> {
+    let source = read_source(path)?;
+    Ok(cm.new_source_file(FileName::Real(path.to_path_buf()), source))
+}
+
+/// Sintaxis de swc para una extensión TypeScript/JavaScript (incluye .mts/.cts/.mjs/.cjs).
+/// Devuelve `None` para extensiones que no son de la familia JS.
+pub(crate) fn syntax_for_extension(extension: &str) -> Option<Syntax> {
+    match extension {
+        "ts" | "tsx" | "mts" | "cts" => Some(Syntax::Typescript(TsConfig {
             decorators: true,
             tsx: extension == "tsx",
             ..Default::default()
-        }),
-        "js" | "jsx" => Syntax::Es(EsConfig {
+        })),
+        "js" | "jsx" | "mjs" | "cjs" => Some(Syntax::Es(EsConfig {
             decorators: true,
             jsx: extension == "jsx",
             ..Default::default()
-        }),
-        _ => return Ok(()),
-    };
+        })),
+        _ => None,
+    }
+}
 
-    let lexer = Lexer::new(
-        syntax,
-        Default::default(),
-        StringInput::from(&*fm),
-        None,
-    );
+/// Archivo cargado y resultado de parsearlo (el error de sintaxis se conserva para señalarlo)
+pub(crate) type ParsedModule = (
+    Lrc<SourceFile>,
+    std::result::Result<Module, swc_ecma_parser::error::Error>,
+);
+
+/// Parsea un archivo TypeScript/JavaScript con swc.
+/// Devuelve `None` si la extensión no aplica.
+pub(crate) fn parse_module(cm: &SourceMap, path: &Path) -> Result<Option<ParsedModule>> {
+    Ok(parse_source_file(load_source_file(cm, path)?, path))
+}
+
+/// Parsea con swc un archivo ya cargado en el SourceMap
+fn parse_source_file(fm: Lrc<SourceFile>, path: &Path) -> Option<ParsedModule> {
+    let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+    let syntax = syntax_for_extension(extension)?;
+
+    let lexer = Lexer::new(syntax, Default::default(), StringInput::from(&*fm), None);
 
     let mut parser = Parser::new_from(lexer);
-    let module = match parser.parse_module() {
-        Ok(m) => m,
-        Err(_) => return Ok(()), // Skip on syntax error
+    let module = parser.parse_module();
+    Some((fm, module))
+}
+
+/// Hallazgo "parse-error" con la posición del error de sintaxis. Las reglas de AST
+/// no pueden ejecutarse, pero sí los hallazgos que no dependen de swc.
+fn parse_error_finding(
+    cm: &SourceMap,
+    fm: &SourceFile,
+    error: swc_ecma_parser::error::Error,
+) -> Finding {
+    let span = error.span();
+    let pos = cm.lookup_char_pos(span.lo);
+    let message = format!(
+        "Error de sintaxis en la línea {}, columna {}: {}. Se omiten las reglas de AST de este archivo.",
+        pos.line,
+        pos.col_display + 1,
+        error.kind().msg()
+    );
+
+    // Algunos errores (ej: fin de archivo inesperado) no tienen un span dentro del archivo
+    let (location, report) = if span.lo >= fm.start_pos && span.hi <= fm.end_pos {
+        (
+            Location::from_span(cm, span),
+            create_error(fm, span, rules::PARSE_ERROR_ID, &message),
+        )
+    } else {
+        (
+            Location::of_line(fm, pos.line),
+            create_error_at_line(fm, pos.line, rules::PARSE_ERROR_ID, &message),
+        )
+    };
+    Finding::error(rules::PARSE_ERROR_ID, location, message, report)
+}
+
+/// Ejecuta las reglas basadas en el AST de swc sobre un archivo TypeScript/JavaScript
+fn analyze_module(
+    cm: &SourceMap,
+    fm: Lrc<SourceFile>,
+    path: &Path,
+    ctx: &LinterContext,
+    hotspots: Option<&HotspotCollector>,
+) -> Vec<Finding> {
+    let (fm, module) = match parse_source_file(fm, path) {
+        Some((fm, Ok(module))) => (fm, module),
+        Some((fm, Err(error))) => return vec![parse_error_finding(cm, &fm, error)],
+        None => return Vec::new(),
     };
+    if let Some(hotspots) = hotspots {
+        hotspots.record_module(path, fm.count_lines(), &module);
+    }
+
+    let rc = RuleContext {
+        cm,
+        fm: &fm,
+        module: &module,
+        path,
+        ctx,
+    };
+
+    let mut findings = validate_method_length(&rc);
+    findings.extend(rules::run_ast_rules(&rc));
+    findings
+}
+
+/// Longitud en líneas de cada método de clase declarado en el módulo
+fn method_lengths(cm: &SourceMap, module: &Module) -> Vec<(swc_common::Span, usize)> {
+    let mut lengths = Vec::new();
 
     for item in &module.body {
         if let swc_ecma_ast::ModuleItem::Stmt(swc_ecma_ast::Stmt::Decl(
@@ -280,39 +630,323 @@ fn validate_method_length(cm: &SourceMap, path: &PathBuf, ctx: &LinterContext) -
                 if let swc_ecma_ast::ClassMember::Method(m) = member {
                     let lo = cm.lookup_char_pos(m.span.lo).line;
                     let hi = cm.lookup_char_pos(m.span.hi).line;
-                    let lines = hi - lo;
-
-                    if lines > ctx.max_lines {
-                        return Err(create_error(
-                            &fm,
-                            m.span,
-                            &format!(
-                                "Método demasiado largo ({} líneas). Máximo: {}.",
-                                lines, ctx.max_lines
-                            ),
-                        ));
-                    }
+                    lengths.push((m.span, hi - lo));
                 }
             }
         }
     }
 
-    Ok(())
+    lengths
+}
+
+/// Líneas del archivo y longitudes de sus métodos (para --metrics y --grade)
+pub fn collect_line_metrics(cm: &SourceMap, path: &Path) -> Result<(usize, Vec<usize>)> {
+    Ok(match parse_module(cm, path)? {
+        Some((fm, Ok(module))) => (
+            fm.count_lines(),
+            method_lengths(cm, &module)
+                .into_iter()
+                .map(|(_, lines)| lines)
+                .collect(),
+        ),
+        Some((fm, Err(_))) => (fm.count_lines(), Vec::new()),
+        None => (0, Vec::new()),
+    })
+}
+
+/// Validate method length for TypeScript/JavaScript files using swc
+fn validate_method_length(rc: &RuleContext) -> Vec<Finding> {
+    method_lengths(rc.cm, rc.module)
+        .into_iter()
+        .filter(|(_, lines)| *lines > rc.ctx.max_lines)
+        .map(|(span, lines)| {
+            rc.finding(
+                "max-lines",
+                Severity::Error,
+                span,
+                format!(
+                    "Método demasiado largo ({} líneas). Máximo: {}.",
+                    lines, rc.ctx.max_lines
+                ),
+            )
+        })
+        .collect()
 }
 
 /// Analiza un archivo y recolecta todas las violaciones sin fallar
 /// Útil para el modo --fix donde queremos procesar todas las violaciones
 pub fn collect_violations_from_file(
     _cm: &SourceMap,
-    path: &PathBuf,
+    path: &Path,
     ctx: &LinterContext,
 ) -> Result<Vec<Violation>> {
     // Try to use multi-language parser first
     if let Some(parser) = parsers::get_parser_for_file(path) {
-        let source_code = fs::read_to_string(path).into_diagnostic()?;
-        return parser.find_violations(&source_code, path, ctx);
+        let source_code = read_source(path)?;
+        let violations = parser.find_violations(&source_code, path, ctx)?;
+        return Ok(violations
+            .into_iter()
+            .filter(|violation| ctx.is_rule_enabled(violation.rule_id, path))
+            .collect());
     }
 
     // Fallback: return empty violations for unsupported files
     Ok(Vec::new())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{analyze_file, find_unresolved_imports};
+    use crate::config::{
+        AllowedImportRule, ForbiddenRule, Framework, LinterContext, NoNonNullAssertionRule,
+        RuleLevel, RuleSettings,
+    };
+    use crate::rules::testing::{check, findings_in, temp_project};
+    use swc_common::SourceMap;
+
+    const SOURCE: &str = "import { Core } from '../core';
+import { Public } from '../core/index';
+import { helper } from '../core/internal/helper';
+import { Internal } from '../core/internal';
+";
+
+    #[test]
+    fn sealed_paths_only_expose_their_own_index() {
+        let root = temp_project(
+            "sealed-paths",
+            &[
+                ("src/core/index.ts", "export * from './internal';\n"),
+                ("src/core/internal/index.ts", "export * from './helper';\n"),
+                ("src/core/internal/helper.ts", "export const helper = 1;\n"),
+                ("src/app/main.ts", SOURCE),
+            ],
+        );
+        let settings = || RuleSettings {
+            sealed_paths: vec!["src/core/**".to_string()],
+            ..RuleSettings::default()
+        };
+        let file = root.join("src/app/main.ts");
+        let file = file.to_str().unwrap();
+
+        // El index de core está permitido; el primer import bloqueado es el profundo
+        let findings = check("sealed-paths", file, SOURCE, settings());
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].location.start_line, 3);
+
+        // Un index anidado sigue siendo un interno de core
+        let nested = "import { Internal } from '../core/internal';\n";
+        let findings = check("sealed-paths", file, nested, settings());
+        assert_eq!(findings.len(), 1);
+        assert!(findings[0].message.contains("'../core/internal'"));
+
+        let allowed = "import { Core } from '../core';\nimport { Public } from '../core/index';\n";
+        assert!(check("sealed-paths", file, allowed, settings()).is_empty());
+    }
+
+    #[test]
+    fn allowed_imports_name_the_allowed_folders() {
+        let source = "import { Money } from '../shared/money';
+import { User } from './user';
+import { Db } from '../infrastructure/db';
+";
+        let root = temp_project(
+            "allowed-imports",
+            &[
+                ("src/shared/money.ts", "export class Money {}\n"),
+                ("src/domain/user.ts", "export class User {}\n"),
+                ("src/infrastructure/db.ts", "export class Db {}\n"),
+                ("src/domain/order.ts", source),
+            ],
+        );
+        let settings = RuleSettings {
+            allowed_imports: vec![AllowedImportRule {
+                from: "src/domain/**".to_string(),
+                allow: vec!["src/domain/**".to_string(), "src/shared/**".to_string()],
+            }],
+            ..RuleSettings::default()
+        };
+        let file = root.join("src/domain/order.ts");
+
+        // Solo el import de infrastructure queda fuera de la lista blanca
+        let findings = check("allowed-imports", file.to_str().unwrap(), source, settings);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].location.start_line, 3);
+        assert_eq!(
+            findings[0].message,
+            "Restricción: Archivos en 'src/domain/**' solo pueden importar de 'src/domain/**', 'src/shared/**'. '../infrastructure/db' no está permitido."
+        );
+    }
+
+    #[test]
+    fn only_relative_imports_that_resolve_nowhere_are_reported() {
+        let source = "import { User } from './usr';
+import { Order } from './order';
+import { UserDto } from './user.dto';
+import { Shared } from '..';
+import express from 'express';
+import { Module } from '@nestjs/common';
+import config from '/etc/app/config';
+import logo from './logo.svg';
+import styles from './missing.css';
+";
+        let root = temp_project(
+            "unresolved",
+            &[
+                ("src/index.ts", "export const shared = 1;\n"),
+                ("src/app/user.ts", "export class User {}\n"),
+                ("src/app/order.ts", "export class Order {}\n"),
+                ("src/app/user.dto.ts", "export class UserDto {}\n"),
+                ("src/app/main.ts", source),
+            ],
+        );
+        let ctx = LinterContext::for_tests(RuleSettings::default());
+        let findings =
+            find_unresolved_imports(&SourceMap::default(), &root.join("src/app/main.ts"), &ctx)
+                .unwrap();
+
+        // Solo el typo: los paquetes, las rutas absolutas y los assets son externos
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "unresolved-import");
+        assert_eq!(findings[0].location.start_line, 1);
+        assert!(findings[0].message.contains("'./usr'"));
+    }
+
+    #[test]
+    fn package_subpaths_and_aliases_resolve_to_the_forbidden_package() {
+        let root = temp_project(
+            "resolve-packages",
+            &[
+                (
+                    "node_modules/typeorm/package.json",
+                    "{ \"name\": \"typeorm\" }",
+                ),
+                // Alias instalado como `"orm": "npm:typeorm@0.3.0"`
+                ("node_modules/orm/package.json", "{ \"name\": \"typeorm\" }"),
+                ("src/domain/user.ts", ""),
+            ],
+        );
+        let file = root.join("src/domain/user.ts");
+        let fires = |source: &str, resolve_packages| {
+            let mut ctx = LinterContext::for_tests(RuleSettings {
+                resolve_packages,
+                ..RuleSettings::default()
+            });
+            ctx.forbidden_imports = vec![ForbiddenRule {
+                from: "src/domain/**".to_string(),
+                to: "typeorm".to_string(),
+            }];
+            !findings_in("forbidden-imports", file.to_str().unwrap(), source, &ctx).is_empty()
+        };
+
+        let subpath = "import { Repository } from 'typeorm/repository/Repository';\n";
+        assert!(fires(subpath, true));
+        // El alias solo se reconoce leyendo su package.json
+        let alias = "import { Entity } from 'orm';\n";
+        assert!(!fires(alias, false));
+        assert!(fires(alias, true));
+    }
+
+    #[test]
+    fn bom_and_shebang_keep_lines_and_columns() {
+        let assertion = "export const email = (user?: User) => user!.email;";
+        let body = format!(
+            "import {{ Db }} from '../infra/db';\nimport {{ User }} from './usr';\n{}\n",
+            assertion
+        );
+        let root = temp_project(
+            "bom-shebang",
+            &[
+                ("src/infra/db.ts", "export class Db {}\n"),
+                (
+                    "src/domain/bom.ts",
+                    &format!("\u{feff}// cabecera\n{}", body),
+                ),
+                (
+                    "src/domain/cli.ts",
+                    &format!("#!/usr/bin/env node\n{}", body),
+                ),
+            ],
+        );
+        let mut ctx = LinterContext::for_tests(RuleSettings {
+            no_non_null_assertion: Some(NoNonNullAssertionRule {
+                paths: Vec::new(),
+                allow: Vec::new(),
+            }),
+            ..RuleSettings::default()
+        });
+        ctx.forbidden_imports = vec![ForbiddenRule {
+            from: "src/domain/**".to_string(),
+            to: "src/infra/**".to_string(),
+        }];
+        let cm = SourceMap::default();
+
+        for file in ["src/domain/bom.ts", "src/domain/cli.ts"] {
+            let path = root.join(file);
+            let findings = analyze_file(&cm, &path, &ctx).unwrap();
+            let location = |rule_id| {
+                let finding = findings.iter().find(|f| f.rule_id == rule_id).unwrap();
+                (finding.location.start_line, finding.location.start_col)
+            };
+            assert_eq!(location("forbidden-imports"), (2, 1), "{}", file);
+            // Los spans de swc no se desplazan por el BOM ni por el shebang
+            let column = assertion.find("user!").unwrap() + 1;
+            assert_eq!(location("no-non-null-assertion"), (4, column), "{}", file);
+
+            let unresolved = find_unresolved_imports(&cm, &path, &ctx).unwrap();
+            assert_eq!(unresolved.len(), 1, "{}", file);
+            assert_eq!(unresolved[0].location.start_line, 3, "{}", file);
+        }
+    }
+
+    #[test]
+    fn nested_dynamic_imports_and_requires_are_checked() {
+        let mut ctx = LinterContext::for_tests(RuleSettings::default());
+        ctx.forbidden_imports = vec![ForbiddenRule {
+            from: "src/domain/**".to_string(),
+            to: "src/infrastructure/**".to_string(),
+        }];
+        let lines = |source: &str| -> Vec<usize> {
+            findings_in("forbidden-imports", "src/domain/user.ts", source, &ctx)
+                .iter()
+                .map(|finding| finding.location.start_line)
+                .collect()
+        };
+
+        let in_function = "export function load() {\n  const { Db } = require('../infrastructure/db');\n  return new Db();\n}\n";
+        assert_eq!(lines(in_function), vec![2]);
+
+        let in_conditional = "export async function cache(enabled: boolean) {\n  if (enabled) {\n    return import('../infrastructure/redis');\n  }\n}\n";
+        assert_eq!(lines(in_conditional), vec![3]);
+
+        let in_try = "let Orm;\ntry {\n  Orm = require('../infrastructure/orm');\n} catch {\n  Orm = null;\n}\n";
+        assert_eq!(lines(in_try), vec![3]);
+
+        // Un especificador calculado no se puede validar
+        let computed =
+            "export const load = (name: string) => import(`../infrastructure/${name}`);\n";
+        assert!(lines(computed).is_empty());
+    }
+
+    #[test]
+    fn controller_repository_rule_is_nestjs_only_and_can_be_turned_off() {
+        let source = "import { UsersRepository } from './users.repository';\n";
+        let file = "src/users/users.controller.ts";
+        let findings = |framework, level: Option<RuleLevel>| {
+            let mut settings = RuleSettings::default();
+            if let Some(level) = level {
+                settings
+                    .rules
+                    .insert("mvc-controller-repository".to_string(), level);
+            }
+            let mut ctx = LinterContext::for_tests(settings);
+            ctx.framework = framework;
+            findings_in("mvc-controller-repository", file, source, &ctx).len()
+        };
+
+        assert_eq!(findings(Framework::NestJS, None), 1);
+        assert_eq!(findings(Framework::NestJS, Some(RuleLevel::Off)), 0);
+        // Fuera de NestJS/MVC solo corre si se activa explícitamente
+        assert_eq!(findings(Framework::Express, None), 0);
+        assert_eq!(findings(Framework::Express, Some(RuleLevel::Error)), 1);
+    }
+}
@@ -0,0 +1,263 @@
+//! Análisis de un proyecto empaquetado (--archive) sin extraerlo a disco.
+//!
+//! Se leen en memoria las entradas con código fuente de un `.zip`, `.tar` o
+//! `.tar.gz`/`.tgz`. La raíz del proyecto es la carpeta del `architect.json` menos
+//! profundo; sin él, la raíz del archivo (o su única carpeta de primer nivel, como
+//! en los tarballs de `git archive --prefix` o de GitHub).
+
+use crate::config::LinterContext;
+use crate::{discovery, parsers};
+use flate2::read::GzDecoder;
+use miette::{IntoDiagnostic, Result};
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+
+/// Entradas mayores que esto no son código escrito a mano (bundles, fixtures)
+const MAX_ENTRY_BYTES: u64 = 5 * 1024 * 1024;
+
+/// Archivo fuente leído del archivo comprimido
+pub struct ArchivedFile {
+    /// Ruta relativa a la raíz del proyecto dentro del archivo
+    pub path: PathBuf,
+    pub source: String,
+}
+
+/// Contenido relevante de un archivo comprimido
+pub struct ArchivedProject {
+    pub files: Vec<ArchivedFile>,
+    /// Contenido del architect.json de la raíz, si lo hay
+    pub config: Option<String>,
+}
+
+impl ArchivedProject {
+    /// Descarta los archivos que el recorrido normal tampoco analizaría
+    /// (extensiones no configuradas, `.d.ts` según `declaration_files` e `ignored_paths`)
+    pub fn retain_analyzed(&mut self, ctx: &LinterContext) {
+        let extensions = discovery::analyzed_extensions(ctx);
+        self.files.retain(|file| {
+            discovery::is_analyzable(&file.path, &extensions, ctx.settings.declaration_files)
+                && discovery::passes_ignored_paths(&file.path, Path::new(""), ctx)
+        });
+    }
+}
+
+/// Lee un `.zip`, `.tar` o `.tar.gz`/`.tgz` y devuelve sus archivos fuente
+pub fn read_archive(path: &Path) -> Result<ArchivedProject> {
+    let name = path.to_string_lossy().to_lowercase();
+    let file = File::open(path)
+        .map_err(|e| miette::miette!("No se pudo abrir {}: {}", path.display(), e))?;
+
+    let entries = if name.ends_with(".zip") {
+        read_zip(file)?
+    } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+        read_tar(GzDecoder::new(file))?
+    } else if name.ends_with(".tar") {
+        read_tar(file)?
+    } else {
+        return Err(miette::miette!(
+            "Formato de archivo no soportado: {}. Usa .zip, .tar, .tar.gz o .tgz.",
+            path.display()
+        ));
+    };
+
+    Ok(into_project(entries))
+}
+
+/// Entrada relevante: código fuente con parser disponible o un architect.json
+fn is_wanted(path: &Path) -> bool {
+    path.file_name()
+        .is_some_and(|name| name == "architect.json")
+        || parsers::get_parser_for_file(path).is_some()
+}
+
+/// Ruta de una entrada normalizada. Se descartan las absolutas y las que salen
+/// de la raíz con `..` (no pueden pertenecer al proyecto).
+fn entry_path(raw: &str) -> Option<PathBuf> {
+    let mut path = PathBuf::new();
+    for component in Path::new(&raw.replace('\\', "/")).components() {
+        match component {
+            Component::Normal(part) => path.push(part),
+            Component::CurDir => {}
+            _ => return None,
+        }
+    }
+    Some(path).filter(|path| !path.as_os_str().is_empty())
+}
+
+/// Contenido de una entrada sin el BOM inicial (igual que `analyzer::read_source`).
+/// Las entradas que no son UTF-8 se omiten.
+fn read_entry(reader: impl Read, size: u64) -> Option<String> {
+    if size > MAX_ENTRY_BYTES {
+        return None;
+    }
+    let mut source = String::new();
+    reader
+        .take(MAX_ENTRY_BYTES)
+        .read_to_string(&mut source)
+        .ok()?;
+    Some(match source.strip_prefix('\u{feff}') {
+        Some(stripped) => stripped.to_string(),
+        None => source,
+    })
+}
+
+fn read_zip(file: File) -> Result<Vec<(PathBuf, String)>> {
+    let mut archive = zip::ZipArchive::new(file).into_diagnostic()?;
+    let mut entries = Vec::new();
+
+    for index in 0..archive.len() {
+        let entry = archive.by_index(index).into_diagnostic()?;
+        if entry.is_dir() {
+            continue;
+        }
+        let path = match entry_path(entry.name()) {
+            Some(path) if is_wanted(&path) => path,
+            _ => continue,
+        };
+        let size = entry.size();
+        if let Some(source) = read_entry(entry, size) {
+            entries.push((path, source));
+        }
+    }
+
+    Ok(entries)
+}
+
+fn read_tar(reader: impl Read) -> Result<Vec<(PathBuf, String)>> {
+    let mut archive = tar::Archive::new(reader);
+    let mut entries = Vec::new();
+
+    for entry in archive.entries().into_diagnostic()? {
+        let entry = entry.into_diagnostic()?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let raw = entry
+            .path()
+            .into_diagnostic()?
+            .to_string_lossy()
+            .to_string();
+        let path = match entry_path(&raw) {
+            Some(path) if is_wanted(&path) => path,
+            _ => continue,
+        };
+        let size = entry.size();
+        if let Some(source) = read_entry(entry, size) {
+            entries.push((path, source));
+        }
+    }
+
+    Ok(entries)
+}
+
+/// Separa el architect.json de la raíz y deja las rutas relativas a ella
+fn into_project(entries: Vec<(PathBuf, String)>) -> ArchivedProject {
+    let root = project_root(&entries);
+
+    let mut config = None;
+    let mut files = Vec::new();
+    for (path, source) in entries {
+        let relative = match path.strip_prefix(&root) {
+            Ok(relative) => relative.to_path_buf(),
+            Err(_) => continue,
+        };
+        if relative == Path::new("architect.json") {
+            config = Some(source);
+        } else if parsers::get_parser_for_file(&relative).is_some() {
+            files.push(ArchivedFile {
+                path: relative,
+                source,
+            });
+        }
+    }
+
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    ArchivedProject { files, config }
+}
+
+/// Carpeta del architect.json menos profundo o, sin él, la única carpeta de primer nivel
+fn project_root(entries: &[(PathBuf, String)]) -> PathBuf {
+    let config_dir = entries
+        .iter()
+        .filter(|(path, _)| {
+            path.file_name()
+                .is_some_and(|name| name == "architect.json")
+        })
+        .filter_map(|(path, _)| path.parent())
+        .min_by_key(|dir| dir.components().count());
+    if let Some(dir) = config_dir {
+        return dir.to_path_buf();
+    }
+
+    let top_level: HashSet<Component> = entries
+        .iter()
+        .filter_map(|(path, _)| path.components().next())
+        .collect();
+    let nested = entries
+        .iter()
+        .all(|(path, _)| path.components().count() > 1);
+    match top_level.into_iter().collect::<Vec<_>>().as_slice() {
+        [dir] if nested => PathBuf::from(dir.as_os_str()),
+        _ => PathBuf::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::circular::CircularDependencyAnalyzer;
+    use std::io::Write;
+    use swc_common::SourceMap;
+
+    #[test]
+    fn a_zip_is_analyzed_from_its_project_root() {
+        let path = std::env::temp_dir().join(format!(
+            "architect-linter-archive-{}.zip",
+            std::process::id()
+        ));
+        let mut zip = zip::ZipWriter::new(File::create(&path).unwrap());
+        let entries = [
+            (
+                "project/architect.json",
+                "{ \"max_lines_per_function\": 30 }",
+            ),
+            (
+                "project/src/a.ts",
+                "import { b } from './b';\nexport const a = 1;\n",
+            ),
+            (
+                "project/src/b.ts",
+                "import { a } from './a';\nexport const b = 1;\n",
+            ),
+            ("project/README.md", "# Proyecto\n"),
+            ("../fuera.ts", "export const x = 1;\n"),
+        ];
+        for (name, content) in entries {
+            zip.start_file(name, zip::write::FileOptions::default())
+                .unwrap();
+            zip.write_all(content.as_bytes()).unwrap();
+        }
+        zip.finish().unwrap();
+
+        let project = read_archive(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(project.config.unwrap().contains("max_lines_per_function"));
+        let mut paths: Vec<&Path> = project.files.iter().map(|f| f.path.as_path()).collect();
+        paths.sort();
+        assert_eq!(paths, [Path::new("src/a.ts"), Path::new("src/b.ts")]);
+
+        // Con todas las entradas en memoria también se detectan los ciclos
+        let sources: Vec<(PathBuf, &str)> = project
+            .files
+            .iter()
+            .map(|file| (file.path.clone(), file.source.as_str()))
+            .collect();
+        let mut graph = CircularDependencyAnalyzer::in_memory();
+        graph.build_graph_in_memory(&sources, &SourceMap::default());
+        let cycles = graph.detect_cycles();
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(cycles[0].cycle, ["src/a.ts", "src/b.ts", "src/a.ts"]);
+    }
+}
@@ -0,0 +1,224 @@
+//! Auditoría de architect.json (--audit-config): detecta reglas de
+//! `forbidden_imports` que ya no aportan nada y conviene podar.
+
+use crate::analyzer::{self, matches_pattern, normalize_pattern};
+use crate::config::{ForbiddenRule, LinterContext};
+use crate::resolver;
+use rayon::prelude::*;
+use std::path::{Path, PathBuf};
+use swc_common::SourceMap;
+
+/// Uso de una regla de forbidden_imports en el proyecto
+pub struct RuleUsage {
+    pub rule: ForbiddenRule,
+    /// Archivos que coinciden con `from`
+    pub from_files: usize,
+    /// Archivos que coinciden con `to`
+    pub to_files: usize,
+    /// `to` es un paquete (ej: "typeorm"), no una carpeta del proyecto: no tiene
+    /// archivos propios que contar
+    pub to_package: bool,
+    /// Violaciones producidas por la regla
+    pub violations: usize,
+}
+
+impl RuleUsage {
+    /// Una regla es muerta si su origen o su destino no existen en el proyecto. Una
+    /// regla que produce violaciones nunca lo es.
+    pub fn is_dead(&self) -> bool {
+        self.violations == 0 && (self.from_files == 0 || (self.to_files == 0 && !self.to_package))
+    }
+}
+
+/// `to` nombra un paquete: un bare specifier sin comodines cuyo primer segmento no
+/// es una carpeta del proyecto (así "src/legacy" sigue siendo una carpeta)
+fn is_package_target(project_root: &Path, to: &str) -> bool {
+    !to.contains('*')
+        && resolver::package_specifier_name(to)
+            .is_some_and(|name| !project_root.join(name).exists())
+}
+
+/// Ejecuta todas las reglas sobre el proyecto y mide el uso de cada forbidden_import
+pub fn audit_forbidden_rules(
+    cm: &SourceMap,
+    project_root: &Path,
+    files: &[PathBuf],
+    ctx: &LinterContext,
+) -> Vec<RuleUsage> {
+    let relative_paths: Vec<String> = files
+        .iter()
+        .map(|file| {
+            file.strip_prefix(project_root)
+                .unwrap_or(file)
+                .to_string_lossy()
+                .replace('\\', "/")
+                .to_lowercase()
+        })
+        .collect();
+
+    let violations: Vec<ForbiddenRule> = files
+        .par_iter()
+        .filter_map(|file| analyzer::collect_violations_from_file(cm, file, ctx).ok())
+        .flatten()
+        .map(|violation| violation.rule)
+        .collect();
+
+    let count_matching = |pattern: &str| {
+        let pattern = normalize_pattern(pattern);
+        relative_paths
+            .iter()
+            .filter(|path| matches_pattern(path, &pattern))
+            .count()
+    };
+
+    ctx.forbidden_imports
+        .iter()
+        .map(|rule| RuleUsage {
+            rule: rule.clone(),
+            from_files: count_matching(&rule.from),
+            to_files: count_matching(&rule.to),
+            to_package: is_package_target(project_root, &rule.to),
+            violations: violations
+                .iter()
+                .filter(|v| v.from == rule.from && v.to == rule.to)
+                .count(),
+        })
+        .collect()
+}
+
+/// Imprime el resultado de la auditoría. Devuelve cuántas reglas muertas hay.
+pub fn print_audit_report(usages: &[RuleUsage]) -> usize {
+    println!("\n🧹 AUDITORÍA DE architect.json\n");
+
+    if usages.is_empty() {
+        println!("ℹ️  No hay reglas en forbidden_imports.");
+        return 0;
+    }
+
+    for usage in usages {
+        let icon = if usage.is_dead() { "💀" } else { "✅" };
+        let target = if usage.to_package {
+            "destino: paquete".to_string()
+        } else {
+            format!("{} archivos destino", usage.to_files)
+        };
+        println!(
+            "{} '{}' → '{}': {} archivos origen, {}, {} violaciones",
+            icon, usage.rule.from, usage.rule.to, usage.from_files, target, usage.violations
+        );
+    }
+
+    let dead: Vec<&RuleUsage> = usages.iter().filter(|u| u.is_dead()).collect();
+    if dead.is_empty() {
+        println!("\n✨ Todas las reglas apuntan a carpetas existentes.");
+        return 0;
+    }
+
+    println!("\n💡 Reglas que se pueden eliminar:");
+    for usage in &dead {
+        let missing = if usage.from_files == 0 {
+            &usage.rule.from
+        } else {
+            &usage.rule.to
+        };
+        println!(
+            "  - {} → {} ('{}' no coincide con ningún archivo)",
+            usage.rule.from, usage.rule.to, missing
+        );
+    }
+
+    dead.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RuleSettings;
+    use crate::rules::testing::temp_project;
+
+    #[test]
+    fn a_rule_whose_from_folder_is_gone_is_dead() {
+        let root = temp_project(
+            "audit-config",
+            &[
+                (
+                    "src/domain/user.ts",
+                    "import { Db } from '../infrastructure/db';\n",
+                ),
+                ("src/infrastructure/db.ts", "export class Db {}\n"),
+            ],
+        );
+        let mut ctx = LinterContext::for_tests(RuleSettings::default());
+        ctx.forbidden_imports = vec![
+            ForbiddenRule {
+                from: "src/domain/**".to_string(),
+                to: "src/infrastructure/**".to_string(),
+            },
+            ForbiddenRule {
+                from: "src/legacy/**".to_string(),
+                to: "src/domain/**".to_string(),
+            },
+        ];
+        let files = vec![
+            root.join("src/domain/user.ts"),
+            root.join("src/infrastructure/db.ts"),
+        ];
+
+        let usages = audit_forbidden_rules(&SourceMap::default(), &root, &files, &ctx);
+        let summary: Vec<(usize, usize, usize, bool)> = usages
+            .iter()
+            .map(|u| (u.from_files, u.to_files, u.violations, u.is_dead()))
+            .collect();
+        assert_eq!(summary, [(1, 1, 1, false), (0, 1, 0, true)]);
+    }
+
+    #[test]
+    fn a_rule_targeting_a_package_is_not_dead() {
+        let root = temp_project(
+            "audit-package",
+            &[
+                (
+                    "src/domain/user.ts",
+                    "import { Entity } from 'typeorm';\nexport class User {}\n",
+                ),
+                ("src/domain/order.ts", "export class Order {}\n"),
+            ],
+        );
+        let rule = |from: &str, to: &str| ForbiddenRule {
+            from: from.to_string(),
+            to: to.to_string(),
+        };
+        let mut ctx = LinterContext::for_tests(RuleSettings::default());
+        ctx.forbidden_imports = vec![
+            rule("src/domain/**", "typeorm"),
+            rule("src/domain/**", "@nestjs/core"),
+            rule("src/domain/**", "src/legacy"),
+        ];
+        let files = vec![
+            root.join("src/domain/user.ts"),
+            root.join("src/domain/order.ts"),
+        ];
+
+        let usages = audit_forbidden_rules(&SourceMap::default(), &root, &files, &ctx);
+        let summary: Vec<(bool, usize, bool)> = usages
+            .iter()
+            .map(|u| (u.to_package, u.violations, u.is_dead()))
+            .collect();
+        // Un paquete sin violaciones hoy sigue protegiendo la capa; una carpeta que
+        // no existe, no
+        assert_eq!(
+            summary,
+            [(true, 1, false), (true, 0, false), (false, 0, true)]
+        );
+
+        // Con violaciones no es muerta aunque no se reconozca el destino
+        let usage = RuleUsage {
+            rule: rule("src/domain/**", "src/gone/**"),
+            from_files: 2,
+            to_files: 0,
+            to_package: false,
+            violations: 1,
+        };
+        assert!(!usage.is_dead());
+    }
+}
@@ -1,6 +1,7 @@
 use crate::config::{AIConfig, ForbiddenRule};
 use miette::{IntoDiagnostic, Result};
 use serde::{Deserialize, Serialize};
+use similar::TextDiff;
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -17,6 +18,8 @@ pub struct Violation {
     pub rule: ForbiddenRule,
     /// Línea donde ocurre la violación
     pub line_number: usize,
+    /// Identificador de la regla en el catálogo (ej: "forbidden-imports")
+    pub rule_id: &'static str,
 }
 
 /// Tipo de fix sugerido por la IA
@@ -48,6 +51,7 @@ pub async fn suggest_fix(
     violation: &Violation,
     project_root: &Path,
     ai_configs: &[AIConfig],
+    race: bool,
 ) -> Result<FixSuggestion> {
     // Obtener estructura de carpetas del proyecto
     let folder_structure = get_project_structure(project_root);
@@ -112,7 +116,8 @@ Responde SOLO con el JSON, sin texto adicional."#,
     );
 
     // Hacer la petición a la IA usando el sistema de fallback
-    let content = crate::ai::consultar_ia_con_fallback(prompt, ai_configs)
+    let content = crate::ai::consultar_ia_con_fallback_async(prompt, ai_configs, race)
+        .await
         .map_err(|e| miette::miette!("No se pudo obtener sugerencia de ningún modelo: {}", e))?;
 
     // Limpiar markdown code blocks si existen
@@ -168,10 +173,123 @@ pub fn apply_fix(
     }
 }
 
-/// Aplica una refactorización de código
-fn apply_refactor(violation: &Violation, old_code: &str, new_code: &str) -> Result<String> {
-    let content = fs::read_to_string(&violation.file_path).into_diagnostic()?;
+/// Cambio de un archivo que produciría un fix (`None` = el archivo no existe antes
+/// o deja de existir después)
+pub struct FileChange {
+    pub path: PathBuf,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+/// Archivos tocados por los fixes de --fix-dry-run, en memoria: cada fix parte del
+/// resultado de los anteriores y el diff de cada archivo se calcula una sola vez
+#[derive(Default)]
+pub struct FixPreview {
+    changes: Vec<FileChange>,
+}
+
+impl FixPreview {
+    /// Contenido de `path` tras los fixes ya simulados
+    fn read(&self, path: &Path) -> Result<String> {
+        match self.changes.iter().find(|change| change.path == path) {
+            Some(change) => change.after.clone().ok_or_else(|| {
+                miette::miette!("{} ya no existe tras un fix anterior", path.display())
+            }),
+            None => fs::read_to_string(path).into_diagnostic(),
+        }
+    }
+
+    fn write(&mut self, path: PathBuf, after: Option<String>) {
+        match self.changes.iter_mut().find(|change| change.path == path) {
+            Some(change) => change.after = after,
+            None => {
+                let before = fs::read_to_string(&path).ok();
+                self.changes.push(FileChange {
+                    path,
+                    before,
+                    after,
+                });
+            }
+        }
+    }
+
+    /// Cambios acumulados, en el orden en que se tocó cada archivo por primera vez
+    pub fn changes(&self) -> &[FileChange] {
+        &self.changes
+    }
+}
 
+/// Simula un fix sobre los cambios anteriores sin tocar el disco (--fix-dry-run)
+pub fn preview_fix(
+    preview: &mut FixPreview,
+    suggestion: &FixSuggestion,
+    violation: &Violation,
+    project_root: &Path,
+) -> Result<()> {
+    match &suggestion.fix_type {
+        FixType::Refactor { old_code, new_code } => {
+            let content = preview.read(&violation.file_path)?;
+            let updated = refactored_content(&content, old_code, new_code)?;
+            preview.write(violation.file_path.clone(), Some(updated));
+        }
+        FixType::MoveFile { from, to } => {
+            let content = preview.read(&project_root.join(from))?;
+            preview.write(project_root.join(from), None);
+            preview.write(project_root.join(to), Some(content));
+        }
+        FixType::CreateInterface {
+            interface_path,
+            interface_code,
+            updated_import,
+        } => {
+            let content = preview.read(&violation.file_path)?;
+            let updated = content.replace(&violation.offensive_import, updated_import);
+            preview.write(
+                project_root.join(interface_path),
+                Some(interface_code.clone()),
+            );
+            preview.write(violation.file_path.clone(), Some(updated));
+        }
+    }
+    Ok(())
+}
+
+/// Diff unificado de los cambios, con rutas relativas a la raíz del proyecto
+pub fn render_diff(changes: &[FileChange], project_root: &Path) -> String {
+    let mut out = String::new();
+    // Un archivo que vuelve a su contenido original no aparece en el diff
+    for change in changes
+        .iter()
+        .filter(|change| change.before != change.after)
+    {
+        let path = change
+            .path
+            .strip_prefix(project_root)
+            .unwrap_or(&change.path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        let old_header = match change.before {
+            Some(_) => format!("a/{}", path),
+            None => "/dev/null".to_string(),
+        };
+        let new_header = match change.after {
+            Some(_) => format!("b/{}", path),
+            None => "/dev/null".to_string(),
+        };
+        let before = change.before.as_deref().unwrap_or("");
+        let after = change.after.as_deref().unwrap_or("");
+        out.push_str(
+            &TextDiff::from_lines(before, after)
+                .unified_diff()
+                .header(&old_header, &new_header)
+                .to_string(),
+        );
+    }
+    out
+}
+
+/// `content` con `old_code` reemplazado por `new_code`
+fn refactored_content(content: &str, old_code: &str, new_code: &str) -> Result<String> {
     // Reemplazar el código antiguo por el nuevo
     let updated_content = content.replace(old_code.trim(), new_code.trim());
 
@@ -180,6 +298,13 @@ fn apply_refactor(violation: &Violation, old_code: &str, new_code: &str) -> Resu
             "No se pudo aplicar el fix: el código antiguo no se encontró exactamente"
         ));
     }
+    Ok(updated_content)
+}
+
+/// Aplica una refactorización de código
+fn apply_refactor(violation: &Violation, old_code: &str, new_code: &str) -> Result<String> {
+    let content = fs::read_to_string(&violation.file_path).into_diagnostic()?;
+    let updated_content = refactored_content(&content, old_code, new_code)?;
 
     // Escribir el archivo actualizado
     fs::write(&violation.file_path, &updated_content).into_diagnostic()?;
@@ -269,3 +394,73 @@ fn get_project_structure(root: &Path) -> String {
 
     structure
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::testing::temp_project;
+
+    const SOURCE: &str = "import { Repo } from '../infrastructure/repo';
+import { Db } from '../infrastructure/db';
+import { User } from './user';
+
+export const load = (id: string) => new User(id);
+";
+
+    fn violation(file_path: &Path, offensive_import: &str) -> Violation {
+        Violation {
+            file_path: file_path.to_path_buf(),
+            file_content: SOURCE.to_string(),
+            offensive_import: offensive_import.to_string(),
+            rule: ForbiddenRule {
+                from: "src/domain/**".to_string(),
+                to: "src/infrastructure/**".to_string(),
+            },
+            line_number: 1,
+            rule_id: "forbidden-imports",
+        }
+    }
+
+    fn refactor(old_code: &str, new_code: &str) -> FixSuggestion {
+        FixSuggestion {
+            fix_type: FixType::Refactor {
+                old_code: old_code.to_string(),
+                new_code: new_code.to_string(),
+            },
+            explanation: String::new(),
+            confidence: "high".to_string(),
+        }
+    }
+
+    #[test]
+    fn fixes_on_the_same_file_produce_a_single_hunk() {
+        let root = temp_project("fix-dry-run", &[("src/domain/user.service.ts", SOURCE)]);
+        let file = root.join("src/domain/user.service.ts");
+        let repo = "import { Repo } from '../infrastructure/repo';";
+        let db = "import { Db } from '../infrastructure/db';";
+
+        // Quitar el import sin usar y luego reescribir el otro con su alias: el
+        // segundo fix parte del resultado del primero
+        let mut preview = FixPreview::default();
+        let unused = refactor(&format!("{}\n{}", repo, db), db);
+        preview_fix(&mut preview, &unused, &violation(&file, repo), &root).unwrap();
+        let alias = refactor("'../infrastructure/db'", "'@infra/db'");
+        preview_fix(&mut preview, &alias, &violation(&file, db), &root).unwrap();
+
+        assert_eq!(
+            render_diff(preview.changes(), &root),
+            "--- a/src/domain/user.service.ts
++++ b/src/domain/user.service.ts
+@@ -1,5 +1,4 @@
+-import { Repo } from '../infrastructure/repo';
+-import { Db } from '../infrastructure/db';
++import { Db } from '@infra/db';
+ import { User } from './user';
+ 
+ export const load = (id: string) => new User(id);
+"
+        );
+        // Nada se escribe en disco
+        assert_eq!(fs::read_to_string(&file).unwrap(), SOURCE);
+    }
+}
@@ -0,0 +1,288 @@
+//! Análisis reanudable (--checkpoint)
+//!
+//! Durante la fase de reglas se guarda periódicamente qué archivos ya se analizaron
+//! y sus violaciones. Una ejecución posterior con el mismo checkpoint solo analiza
+//! los archivos que faltan (o que cambiaron) y mezcla el resto desde el disco.
+//!
+//! El checkpoint entero se descarta si cambia la configuración (architect.json,
+//! package.json, tsconfig.json, los `--rule`/`--extensions`/`--report-unresolved` o la
+//! versión del linter). Cada archivo se vuelve a analizar si cambia su contenido o algo
+//! de lo que depende: los archivos que importa y los architect.json anidados.
+
+use crate::circular;
+use crate::cli::CliArgs;
+use crate::compare::{self, ReportEntry};
+use crate::rules::Finding;
+use miette::{IntoDiagnostic, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Versión del formato del checkpoint en disco
+const CHECKPOINT_VERSION: u32 = 2;
+
+/// Archivos analizados entre dos escrituras del checkpoint
+const SAVE_EVERY: usize = 50;
+
+/// Estado guardado en disco
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CheckpointState {
+    version: u32,
+    /// Huella de la configuración con la que se analizaron los archivos
+    config_hash: String,
+    /// Ruta relativa -> resultado del archivo
+    files: BTreeMap<String, FileResult>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct FileResult {
+    /// FNV-1a del contenido analizado
+    content_hash: String,
+    /// Huella de lo que el resultado lee fuera del archivo (`dependencies_hash`)
+    dependencies_hash: String,
+    violations: Vec<ReportEntry>,
+}
+
+/// Checkpoint compartido por los hilos de la fase de reglas
+pub struct Checkpoint {
+    path: PathBuf,
+    project_root: PathBuf,
+    state: Mutex<CheckpointState>,
+    /// Archivos registrados desde la última escritura
+    pending: Mutex<usize>,
+}
+
+impl Checkpoint {
+    /// Carga el checkpoint si existe y corresponde a la configuración actual;
+    /// si no, empieza uno vacío
+    pub fn load(path: &Path, project_root: &Path, config_hash: &str) -> Self {
+        let previous = fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<CheckpointState>(&content).ok())
+            .filter(|state| state.version == CHECKPOINT_VERSION);
+
+        let state = match previous {
+            Some(state) if state.config_hash == config_hash => {
+                eprintln!(
+                    "♻️  Checkpoint: {} archivo(s) ya analizados en {}",
+                    state.files.len(),
+                    path.display()
+                );
+                state
+            }
+            Some(_) => {
+                eprintln!(
+                    "♻️  Checkpoint descartado: la configuración cambió desde la última ejecución"
+                );
+                Self::empty_state(config_hash)
+            }
+            None => Self::empty_state(config_hash),
+        };
+
+        Self {
+            path: path.to_path_buf(),
+            project_root: project_root.to_path_buf(),
+            state: Mutex::new(state),
+            pending: Mutex::new(0),
+        }
+    }
+
+    fn empty_state(config_hash: &str) -> CheckpointState {
+        CheckpointState {
+            version: CHECKPOINT_VERSION,
+            config_hash: config_hash.to_string(),
+            files: BTreeMap::new(),
+        }
+    }
+
+    /// Violaciones guardadas de un archivo, si se analizó con el contenido actual
+    pub fn completed(&self, file_path: &Path) -> Option<Vec<ReportEntry>> {
+        let content_hash = content_hash(file_path)?;
+        let result = self
+            .state
+            .lock()
+            .unwrap()
+            .files
+            .get(&self.key(file_path))
+            .filter(|result| result.content_hash == content_hash)
+            .cloned()?;
+        // Fuera del lock: recorre los imports del archivo
+        (result.dependencies_hash == self.dependencies_hash(file_path)).then_some(result.violations)
+    }
+
+    /// Registra el resultado de un archivo
+    pub fn record(&self, file_path: &Path, findings: &[Finding]) {
+        let content_hash = match content_hash(file_path) {
+            Some(hash) => hash,
+            None => return,
+        };
+        let dependencies_hash = self.dependencies_hash(file_path);
+        let key = self.key(file_path);
+        let violations = entries(&key, findings);
+        self.state.lock().unwrap().files.insert(
+            key,
+            FileResult {
+                content_hash,
+                dependencies_hash,
+                violations,
+            },
+        );
+        self.save_periodically();
+    }
+
+    /// Agrega hallazgos de otra pasada (ej: --report-unresolved) a un archivo ya
+    /// registrado. Si no se registró (el análisis falló) no se agrega nada, para
+    /// que se vuelva a analizar completo.
+    pub fn append(&self, file_path: &Path, findings: &[Finding]) {
+        let key = self.key(file_path);
+        if let Some(result) = self.state.lock().unwrap().files.get_mut(&key) {
+            result.violations.extend(entries(&key, findings));
+        }
+    }
+
+    /// Cuenta un archivo registrado y escribe el checkpoint cada `SAVE_EVERY`
+    fn save_periodically(&self) {
+        let mut pending = self.pending.lock().unwrap();
+        *pending += 1;
+        if *pending >= SAVE_EVERY {
+            *pending = 0;
+            if let Err(e) = self.save() {
+                eprintln!("⚠️  No se pudo guardar el checkpoint: {}", e);
+            }
+        }
+    }
+
+    /// Escribe el checkpoint (en un temporal y luego renombrado, para que una
+    /// interrupción a mitad de la escritura no lo corrompa)
+    pub fn save(&self) -> Result<()> {
+        // Se serializa una copia para no frenar a los hilos que registran archivos
+        let state = self.state.lock().unwrap().clone();
+        let json = serde_json::to_string(&state).into_diagnostic()?;
+        let tmp = self.path.with_extension("tmp");
+        fs::write(&tmp, json).into_diagnostic()?;
+        fs::rename(&tmp, &self.path).into_diagnostic()
+    }
+
+    /// Huella de lo que, fuera del propio archivo, cambia su resultado: los
+    /// architect.json entre su carpeta y la raíz (`cascade`), los archivos que importa
+    /// y los que estos re-exportan (las reglas de DI siguen los barrels). Un import
+    /// sin resolver también cuenta, por si el archivo aparece.
+    fn dependencies_hash(&self, file_path: &Path) -> String {
+        let mut inputs = Vec::new();
+        let nested_dirs = file_path
+            .ancestors()
+            .skip(1)
+            .take_while(|dir| dir.starts_with(&self.project_root) && *dir != self.project_root);
+        for dir in nested_dirs {
+            if let Ok(config) = fs::read_to_string(dir.join("architect.json")) {
+                inputs.push(format!("{}\0{}", self.key(dir), config));
+            }
+        }
+
+        let mut seen = HashSet::new();
+        let mut pending = vec![(file_path.to_path_buf(), true)];
+        while let Some((file, direct)) = pending.pop() {
+            for (source, is_reexport) in circular::import_specifiers(&file) {
+                if !direct && !is_reexport {
+                    continue;
+                }
+                match circular::resolve_import_path(&file, &source) {
+                    Some(target) => {
+                        let hash = content_hash(&target).unwrap_or_default();
+                        inputs.push(format!("{}\0{}\0{}", source, self.key(&target), hash));
+                        if seen.insert(target.clone()) {
+                            pending.push((target, false));
+                        }
+                    }
+                    None => inputs.push(source),
+                }
+            }
+        }
+
+        inputs.sort();
+        format!("{:016x}", compare::fnv1a(inputs.join("\n").as_bytes()))
+    }
+
+    /// Clave estable del archivo: ruta relativa a la raíz del proyecto
+    fn key(&self, file_path: &Path) -> String {
+        file_path
+            .strip_prefix(&self.project_root)
+            .unwrap_or(file_path)
+            .to_string_lossy()
+            .replace('\\', "/")
+    }
+}
+
+/// Huella de todo lo que cambia el resultado del análisis por archivo
+pub fn config_hash(project_root: &Path, cli_args: &CliArgs) -> String {
+    // package.json decide el preset del framework y los subpath imports; tsconfig.json,
+    // los proyectos referenciados
+    let read = |name: &str| fs::read_to_string(project_root.join(name)).unwrap_or_default();
+    let overrides = format!(
+        "{:?}{:?}{}",
+        cli_args.rule_overrides, cli_args.extensions, cli_args.report_unresolved
+    );
+    let input = [
+        env!("CARGO_PKG_VERSION"),
+        &read("architect.json"),
+        &read("package.json"),
+        &read("tsconfig.json"),
+        &overrides,
+    ]
+    .join("\0");
+    format!("{:016x}", compare::fnv1a(input.as_bytes()))
+}
+
+fn entries(key: &str, findings: &[Finding]) -> Vec<ReportEntry> {
+    findings
+        .iter()
+        .map(|finding| ReportEntry::from_finding(key, finding))
+        .collect()
+}
+
+fn content_hash(file_path: &Path) -> Option<String> {
+    let content = fs::read(file_path).ok()?;
+    Some(format!("{:016x}", compare::fnv1a(&content)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::testing::temp_project;
+
+    #[test]
+    fn a_file_is_reanalyzed_when_a_dependency_changes() {
+        let root = temp_project(
+            "checkpoint-deps",
+            &[
+                (
+                    "src/orders/orders.controller.ts",
+                    "import { Orders } from '../users';\n",
+                ),
+                ("src/users/index.ts", "export * from './users.service';\n"),
+                (
+                    "src/users/users.service.ts",
+                    "export class UsersService {}\n",
+                ),
+            ],
+        );
+        let controller = root.join("src/orders/orders.controller.ts");
+        let checkpoint = Checkpoint::load(&root.join("checkpoint.json"), &root, "config");
+        checkpoint.record(&controller, &[]);
+        assert!(checkpoint.completed(&controller).is_some());
+
+        // El servicio llega a través del barrel: cambiarlo invalida el controlador
+        fs::write(
+            root.join("src/users/users.service.ts"),
+            "@Injectable()\nexport class UsersService {}\n",
+        )
+        .unwrap();
+        assert!(checkpoint.completed(&controller).is_none());
+
+        checkpoint.record(&controller, &[]);
+        fs::write(root.join("src/orders/architect.json"), "{}").unwrap();
+        assert!(checkpoint.completed(&controller).is_none());
+    }
+}
@@ -1,8 +1,15 @@
-use miette::{IntoDiagnostic, Result};
+use crate::config::RuleSettings;
+use crate::resolver::ProjectReferences;
+use miette::Result;
 use std::collections::{HashMap, HashSet};
-use std::path::{Path, PathBuf};
-use swc_common::SourceMap;
-use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax, TsConfig, EsConfig};
+use std::io::Write;
+use std::path::{Component, Path, PathBuf};
+use swc_common::sync::Lrc;
+use swc_common::{FileName, SourceFile, SourceMap, Spanned};
+use swc_ecma_parser::{lexer::Lexer, Parser, StringInput};
+
+/// Máximo de módulos listados por --graph-metrics
+const MAX_COUPLING_LISTED: usize = 15;
 
 /// Representa una dependencia cíclica detectada
 #[derive(Debug, Clone)]
@@ -11,6 +18,44 @@ pub struct CircularDependency {
     pub cycle: Vec<String>,
     /// Descripción legible del problema
     pub description: String,
+    /// Indica si alguna arista del ciclo es un re-export de un barrel (`export * from`)
+    pub via_barrel: bool,
+}
+
+impl CircularDependency {
+    /// Módulos distintos del ciclo (`cycle` repite el primero al final)
+    pub fn module_count(&self) -> usize {
+        self.cycle.len().saturating_sub(1)
+    }
+}
+
+/// Archivo que quedó fuera del grafo por no poder leerse o parsearse
+#[derive(Debug, Clone)]
+pub struct SkippedFile {
+    pub path: PathBuf,
+    /// Error con la posición, si es de sintaxis
+    pub error: String,
+}
+
+/// Métricas de acoplamiento de un módulo (Robert C. Martin)
+#[derive(Debug, Clone)]
+pub struct ModuleCoupling {
+    /// Ruta normalizada del módulo
+    pub module: String,
+    /// Acoplamiento aferente: módulos distintos que lo importan
+    pub fan_in: usize,
+    /// Acoplamiento eferente: módulos distintos que importa
+    pub fan_out: usize,
+    /// fan-out / (fan-in + fan-out): 0 = estable, 1 = inestable
+    pub instability: f64,
+}
+
+/// Import extraído de un archivo para construir el grafo
+struct ImportEdge {
+    /// Especificador tal como aparece en el código
+    source: String,
+    /// Si proviene de un re-export (`export * from`, `export { x } from`)
+    is_reexport: bool,
 }
 
 /// Analizador de dependencias cíclicas
@@ -21,6 +66,10 @@ pub struct CircularDependencyAnalyzer {
     project_root: PathBuf,
     /// Grafo inverso: node -> [nodes que lo importan]
     reverse_graph: HashMap<String, Vec<String>>,
+    /// Aristas (origen, destino) que corresponden a re-exports de barrels
+    reexport_edges: HashSet<(String, String)>,
+    /// Proyectos referenciados desde tsconfig.json (`references`)
+    references: ProjectReferences,
 }
 
 impl CircularDependencyAnalyzer {
@@ -30,45 +79,227 @@ impl CircularDependencyAnalyzer {
             graph: HashMap::new(),
             project_root: project_root.to_path_buf(),
             reverse_graph: HashMap::new(),
+            reexport_edges: HashSet::new(),
+            references: ProjectReferences::load(project_root),
         }
     }
 
-    /// Analiza todos los archivos y construye el grafo de dependencias
-    pub fn build_graph(&mut self, files: &[PathBuf], cm: &SourceMap) -> Result<()> {
-        for file_path in files {
+    /// Analizador para un proyecto que solo existe en memoria (--archive): sin
+    /// raíz en disco ni proyectos referenciados
+    pub fn in_memory() -> Self {
+        Self {
+            graph: HashMap::new(),
+            project_root: PathBuf::new(),
+            reverse_graph: HashMap::new(),
+            reexport_edges: HashSet::new(),
+            references: ProjectReferences::default(),
+        }
+    }
+
+    /// Resuelve un import a archivo fuente. Los imports hacia otros proyectos
+    /// referenciados (por outDir o por nombre de paquete) se mapean a su código fuente.
+    fn resolve(&self, current_file: &Path, import_path: &str) -> Option<PathBuf> {
+        self.references
+            .resolve(current_file, import_path)
+            .or_else(|| resolve_import_path(current_file, import_path))
+    }
+
+    /// Analiza los archivos JavaScript/TypeScript y construye el grafo de dependencias.
+    /// Un archivo que no se puede parsear (ej: a medio editar) no detiene el análisis:
+    /// queda fuera del grafo y se devuelve para avisar.
+    pub fn build_graph(&mut self, files: &[PathBuf], cm: &SourceMap) -> Vec<SkippedFile> {
+        let mut skipped = Vec::new();
+        for file_path in files.iter().filter(|file| is_module_file(file)) {
             // Extraer imports del archivo
-            let imports = self.extract_imports(file_path, cm)?;
+            let imports = match self.extract_imports(file_path, cm) {
+                Ok(imports) => imports,
+                Err(e) => {
+                    skipped.push(SkippedFile {
+                        path: file_path.clone(),
+                        error: e.to_string(),
+                    });
+                    continue;
+                }
+            };
 
             // Normalizar la ruta del archivo actual
             let normalized_current = self.normalize_file_path(file_path);
             let current_key = normalized_current.clone();
 
             // Insertar en el grafo
-            self.graph.entry(current_key.clone()).or_insert_with(Vec::new);
+            self.graph.entry(current_key.clone()).or_default();
 
             // Procesar cada import
-            for import_path in imports {
-                if let Some(resolved) = self.resolve_import_path(file_path, &import_path) {
+            for import in imports {
+                if let Some(resolved) = self.resolve(file_path, &import.source) {
                     let normalized_import = self.normalize_file_path(&resolved);
 
                     // Solo agregar dependencias internas del proyecto
                     if self.is_internal_dependency(&normalized_import) {
-                        self.graph
-                            .entry(current_key.clone())
-                            .or_insert_with(Vec::new)
-                            .push(normalized_import.clone());
-
-                        // Actualizar grafo inverso
-                        self.reverse_graph
-                            .entry(normalized_import)
-                            .or_insert_with(Vec::new)
-                            .push(current_key.clone());
+                        self.add_edge(&current_key, normalized_import, import.is_reexport);
                     }
                 }
             }
         }
 
-        Ok(())
+        skipped
+    }
+
+    /// Construye el grafo con archivos cuyo contenido está en memoria (--archive).
+    /// Solo se resuelven los imports relativos, contra las rutas de `sources`.
+    pub fn build_graph_in_memory(
+        &mut self,
+        sources: &[(PathBuf, &str)],
+        cm: &SourceMap,
+    ) -> Vec<SkippedFile> {
+        let known: HashSet<&Path> = sources.iter().map(|(path, _)| path.as_path()).collect();
+        let mut skipped = Vec::new();
+
+        for (file_path, source) in sources.iter().filter(|(file, _)| is_module_file(file)) {
+            let fm = cm.new_source_file(FileName::Real(file_path.clone()), source.to_string());
+            let imports = match module_imports(cm, fm, file_path) {
+                Ok(imports) => imports,
+                Err(e) => {
+                    skipped.push(SkippedFile {
+                        path: file_path.clone(),
+                        error: e.to_string(),
+                    });
+                    continue;
+                }
+            };
+
+            let current_key = self.normalize_file_path(file_path);
+            self.graph.entry(current_key.clone()).or_default();
+
+            for import in imports {
+                if let Some(resolved) = resolve_in_memory(file_path, &import.source, &known) {
+                    let normalized_import = self.normalize_file_path(&resolved);
+                    self.add_edge(&current_key, normalized_import, import.is_reexport);
+                }
+            }
+        }
+
+        skipped
+    }
+
+    /// Agrega la arista `from -> to` al grafo y al grafo inverso
+    fn add_edge(&mut self, from: &str, to: String, is_reexport: bool) {
+        if is_reexport {
+            self.reexport_edges.insert((from.to_string(), to.clone()));
+        }
+
+        self.graph
+            .entry(from.to_string())
+            .or_default()
+            .push(to.clone());
+
+        // Actualizar grafo inverso
+        self.reverse_graph
+            .entry(to)
+            .or_default()
+            .push(from.to_string());
+    }
+
+    /// Aristas distintas del grafo (módulo que importa, módulo importado), con las
+    /// rutas relativas a la raíz en minúsculas
+    pub fn edges(&self) -> HashSet<(&str, &str)> {
+        self.graph
+            .iter()
+            .flat_map(|(from, targets)| targets.iter().map(move |to| (from.as_str(), to.as_str())))
+            .collect()
+    }
+
+    /// Escribe el grafo en formato DOT de Graphviz (graph.dot de --output-dir). Las
+    /// aristas de los ciclos detectados van en rojo y los re-exports, punteadas
+    pub fn write_dot(
+        &self,
+        out: &mut impl Write,
+        cycles: &[CircularDependency],
+    ) -> std::io::Result<()> {
+        let in_cycle: HashSet<(&str, &str)> = cycles
+            .iter()
+            .flat_map(|cycle| {
+                cycle
+                    .cycle
+                    .windows(2)
+                    .map(|pair| (pair[0].as_str(), pair[1].as_str()))
+            })
+            .collect();
+        let mut edges: Vec<(&str, &str)> = self.edges().into_iter().collect();
+        edges.sort_unstable();
+        let mut isolated: Vec<&String> = self
+            .graph
+            .iter()
+            .filter(|(module, targets)| {
+                targets.is_empty()
+                    && self
+                        .reverse_graph
+                        .get(*module)
+                        .is_none_or(|importers| importers.is_empty())
+            })
+            .map(|(module, _)| module)
+            .collect();
+        isolated.sort_unstable();
+
+        writeln!(out, "digraph dependencies {{")?;
+        writeln!(out, "  rankdir=LR;")?;
+        writeln!(out, "  node [shape=box];")?;
+        for module in isolated {
+            writeln!(out, "  {:?};", module)?;
+        }
+        for (from, to) in edges {
+            let mut attributes = Vec::new();
+            if in_cycle.contains(&(from, to)) {
+                attributes.push("color=red");
+            }
+            if self
+                .reexport_edges
+                .contains(&(from.to_string(), to.to_string()))
+            {
+                attributes.push("style=dashed");
+            }
+            if attributes.is_empty() {
+                writeln!(out, "  {:?} -> {:?};", from, to)?;
+            } else {
+                writeln!(out, "  {:?} -> {:?} [{}];", from, to, attributes.join(", "))?;
+            }
+        }
+        writeln!(out, "}}")
+    }
+
+    /// Calcula fan-in, fan-out e inestabilidad de cada módulo del grafo, ordenados
+    /// de más a menos acoplado. Los módulos aislados (sin aristas) se omiten.
+    pub fn coupling_metrics(&self) -> Vec<ModuleCoupling> {
+        // Un mismo módulo puede importarse varias veces (ej: `import type` + `import`)
+        let distinct = |edges: Option<&Vec<String>>| {
+            edges.map_or(0, |edges| edges.iter().collect::<HashSet<_>>().len())
+        };
+
+        let modules: HashSet<&String> =
+            self.graph.keys().chain(self.reverse_graph.keys()).collect();
+        let mut metrics: Vec<ModuleCoupling> = modules
+            .into_iter()
+            .filter_map(|module| {
+                let fan_in = distinct(self.reverse_graph.get(module));
+                let fan_out = distinct(self.graph.get(module));
+                if fan_in + fan_out == 0 {
+                    return None;
+                }
+                Some(ModuleCoupling {
+                    module: module.clone(),
+                    fan_in,
+                    fan_out,
+                    instability: fan_out as f64 / (fan_in + fan_out) as f64,
+                })
+            })
+            .collect();
+
+        metrics.sort_by(|a, b| {
+            (b.fan_in + b.fan_out)
+                .cmp(&(a.fan_in + a.fan_out))
+                .then_with(|| a.module.cmp(&b.module))
+        });
+        metrics
     }
 
     /// Detecta todos los ciclos en el grafo de dependencias
@@ -78,19 +309,16 @@ impl CircularDependencyAnalyzer {
         let mut rec_stack = HashSet::new();
         let mut path = Vec::new();
 
-        for node in self.graph.keys() {
+        // Orden fijo: el HashMap cambia de orden entre ejecuciones
+        let mut nodes: Vec<&String> = self.graph.keys().collect();
+        nodes.sort();
+        for node in nodes {
             if !visited.contains(node) {
-                self.dfs_detect_cycles(
-                    node,
-                    &mut visited,
-                    &mut rec_stack,
-                    &mut path,
-                    &mut cycles,
-                );
+                self.dfs_detect_cycles(node, &mut visited, &mut rec_stack, &mut path, &mut cycles);
             }
         }
 
-        cycles
+        sort_cycles(cycles)
     }
 
     /// DFS para detectar ciclos en el grafo
@@ -116,10 +344,7 @@ impl CircularDependencyAnalyzer {
                     let mut cycle = path[cycle_start..].to_vec();
                     cycle.push(neighbor.clone());
 
-                    cycles.push(CircularDependency {
-                        cycle: cycle.clone(),
-                        description: self.format_cycle_description(&cycle),
-                    });
+                    cycles.push(self.build_cycle(cycle));
                 }
             }
         }
@@ -128,103 +353,44 @@ impl CircularDependencyAnalyzer {
         rec_stack.remove(node);
     }
 
-    /// Extrae todos los imports de un archivo
-    fn extract_imports(&self, file_path: &Path, cm: &SourceMap) -> Result<Vec<String>> {
-        let mut imports = Vec::new();
-
-        // Parsear según la extensión
-        let extension = file_path
-            .extension()
-            .and_then(|e| e.to_str())
-            .unwrap_or("");
-
-        let syntax = match extension {
-            "ts" | "tsx" => Syntax::Typescript(TsConfig {
-                decorators: true,
-                tsx: extension == "tsx",
-                ..Default::default()
-            }),
-            "js" | "jsx" => Syntax::Es(EsConfig {
-                decorators: true,
-                jsx: extension == "jsx",
-                ..Default::default()
-            }),
-            _ => Syntax::Typescript(TsConfig::default()),
-        };
-
-        let fm = cm.load_file(file_path).into_diagnostic()?;
-        let lexer = Lexer::new(syntax, Default::default(), StringInput::from(&*fm), None);
-        let mut parser = Parser::new_from(lexer);
-
-        let module = parser
-            .parse_module()
-            .map_err(|e| miette::miette!("Error parsing {}: {:?}", file_path.display(), e))?;
-
-        // Extraer imports estáticos
-        for item in &module.body {
-            if let swc_ecma_ast::ModuleItem::ModuleDecl(swc_ecma_ast::ModuleDecl::Import(
-                import,
-            )) = item
-            {
-                imports.push(import.src.value.to_string());
-            }
+    /// Construye la representación de un ciclo detectado. El ciclo se rota para
+    /// empezar en su módulo menor, así su huella no depende del recorrido.
+    fn build_cycle(&self, mut cycle: Vec<String>) -> CircularDependency {
+        cycle.pop();
+        let start = cycle
+            .iter()
+            .min()
+            .and_then(|min| cycle.iter().position(|node| node == min));
+        if let Some(start) = start {
+            cycle.rotate_left(start);
         }
-
-        Ok(imports)
-    }
-
-    /// Resuelve un path de import a una ruta de archivo real
-    fn resolve_import_path(&self, current_file: &Path, import_path: &str) -> Option<PathBuf> {
-        // Ignorar imports externos (node_modules, @/aliases si no se resuelven, etc.)
-        if import_path.starts_with('@')
-            || import_path.starts_with("node_modules")
-            || (!import_path.starts_with('.') && !import_path.starts_with('/'))
-        {
-            // Podríamos agregar lógica para resolver alias de TypeScript aquí
-            // Por ahora, solo procesamos imports relativos
-            return None;
-        }
-
-        // Resolver path relativo
-        let current_dir = current_file.parent()?;
-        let resolved = current_dir.join(import_path);
-
-        // Intentar diferentes extensiones
-        let extensions = ["ts", "tsx", "js", "jsx"];
-        for ext in &extensions {
-            let with_ext = resolved.with_extension(ext);
-            if with_ext.exists() {
-                return Some(with_ext);
-            }
+        if let Some(first) = cycle.first().cloned() {
+            cycle.push(first);
         }
 
-        // Intentar index.ts/js en directorios
-        let index_ts = resolved.join("index.ts");
-        let index_js = resolved.join("index.js");
+        let via_barrel = cycle.windows(2).any(|edge| {
+            self.reexport_edges
+                .contains(&(edge[0].clone(), edge[1].clone()))
+        });
 
-        if index_ts.exists() {
-            return Some(index_ts);
-        }
-        if index_js.exists() {
-            return Some(index_js);
+        CircularDependency {
+            description: self.format_cycle_description(&cycle, via_barrel),
+            cycle,
+            via_barrel,
         }
+    }
 
-        // Si el archivo existe tal cual (sin cambiar extensión)
-        if resolved.exists() {
-            Some(resolved)
-        } else {
-            None
-        }
+    /// Extrae todos los imports (y re-exports) de un archivo
+    fn extract_imports(&self, file_path: &Path, cm: &SourceMap) -> Result<Vec<ImportEdge>> {
+        let fm = crate::analyzer::load_source_file(cm, file_path)?;
+        module_imports(cm, fm, file_path)
     }
 
     /// Normaliza una ruta de archivo a una representación canónica
     fn normalize_file_path(&self, path: &Path) -> String {
         // Obtener ruta relativa al directorio raíz del proyecto
         if let Ok(relative) = path.strip_prefix(&self.project_root) {
-            relative
-                .to_string_lossy()
-                .replace('\\', "/")
-                .to_lowercase()
+            relative.to_string_lossy().replace('\\', "/").to_lowercase()
         } else {
             path.to_string_lossy().replace('\\', "/").to_lowercase()
         }
@@ -237,7 +403,7 @@ impl CircularDependencyAnalyzer {
     }
 
     /// Formatea una descripción legible del ciclo
-    fn format_cycle_description(&self, cycle: &[String]) -> String {
+    fn format_cycle_description(&self, cycle: &[String], via_barrel: bool) -> String {
         if cycle.is_empty() {
             return "Ciclo vacío".to_string();
         }
@@ -248,9 +414,12 @@ impl CircularDependencyAnalyzer {
                 desc.push_str(&format!("  {} → {}\n", node, cycle[i + 1]));
             }
         }
-        desc.push_str(&format!(
-            "\n  ⚠️  Esto rompe la jerarquía de capas y crea acoplamiento circular."
-        ));
+        desc.push_str("\n  ⚠️  Esto rompe la jerarquía de capas y crea acoplamiento circular.");
+        if via_barrel {
+            desc.push_str(
+                "\n  🧺 El ciclo pasa por un barrel (re-export): en runtime algunos exports pueden llegar como `undefined`.",
+            );
+        }
 
         desc
     }
@@ -261,27 +430,35 @@ impl CircularDependencyAnalyzer {
 
         // Eliminar aristas antiguas del nodo
         self.invalidate_node(&normalized_current);
+        if !is_module_file(file_path) {
+            return Ok(());
+        }
 
         // Re-extraer imports
         let imports = self.extract_imports(file_path, cm)?;
 
         // Reconstruir aristas
-        self.graph.entry(normalized_current.clone()).or_insert_with(Vec::new);
+        self.graph.entry(normalized_current.clone()).or_default();
 
-        for import_path in imports {
-            if let Some(resolved) = self.resolve_import_path(file_path, &import_path) {
+        for import in imports {
+            if let Some(resolved) = self.resolve(file_path, &import.source) {
                 let normalized_import = self.normalize_file_path(&resolved);
 
                 if self.is_internal_dependency(&normalized_import) {
+                    if import.is_reexport {
+                        self.reexport_edges
+                            .insert((normalized_current.clone(), normalized_import.clone()));
+                    }
+
                     self.graph
                         .entry(normalized_current.clone())
-                        .or_insert_with(Vec::new)
+                        .or_default()
                         .push(normalized_import.clone());
 
                     // Actualizar grafo inverso
                     self.reverse_graph
                         .entry(normalized_import)
-                        .or_insert_with(Vec::new)
+                        .or_default()
                         .push(normalized_current.clone());
                 }
             }
@@ -313,6 +490,10 @@ impl CircularDependencyAnalyzer {
             }
         }
         self.reverse_graph.remove(node);
+
+        // Eliminar las aristas de re-export que involucran al nodo
+        self.reexport_edges
+            .retain(|(from, to)| from != node && to != node);
     }
 
     /// Obtiene todos los nodos conectados a un nodo dado (componente fuertemente conexo aproximado)
@@ -356,7 +537,9 @@ impl CircularDependencyAnalyzer {
         let mut rec_stack = HashSet::new();
         let mut path = Vec::new();
 
-        for node in nodes {
+        let mut sorted: Vec<&String> = nodes.iter().collect();
+        sorted.sort();
+        for node in sorted {
             if !visited.contains(node) {
                 self.dfs_detect_cycles_filtered(
                     node,
@@ -369,7 +552,7 @@ impl CircularDependencyAnalyzer {
             }
         }
 
-        cycles
+        sort_cycles(cycles)
     }
 
     /// DFS para detectar ciclos solo en un subgrafo específico
@@ -408,10 +591,7 @@ impl CircularDependencyAnalyzer {
                     let mut cycle = path[cycle_start..].to_vec();
                     cycle.push(neighbor.clone());
 
-                    cycles.push(CircularDependency {
-                        cycle: cycle.clone(),
-                        description: self.format_cycle_description(&cycle),
-                    });
+                    cycles.push(self.build_cycle(cycle));
                 }
             }
         }
@@ -421,18 +601,247 @@ impl CircularDependencyAnalyzer {
     }
 }
 
+/// Imports estáticos y re-exports de un archivo ya cargado en el SourceMap
+fn module_imports(
+    cm: &SourceMap,
+    fm: Lrc<SourceFile>,
+    file_path: &Path,
+) -> Result<Vec<ImportEdge>> {
+    let mut imports = Vec::new();
+
+    // Parsear según la extensión
+    let extension = file_path.extension().and_then(|e| e.to_str()).unwrap_or("");
+    let syntax = crate::analyzer::syntax_for_extension(extension).ok_or_else(|| {
+        miette::miette!(
+            "{} no es un archivo JavaScript/TypeScript",
+            file_path.display()
+        )
+    })?;
+
+    let lexer = Lexer::new(syntax, Default::default(), StringInput::from(&*fm), None);
+    let mut parser = Parser::new_from(lexer);
+
+    let module = parser.parse_module().map_err(|e| {
+        let pos = cm.lookup_char_pos(e.span().lo);
+        miette::miette!(
+            "Error de sintaxis en la línea {}, columna {}: {}",
+            pos.line,
+            pos.col_display + 1,
+            e.kind().msg()
+        )
+    })?;
+
+    // Extraer imports estáticos y re-exports (barrels)
+    for item in &module.body {
+        if let swc_ecma_ast::ModuleItem::ModuleDecl(decl) = item {
+            match decl {
+                swc_ecma_ast::ModuleDecl::Import(import) => imports.push(ImportEdge {
+                    source: import.src.value.to_string(),
+                    is_reexport: false,
+                }),
+                swc_ecma_ast::ModuleDecl::ExportAll(export) => imports.push(ImportEdge {
+                    source: export.src.value.to_string(),
+                    is_reexport: true,
+                }),
+                swc_ecma_ast::ModuleDecl::ExportNamed(export) => {
+                    if let Some(src) = &export.src {
+                        imports.push(ImportEdge {
+                            source: src.value.to_string(),
+                            is_reexport: true,
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Ok(imports)
+}
+
+/// Resuelve un import relativo contra las rutas conocidas, sin acceder al disco
+/// (--archive). Prueba las mismas candidatas que `resolve_candidate`.
+fn resolve_in_memory(
+    current_file: &Path,
+    import_path: &str,
+    known: &HashSet<&Path>,
+) -> Option<PathBuf> {
+    if is_asset_import(import_path) {
+        return None;
+    }
+    let import_path = strip_import_suffix(import_path);
+    if !import_path.starts_with('.') {
+        return None;
+    }
+
+    let mut resolved = current_file.parent()?.to_path_buf();
+    for component in Path::new(import_path).components() {
+        match component {
+            Component::Normal(part) => resolved.push(part),
+            // Un import que sale de la raíz no puede estar en el archivo
+            Component::ParentDir if !resolved.pop() => return None,
+            _ => {}
+        }
+    }
+
+    import_candidates(&resolved)
+        .into_iter()
+        .find(|candidate| known.contains(candidate.as_path()))
+}
+
+/// Imports de un archivo como (especificador, si es re-export). Vacío si el archivo
+/// no es JavaScript/TypeScript o no se puede leer o parsear.
+pub fn import_specifiers(file_path: &Path) -> Vec<(String, bool)> {
+    if !is_module_file(file_path) {
+        return Vec::new();
+    }
+    let cm = SourceMap::default();
+    crate::analyzer::load_source_file(&cm, file_path)
+        .and_then(|fm| module_imports(&cm, fm, file_path))
+        .map(|imports| {
+            imports
+                .into_iter()
+                .map(|import| (import.source, import.is_reexport))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Solo los módulos JavaScript/TypeScript forman parte del grafo
+fn is_module_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .and_then(crate::analyzer::syntax_for_extension)
+        .is_some()
+}
+
+/// Ordena los ciclos y quita los repetidos, para que el reporte sea estable
+fn sort_cycles(mut cycles: Vec<CircularDependency>) -> Vec<CircularDependency> {
+    cycles.sort_by(|a, b| a.cycle.cmp(&b.cycle));
+    cycles.dedup_by(|a, b| a.cycle == b.cycle);
+    cycles
+}
+
+/// Extensiones de assets que los bundlers importan como módulos (estilos, imágenes, fuentes...)
+const ASSET_EXTENSIONS: &[&str] = &[
+    "css", "scss", "sass", "less", "styl", "svg", "png", "jpg", "jpeg", "gif", "webp", "avif",
+    "ico", "bmp", "woff", "woff2", "ttf", "otf", "eot", "mp3", "mp4", "webm", "wav",
+];
+
+/// Quita los sufijos `?query` y `#hash` de los bundlers (ej: `./a.css?inline`, `./icon.svg?raw`).
+/// Un `#` inicial es un subpath import de package.json y se conserva.
+pub fn strip_import_suffix(import_path: &str) -> &str {
+    let start = usize::from(import_path.starts_with('#'));
+    match import_path[start..].find(['?', '#']) {
+        Some(index) => &import_path[..start + index],
+        None => import_path,
+    }
+}
+
+/// Import de un asset: no forma parte del grafo de módulos ni es un import roto
+pub fn is_asset_import(import_path: &str) -> bool {
+    Path::new(strip_import_suffix(import_path))
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ASSET_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+}
+
+/// Resuelve un path de import relativo a una ruta de archivo real
+pub fn resolve_import_path(current_file: &Path, import_path: &str) -> Option<PathBuf> {
+    if is_asset_import(import_path) {
+        return None;
+    }
+    let import_path = strip_import_suffix(import_path);
+
+    // Subpath imports de Node (`#domain/user`), definidos en package.json
+    if import_path.starts_with('#') {
+        return crate::resolver::resolve_subpath_import(current_file, import_path);
+    }
+
+    // Ignorar imports externos (node_modules, @/aliases si no se resuelven, etc.)
+    if import_path.starts_with('@')
+        || import_path.starts_with("node_modules")
+        || (!import_path.starts_with('.') && !import_path.starts_with('/'))
+    {
+        // Podríamos agregar lógica para resolver alias de TypeScript aquí
+        // Por ahora, solo procesamos imports relativos
+        return None;
+    }
+
+    // Resolver path relativo. Sin `.` ni `..`, la ruta sirve como clave del grafo
+    let current_dir = current_file.parent()?;
+    resolve_candidate(&crate::resolver::normalize_lexically(
+        &current_dir.join(import_path),
+    ))
+}
+
+/// Busca el archivo real de una ruta de import ya unida a su directorio base:
+/// prueba extensiones, `index.*` y finalmente la ruta tal cual
+pub fn resolve_candidate(resolved: &Path) -> Option<PathBuf> {
+    import_candidates(resolved)
+        .into_iter()
+        .find(|candidate| candidate.exists())
+}
+
+/// Rutas candidatas de un import, en orden de preferencia
+fn import_candidates(resolved: &Path) -> Vec<PathBuf> {
+    // Las extensiones se agregan, no se reemplazan: en `./users.service`
+    // el `.service` es parte del nombre
+    let mut candidates: Vec<PathBuf> = ["ts", "tsx", "js", "jsx"]
+        .iter()
+        .map(|ext| {
+            let mut with_ext = resolved.as_os_str().to_owned();
+            with_ext.push(".");
+            with_ext.push(ext);
+            PathBuf::from(with_ext)
+        })
+        .collect();
+
+    // index.ts/js en directorios y la ruta tal cual
+    candidates.push(resolved.join("index.ts"));
+    candidates.push(resolved.join("index.js"));
+    candidates.push(resolved.to_path_buf());
+
+    // ESM en TypeScript: `import './user.js'` apunta a `user.ts`
+    let is_js = resolved
+        .extension()
+        .is_some_and(|ext| ext == "js" || ext == "jsx");
+    if is_js {
+        candidates.push(resolved.with_extension("ts"));
+        candidates.push(resolved.with_extension("tsx"));
+    }
+    candidates
+}
+
 /// Función pública para analizar dependencias cíclicas en un proyecto
+/// Los archivos que no se pueden parsear quedan fuera del grafo.
 pub fn analyze_circular_dependencies(
     files: &[PathBuf],
     project_root: &Path,
     cm: &SourceMap,
-) -> Result<Vec<CircularDependency>> {
+) -> Vec<CircularDependency> {
     let mut analyzer = CircularDependencyAnalyzer::new(project_root);
-    analyzer.build_graph(files, cm)?;
-    Ok(analyzer.detect_cycles())
+    analyzer.build_graph(files, cm);
+    analyzer.detect_cycles()
+}
+
+/// Conserva los ciclos dentro de `min_cycle_length`..=`max_cycle_length` (0 = sin
+/// límite). La detección siempre es completa; el filtro solo acota lo reportado.
+pub fn filter_by_length(
+    cycles: Vec<CircularDependency>,
+    settings: &RuleSettings,
+) -> Vec<CircularDependency> {
+    cycles
+        .into_iter()
+        .filter(|cycle| {
+            let length = cycle.module_count();
+            length >= settings.min_cycle_length
+                && (settings.max_cycle_length == 0 || length <= settings.max_cycle_length)
+        })
+        .collect()
 }
 
-/// Imprime un reporte de dependencias cíclicas
+/// Imprime un reporte de dependencias cíclicas, agrupadas de más corta a más larga
 pub fn print_circular_dependency_report(cycles: &[CircularDependency]) {
     if cycles.is_empty() {
         println!("✅ No se detectaron dependencias cíclicas.");
@@ -440,11 +849,39 @@ pub fn print_circular_dependency_report(cycles: &[CircularDependency]) {
     }
 
     println!("\n🔴 DEPENDENCIAS CÍCLICAS DETECTADAS\n");
-    println!("Se encontraron {} ciclo(s) de dependencias:\n", cycles.len());
+    println!(
+        "Se encontraron {} ciclo(s) de dependencias:\n",
+        cycles.len()
+    );
+
+    let barrel_cycles = cycles.iter().filter(|c| c.via_barrel).count();
+    if barrel_cycles > 0 {
+        println!(
+            "🧺 {} de ellos pasan por re-exports de barrels (los más peligrosos y menos evidentes).\n",
+            barrel_cycles
+        );
+    }
 
-    for (i, cycle) in cycles.iter().enumerate() {
+    // Los ciclos cortos son los más fáciles de romper: primero
+    let mut sorted: Vec<&CircularDependency> = cycles.iter().collect();
+    sorted.sort_by_key(|cycle| cycle.module_count());
+
+    let mut current_length = 0;
+    for (i, cycle) in sorted.into_iter().enumerate() {
+        if cycle.module_count() != current_length {
+            current_length = cycle.module_count();
+            let count = cycles
+                .iter()
+                .filter(|c| c.module_count() == current_length)
+                .count();
+            println!("🔗 Ciclos de {} módulo(s): {}\n", current_length, count);
+        }
         println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-        println!("Ciclo #{}", i + 1);
+        if cycle.via_barrel {
+            println!("Ciclo #{} 🧺 [vía barrel]", i + 1);
+        } else {
+            println!("Ciclo #{}", i + 1);
+        }
         println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
 
         // Mostrar el ciclo completo usando el campo cycle
@@ -467,4 +904,356 @@ pub fn print_circular_dependency_report(cycles: &[CircularDependency]) {
     println!("  2. Extraer la lógica compartida a un tercer módulo");
     println!("  3. Usar eventos/observadores en lugar de llamadas directas");
     println!("  4. Aplicar el principio de inversión de dependencias (DIP)");
+    if barrel_cycles > 0 {
+        println!("  5. En ciclos vía barrel, importar el módulo concreto en lugar del index");
+    }
+}
+
+/// Imprime los módulos más acoplados con sus métricas de Martin
+pub fn print_coupling_report(metrics: &[ModuleCoupling]) {
+    if metrics.is_empty() {
+        println!("\n📐 No hay dependencias internas entre módulos.");
+        return;
+    }
+
+    println!(
+        "\n📐 MÓDULOS MÁS ACOPLADOS ({} con dependencias internas)\n",
+        metrics.len()
+    );
+    println!(
+        "  {:>6}  {:>7}  {:>13}  Módulo",
+        "fan-in", "fan-out", "inestabilidad"
+    );
+    for metric in metrics.iter().take(MAX_COUPLING_LISTED) {
+        println!(
+            "  {:>6}  {:>7}  {:>13.2}  {}",
+            metric.fan_in, metric.fan_out, metric.instability, metric.module
+        );
+    }
+    if metrics.len() > MAX_COUPLING_LISTED {
+        println!("  ... y {} más", metrics.len() - MAX_COUPLING_LISTED);
+    }
+
+    println!();
+    println!("💡 Inestabilidad cercana a 0: muchos dependen del módulo, cámbialo con cuidado.");
+    println!("   Cercana a 1: depende de muchos y nadie de él, fácil de cambiar.");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::testing::temp_project;
+
+    #[test]
+    fn resolves_dotted_file_names() {
+        let root = temp_project(
+            "resolve-dotted",
+            &[
+                (
+                    "src/users/users.service.ts",
+                    "export class UsersService {}\n",
+                ),
+                ("src/users/user.ts", "export class User {}\n"),
+                ("src/users/index.ts", "export * from './users.service';\n"),
+                ("src/orders/orders.controller.ts", ""),
+            ],
+        );
+        let controller = root.join("src/orders/orders.controller.ts");
+
+        // `.service` es parte del nombre, no una extensión
+        assert_eq!(
+            resolve_import_path(&controller, "../users/users.service"),
+            Some(root.join("src/users/users.service.ts"))
+        );
+        assert_eq!(
+            resolve_import_path(&controller, "../users"),
+            Some(root.join("src/users/index.ts"))
+        );
+        // Import ESM con `.js` hacia la fuente TypeScript
+        assert_eq!(
+            resolve_import_path(&controller, "../users/user.js"),
+            Some(root.join("src/users/user.ts"))
+        );
+        assert_eq!(
+            resolve_import_path(&controller, "../users/users.repository"),
+            None
+        );
+    }
+
+    /// Ciclo `a → b → c → a` cargado en memoria con los archivos en `order`
+    fn cycles_with_order(order: &[usize]) -> Vec<CircularDependency> {
+        let files = [
+            ("src/a.ts", "import { b } from './b';\n"),
+            ("src/b.ts", "import { c } from './c';\n"),
+            ("src/c.ts", "import { a } from './a';\n"),
+        ];
+        let sources: Vec<(PathBuf, &str)> = order
+            .iter()
+            .map(|&i| (PathBuf::from(files[i].0), files[i].1))
+            .collect();
+        let mut analyzer = CircularDependencyAnalyzer::in_memory();
+        analyzer.build_graph_in_memory(&sources, &SourceMap::default());
+        analyzer.detect_cycles()
+    }
+
+    #[test]
+    fn cycles_start_at_their_smallest_module() {
+        for order in [[0, 1, 2], [2, 1, 0], [1, 2, 0]] {
+            let cycles = cycles_with_order(&order);
+            assert_eq!(cycles.len(), 1);
+            assert_eq!(
+                cycles[0].cycle,
+                ["src/a.ts", "src/b.ts", "src/c.ts", "src/a.ts"]
+            );
+        }
+    }
+
+    #[test]
+    fn a_syntax_error_does_not_stop_cycle_detection() {
+        let root = temp_project(
+            "cycles-parse-error",
+            &[
+                ("src/a.ts", "import { b } from './b';\n"),
+                ("src/b.ts", "import { a } from './a';\n"),
+                ("src/broken.ts", "import { a } from './a';\nconst = ;\n"),
+                ("scripts/seed.py", "from app import db\n"),
+            ],
+        );
+        let files: Vec<PathBuf> = ["src/a.ts", "src/b.ts", "src/broken.ts", "scripts/seed.py"]
+            .iter()
+            .map(|file| root.join(file))
+            .collect();
+
+        let mut analyzer = CircularDependencyAnalyzer::new(&root);
+        let skipped = analyzer.build_graph(&files, &SourceMap::default());
+
+        // Solo el archivo roto queda fuera; el .py ni siquiera entra al grafo
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].path, root.join("src/broken.ts"));
+        assert!(skipped[0].error.contains("línea 2, columna 7"));
+        let cycles = analyzer.detect_cycles();
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(cycles[0].cycle, ["src/a.ts", "src/b.ts", "src/a.ts"]);
+    }
+
+    #[test]
+    fn cycles_through_a_barrel_re_export_are_tagged() {
+        let files = [
+            ("src/users/index.ts", "export * from './users.service';\n"),
+            (
+                "src/users/users.service.ts",
+                "import { OrdersService } from '../orders/orders.service';\n",
+            ),
+            (
+                "src/orders/orders.service.ts",
+                "import { UsersService } from '../users';\n",
+            ),
+            ("src/a.ts", "import { b } from './b';\n"),
+            ("src/b.ts", "import { a } from './a';\n"),
+        ];
+        let sources: Vec<(PathBuf, &str)> = files
+            .iter()
+            .map(|(path, source)| (PathBuf::from(path), *source))
+            .collect();
+        let mut analyzer = CircularDependencyAnalyzer::in_memory();
+        analyzer.build_graph_in_memory(&sources, &SourceMap::default());
+        let cycles = analyzer.detect_cycles();
+        assert_eq!(cycles.len(), 2);
+
+        let barrel = cycles.iter().find(|cycle| cycle.via_barrel).unwrap();
+        assert_eq!(
+            barrel.cycle,
+            [
+                "src/orders/orders.service.ts",
+                "src/users/index.ts",
+                "src/users/users.service.ts",
+                "src/orders/orders.service.ts",
+            ]
+        );
+        assert!(barrel.description.contains("barrel"));
+
+        // Un ciclo de imports directos no pasa por ningún barrel
+        let plain = cycles.iter().find(|cycle| !cycle.via_barrel).unwrap();
+        assert_eq!(plain.cycle, ["src/a.ts", "src/b.ts", "src/a.ts"]);
+        assert!(!plain.description.contains("barrel"));
+    }
+
+    #[test]
+    fn cycles_between_referenced_projects_are_detected() {
+        let root = temp_project(
+            "project-references",
+            &[
+                (
+                    "tsconfig.json",
+                    "{\n  // monorepo\n  \"references\": [{ \"path\": \"./packages/core\" }, { \"path\": \"./packages/app\" },]\n}\n",
+                ),
+                (
+                    "packages/core/tsconfig.json",
+                    "{ \"compilerOptions\": { \"composite\": true, \"rootDir\": \"src\", \"outDir\": \"dist\" } }",
+                ),
+                ("packages/core/package.json", "{ \"name\": \"@org/core\" }"),
+                (
+                    "packages/core/src/user.ts",
+                    "import { Order } from '../../app/dist/orders';\nexport class User {}\n",
+                ),
+                (
+                    "packages/app/tsconfig.json",
+                    "{ \"compilerOptions\": { \"outDir\": \"dist\" }, \"references\": [{ \"path\": \"../core\" }] }",
+                ),
+                (
+                    "packages/app/src/orders.ts",
+                    "import { User } from '@org/core/user';\nexport class Order {}\n",
+                ),
+            ],
+        );
+        let files = [
+            root.join("packages/core/src/user.ts"),
+            root.join("packages/app/src/orders.ts"),
+        ];
+
+        // Ni el outDir compilado ni el nombre del paquete son rutas de fuente
+        let cycles = analyze_circular_dependencies(&files, &root, &SourceMap::default());
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(
+            cycles[0].cycle,
+            [
+                "packages/app/src/orders.ts",
+                "packages/core/src/user.ts",
+                "packages/app/src/orders.ts",
+            ]
+        );
+    }
+
+    #[test]
+    fn coupling_metrics_count_distinct_importers_and_imports() {
+        let files = [
+            (
+                "src/a.ts",
+                "import type { S } from './shared';\nimport { s } from './shared';\nimport { b } from './b';\n",
+            ),
+            ("src/b.ts", "import { s } from './shared';\n"),
+            ("src/c.ts", "import { s } from './shared';\n"),
+            ("src/shared.ts", "export const s = 1;\n"),
+            ("src/isolated.ts", "export const i = 1;\n"),
+        ];
+        let sources: Vec<(PathBuf, &str)> = files
+            .iter()
+            .map(|(path, source)| (PathBuf::from(path), *source))
+            .collect();
+        let mut analyzer = CircularDependencyAnalyzer::in_memory();
+        analyzer.build_graph_in_memory(&sources, &SourceMap::default());
+
+        let coupling = analyzer.coupling_metrics();
+        let metrics: Vec<(&str, usize, usize, f64)> = coupling
+            .iter()
+            .map(|m| (m.module.as_str(), m.fan_in, m.fan_out, m.instability))
+            .collect();
+        // El aislado no aparece y los dos imports de shared desde a cuentan una vez
+        assert_eq!(
+            metrics,
+            [
+                ("src/shared.ts", 3, 0, 0.0),
+                ("src/a.ts", 0, 2, 1.0),
+                ("src/b.ts", 1, 1, 0.5),
+                ("src/c.ts", 0, 1, 1.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn bundler_asset_imports_are_neither_edges_nor_unresolved() {
+        let source = "import styles from './a.css?inline';\nimport icon from './icon.svg?raw';\nimport { b } from './b?worker#main';\n";
+        let root = temp_project(
+            "asset-imports",
+            &[
+                ("src/app.ts", source),
+                ("src/a.css", ".a {}\n"),
+                ("src/icon.svg", "<svg/>\n"),
+                ("src/b.ts", "export const b = 1;\n"),
+            ],
+        );
+        let app = root.join("src/app.ts");
+        let cm = SourceMap::default();
+
+        let mut analyzer = CircularDependencyAnalyzer::new(&root);
+        analyzer.build_graph(&[app.clone(), root.join("src/b.ts")], &cm);
+        // Solo el módulo con sufijo de bundler es una arista, ya sin el sufijo
+        assert_eq!(analyzer.graph["src/app.ts"], ["src/b.ts"]);
+
+        let ctx = crate::config::LinterContext::for_tests(Default::default());
+        assert!(crate::analyzer::find_unresolved_imports(&cm, &app, &ctx)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn subpath_imports_form_graph_edges() {
+        let root = temp_project(
+            "subpath-imports",
+            &[
+                (
+                    "package.json",
+                    r##"{ "imports": { "#domain/*": "./src/domain/*.ts", "#app": { "types": "./dist/app.d.ts", "import": "./src/app/index.ts" } } }"##,
+                ),
+                (
+                    "src/domain/user.ts",
+                    "import { App } from '#app';\nexport class User {}\n",
+                ),
+                (
+                    "src/app/index.ts",
+                    "import { User } from '#domain/user';\nexport class App {}\n",
+                ),
+            ],
+        );
+        let files = [
+            root.join("src/domain/user.ts"),
+            root.join("src/app/index.ts"),
+        ];
+
+        let cycles = analyze_circular_dependencies(&files, &root, &SourceMap::default());
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(
+            cycles[0].cycle,
+            ["src/app/index.ts", "src/domain/user.ts", "src/app/index.ts"]
+        );
+    }
+
+    #[test]
+    fn cycle_length_limits_filter_only_the_report() {
+        let files = [
+            ("src/a.ts", "import { b } from './b';\n"),
+            ("src/b.ts", "import { a } from './a';\n"),
+            ("src/p.ts", "import { q } from './q';\n"),
+            ("src/q.ts", "import { r } from './r';\n"),
+            ("src/r.ts", "import { s } from './s';\n"),
+            ("src/s.ts", "import { t } from './t';\n"),
+            ("src/t.ts", "import { p } from './p';\n"),
+        ];
+        let sources: Vec<(PathBuf, &str)> = files
+            .iter()
+            .map(|(path, source)| (PathBuf::from(path), *source))
+            .collect();
+        let mut analyzer = CircularDependencyAnalyzer::in_memory();
+        analyzer.build_graph_in_memory(&sources, &SourceMap::default());
+        let lengths = |cycles: &[CircularDependency]| {
+            let mut lengths: Vec<usize> = cycles.iter().map(|c| c.module_count()).collect();
+            lengths.sort();
+            lengths
+        };
+
+        // La detección siempre es completa
+        let cycles = analyzer.detect_cycles();
+        assert_eq!(lengths(&cycles), [2, 5]);
+
+        let settings = |min_cycle_length, max_cycle_length| RuleSettings {
+            min_cycle_length,
+            max_cycle_length,
+            ..RuleSettings::default()
+        };
+        let short = filter_by_length(cycles.clone(), &settings(1, 3));
+        assert_eq!(lengths(&short), [2]);
+        let long = filter_by_length(cycles.clone(), &settings(3, 0));
+        assert_eq!(lengths(&long), [5]);
+        assert_eq!(lengths(&filter_by_length(cycles, &settings(1, 0))), [2, 5]);
+    }
 }
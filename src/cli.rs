@@ -1,9 +1,17 @@
 /// Módulo CLI - Funciones relacionadas con la interfaz de línea de comandos
-
+use crate::config::RuleLevel;
+use crate::rules::RuleMeta;
+use miette::{Diagnostic, IntoDiagnostic};
 use std::env;
+use std::io::{self, Write};
+use std::path::Path;
+use thiserror::Error;
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// Límite por defecto de archivos a analizar (protege contra rutas como `~` o `/`)
+const DEFAULT_MAX_FILES: usize = 10_000;
+
 /// Argumentos procesados de la línea de comandos
 #[derive(Debug, Clone)]
 pub struct CliArgs {
@@ -13,6 +21,205 @@ pub struct CliArgs {
     pub watch_mode: bool,
     /// Activar modo fix (auto-reparación con IA)
     pub fix_mode: bool,
+    /// Con --fix, mostrar el diff de cada fix sin escribir archivos (--fix-dry-run)
+    pub fix_dry_run: bool,
+    /// Regenerar architect.json con el descubrimiento asistido por IA (--ai)
+    pub ai_init: bool,
+    /// Consultar todos los modelos de IA a la vez y usar la primera respuesta válida
+    pub ai_race: bool,
+    /// Confianza mínima (0 a 1) para aplicar o marcar por defecto una regla de la IA
+    pub ai_min_confidence: Option<f32>,
+    /// Sin IA ni red (--offline, o automáticamente con la variable CI salvo con --non-interactive)
+    pub offline: bool,
+    /// Sin preguntas: la IA se configura con variables ARCHITECT_AI_* (--non-interactive)
+    pub non_interactive: bool,
+    /// Reportar imports relativos que no resuelven a ningún archivo
+    pub report_unresolved: bool,
+    /// Reportar reglas de forbidden_imports muertas en lugar de analizar (--audit-config)
+    pub audit_config: bool,
+    /// Proponer forbidden_imports a partir de los cruces de capas actuales (--suggest-rules)
+    pub suggest_rules: bool,
+    /// Imprimir la configuración efectiva (con el preset del framework) y salir
+    pub print_config: bool,
+    /// Explicar esta regla del catálogo y salir (`explain <regla>`)
+    pub explain: Option<String>,
+    /// Ejecutar solo esta fase del análisis (--only)
+    pub only_phase: Option<Phase>,
+    /// Fases a omitir (--skip)
+    pub skip_phases: Vec<Phase>,
+    /// Analizar solo lo cambiado desde esta referencia git (--since)
+    pub since: Option<String>,
+    /// Archivo donde escribir las métricas de la ejecución (--metrics)
+    pub metrics_path: Option<String>,
+    /// Máximo de archivos a recolectar antes de pedir confirmación (--max-files)
+    pub max_files: usize,
+    /// Máximo de hallazgos que se muestran por archivo; el resto se resume en una línea
+    pub max_violations_per_file: Option<usize>,
+    /// Extensiones a analizar, reemplaza a `extensions` de architect.json (--extensions)
+    pub extensions: Option<Vec<String>>,
+    /// Niveles por regla que se superponen al mapa `rules` de architect.json (--rule)
+    pub rule_overrides: Vec<(String, RuleLevel)>,
+    /// Agrupar hallazgos idénticos (misma regla y mensaje) en una sola entrada (--dedupe)
+    pub dedupe: bool,
+    /// Explorar los hallazgos en una interfaz de terminal al terminar (--tui)
+    pub tui: bool,
+    /// Mostrar rutas absolutas en lugar de relativas a la raíz del proyecto
+    pub absolute_paths: bool,
+    /// Imprimir fan-in, fan-out e inestabilidad de los módulos más acoplados (--graph-metrics)
+    pub graph_metrics: bool,
+    /// Medir el tiempo de cada fase y de los archivos más lentos (--profile)
+    pub profile: bool,
+    /// Listar los N archivos con mayor puntuación compuesta de métricas (--hotspots)
+    pub hotspots: Option<usize>,
+    /// Resumir la salud del proyecto en una nota de A a F (--grade)
+    pub grade: bool,
+    /// Reportar los archivos .ts fuera de todas las capas del patrón (--require-layer)
+    pub require_layer: bool,
+    /// Salir con 2 si solo hay advertencias y con 3 si el análisis no se completó
+    pub detailed_exit_codes: bool,
+    /// Archivo con la lista de rutas a analizar, o `-` para stdin (--files-from)
+    pub files_from: Option<String>,
+    /// Archivo donde guardar el reporte de violaciones en JSON (--report)
+    pub report_path: Option<String>,
+    /// Reporte previo o referencia git contra la que comparar (--compare-to)
+    pub compare_to: Option<String>,
+    /// Cuándo falla la ejecución con --compare-to (--compare-fail-on)
+    pub compare_fail_on: CompareFailOn,
+    /// Formato de salida de las violaciones (--format)
+    pub format: OutputFormat,
+    /// Proyecto empaquetado (.zip, .tar, .tar.gz) a analizar sin extraer (--archive)
+    pub archive: Option<String>,
+    /// Archivo donde guardar el progreso para reanudar un análisis interrumpido (--checkpoint)
+    pub checkpoint: Option<String>,
+    /// Carpeta donde escribir los artefactos con nombres convencionales (--output-dir)
+    pub output_dir: Option<String>,
+    /// Violaciones en SARIF (report.sarif de --output-dir)
+    pub sarif_path: Option<String>,
+    /// Violaciones en una página HTML (report.html de --output-dir)
+    pub html_path: Option<String>,
+    /// Grafo de dependencias en DOT (graph.dot de --output-dir)
+    pub graph_path: Option<String>,
+}
+
+/// Fases del análisis en modo normal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    /// Reglas por archivo (imports prohibidos, longitud, complejidad...)
+    Rules,
+    /// Detección de dependencias cíclicas
+    Circular,
+}
+
+impl Phase {
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "rules" => Some(Phase::Rules),
+            "circular" => Some(Phase::Circular),
+            _ => None,
+        }
+    }
+}
+
+/// Condición de fallo con --compare-to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareFailOn {
+    /// Solo si se añadieron violaciones nuevas (por defecto)
+    New,
+    /// Si hay cualquier violación, como sin --compare-to
+    Any,
+    /// Nunca: la comparación es solo informativa
+    Never,
+}
+
+impl CompareFailOn {
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "new" => Some(CompareFailOn::New),
+            "any" => Some(CompareFailOn::Any),
+            "never" => Some(CompareFailOn::Never),
+            _ => None,
+        }
+    }
+}
+
+/// Formato de salida de las violaciones
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Diagnósticos legibles con el código señalado (por defecto)
+    Text,
+    /// Una fila por violación en stdout, sin banner ni resúmenes
+    Csv,
+    /// La salida de texto más anotaciones de GitHub Actions (solo las nuevas con --compare-to)
+    Github,
+    /// Un documento SARIF 2.1.0 en stdout (solo las violaciones nuevas con --compare-to)
+    Sarif,
+}
+
+impl OutputFormat {
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "text" => Some(OutputFormat::Text),
+            "csv" => Some(OutputFormat::Csv),
+            "github" => Some(OutputFormat::Github),
+            "sarif" => Some(OutputFormat::Sarif),
+            _ => None,
+        }
+    }
+}
+
+/// Parsea `<id>=<off|warn|error>` de --rule
+fn parse_rule_override(value: &str) -> Option<(String, RuleLevel)> {
+    let (id, level) = value.split_once('=')?;
+    let level = match level.trim() {
+        "off" => RuleLevel::Off,
+        "warn" => RuleLevel::Warn,
+        "error" => RuleLevel::Error,
+        _ => return None,
+    };
+    let id = id.trim();
+    (!id.is_empty()).then(|| (id.to_string(), level))
+}
+
+impl CliArgs {
+    /// Indica si la fase debe ejecutarse según --only/--skip
+    pub fn runs_phase(&self, phase: Phase) -> bool {
+        if let Some(only) = self.only_phase {
+            return only == phase;
+        }
+        !self.skip_phases.contains(&phase)
+    }
+
+    /// Indica si hay que reunir las violaciones para --report, --output-dir, --compare-to o --format csv/github/sarif
+    pub fn wants_report(&self) -> bool {
+        self.report_path.is_some()
+            || self.sarif_path.is_some()
+            || self.html_path.is_some()
+            || self.compare_to.is_some()
+            || self.format != OutputFormat::Text
+    }
+
+    /// Con --format csv se escriben las filas del CSV en stdout
+    pub fn is_csv(&self) -> bool {
+        self.format == OutputFormat::Csv
+    }
+
+    /// Con --format csv o sarif, stdout solo lleva el documento: los mensajes
+    /// legibles se omiten o van a stderr
+    pub fn is_machine_output(&self) -> bool {
+        matches!(self.format, OutputFormat::Csv | OutputFormat::Sarif)
+    }
+}
+
+#[cfg(test)]
+impl CliArgs {
+    /// Argumentos como si se pasaran en la línea de comandos, sin el binario
+    pub fn for_tests(args: &[&str]) -> Self {
+        let args = std::iter::once("architect-linter")
+            .chain(args.iter().copied())
+            .map(String::from)
+            .collect();
+        parse_args(args).expect("los argumentos de test no deben ser un comando")
+    }
 }
 
 /// Muestra la ayuda del CLI
@@ -23,15 +230,89 @@ pub fn print_help() {
     println!();
     println!("USO:");
     println!("  architect-linter [OPCIONES] [RUTA]");
+    println!("  architect-linter <COMANDO>");
+    println!();
+    println!("COMANDOS:");
+    println!("  schema    Emite el JSON Schema de architect.json con el catálogo de reglas");
+    println!("  explain <REGLA>  Explica una regla con ejemplos y su configuración");
     println!();
     println!("ARGUMENTOS:");
     println!("  [RUTA]    Ruta del proyecto a analizar (opcional, modo interactivo si se omite)");
+    println!("            También acepta un glob de archivos, ej: 'src/**/*.controller.ts'");
     println!();
     println!("OPCIONES:");
     println!("  -h, --help       Muestra esta ayuda");
     println!("  -v, --version    Muestra la versión");
     println!("  -w, --watch      Modo watch: observa cambios y re-analiza automáticamente");
     println!("  -f, --fix        Modo fix: sugiere y aplica correcciones automáticas con IA");
+    println!("  --fix-dry-run    Como --fix, pero imprime el diff de cada corrección sin escribir archivos");
+    println!("  --ai             Regenera architect.json con las reglas sugeridas por la IA");
+    println!("  --ai-race        Consulta todos los modelos de IA en paralelo y usa el más rápido (más llamadas a la API)");
+    println!("  --ai-min-confidence <N>  Solo aplica (--non-interactive) o marca las reglas de la IA con confianza >= N (0 a 1)");
+    println!("  --offline        Sin IA ni red: crea un architect.json por defecto si falta (automático con CI=true)");
+    println!("  --non-interactive  Sin preguntas: la IA se toma de ARCHITECT_AI_PROVIDER, ARCHITECT_AI_URL,");
+    println!("                   ARCHITECT_AI_KEY y ARCHITECT_AI_MODEL (con CI=true no activa --offline)");
+    println!("  --report-unresolved  Reporta imports relativos que no resuelven a ningún archivo");
+    println!(
+        "  --print-config   Muestra la configuración efectiva (incluye el preset del framework)"
+    );
+    println!(
+        "  --audit-config   Reporta reglas de forbidden_imports cuyo origen o destino no existen"
+    );
+    println!("  --suggest-rules  Propone forbidden_imports según los imports que hoy cruzan capas del patrón");
+    println!("  --only <FASE>    Ejecuta solo una fase: rules | circular");
+    println!("  --skip <FASE>    Omite una fase: rules | circular");
+    println!(
+        "  --since <REF>    Analiza solo archivos cambiados desde un tag/commit y compara la deuda"
+    );
+    println!("  --extensions <LISTA>  Extensiones a analizar separadas por coma (ej: ts,tsx)");
+    println!("  --rule <ID>=<NIVEL>  Cambia el nivel de una regla: off | warn | error (repetible)");
+    println!(
+        "  --files-from <RUTA>  Analiza solo los archivos listados (uno por línea, '-' para stdin)"
+    );
+    println!("  --metrics <RUTA> Escribe un JSON compacto con métricas de la ejecución");
+    println!("  --report <RUTA>  Guarda las violaciones en JSON (para usarlas con --compare-to)");
+    println!("  --output-dir <DIR>  Escribe report.json, report.sarif, report.html, metrics.json");
+    println!("                   y graph.dot (con la fase de ciclos) en DIR; la crea si no existe");
+    println!(
+        "  --compare-to <REF|REPORTE>  Muestra las violaciones nuevas, corregidas y sin cambios"
+    );
+    println!("                   respecto a un reporte de --report o a una referencia git");
+    println!(
+        "  --compare-fail-on <MODO>  Con --compare-to, falla con: new (default) | any | never"
+    );
+    println!("  --format <FORMATO>  Formato de salida: text (default) | csv (file,line,column,rule_id,severity,message,end_line,end_column)");
+    println!("                   | github (anotaciones de GitHub Actions; con --compare-to, solo las violaciones nuevas)");
+    println!("                   | sarif (SARIF 2.1.0 en stdout; con --compare-to, solo las violaciones nuevas)");
+    println!("  --dedupe         Agrupa hallazgos idénticos (código generado) mostrando un ejemplo y el total");
+    println!("  --tui            Explora los hallazgos en una interfaz de terminal (filtros por regla/severidad)");
+    println!("  --absolute-paths Muestra rutas absolutas en lugar de relativas al proyecto");
+    println!(
+        "  --graph-metrics  Muestra fan-in, fan-out e inestabilidad de los módulos más acoplados"
+    );
+    println!(
+        "  --profile        Muestra al final cuánto tardó cada fase y los archivos más lentos"
+    );
+    println!("  --hotspots [N]   Lista los N archivos (default: {}) que más combinan longitud, complejidad,", crate::hotspots::DEFAULT_HOTSPOTS_LISTED);
+    println!("                   fan-out y exports: candidatos a refactor");
+    println!(
+        "  --grade          Resume la salud del proyecto en una nota de A a F y lo que más la baja"
+    );
+    println!("  --require-layer  Con patrón Hexagonal o Clean, falla si un archivo .ts no está en ninguna capa");
+    println!("  --checkpoint <RUTA>  Guarda el progreso y, si se interrumpe, la siguiente ejecución solo analiza lo que falta");
+    println!(
+        "  --archive <RUTA> Analiza un .zip, .tar o .tar.gz sin extraerlo (usa su architect.json)"
+    );
+    println!(
+        "  --max-files <N>  Límite de archivos a recorrer antes de detenerse (default: {})",
+        DEFAULT_MAX_FILES
+    );
+    println!("  --max-violations-per-file <N>  Muestra como máximo N hallazgos por archivo (código generado);");
+    println!(
+        "                   el resto se resume en una línea y sigue contando para el resultado"
+    );
+    println!("  --detailed-exit-codes  Sale con 2 si solo hay advertencias y con 3 si el análisis no se completó");
+    println!("  --list-exit-codes  Muestra los códigos de salida y termina");
     println!();
     println!("EJEMPLOS:");
     println!("  architect-linter                    # Modo interactivo");
@@ -39,6 +320,27 @@ pub fn print_help() {
     println!("  architect-linter /ruta/a/proyecto   # Analizar proyecto específico");
     println!("  architect-linter --watch .          # Modo watch en directorio actual");
     println!("  architect-linter --fix .            # Analizar y auto-corregir con IA");
+    println!(
+        "  architect-linter --fix-dry-run . > fixes.diff  # Revisar los fixes antes de aplicarlos"
+    );
+    println!("  architect-linter --fix --ai-race .  # Auto-corregir usando el modelo que responda primero");
+    println!("  architect-linter --report-unresolved .  # Detectar imports rotos");
+    println!("  architect-linter 'src/**/*.controller.ts'  # Solo los archivos del glob (entre comillas)");
+    println!("  architect-linter --only circular .  # Solo dependencias cíclicas");
+    println!("  architect-linter --only circular --graph-metrics .  # Acoplamiento entre módulos");
+    println!("  architect-linter --hotspots 20 .    # Los 20 archivos a refactorizar primero");
+    println!("  architect-linter --rule max-lines=warn --rule import-order=off .");
+    println!("  architect-linter --since v3.1.0 .   # Deuda añadida desde la última release");
+    println!("  architect-linter --suggest-rules .  # Reglas iniciales para un proyecto existente");
+    println!("  architect-linter --compare-to main .  # Violaciones que introduce la rama actual");
+    println!("  architect-linter --format csv . > violaciones.csv");
+    println!(
+        "  architect-linter --output-dir reports/ .  # reports/report.json y reports/metrics.json"
+    );
+    println!("  architect-linter --archive build/fuentes.tar.gz");
+    println!("  git diff --name-only main | architect-linter --files-from - .");
+    println!("  architect-linter schema > architect.schema.json");
+    println!("  architect-linter explain max-lines");
     println!();
     println!("DOCUMENTACIÓN:");
     println!("  https://github.com/sergio/architect-linter");
@@ -49,14 +351,149 @@ pub fn print_version() {
     println!("architect-linter {}", VERSION);
 }
 
+/// Emite el JSON Schema de architect.json junto con los metadatos de las reglas.
+/// El catálogo se incluye como palabra clave extendida (`x-architect-rules`),
+/// por lo que el documento sigue siendo un schema válido para los editores.
+pub fn print_schema() {
+    let mut schema = crate::config::config_json_schema();
+    if let Some(obj) = schema.as_object_mut() {
+        obj.insert(
+            "x-architect-rules".to_string(),
+            serde_json::to_value(crate::rules::RULES).unwrap_or_default(),
+        );
+    }
+
+    match serde_json::to_string_pretty(&schema) {
+        Ok(json) => println!("{}", json),
+        Err(e) => eprintln!("❌ No se pudo generar el schema: {}", e),
+    }
+}
+
+/// Explica una regla del catálogo: por qué importa, un ejemplo incorrecto y otro
+/// correcto, cómo corregirla y qué claves de architect.json la controlan
+pub fn print_explain(rule_id: &str) -> miette::Result<()> {
+    write_explain(&mut std::io::stdout().lock(), rule_id)
+}
+
+pub(crate) fn write_explain(out: &mut impl Write, rule_id: &str) -> miette::Result<()> {
+    let rule = crate::rules::RULES
+        .iter()
+        .find(|rule| rule.id == rule_id)
+        .ok_or_else(|| UnknownRule {
+            rule_id: rule_id.to_string(),
+            available: crate::rules::RULES
+                .iter()
+                .map(|rule| rule.id)
+                .collect::<Vec<_>>()
+                .join(", "),
+        })?;
+
+    write_rule(out, rule).into_diagnostic()
+}
+
+fn write_rule(out: &mut impl Write, rule: &RuleMeta) -> io::Result<()> {
+    let indent = |code: &str| {
+        code.lines()
+            .map(|line| format!("   {}", line))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    writeln!(out, "📖 {} ({})", rule.id, rule.severity)?;
+    writeln!(out)?;
+    writeln!(out, "{}", rule.description)?;
+    writeln!(out)?;
+    writeln!(out, "❓ Por qué importa:")?;
+    writeln!(out, "   {}", rule.rationale)?;
+    writeln!(out)?;
+    writeln!(out, "❌ Incorrecto:")?;
+    writeln!(out, "{}", indent(rule.bad_example))?;
+    writeln!(out)?;
+    writeln!(out, "✅ Correcto:")?;
+    writeln!(out, "{}", indent(rule.good_example))?;
+    writeln!(out)?;
+    writeln!(out, "💡 Cómo corregirlo:")?;
+    writeln!(out, "   {}", rule.help)?;
+    writeln!(out)?;
+    if rule.config_keys.is_empty() {
+        writeln!(
+            out,
+            "⚙️  No tiene claves propias en architect.json (se controla con --rule o \"rules\")"
+        )
+    } else {
+        writeln!(
+            out,
+            "⚙️  Configuración en architect.json: {}",
+            rule.config_keys.join(", ")
+        )
+    }
+}
+
+/// `explain` con un id que no está en el catálogo. `main` lo trata como un error de
+/// uso (código 4), no como un análisis fallido
+#[derive(Error, Debug, Diagnostic)]
+#[error("Regla desconocida '{rule_id}'")]
+#[diagnostic(help("Reglas disponibles: {available}"))]
+pub struct UnknownRule {
+    pub rule_id: String,
+    pub available: String,
+}
+
+/// Heurística de CI: la mayoría de proveedores (GitHub Actions, GitLab, CircleCI...)
+/// definen `CI`. Ahí no hay nadie para responder al wizard ni credenciales de IA.
+fn is_ci() -> bool {
+    env::var("CI").is_ok_and(|value| {
+        !value.is_empty() && value != "0" && !value.eq_ignore_ascii_case("false")
+    })
+}
+
 /// Procesa los argumentos de línea de comandos
-/// Retorna None si se procesó un flag especial (--help, --version)
+/// Retorna None si se procesó un flag especial (--help, --version) o un comando (schema)
 /// Retorna Some(CliArgs) si hay que continuar con el análisis
 pub fn process_args() -> Option<CliArgs> {
-    let args: Vec<String> = env::args().collect();
+    parse_args(env::args().collect())
+}
 
+/// Procesa `args` como `process_args` (el primero es el nombre del binario)
+fn parse_args(args: Vec<String>) -> Option<CliArgs> {
     let mut watch_mode = false;
     let mut fix_mode = false;
+    let mut fix_dry_run = false;
+    let mut ai_race = false;
+    let mut ai_init = false;
+    let mut ai_min_confidence: Option<f32> = None;
+    let mut offline = false;
+    let mut non_interactive = false;
+    let mut report_unresolved = false;
+    let mut print_config = false;
+    let mut explain: Option<String> = None;
+    let mut audit_config = false;
+    let mut suggest_rules = false;
+    let mut absolute_paths = false;
+    let mut dedupe = false;
+    let mut tui = false;
+    let mut graph_metrics = false;
+    let mut profile = false;
+    let mut hotspots: Option<usize> = None;
+    let mut grade = false;
+    let mut require_layer = false;
+    let mut detailed_exit_codes = false;
+    let mut only_phase: Option<Phase> = None;
+    let mut skip_phases: Vec<Phase> = Vec::new();
+    let mut since: Option<String> = None;
+    let mut extensions: Option<Vec<String>> = None;
+    let mut rule_overrides: Vec<(String, RuleLevel)> = Vec::new();
+    let mut metrics_path: Option<String> = None;
+    let mut files_from: Option<String> = None;
+    let mut report_path: Option<String> = None;
+    let mut compare_to: Option<String> = None;
+    let mut compare_fail_on = CompareFailOn::New;
+    let mut format = OutputFormat::Text;
+    let mut archive: Option<String> = None;
+    let mut checkpoint: Option<String> = None;
+    let mut output_dir: Option<String> = None;
+    let mut max_files = DEFAULT_MAX_FILES;
+    let mut max_violations_per_file: Option<usize> = None;
     let mut project_path: Option<String> = None;
 
     // Procesar argumentos
@@ -77,6 +514,263 @@ pub fn process_args() -> Option<CliArgs> {
             "--fix" | "-f" => {
                 fix_mode = true;
             }
+            "--fix-dry-run" => {
+                fix_mode = true;
+                fix_dry_run = true;
+            }
+            "--ai" => {
+                ai_init = true;
+            }
+            "--ai-race" => {
+                ai_race = true;
+            }
+            "--ai-min-confidence" => {
+                i += 1;
+                match args.get(i).and_then(|v| v.parse::<f32>().ok()) {
+                    Some(min) if (0.0..=1.0).contains(&min) => ai_min_confidence = Some(min),
+                    _ => {
+                        eprintln!("❌ --ai-min-confidence requiere un número entre 0 y 1");
+                        std::process::exit(crate::exit_codes::USAGE);
+                    }
+                }
+            }
+            "--offline" => {
+                offline = true;
+            }
+            "--non-interactive" => {
+                non_interactive = true;
+            }
+            "--report-unresolved" => {
+                report_unresolved = true;
+            }
+            "--print-config" => {
+                print_config = true;
+            }
+            "--audit-config" => {
+                audit_config = true;
+            }
+            "--suggest-rules" => {
+                suggest_rules = true;
+            }
+            "--absolute-paths" => {
+                absolute_paths = true;
+            }
+            "--dedupe" => {
+                dedupe = true;
+            }
+            "--tui" => {
+                tui = true;
+            }
+            "--graph-metrics" => {
+                graph_metrics = true;
+            }
+            "--profile" => {
+                profile = true;
+            }
+            "--grade" => {
+                grade = true;
+            }
+            "--require-layer" => {
+                require_layer = true;
+            }
+            "--detailed-exit-codes" => {
+                detailed_exit_codes = true;
+            }
+            "--list-exit-codes" => {
+                crate::exit_codes::print_exit_codes();
+                return None;
+            }
+            "--hotspots" => {
+                // El número es opcional: `--hotspots .` usa el valor por defecto
+                let limit = args.get(i + 1).and_then(|v| v.parse::<usize>().ok());
+                if limit.is_some() {
+                    i += 1;
+                }
+                hotspots = Some(limit.unwrap_or(crate::hotspots::DEFAULT_HOTSPOTS_LISTED));
+            }
+            "--only" | "--skip" => {
+                let flag = args[i].clone();
+                i += 1;
+                let phase = match args.get(i).and_then(|v| Phase::parse(v)) {
+                    Some(phase) => phase,
+                    None => {
+                        eprintln!("❌ {} requiere una fase válida: rules | circular", flag);
+                        std::process::exit(crate::exit_codes::USAGE);
+                    }
+                };
+                if flag == "--only" {
+                    only_phase = Some(phase);
+                } else {
+                    skip_phases.push(phase);
+                }
+            }
+            "--since" => {
+                i += 1;
+                match args.get(i) {
+                    Some(git_ref) => since = Some(git_ref.clone()),
+                    None => {
+                        eprintln!("❌ --since requiere una referencia git (tag, rama o commit)");
+                        std::process::exit(crate::exit_codes::USAGE);
+                    }
+                }
+            }
+            "--metrics" => {
+                i += 1;
+                match args.get(i) {
+                    Some(path) => metrics_path = Some(path.clone()),
+                    None => {
+                        eprintln!("❌ --metrics requiere la ruta del archivo de salida");
+                        std::process::exit(crate::exit_codes::USAGE);
+                    }
+                }
+            }
+            "--rule" => {
+                i += 1;
+                match args.get(i).and_then(|v| parse_rule_override(v)) {
+                    Some(rule_override) => rule_overrides.push(rule_override),
+                    None => {
+                        eprintln!(
+                            "❌ --rule requiere <id>=<off|warn|error>, ej: --rule max-lines=warn"
+                        );
+                        std::process::exit(crate::exit_codes::USAGE);
+                    }
+                }
+            }
+            "--files-from" => {
+                i += 1;
+                match args.get(i) {
+                    Some(source) => files_from = Some(source.clone()),
+                    None => {
+                        eprintln!("❌ --files-from requiere un archivo con rutas o '-' para stdin");
+                        std::process::exit(crate::exit_codes::USAGE);
+                    }
+                }
+            }
+            "--report" => {
+                i += 1;
+                match args.get(i) {
+                    Some(path) => report_path = Some(path.clone()),
+                    None => {
+                        eprintln!("❌ --report requiere la ruta del archivo de salida");
+                        std::process::exit(crate::exit_codes::USAGE);
+                    }
+                }
+            }
+            "--output-dir" => {
+                i += 1;
+                match args.get(i) {
+                    Some(dir) => output_dir = Some(dir.clone()),
+                    None => {
+                        eprintln!("❌ --output-dir requiere la carpeta de salida");
+                        std::process::exit(crate::exit_codes::USAGE);
+                    }
+                }
+            }
+            "--archive" => {
+                i += 1;
+                match args.get(i) {
+                    Some(path) => archive = Some(path.clone()),
+                    None => {
+                        eprintln!("❌ --archive requiere la ruta de un .zip, .tar o .tar.gz");
+                        std::process::exit(crate::exit_codes::USAGE);
+                    }
+                }
+            }
+            "--checkpoint" => {
+                i += 1;
+                match args.get(i) {
+                    Some(path) => checkpoint = Some(path.clone()),
+                    None => {
+                        eprintln!("❌ --checkpoint requiere la ruta del archivo de progreso");
+                        std::process::exit(crate::exit_codes::USAGE);
+                    }
+                }
+            }
+            "--compare-to" => {
+                i += 1;
+                match args.get(i) {
+                    Some(target) => compare_to = Some(target.clone()),
+                    None => {
+                        eprintln!("❌ --compare-to requiere un reporte JSON o una referencia git");
+                        std::process::exit(crate::exit_codes::USAGE);
+                    }
+                }
+            }
+            "--compare-fail-on" => {
+                i += 1;
+                match args.get(i).and_then(|v| CompareFailOn::parse(v)) {
+                    Some(mode) => compare_fail_on = mode,
+                    None => {
+                        eprintln!(
+                            "❌ --compare-fail-on requiere un modo válido: new | any | never"
+                        );
+                        std::process::exit(crate::exit_codes::USAGE);
+                    }
+                }
+            }
+            "--format" => {
+                i += 1;
+                match args.get(i).and_then(|v| OutputFormat::parse(v)) {
+                    Some(value) => format = value,
+                    None => {
+                        eprintln!(
+                            "❌ --format requiere un formato válido: text | csv | github | sarif"
+                        );
+                        std::process::exit(crate::exit_codes::USAGE);
+                    }
+                }
+            }
+            "--max-files" => {
+                i += 1;
+                match args.get(i).and_then(|v| v.parse::<usize>().ok()) {
+                    Some(limit) if limit > 0 => max_files = limit,
+                    _ => {
+                        eprintln!("❌ --max-files requiere un número mayor que 0");
+                        std::process::exit(crate::exit_codes::USAGE);
+                    }
+                }
+            }
+            "--max-violations-per-file" => {
+                i += 1;
+                match args.get(i).and_then(|v| v.parse::<usize>().ok()) {
+                    Some(limit) if limit > 0 => max_violations_per_file = Some(limit),
+                    _ => {
+                        eprintln!("❌ --max-violations-per-file requiere un número mayor que 0");
+                        std::process::exit(crate::exit_codes::USAGE);
+                    }
+                }
+            }
+            "--extensions" => {
+                i += 1;
+                match args.get(i) {
+                    Some(list) => {
+                        extensions = Some(
+                            list.split(',')
+                                .map(|ext| ext.trim().to_string())
+                                .filter(|ext| !ext.is_empty())
+                                .collect(),
+                        )
+                    }
+                    None => {
+                        eprintln!("❌ --extensions requiere una lista, ej: --extensions ts,tsx");
+                        std::process::exit(crate::exit_codes::USAGE);
+                    }
+                }
+            }
+            "schema" if i == 1 => {
+                print_schema();
+                return None;
+            }
+            "explain" if i == 1 => match args.get(2) {
+                Some(rule_id) => {
+                    explain = Some(rule_id.clone());
+                    break;
+                }
+                None => {
+                    eprintln!("❌ explain requiere el id de una regla, ej: explain max-lines");
+                    std::process::exit(crate::exit_codes::USAGE);
+                }
+            },
             _ => {
                 // Si no es un flag, asumimos que es la ruta del proyecto
                 if !args[i].starts_with('-') {
@@ -87,9 +781,93 @@ pub fn process_args() -> Option<CliArgs> {
         i += 1;
     }
 
+    // Con --output-dir, los artefactos sin ruta explícita (--report, --metrics) van a
+    // la carpeta con su nombre convencional
+    let mut sarif_path = None;
+    let mut html_path = None;
+    let mut graph_path = None;
+    if let Some(dir) = &output_dir {
+        let artifact = |name: &str| Path::new(dir).join(name).to_string_lossy().into_owned();
+        report_path.get_or_insert_with(|| artifact("report.json"));
+        metrics_path.get_or_insert_with(|| artifact("metrics.json"));
+        sarif_path = Some(artifact("report.sarif"));
+        html_path = Some(artifact("report.html"));
+        graph_path = Some(artifact("graph.dot"));
+    }
+
+    // En CI se asume --offline, salvo que --non-interactive traiga la IA del entorno
+    let offline = offline || (is_ci() && !non_interactive);
+
     Some(CliArgs {
         project_path,
         watch_mode,
         fix_mode,
+        fix_dry_run,
+        ai_init,
+        ai_race,
+        ai_min_confidence,
+        offline,
+        non_interactive,
+        report_unresolved,
+        print_config,
+        explain,
+        audit_config,
+        suggest_rules,
+        only_phase,
+        skip_phases,
+        since,
+        metrics_path,
+        max_files,
+        max_violations_per_file,
+        extensions,
+        rule_overrides,
+        dedupe,
+        tui,
+        absolute_paths,
+        graph_metrics,
+        profile,
+        hotspots,
+        grade,
+        require_layer,
+        detailed_exit_codes,
+        files_from,
+        report_path,
+        compare_to,
+        compare_fail_on,
+        format,
+        archive,
+        checkpoint,
+        output_dir,
+        sarif_path,
+        html_path,
+        graph_path,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explain_describes_a_known_rule() {
+        let args = CliArgs::for_tests(&["explain", "max-lines"]);
+        assert_eq!(args.explain.as_deref(), Some("max-lines"));
+
+        let mut out = Vec::new();
+        write_explain(&mut out, "max-lines").unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.starts_with("📖 max-lines"), "{}", text);
+        assert!(text.contains("❌ Incorrecto:"));
+        assert!(text.contains("✅ Correcto:"));
+    }
+
+    #[test]
+    fn explain_rejects_an_unknown_rule_as_a_usage_error() {
+        let mut out = Vec::new();
+        let report = write_explain(&mut out, "no-such-rule").unwrap_err();
+        let unknown = report.downcast_ref::<UnknownRule>().unwrap();
+        assert_eq!(unknown.rule_id, "no-such-rule");
+        assert!(unknown.available.contains("max-lines"));
+        assert!(out.is_empty());
+    }
+}
@@ -0,0 +1,293 @@
+//! Comparación de dos ejecuciones (--compare-to): qué violaciones introduce y
+//! cuáles corrige la rama actual respecto a un reporte previo o a una referencia git.
+//!
+//! Las violaciones se emparejan por huella (regla, archivo y mensaje). La línea no
+//! forma parte de la huella para que editar código no relacionado no las convierta
+//! en "nuevas".
+
+use crate::circular::CircularDependency;
+use crate::crowded_dirs::{self, CrowdedDir};
+use crate::rules::{Finding, Severity};
+use crate::unclassified;
+use crate::unused_exports::{self, UnusedExport};
+use miette::{IntoDiagnostic, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// Versión del formato del reporte en disco
+const REPORT_VERSION: u32 = 1;
+
+/// Máximo de violaciones listadas por grupo en el resumen
+const MAX_LISTED: usize = 20;
+
+/// Violación tal como se guarda en el reporte (--report) y se compara
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportEntry {
+    pub fingerprint: String,
+    pub rule_id: String,
+    pub severity: Severity,
+    /// Ruta relativa a la raíz del proyecto
+    pub file: String,
+    pub line: usize,
+    /// Columna (1-based); 0 si no aplica (ciclos, reportes anteriores a este campo)
+    #[serde(default)]
+    pub column: usize,
+    /// Final del código señalado; 0 si no aplica, igual que `column`
+    #[serde(default)]
+    pub end_line: usize,
+    #[serde(default)]
+    pub end_column: usize,
+    pub message: String,
+    /// Sugerencia de corrección de la regla
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub help: Option<String>,
+}
+
+impl ReportEntry {
+    pub fn from_finding(file: &str, finding: &Finding) -> Self {
+        let location = finding.location;
+        let mut entry = Self::new(
+            finding.rule_id,
+            finding.severity,
+            file,
+            location.start_line,
+            &finding.message,
+        );
+        entry.column = location.start_col;
+        entry.end_line = location.end_line;
+        entry.end_column = location.end_col;
+        entry.help = finding.help().map(str::to_string);
+        entry
+    }
+
+    /// Un ciclo se atribuye a su primer archivo, con la ruta completa como mensaje
+    pub fn from_cycle(cycle: &CircularDependency) -> Self {
+        let file = cycle.cycle.first().map(String::as_str).unwrap_or_default();
+        Self::new(
+            "circular-dependency",
+            Severity::Error,
+            file,
+            0,
+            &cycle.cycle.join(" → "),
+        )
+    }
+
+    pub fn from_unused_export(export: &UnusedExport) -> Self {
+        Self::new(
+            unused_exports::RULE_ID,
+            Severity::Warning,
+            &export.file,
+            export.line,
+            &format!("Export sin uso: '{}'", export.name),
+        )
+    }
+
+    /// Una carpeta con demasiados archivos se atribuye a la propia carpeta
+    pub fn from_crowded_dir(crowded: &CrowdedDir) -> Self {
+        Self::new(
+            crowded_dirs::RULE_ID,
+            Severity::Warning,
+            &crowded.dir,
+            0,
+            &format!("La carpeta tiene {} archivos", crowded.files),
+        )
+    }
+
+    /// Un archivo fuera de todas las capas se reporta sin línea
+    pub fn from_unclassified(file: &str) -> Self {
+        Self::new(
+            unclassified::RULE_ID,
+            Severity::Error,
+            file,
+            0,
+            "Archivo sin clasificar: no está en ninguna capa",
+        )
+    }
+
+    fn new(rule_id: &str, severity: Severity, file: &str, line: usize, message: &str) -> Self {
+        Self {
+            fingerprint: fingerprint(rule_id, file, message),
+            rule_id: rule_id.to_string(),
+            severity,
+            file: file.to_string(),
+            line,
+            column: 0,
+            end_line: 0,
+            end_column: 0,
+            message: message.to_string(),
+            help: None,
+        }
+    }
+}
+
+fn fingerprint(rule_id: &str, file: &str, message: &str) -> String {
+    format!(
+        "{:016x}",
+        fnv1a([rule_id, file, message].join("\0").as_bytes())
+    )
+}
+
+/// FNV-1a de 64 bits: estable entre versiones de Rust y plataformas
+pub fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for &byte in bytes {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash
+}
+
+/// Reporte de violaciones de una ejecución, tal como se escribe en disco
+#[derive(Debug, Serialize, Deserialize)]
+struct ViolationReport {
+    version: u32,
+    violations: Vec<ReportEntry>,
+}
+
+/// Escribe el reporte de violaciones (--report) para compararlo más adelante
+pub fn write_report(path: &Path, entries: &[ReportEntry]) -> Result<()> {
+    let report = ViolationReport {
+        version: REPORT_VERSION,
+        violations: entries.to_vec(),
+    };
+    let json = serde_json::to_string_pretty(&report).into_diagnostic()?;
+    fs::write(path, json).into_diagnostic()
+}
+
+/// Lee un reporte generado con --report
+pub fn load_report(path: &Path) -> Result<Vec<ReportEntry>> {
+    let content = fs::read_to_string(path).into_diagnostic()?;
+    let report: ViolationReport = serde_json::from_str(&content).map_err(|e| {
+        miette::miette!(
+            "{} no es un reporte de violaciones válido: {}",
+            path.display(),
+            e
+        )
+    })?;
+    Ok(report.violations)
+}
+
+/// Partición de las violaciones actuales respecto a las anteriores
+pub struct Comparison {
+    pub added: Vec<ReportEntry>,
+    pub removed: Vec<ReportEntry>,
+    pub unchanged: Vec<ReportEntry>,
+}
+
+impl Comparison {
+    /// Errores nuevos (las advertencias nuevas no hacen fallar la comparación)
+    pub fn added_errors(&self) -> usize {
+        self.added
+            .iter()
+            .filter(|entry| entry.severity == Severity::Error)
+            .count()
+    }
+}
+
+/// Compara por huella. Las huellas repetidas se emparejan una a una, así que
+/// una segunda aparición de la misma violación cuenta como nueva.
+pub fn compare(previous: Vec<ReportEntry>, current: Vec<ReportEntry>) -> Comparison {
+    let mut pending: HashMap<String, Vec<ReportEntry>> = HashMap::new();
+    for entry in previous {
+        pending
+            .entry(entry.fingerprint.clone())
+            .or_default()
+            .push(entry);
+    }
+
+    let mut added = Vec::new();
+    let mut unchanged = Vec::new();
+    for entry in current {
+        match pending
+            .get_mut(&entry.fingerprint)
+            .and_then(|list| list.pop())
+        {
+            Some(_) => unchanged.push(entry),
+            None => added.push(entry),
+        }
+    }
+
+    let mut removed: Vec<ReportEntry> = pending.into_values().flatten().collect();
+    for list in [&mut added, &mut removed, &mut unchanged] {
+        list.sort_by(|a, b| a.file.cmp(&b.file).then(a.line.cmp(&b.line)));
+    }
+
+    Comparison {
+        added,
+        removed,
+        unchanged,
+    }
+}
+
+/// Imprime el resumen de la comparación contra `baseline`
+pub fn print_comparison(comparison: &Comparison, baseline: &str) {
+    println!("\n🔀 Comparación con '{}':", baseline);
+    println!(
+        "   ➕ Nuevas: {} | ➖ Corregidas: {} | ⏸️  Sin cambios: {}",
+        comparison.added.len(),
+        comparison.removed.len(),
+        comparison.unchanged.len()
+    );
+
+    print_group("➕ Violaciones nuevas", &comparison.added);
+    print_group("➖ Violaciones corregidas", &comparison.removed);
+}
+
+fn print_group(title: &str, entries: &[ReportEntry]) {
+    if entries.is_empty() {
+        return;
+    }
+
+    println!("\n{}:", title);
+    for entry in entries.iter().take(MAX_LISTED) {
+        let location = if entry.line > 0 {
+            format!("{}:{}", entry.file, entry.line)
+        } else {
+            entry.file.clone()
+        };
+        println!("   {} [{}] {}", location, entry.rule_id, entry.message);
+    }
+    if entries.len() > MAX_LISTED {
+        println!("   ... y {} más", entries.len() - MAX_LISTED);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(rule_id: &str, file: &str, line: usize, message: &str) -> ReportEntry {
+        ReportEntry::new(rule_id, Severity::Error, file, line, message)
+    }
+
+    #[test]
+    fn compares_two_json_reports() {
+        let dir =
+            std::env::temp_dir().join(format!("architect-linter-compare-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let before = dir.join("before.json");
+        let after = dir.join("after.json");
+
+        let kept = entry("max-imports", "src/a.ts", 3, "Demasiados imports");
+        let fixed = entry("no-default-export", "src/b.ts", 1, "Export default");
+        let introduced = entry("no-magic-numbers", "src/c.ts", 8, "Número mágico 42");
+        write_report(&before, &[kept.clone(), fixed]).unwrap();
+        // La violación que se mantiene cambió de línea: sigue siendo la misma
+        let mut moved = kept;
+        moved.line = 10;
+        write_report(&after, &[moved, introduced]).unwrap();
+
+        let comparison = compare(load_report(&before).unwrap(), load_report(&after).unwrap());
+        let rules = |entries: &[ReportEntry]| -> Vec<String> {
+            entries.iter().map(|entry| entry.rule_id.clone()).collect()
+        };
+        assert_eq!(rules(&comparison.added), ["no-magic-numbers"]);
+        assert_eq!(rules(&comparison.removed), ["no-default-export"]);
+        assert_eq!(rules(&comparison.unchanged), ["max-imports"]);
+        assert_eq!(comparison.added_errors(), 1);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
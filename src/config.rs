@@ -2,9 +2,12 @@
 
 use crate::ai::SuggestedRule;
 use miette::{Diagnostic, IntoDiagnostic, Result, SourceSpan};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 use thiserror::Error;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -28,7 +31,8 @@ impl Framework {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[allow(clippy::upper_case_acronyms)] // "MVC" es el valor que se escribe en architect.json
 pub enum ArchPattern {
     Hexagonal,
     Clean,
@@ -36,12 +40,341 @@ pub enum ArchPattern {
     Ninguno,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ForbiddenRule {
     pub from: String,
     pub to: String,
 }
 
+/// Regla de lista blanca: los archivos en `from` solo pueden importar de `allow`
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AllowedImportRule {
+    pub from: String,
+    pub allow: Vec<String>,
+}
+
+/// Patrón de arquitectura que gobierna una parte del proyecto (monorepos con
+/// núcleo Clean y capa de entrega MVC, por ejemplo)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PatternScope {
+    pub pattern: ArchPattern,
+    /// Carpetas a las que aplica (ej: "src/core/**")
+    pub paths: Vec<String>,
+}
+
+/// Regla no-generic-error: capas donde no se permite lanzar errores nativos
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct NoGenericErrorRule {
+    /// Carpetas a las que aplica (ej: "src/domain/")
+    pub paths: Vec<String>,
+    /// Errores nativos permitidos igualmente (ej: "TypeError")
+    #[serde(default)]
+    pub allow: Vec<String>,
+}
+
+/// Regla no-sync-fs: capas que atienden peticiones y no deben bloquear el event loop
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct NoSyncFsRule {
+    /// Carpetas a las que aplica (ej: "src/controllers/", "src/services/")
+    pub paths: Vec<String>,
+    /// Métodos considerados bloqueantes (por defecto, los *Sync de fs)
+    #[serde(default = "crate::rules::no_sync_fs::default_sync_methods")]
+    pub methods: Vec<String>,
+}
+
+/// Regla no-browser-globals: solo la capa de presentación puede tocar el navegador
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct NoBrowserGlobalsRule {
+    /// Carpetas de presentación donde se permiten (ej: "src/components/", "src/pages/")
+    pub allowed_paths: Vec<String>,
+    /// Globals revisados (por defecto window, document, localStorage, sessionStorage y navigator)
+    #[serde(default = "crate::rules::no_browser_globals::default_browser_globals")]
+    pub globals: Vec<String>,
+}
+
+/// Regla no-concrete-events: el dominio publica eventos a través de una abstracción
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct NoConcreteEventsRule {
+    /// Carpetas a las que aplica (ej: "src/domain/")
+    pub paths: Vec<String>,
+    /// Clases que no se pueden instanciar (vacío = las del framework, ej: EventEmitter)
+    #[serde(default)]
+    pub symbols: Vec<String>,
+    /// Módulos de bus de eventos que no se pueden importar (vacío = los del framework)
+    #[serde(default)]
+    pub modules: Vec<String>,
+}
+
+/// Regla no-direct-orm: capas que deben depender de un puerto de repositorio
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct NoDirectOrmRule {
+    /// Carpetas a las que aplica (ej: "src/application/", "src/domain/")
+    pub paths: Vec<String>,
+    /// Paquetes de ORM prohibidos (por defecto typeorm, @prisma/client, mongoose, sequelize...)
+    #[serde(default = "crate::rules::no_direct_orm::default_orm_packages")]
+    pub packages: Vec<String>,
+}
+
+/// Regla async-controller-error-handling: handlers async con manejo de errores
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AsyncControllerErrorHandlingRule {
+    /// Archivos de controladores (por defecto *.controller.ts y *.controller.js)
+    #[serde(default = "crate::rules::async_controller_error_handling::default_controller_files")]
+    pub files: Vec<String>,
+}
+
+/// Regla forbid-cross-layer-types: orden de las capas para los imports de solo tipos
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ForbidCrossLayerTypesRule {
+    /// Carpetas de cada capa, de la más interna a la más externa
+    /// (ej: "src/domain/", "src/application/", "src/infrastructure/")
+    pub layers: Vec<String>,
+}
+
+/// Regla no-direct-logger: capas que registran a través de un puerto Logger inyectado
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct NoDirectLoggerRule {
+    /// Carpetas a las que aplica (ej: "src/application/", "src/domain/")
+    pub paths: Vec<String>,
+    /// Paquetes de logging prohibidos (por defecto winston, pino, bunyan, log4js...)
+    #[serde(default = "crate::rules::no_direct_logger::default_logger_packages")]
+    pub packages: Vec<String>,
+}
+
+/// Regla no-cross-layer-new: capas cuyas clases se reciben por inyección, no con `new`
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct NoCrossLayerNewRule {
+    /// Carpetas de cada capa (ej: "src/controllers/", "src/services/", "src/repositories/")
+    pub layers: Vec<String>,
+}
+
+/// Regla no-manual-di-instantiation: los providers los crea el contenedor, no `new`
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct NoManualDiInstantiationRule {
+    /// Decoradores de clase que la convierten en provider (por defecto "Injectable")
+    #[serde(default = "crate::rules::no_manual_di_instantiation::default_provider_decorators")]
+    pub decorators: Vec<String>,
+    /// Archivos donde se permite instanciarlos (por defecto tests y factories)
+    #[serde(default = "crate::rules::no_manual_di_instantiation::default_allowed_paths")]
+    pub allow: Vec<String>,
+}
+
+/// Regla no-magic-numbers: capas donde los números deben tener nombre
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct NoMagicNumbersRule {
+    /// Carpetas a las que aplica (ej: "src/services/", "src/domain/")
+    pub paths: Vec<String>,
+    /// Números permitidos además de 0, 1 y -1 (ej: [2, 100])
+    #[serde(default)]
+    pub ignore: Vec<f64>,
+}
+
+/// Regla no-non-null-assertion: capas donde los nulls se comprueban en lugar de afirmarse
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct NoNonNullAssertionRule {
+    /// Carpetas a las que aplica (vacío = todo el proyecto)
+    #[serde(default)]
+    pub paths: Vec<String>,
+    /// Archivos donde se permiten (por defecto los tests)
+    #[serde(default = "crate::rules::no_non_null_assertion::default_allowed_paths")]
+    pub allow: Vec<String>,
+}
+
+/// Regla no-param-reassign: funciones que no cambian lo que reciben
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct NoParamReassignRule {
+    /// Carpetas a las que aplica (vacío = todo el proyecto)
+    #[serde(default)]
+    pub paths: Vec<String>,
+    /// Señalar también las asignaciones a propiedades (`param.campo = x`)
+    #[serde(default = "default_true")]
+    pub props: bool,
+    /// Parámetros cuyas propiedades se pueden modificar (por defecto acc, ctx, req, res...)
+    #[serde(default = "crate::rules::no_param_reassign::default_ignored_props_for")]
+    pub ignore_props_for: Vec<String>,
+}
+
+/// Regla no-mutable-module-state: sin variables de módulo que se reasignan o modifican
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct NoMutableModuleStateRule {
+    /// Carpetas a las que aplica (vacío = todo el proyecto)
+    #[serde(default)]
+    pub paths: Vec<String>,
+    /// Nombres de variables permitidas: singletons intencionales (ej: "instance")
+    #[serde(default)]
+    pub allow: Vec<String>,
+}
+
+/// Regla no-weak-public-return: API pública sin retornos `any`/`unknown`
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct NoWeakPublicReturnRule {
+    /// Carpetas a las que aplica (vacío = todo el proyecto)
+    #[serde(default)]
+    pub paths: Vec<String>,
+    /// Aceptar `unknown` como retorno: obliga a comprobar el tipo antes de usarlo
+    #[serde(default)]
+    pub allow_unknown: bool,
+}
+
+/// Regla no-default-export: proyectos que solo usan exports nombrados
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct NoDefaultExportRule {
+    /// Archivos donde se permite export default (por defecto pages/, *.config.* y *.stories.*)
+    #[serde(default = "crate::rules::no_default_export::default_allowed_paths")]
+    pub allow: Vec<String>,
+}
+
+/// Regla feature-isolation: organización vertical por carpetas de feature
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FeatureIsolationRule {
+    /// Carpeta cuyas subcarpetas de primer nivel son features (ej: "src/features/")
+    pub features_root: String,
+}
+
+/// Regla test-public-api-only: los tests importan los módulos por su index
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TestPublicApiOnlyRule {
+    /// Patrones de los archivos de test (por defecto *.spec.*, *.test.* y __tests__/)
+    #[serde(default = "crate::rules::test_public_api_only::default_test_patterns")]
+    pub tests: Vec<String>,
+    /// Internals que los tests sí pueden importar (ej: "src/testing/")
+    #[serde(default)]
+    pub allow: Vec<String>,
+}
+
+/// Regla no-unused-exports: exports que ningún módulo del proyecto importa
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct NoUnusedExportsRule {
+    /// Puntos de entrada además de los inferidos (ej: "src/pages/", "src/cli.ts")
+    #[serde(default)]
+    pub entry_points: Vec<String>,
+    /// Patrones de los archivos de test, que no se reportan (por defecto *.spec.*,
+    /// *.test.* y __tests__/)
+    #[serde(default = "crate::rules::test_public_api_only::default_test_patterns")]
+    pub tests: Vec<String>,
+}
+
+/// Regla no-service-to-service: los servicios de distintas features se comunican
+/// a través de un mediador o bus de eventos
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct NoServiceToServiceRule {
+    /// Carpetas donde se aplica (vacío = todos los servicios)
+    #[serde(default)]
+    pub paths: Vec<String>,
+    /// Carpeta cuyas subcarpetas de primer nivel son features. Sin ella, la
+    /// feature de un servicio es la carpeta que lo contiene
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub features_root: Option<String>,
+}
+
+/// Regla no-self-package-import: sin imports del propio paquete por su nombre
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct NoSelfPackageImportRule {
+    /// Archivos donde se permite (por defecto los tests)
+    #[serde(default = "crate::rules::no_self_package_import::default_allowed_paths")]
+    pub allow: Vec<String>,
+}
+
+/// Regla dto-location: los DTOs y clases de validación viven en su capa
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DtoLocationRule {
+    /// Carpetas donde pueden declararse DTOs (ej: ["src/presentation/dto/"])
+    pub paths: Vec<String>,
+    /// Decoradores que convierten una clase en DTO (por defecto los de class-validator)
+    #[serde(default = "crate::rules::dto_location::default_validation_decorators")]
+    pub validation_decorators: Vec<String>,
+}
+
+/// Regla enum-location: los enums compartidos viven en un solo lugar
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct EnumLocationRule {
+    /// Carpetas donde pueden declararse enums (ej: ["src/domain/enums/", "shared/"])
+    pub paths: Vec<String>,
+}
+
+/// Regla barrel-purity: los barrels solo contienen imports y re-exports
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BarrelPurityRule {
+    /// Nombres de archivo que son barrels (por defecto index.ts e index.js)
+    #[serde(default = "crate::rules::barrel_purity::default_barrel_files")]
+    pub files: Vec<String>,
+}
+
+/// Nivel de una regla en el mapa `rules` de architect.json
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleLevel {
+    Off,
+    Warn,
+    Error,
+}
+
+/// Grupos de imports para la regla import-order
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportGroup {
+    /// Paquetes (react, @nestjs/core)
+    External,
+    /// Alias del proyecto (@/, ~/, #)
+    Alias,
+    /// Rutas relativas (./, ../)
+    Relative,
+}
+
+impl ImportGroup {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ImportGroup::External => "external",
+            ImportGroup::Alias => "alias",
+            ImportGroup::Relative => "relative",
+        }
+    }
+}
+
+/// Líneas que max-class-body-lines no cuenta en el cuerpo de una clase
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ClassBodyExclusion {
+    /// El constructor completo (inyección de dependencias)
+    Constructor,
+    /// Las líneas ocupadas solo por decoradores de los miembros
+    Decorators,
+}
+
+/// Tratamiento de los archivos de declaración (`.d.ts`)
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum DeclarationFiles {
+    /// No se analizan
+    #[default]
+    Skip,
+    /// Solo las reglas de imports (forbidden_imports, sealed_paths): sin max-lines
+    /// ni reglas de AST, que no aplican a archivos sin implementación
+    Structural,
+}
+
+/// Estilos de export para la regla export-style
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum ExportStyle {
+    /// Un único export default por archivo
+    DefaultOnly,
+    /// Solo exports nombrados
+    NamedOnly,
+    /// Cualquiera de los dos, pero no ambos en el mismo archivo
+    NoMixing,
+}
+
+/// Convenciones de nombres para la regla interface-naming
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum InterfaceNaming {
+    /// Sin prefijo: `UserRepository`
+    NoIPrefix,
+    /// Siempre con prefijo: `IUserRepository`
+    RequireIPrefix,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum AIProvider {
     Claude,
@@ -54,6 +387,17 @@ pub enum AIProvider {
 }
 
 impl AIProvider {
+    /// Todos los proveedores, en el orden en que los ofrece el asistente
+    pub const ALL: [AIProvider; 7] = [
+        AIProvider::Claude,
+        AIProvider::Gemini,
+        AIProvider::OpenAI,
+        AIProvider::Groq,
+        AIProvider::Ollama,
+        AIProvider::Kimi,
+        AIProvider::DeepSeek,
+    ];
+
     pub fn as_str(&self) -> &str {
         match self {
             AIProvider::Claude => "Claude",
@@ -65,6 +409,75 @@ impl AIProvider {
             AIProvider::DeepSeek => "DeepSeek",
         }
     }
+
+    /// Nombre que se muestra en el asistente de configuración
+    pub fn label(&self) -> &'static str {
+        match self {
+            AIProvider::Claude => "Claude (Anthropic)",
+            AIProvider::Gemini => "Gemini (Google)",
+            AIProvider::OpenAI => "OpenAI",
+            AIProvider::Groq => "Groq",
+            AIProvider::Ollama => "Ollama (Local)",
+            AIProvider::Kimi => "Kimi (Moonshot)",
+            AIProvider::DeepSeek => "DeepSeek",
+        }
+    }
+
+    /// Proveedor por su nombre (el de `as_str`, sin distinguir mayúsculas)
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|provider| provider.as_str().eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// URL base de la API oficial del proveedor
+    pub fn default_api_url(&self) -> &'static str {
+        match self {
+            AIProvider::Claude => "https://api.anthropic.com",
+            AIProvider::Gemini => "https://generativelanguage.googleapis.com",
+            AIProvider::OpenAI => "https://api.openai.com/v1",
+            AIProvider::Groq => "https://api.groq.com/openai/v1",
+            AIProvider::Ollama => "http://localhost:11434/v1",
+            AIProvider::Kimi => "https://api.moonshot.ai/v1",
+            AIProvider::DeepSeek => "https://api.deepseek.com",
+        }
+    }
+
+    /// Respaldo para los .architect.ai.json escritos a mano o por versiones que no
+    /// guardaban `provider` (el asistente siempre lo guarda). Solo mira el host de la
+    /// URL: el dominio oficial de cada proveedor o el puerto 11434 de Ollama. `None`
+    /// si no se reconoce; un proxy o un gateway propio necesita `provider` explícito.
+    pub fn from_api_url(api_url: &str) -> Option<Self> {
+        let url = api_url.trim().to_lowercase();
+        let rest = url.split_once("://").map_or(url.as_str(), |(_, rest)| rest);
+        let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+        let authority = authority
+            .rsplit_once('@')
+            .map_or(authority, |(_, host)| host);
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (authority, None),
+        };
+        let is = |domain: &str| host == domain || host.ends_with(&format!(".{}", domain));
+
+        if is("anthropic.com") {
+            Some(AIProvider::Claude)
+        } else if is("generativelanguage.googleapis.com") {
+            Some(AIProvider::Gemini)
+        } else if is("openai.com") {
+            Some(AIProvider::OpenAI)
+        } else if is("groq.com") {
+            Some(AIProvider::Groq)
+        } else if is("moonshot.ai") || is("moonshot.cn") {
+            Some(AIProvider::Kimi)
+        } else if is("deepseek.com") {
+            Some(AIProvider::DeepSeek)
+        } else if port == Some("11434") {
+            Some(AIProvider::Ollama)
+        } else {
+            None
+        }
+    }
 }
 
 /// Configuración de IA para análisis arquitectónico
@@ -75,6 +488,116 @@ pub struct AIConfig {
     pub api_url: String,
     pub api_key: String,
     pub model: String,
+    /// Máximo de peticiones simultáneas a este proveedor; las demás esperan turno
+    /// (sin valor = sin límite)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_concurrent_requests: Option<usize>,
+    /// Temperatura de las respuestas (sin valor = 0.1 en las APIs compatibles con
+    /// OpenAI y el default del proveedor en Claude y Gemini)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    /// Máximo de tokens de la respuesta (sin valor = 8192)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u32>,
+}
+
+/// Tokens de respuesta pedidos cuando la configuración no indica `max_tokens`
+pub const DEFAULT_AI_MAX_TOKENS: u32 = 8192;
+
+/// Variables de entorno de la configuración de IA sin preguntas (--non-interactive)
+pub const AI_PROVIDER_ENV: &str = "ARCHITECT_AI_PROVIDER";
+pub const AI_URL_ENV: &str = "ARCHITECT_AI_URL";
+pub const AI_KEY_ENV: &str = "ARCHITECT_AI_KEY";
+pub const AI_MODEL_ENV: &str = "ARCHITECT_AI_MODEL";
+
+impl AIConfig {
+    /// Construye la configuración solo con variables de entorno, para CI.
+    /// Son obligatorias el proveedor, el modelo y la API key (salvo con Ollama);
+    /// sin `ARCHITECT_AI_URL` se usa la URL oficial del proveedor.
+    pub fn from_env() -> Result<Self> {
+        let var = |name: &str| {
+            std::env::var(name)
+                .ok()
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+
+        let provider_name = var(AI_PROVIDER_ENV);
+        let provider = match &provider_name {
+            Some(name) => Some(AIProvider::from_name(name).ok_or_else(|| {
+                miette::miette!(
+                    "{} = '{}' no es un proveedor válido. Usa uno de: Claude, Gemini, OpenAI, Groq, Ollama, Kimi, DeepSeek.",
+                    AI_PROVIDER_ENV,
+                    name
+                )
+            })?),
+            None => None,
+        };
+        let api_key = var(AI_KEY_ENV);
+        let model = var(AI_MODEL_ENV);
+
+        let mut missing = Vec::new();
+        if provider.is_none() {
+            missing.push(AI_PROVIDER_ENV);
+        }
+        if api_key.is_none() && provider != Some(AIProvider::Ollama) {
+            missing.push(AI_KEY_ENV);
+        }
+        if model.is_none() {
+            missing.push(AI_MODEL_ENV);
+        }
+        let provider = match provider {
+            Some(provider) if missing.is_empty() => provider,
+            _ => {
+                return Err(miette::miette!(
+                    "--non-interactive necesita la configuración de IA en variables de entorno. Faltan: {}",
+                    missing.join(", ")
+                ))
+            }
+        };
+
+        Ok(AIConfig {
+            name: format!("{} (entorno)", provider.as_str()),
+            api_url: var(AI_URL_ENV).unwrap_or_else(|| provider.default_api_url().to_string()),
+            provider,
+            api_key: api_key.unwrap_or_default(),
+            model: model.unwrap_or_default(),
+            max_concurrent_requests: None,
+            temperature: None,
+            max_tokens: None,
+        })
+    }
+
+    /// Máximo de tokens de la respuesta que se pide al proveedor
+    pub fn max_tokens(&self) -> u32 {
+        self.max_tokens.unwrap_or(DEFAULT_AI_MAX_TOKENS)
+    }
+
+    /// Verifica que `temperature` y `max_tokens` estén en el rango que acepta el proveedor
+    fn validate(&self) -> Result<()> {
+        if let Some(temperature) = self.temperature {
+            let max = match self.provider {
+                AIProvider::Claude => 1.0,
+                _ => 2.0,
+            };
+            if !(0.0..=max).contains(&temperature) {
+                return Err(miette::miette!(
+                    "La configuración de IA '{}' tiene temperature = {}; {} acepta valores entre 0 y {}.",
+                    self.name,
+                    temperature,
+                    self.provider.as_str(),
+                    max
+                ));
+            }
+        }
+        if self.max_tokens == Some(0) {
+            return Err(miette::miette!(
+                "La configuración de IA '{}' tiene max_tokens = 0; debe ser mayor que 0.",
+                self.name
+            ));
+        }
+        Ok(())
+    }
 }
 
 impl Default for AIConfig {
@@ -85,18 +608,263 @@ impl Default for AIConfig {
             api_url: "https://api.anthropic.com".to_string(),
             api_key: String::new(),
             model: "claude-3-7-sonnet-20250219".to_string(),
+            max_concurrent_requests: None,
+            temperature: None,
+            max_tokens: None,
         }
     }
 }
 
 /// Estructura para mapear el architect.json tal cual está en el disco
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 struct ConfigFile {
     pub max_lines_per_function: usize,
     pub architecture_pattern: ArchPattern,
     pub forbidden_imports: Vec<ForbiddenRule>,
     #[serde(default = "default_ignored_paths")]
     pub ignored_paths: Vec<String>,
+    #[serde(flatten)]
+    pub settings: RuleSettings,
+}
+
+/// Configuración opcional de reglas adicionales (todas con valores por defecto)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct RuleSettings {
+    /// Directorios sellados: desde fuera solo se puede importar su index
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub sealed_paths: Vec<String>,
+    /// Listas blancas de imports internos permitidos por carpeta
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub allowed_imports: Vec<AllowedImportRule>,
+    /// Patrones de arquitectura por carpeta; prevalece el de la ruta más específica.
+    /// Fuera de ellas rige `architecture_pattern`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub pattern_scopes: Vec<PatternScope>,
+    /// Complejidad cognitiva máxima por función (0 desactiva la regla)
+    pub max_cognitive_complexity: usize,
+    /// Máximo de módulos distintos importados por archivo (0 desactiva la regla)
+    pub max_imports: usize,
+    /// Los barrels (index.*) no cuentan para max_imports
+    pub max_imports_exempt_barrels: bool,
+    /// Máximo de propiedades/elementos en un literal de objeto o array (0 desactiva)
+    pub max_literal_size: usize,
+    /// Máximo de líneas que puede ocupar un literal de objeto o array (0 desactiva)
+    pub max_literal_lines: usize,
+    /// Máximo de parámetros genéricos por función, clase, interfaz o type alias (0 desactiva)
+    pub max_type_params: usize,
+    /// Máximo de decoradores por clase, método o propiedad (0 desactiva)
+    pub max_decorators: usize,
+    /// Máximo de miembros por interfaz o type literal (0 desactiva)
+    pub max_interface_members: usize,
+    /// Máximo de líneas en el cuerpo de una clase, sin contar las exclusiones (0 desactiva)
+    pub max_class_body_lines: usize,
+    /// Partes del cuerpo que no cuentan para `max_class_body_lines`
+    pub max_class_body_lines_exclude: Vec<ClassBodyExclusion>,
+    /// Máximo de accesos encadenados a propiedades, `a.b.c.d` (0 desactiva)
+    pub max_member_chain: usize,
+    /// Las llamadas a métodos cortan la cadena (builders fluidos permitidos)
+    pub max_member_chain_allow_calls: bool,
+    /// Máximo de casos por `switch` (0 desactiva)
+    pub max_switch_cases: usize,
+    /// No contar el caso `default` en `max_switch_cases`
+    pub max_switch_cases_exclude_default: bool,
+    /// Máximo de comentarios con marcadores de deuda (TODO, FIXME...) por archivo (0 desactiva)
+    pub max_todos: usize,
+    /// Máximo de archivos analizados directamente dentro de una carpeta (0 desactiva)
+    pub max_files_per_dir: usize,
+    /// Carpetas planas por convención que no cuentan para `max_files_per_dir`
+    pub max_files_per_dir_exempt: Vec<String>,
+    /// Con patrón Hexagonal o Clean, reportar los archivos .ts que no están en
+    /// ninguna capa (también con --require-layer)
+    pub require_layer: bool,
+    /// Carpetas de las capas para `require_layer` (vacío = las del patrón)
+    pub layer_paths: Vec<String>,
+    /// Archivos que no necesitan estar en una capa (tests, puntos de entrada...)
+    pub require_layer_exempt: Vec<String>,
+    /// Marcadores que cuentan para `max_todos`
+    pub todo_markers: Vec<String>,
+    /// Longitud máxima (en módulos) de los ciclos reportados (0 = sin límite)
+    pub max_cycle_length: usize,
+    /// Longitud mínima (en módulos) de los ciclos reportados. 2 omite los
+    /// archivos que se importan a sí mismos
+    pub min_cycle_length: usize,
+    /// Mínimo de statements dentro del `if` que envuelve toda una función para
+    /// sugerir un guard clause (0 desactiva)
+    pub prefer_early_return_min_statements: usize,
+    /// Nivel por regla (ej: {"mvc-controller-repository": "off"}). Las reglas
+    /// no listadas usan su severidad por defecto
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub rules: BTreeMap<String, RuleLevel>,
+    /// Capas donde se prohíbe lanzar errores genéricos
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_generic_error: Option<NoGenericErrorRule>,
+    /// Capas donde se prohíben las llamadas síncronas de fs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_sync_fs: Option<NoSyncFsRule>,
+    /// Capas de presentación, las únicas que pueden usar globals del navegador
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_browser_globals: Option<NoBrowserGlobalsRule>,
+    /// Capas donde se prohíben los eventos concretos del framework
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_concrete_events: Option<NoConcreteEventsRule>,
+    /// Capas donde se prohíbe importar ORMs directamente
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_direct_orm: Option<NoDirectOrmRule>,
+    /// Capas donde se prohíbe importar librerías de logging directamente
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_direct_logger: Option<NoDirectLoggerRule>,
+    /// Controladores cuyos handlers async deben manejar los rechazos (heurística)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub async_controller_error_handling: Option<AsyncControllerErrorHandlingRule>,
+    /// Modo estricto: prohíbe también los imports de solo tipos hacia capas más externas
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forbid_cross_layer_types: Option<ForbidCrossLayerTypesRule>,
+    /// Capas entre las que no se permite instanciar clases con `new`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_cross_layer_new: Option<NoCrossLayerNewRule>,
+    /// Providers (`@Injectable()`) que no se pueden instanciar con `new`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_manual_di_instantiation: Option<NoManualDiInstantiationRule>,
+    /// Capas donde se prohíben los números mágicos
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_magic_numbers: Option<NoMagicNumbersRule>,
+    /// Capas donde se prohíben las aserciones non-null (`valor!`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_non_null_assertion: Option<NoNonNullAssertionRule>,
+    /// Funciones y métodos públicos que retornan `any` o `unknown`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_weak_public_return: Option<NoWeakPublicReturnRule>,
+    /// Funciones que reasignan o modifican sus parámetros
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_param_reassign: Option<NoParamReassignRule>,
+    /// Variables de módulo reasignables (`let`/`var`) o constantes que se modifican
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_mutable_module_state: Option<NoMutableModuleStateRule>,
+    /// Prohíbe export default (salvo en los archivos permitidos)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_default_export: Option<NoDefaultExportRule>,
+    /// Raíz de las features que solo se comunican a través de su index
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feature_isolation: Option<FeatureIsolationRule>,
+    /// Tests que solo pueden importar la API pública del código bajo prueba
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test_public_api_only: Option<TestPublicApiOnlyRule>,
+    /// Exports que ningún módulo importa (análisis de todo el proyecto)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unused_exports: Option<NoUnusedExportsRule>,
+    /// Prohíbe que un servicio importe servicios de otra feature (patrón mediador)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_service_to_service: Option<NoServiceToServiceRule>,
+    /// Prohíbe que un paquete se importe a sí mismo por su nombre (monorepos)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_self_package_import: Option<NoSelfPackageImportRule>,
+    /// Carpetas donde deben declararse los DTOs y clases de validación
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dto_location: Option<DtoLocationRule>,
+    /// Carpetas donde deben declararse los enums
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enum_location: Option<EnumLocationRule>,
+    /// Barrels que solo pueden re-exportar, sin lógica propia
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub barrel_purity: Option<BarrelPurityRule>,
+    /// Orden de grupos de imports (ej: ["external", "alias", "relative"]). Ausente = sin regla
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub import_order: Option<Vec<ImportGroup>>,
+    /// Estilo de exports por archivo ("default-only", "named-only", "no-mixing"). Ausente = sin regla
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub export_style: Option<ExportStyle>,
+    /// Prefijo `I` en las interfaces ("no-i-prefix", "require-i-prefix"). Ausente = sin regla
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interface_naming: Option<InterfaceNaming>,
+    /// Atribuir los imports de paquetes (incluidos subpaths y alias) a su paquete real
+    /// leyendo node_modules. Desactivado por defecto por rendimiento.
+    pub resolve_packages: bool,
+    /// Extensiones a analizar (ej: ["ts", "tsx"]). Vacío = todas las soportadas
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub extensions: Vec<String>,
+    /// Omitir los archivos ignorados por git (.gitignore, incluidos los anidados)
+    pub respect_gitignore: bool,
+    /// Archivos `.d.ts`: "skip" (por defecto) o "structural" (solo reglas de imports)
+    pub declaration_files: DeclarationFiles,
+    /// Superponer los architect.json de las subcarpetas a los de sus ancestros: el
+    /// más cercano al archivo manda y los arrays se concatenan
+    pub cascade: bool,
+}
+
+impl Default for RuleSettings {
+    fn default() -> Self {
+        Self {
+            sealed_paths: Vec::new(),
+            allowed_imports: Vec::new(),
+            pattern_scopes: Vec::new(),
+            max_cognitive_complexity: 15,
+            max_imports: 15,
+            max_imports_exempt_barrels: true,
+            max_literal_size: 50,
+            max_literal_lines: 100,
+            max_type_params: 3,
+            max_decorators: 5,
+            max_interface_members: 15,
+            max_class_body_lines: 0,
+            max_class_body_lines_exclude: vec![
+                ClassBodyExclusion::Constructor,
+                ClassBodyExclusion::Decorators,
+            ],
+            max_member_chain: 4,
+            max_member_chain_allow_calls: true,
+            max_switch_cases: 7,
+            max_switch_cases_exclude_default: true,
+            max_todos: 0,
+            max_files_per_dir: 0,
+            max_files_per_dir_exempt: crate::crowded_dirs::default_exempt_dirs(),
+            require_layer: false,
+            layer_paths: Vec::new(),
+            require_layer_exempt: crate::unclassified::default_exempt_paths(),
+            todo_markers: crate::rules::max_todos::default_todo_markers(),
+            max_cycle_length: 0,
+            min_cycle_length: 1,
+            prefer_early_return_min_statements: 3,
+            rules: BTreeMap::new(),
+            no_generic_error: None,
+            no_sync_fs: None,
+            no_browser_globals: None,
+            no_concrete_events: None,
+            no_direct_orm: None,
+            no_direct_logger: None,
+            async_controller_error_handling: None,
+            forbid_cross_layer_types: None,
+            no_cross_layer_new: None,
+            no_manual_di_instantiation: None,
+            no_magic_numbers: None,
+            no_non_null_assertion: None,
+            no_weak_public_return: None,
+            no_param_reassign: None,
+            no_mutable_module_state: None,
+            no_default_export: None,
+            feature_isolation: None,
+            test_public_api_only: None,
+            no_unused_exports: None,
+            no_service_to_service: None,
+            no_self_package_import: None,
+            dto_location: None,
+            enum_location: None,
+            barrel_purity: None,
+            import_order: None,
+            export_style: None,
+            interface_naming: None,
+            resolve_packages: false,
+            extensions: Vec::new(),
+            respect_gitignore: true,
+            declaration_files: DeclarationFiles::Skip,
+            cascade: false,
+        }
+    }
+}
+
+/// Default de los campos booleanos que están activos salvo que se desactiven
+fn default_true() -> bool {
+    true
 }
 
 /// Valores por defecto para ignored_paths
@@ -130,20 +898,326 @@ struct AIConfigFile {
     pub selected_name: String,
 }
 
+/// Lee .architect.ai.json. Las configuraciones sin `provider` lo toman de
+/// `AIProvider::from_api_url`; si la URL no lo identifica, la carga falla en lugar
+/// de suponer un proveedor con el que `consultar_ia` usaría la API equivocada.
+fn parse_ai_config_file(content: &str) -> Result<AIConfigFile> {
+    let mut value: serde_json::Value = serde_json::from_str(content).into_diagnostic()?;
+
+    if let Some(configs) = value.get_mut("configs").and_then(|c| c.as_array_mut()) {
+        for config in configs.iter_mut().filter_map(|c| c.as_object_mut()) {
+            if config.contains_key("provider") {
+                continue;
+            }
+            let api_url = config.get("api_url").and_then(|u| u.as_str()).unwrap_or("");
+            let provider = AIProvider::from_api_url(api_url).ok_or_else(|| {
+                miette::miette!(
+                    "La configuración de IA '{}' no indica \"provider\" y su URL ({}) no permite deducirlo. Agrega \"provider\": uno de Claude, Gemini, OpenAI, Groq, Ollama, Kimi, DeepSeek.",
+                    config.get("name").and_then(|n| n.as_str()).unwrap_or("?"),
+                    api_url
+                )
+            })?;
+            config.insert(
+                "provider".to_string(),
+                serde_json::to_value(&provider).into_diagnostic()?,
+            );
+        }
+    }
+
+    let file: AIConfigFile = serde_json::from_value(value).into_diagnostic()?;
+    for config in &file.configs {
+        config.validate()?;
+    }
+    Ok(file)
+}
+
 pub struct LinterContext {
     pub max_lines: usize,
-    #[allow(dead_code)]
     pub framework: Framework,
-    #[allow(dead_code)]
     pub pattern: ArchPattern,
     pub forbidden_imports: Vec<ForbiddenRule>,
     pub ignored_paths: Vec<String>,
+    pub settings: RuleSettings,
     #[allow(dead_code)]
     pub ai_configs: Vec<AIConfig>,
+    /// Configuraciones de las subcarpetas, con `cascade: true`
+    pub cascade: Option<ConfigCascade>,
+}
+
+impl LinterContext {
+    /// Contexto que rige un archivo cuando alguna carpeta entre él y la raíz tiene su
+    /// propio architect.json (`cascade: true`). `None` si rige este mismo contexto.
+    pub fn cascaded_for(&self, path: &Path) -> Option<Arc<LinterContext>> {
+        self.cascade.as_ref()?.context_for(path)
+    }
+
+    /// Nivel configurado para una regla en el mapa `rules`, si lo hay
+    pub fn rule_level(&self, rule_id: &str) -> Option<RuleLevel> {
+        self.settings.rules.get(rule_id).copied()
+    }
+
+    /// Patrón de `pattern_scopes` que gobierna un archivo: el de la ruta más
+    /// específica (la más larga) que coincide. `None` si ninguna coincide.
+    pub fn scoped_pattern(&self, path: &Path) -> Option<&ArchPattern> {
+        let file_path = path.to_string_lossy().to_lowercase();
+        self.settings
+            .pattern_scopes
+            .iter()
+            .flat_map(|scope| {
+                scope
+                    .paths
+                    .iter()
+                    .map(|p| crate::analyzer::normalize_pattern(p))
+                    .filter(|p| crate::analyzer::matches_pattern(&file_path, p))
+                    .map(move |p| (p.len(), &scope.pattern))
+            })
+            .max_by_key(|(specificity, _)| *specificity)
+            .map(|(_, pattern)| pattern)
+    }
+
+    /// Indica si una regla debe ejecutarse sobre un archivo. El mapa `rules` manda;
+    /// sin entrada, las reglas son activas salvo las que dependen del contexto del
+    /// proyecto o del patrón que gobierna el archivo.
+    pub fn is_rule_enabled(&self, rule_id: &str, path: &Path) -> bool {
+        match self.rule_level(rule_id) {
+            Some(level) => level != RuleLevel::Off,
+            None => match rule_id {
+                // Convención de NestJS/MVC: no aplica a otros frameworks ni a las
+                // partes del proyecto con otro patrón en `pattern_scopes`
+                "mvc-controller-repository" => match self.scoped_pattern(path) {
+                    Some(pattern) => *pattern == ArchPattern::MVC,
+                    None => self.framework == Framework::NestJS || self.pattern == ArchPattern::MVC,
+                },
+                // Proyectos en capas: NestJS o cualquier patrón distinto de Ninguno
+                "no-repository-to-service" => match self.scoped_pattern(path) {
+                    Some(pattern) => *pattern != ArchPattern::Ninguno,
+                    None => {
+                        self.framework == Framework::NestJS || self.pattern != ArchPattern::Ninguno
+                    }
+                },
+                // Solo tiene sentido con un contenedor de inyección de dependencias
+                "no-cross-layer-new" => {
+                    matches!(self.framework, Framework::NestJS | Framework::Angular)
+                }
+                _ => true,
+            },
+        }
+    }
 }
 
 /// CARGA SILENCIOSA: Lee architect.json y .architect.ai.json y los convierte en contexto
 pub fn load_config(root: &Path) -> Result<LinterContext> {
+    // Re-detectamos el framework para el contexto actual
+    let framework = crate::detector::detect_framework(root);
+    let config = read_config_file(root, &framework)?;
+
+    // Cargar configuración de IA (si existe, es opcional)
+    let ai_config_path = root.join(".architect.ai.json");
+    let ai_configs = if ai_config_path.exists() {
+        let ai_content = fs::read_to_string(&ai_config_path).into_diagnostic()?;
+        let ai_file = parse_ai_config_file(&ai_content)?;
+
+        let mut configs = ai_file.configs;
+        // Mover la configuración seleccionada al principio de la lista
+        if let Some(pos) = configs.iter().position(|c| c.name == ai_file.selected_name) {
+            let selected = configs.remove(pos);
+            configs.insert(0, selected);
+        }
+        configs
+    } else {
+        Vec::new()
+    };
+
+    let cascade = if config.settings.cascade {
+        ConfigCascade::load(root, &framework)
+    } else {
+        None
+    };
+
+    Ok(LinterContext {
+        max_lines: config.max_lines_per_function,
+        framework,
+        pattern: config.architecture_pattern,
+        forbidden_imports: config.forbidden_imports,
+        ignored_paths: config.ignored_paths,
+        settings: config.settings,
+        ai_configs,
+        cascade,
+    })
+}
+
+/// Configuraciones en cascada (`cascade: true`): el architect.json de una subcarpeta
+/// se superpone al de sus ancestros hasta la raíz. Los objetos se combinan clave a
+/// clave, los valores simples del más cercano reemplazan a los heredados y los
+/// arrays se concatenan sin repetir elementos.
+pub struct ConfigCascade {
+    root: PathBuf,
+    /// architect.json de la raíz tal como está en disco (sin el preset)
+    root_json: serde_json::Value,
+    framework: Framework,
+    /// Flags de CLI, que mandan sobre todos los niveles (--extensions, --rule)
+    pub extensions: Option<Vec<String>>,
+    pub rule_overrides: Vec<(String, RuleLevel)>,
+    /// Contexto resuelto por carpeta (`None` = rige el de la raíz)
+    contexts: Mutex<HashMap<PathBuf, Option<Arc<LinterContext>>>>,
+}
+
+impl ConfigCascade {
+    fn load(root: &Path, framework: &Framework) -> Option<Self> {
+        let content = fs::read_to_string(root.join("architect.json")).ok()?;
+        Some(Self {
+            root: root.to_path_buf(),
+            root_json: serde_json::from_str(&content).ok()?,
+            framework: framework.clone(),
+            extensions: None,
+            rule_overrides: Vec::new(),
+            contexts: Mutex::new(HashMap::new()),
+        })
+    }
+
+    fn context_for(&self, path: &Path) -> Option<Arc<LinterContext>> {
+        let dir = path.parent()?;
+        if let Some(context) = self.contexts.lock().unwrap().get(dir) {
+            return context.clone();
+        }
+
+        let context = self.resolve(dir).map(Arc::new);
+        self.contexts
+            .lock()
+            .unwrap()
+            .insert(dir.to_path_buf(), context.clone());
+        context
+    }
+
+    /// Combina los architect.json entre la raíz (excluida) y `dir`. Un archivo
+    /// inválido se informa y se ignora, rigiendo la configuración de sus ancestros.
+    fn resolve(&self, dir: &Path) -> Option<LinterContext> {
+        let mut configs: Vec<PathBuf> = dir
+            .ancestors()
+            .take_while(|ancestor| ancestor.starts_with(&self.root) && *ancestor != self.root)
+            .map(|ancestor| ancestor.join("architect.json"))
+            .filter(|config| config.is_file())
+            .collect();
+        if configs.is_empty() {
+            return None;
+        }
+        // De la más lejana a la más cercana, para que esta última mande
+        configs.reverse();
+
+        let mut merged = self.root_json.clone();
+        for config_path in &configs {
+            let overlay = fs::read_to_string(config_path)
+                .into_diagnostic()
+                .and_then(|content| serde_json::from_str(&content).into_diagnostic());
+            match overlay {
+                Ok(overlay) => merge_json(&mut merged, overlay),
+                Err(e) => {
+                    eprintln!("⚠️  No se pudo leer {}: {}", config_path.display(), e);
+                }
+            }
+        }
+
+        let config = match config_from_value(merged, &self.framework) {
+            Ok(config) => config,
+            Err(e) => {
+                eprintln!(
+                    "⚠️  La configuración en cascada de {} es inválida: {}. Se usa la de la raíz.",
+                    dir.display(),
+                    e
+                );
+                return None;
+            }
+        };
+
+        let mut settings = config.settings;
+        if let Some(extensions) = &self.extensions {
+            settings.extensions = extensions.clone();
+        }
+        for (rule_id, level) in &self.rule_overrides {
+            settings.rules.insert(rule_id.clone(), *level);
+        }
+
+        Some(LinterContext {
+            max_lines: config.max_lines_per_function,
+            framework: self.framework.clone(),
+            pattern: config.architecture_pattern,
+            forbidden_imports: config.forbidden_imports,
+            ignored_paths: config.ignored_paths,
+            settings,
+            ai_configs: Vec::new(),
+            cascade: None,
+        })
+    }
+}
+
+/// Superpone `overlay` a `base`: objetos clave a clave, arrays concatenados sin
+/// duplicados y el resto reemplazado
+fn merge_json(base: &mut serde_json::Value, overlay: serde_json::Value) {
+    use serde_json::Value;
+
+    match (base, overlay) {
+        (Value::Object(base), Value::Object(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(&key) {
+                    Some(existing) => merge_json(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (Value::Array(base), Value::Array(overlay)) => {
+            for item in overlay {
+                if !base.contains(&item) {
+                    base.push(item);
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
+/// Contexto de un proyecto que no está en disco (--archive): el architect.json de
+/// su raíz si lo hay o, si no, la configuración por defecto. Sin acceso a package.json
+/// no se detecta framework ni se aplica su preset, y no hay configuración de IA.
+pub fn context_from_archive(config: Option<&str>) -> Result<LinterContext> {
+    let framework = Framework::Unknown;
+    let config = match config {
+        Some(content) => parse_config_file(content, &framework)?,
+        None => ConfigFile {
+            max_lines_per_function: crate::detector::get_loc_suggestion(&framework),
+            architecture_pattern: ArchPattern::Ninguno,
+            forbidden_imports: Vec::new(),
+            ignored_paths: default_ignored_paths(),
+            settings: RuleSettings::default(),
+        },
+    };
+
+    Ok(LinterContext {
+        max_lines: config.max_lines_per_function,
+        framework,
+        pattern: config.architecture_pattern,
+        forbidden_imports: config.forbidden_imports,
+        ignored_paths: config.ignored_paths,
+        settings: config.settings,
+        ai_configs: Vec::new(),
+        cascade: None,
+    })
+}
+
+/// Imprime la configuración efectiva (architect.json + preset del framework) como JSON
+pub fn print_effective_config(root: &Path) -> Result<()> {
+    let framework = crate::detector::detect_framework(root);
+    let config = read_config_file(root, &framework)?;
+
+    eprintln!("🧩 Preset aplicado: {}", framework.as_str());
+    let json = serde_json::to_string_pretty(&config).into_diagnostic()?;
+    println!("{}", json);
+    Ok(())
+}
+
+/// Lee, valida y deserializa architect.json aplicando el preset del framework
+fn read_config_file(root: &Path, framework: &Framework) -> Result<ConfigFile> {
     let config_path = root.join("architect.json");
 
     // Leer el archivo de reglas
@@ -157,16 +1231,34 @@ pub fn load_config(root: &Path) -> Result<LinterContext> {
         )
     })?;
 
+    parse_config_file(&content, framework)
+}
+
+/// Valida y deserializa el contenido de un architect.json aplicando el preset del framework
+fn parse_config_file(content: &str, framework: &Framework) -> Result<ConfigFile> {
     // Validar que es JSON válido
-    let json_value: serde_json::Value = serde_json::from_str(&content)
+    let json_value: serde_json::Value = serde_json::from_str(content)
         .map_err(|e| ConfigError::new(
             format!("JSON inválido: {}", e),
             "Verifica que el archivo architect.json tenga sintaxis JSON válida. Usa un validador JSON online si es necesario.".to_string()
         ))?;
 
+    config_from_value(json_value, framework)
+}
+
+/// Valida y deserializa un architect.json ya parseado aplicando el preset del framework
+fn config_from_value(json_value: serde_json::Value, framework: &Framework) -> Result<ConfigFile> {
     // Validar el esquema antes de deserializar
     validate_schema(&json_value)?;
 
+    // El preset del framework solo rellena las claves que el usuario no definió
+    let mut json_value = json_value;
+    if let Some(obj) = json_value.as_object_mut() {
+        for (key, value) in crate::detector::get_framework_preset(framework) {
+            obj.entry(key).or_insert(value);
+        }
+    }
+
     // Ahora sí deserializar con mejor manejo de errores
     let config: ConfigFile = serde_json::from_value(json_value).map_err(|e| {
         ConfigError::new(
@@ -178,34 +1270,13 @@ pub fn load_config(root: &Path) -> Result<LinterContext> {
     // Validar los valores
     validate_config_values(&config)?;
 
-    // Cargar configuración de IA (si existe, es opcional)
-    let ai_config_path = root.join(".architect.ai.json");
-    let ai_configs = if ai_config_path.exists() {
-        let ai_content = fs::read_to_string(&ai_config_path).into_diagnostic()?;
-        let ai_file: AIConfigFile = serde_json::from_str(&ai_content).into_diagnostic()?;
-
-        let mut configs = ai_file.configs;
-        // Mover la configuración seleccionada al principio de la lista
-        if let Some(pos) = configs.iter().position(|c| c.name == ai_file.selected_name) {
-            let selected = configs.remove(pos);
-            configs.insert(0, selected);
-        }
-        configs
-    } else {
-        Vec::new()
-    };
-
-    // Re-detectamos el framework para el contexto actual
-    let framework = crate::detector::detect_framework(root);
+    Ok(config)
+}
 
-    Ok(LinterContext {
-        max_lines: config.max_lines_per_function,
-        framework,
-        pattern: config.architecture_pattern,
-        forbidden_imports: config.forbidden_imports,
-        ignored_paths: config.ignored_paths,
-        ai_configs,
-    })
+/// Genera el JSON Schema de architect.json a partir de las estructuras de configuración
+pub fn config_json_schema() -> serde_json::Value {
+    let schema = schemars::schema_for!(ConfigFile);
+    serde_json::to_value(schema).unwrap_or_default()
 }
 
 /// Valida que el JSON tenga todos los campos requeridos
@@ -384,23 +1455,88 @@ fn validate_config_values(config: &ConfigFile) -> Result<()> {
     Ok(())
 }
 
-/// Orquestador de configuración: Carga silenciosa o Wizard con IA
-pub fn setup_or_load_config(root: &Path) -> Result<std::sync::Arc<LinterContext>> {
-    use std::sync::Arc;
+/// Límite de líneas por función propuesto cuando no hay sugerencia de la IA
+const DEFAULT_MANUAL_MAX_LINES: usize = 40;
+
+/// Cómo se obtiene la configuración cuando hay que crearla o regenerarla
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SetupOptions {
+    /// Consultar todos los modelos a la vez (--ai-race)
+    pub ai_race: bool,
+    /// Repetir el descubrimiento aunque ya exista architect.json (--ai)
+    pub force_ai: bool,
+    /// Sin wizard ni IA (--offline)
+    pub offline: bool,
+    /// Sin preguntas: la IA se configura con las variables `ARCHITECT_AI_*`
+    /// (--non-interactive)
+    pub non_interactive: bool,
+    /// Confianza mínima de las reglas sugeridas que se aplican o vienen marcadas
+    /// (--ai-min-confidence)
+    pub min_confidence: Option<f32>,
+}
 
+/// Orquestador de configuración: Carga silenciosa o Wizard con IA.
+/// Con `force_ai` se repite el descubrimiento aunque ya exista architect.json.
+/// Con `non_interactive` no se pregunta nada y se aceptan las reglas sugeridas que
+/// alcanzan `min_confidence` (todas si no hay umbral).
+pub fn setup_or_load_config(root: &Path, options: SetupOptions) -> Result<Arc<LinterContext>> {
+    let SetupOptions {
+        ai_race,
+        force_ai,
+        offline,
+        non_interactive,
+        min_confidence,
+    } = options;
     let config_path = root.join("architect.json");
 
-    if config_path.exists() {
-        // MODO AUTOMÁTICO: carga silenciosa
-        let ctx = load_config(root)?;
-        return Ok(Arc::new(ctx));
+    // MODO OFFLINE: sin wizard ni IA; si falta la configuración se crea una mínima
+    if offline {
+        if force_ai {
+            eprintln!("⚠️  --ai se ignora en modo offline.");
+        }
+        if !config_path.exists() {
+            create_default_config(root)?;
+            println!("📝 Modo offline: se creó un 'architect.json' por defecto (sin reglas sugeridas por IA).\n");
+        }
+        return Ok(Arc::new(load_config(root)?));
     }
 
-    // MODO CONFIGURACIÓN (IA Discovery)
-    println!("📝 No encontré 'architect.json'. Iniciando descubrimiento asistido por IA...\n");
+    if config_path.exists() {
+        // Sin terminal, pasar --ai ya es la confirmación
+        let regenerate = force_ai
+            && (non_interactive
+                || dialoguer::Confirm::with_theme(&dialoguer::theme::ColorfulTheme::default())
+                    .with_prompt(
+                        "Ya existe 'architect.json'. ¿Reemplazarlo con la sugerencia de la IA?",
+                    )
+                    .default(false)
+                    .interact()
+                    .into_diagnostic()?);
+
+        if !regenerate {
+            // MODO AUTOMÁTICO: carga silenciosa
+            let mut ctx = load_config(root)?;
+            // En CI la IA suele venir del entorno en lugar de .architect.ai.json
+            if non_interactive
+                && ctx.ai_configs.is_empty()
+                && std::env::var_os(AI_PROVIDER_ENV).is_some()
+            {
+                ctx.ai_configs = vec![AIConfig::from_env()?];
+            }
+            return Ok(Arc::new(ctx));
+        }
+        println!("🤖 Regenerando 'architect.json' con descubrimiento asistido por IA...\n");
+    } else {
+        // MODO CONFIGURACIÓN (IA Discovery)
+        println!("📝 No encontré 'architect.json'. Iniciando descubrimiento asistido por IA...\n");
+    }
 
     // 0. Pedir configuración de IA si no existe
-    let ai_configs = crate::ui::ask_ai_configs()?;
+    let ai_configs = if non_interactive {
+        vec![AIConfig::from_env()?]
+    } else {
+        crate::ui::ask_ai_configs()?
+    };
 
     // Seleccionar cuál usar para el descubrimiento inicial
     let ai_config = if ai_configs.len() > 1 {
@@ -419,26 +1555,120 @@ pub fn setup_or_load_config(root: &Path) -> Result<std::sync::Arc<LinterContext>
     // 1. Discovery (Input local)
     let project_info = crate::discovery::get_architecture_snapshot(root);
 
-    // 2. IA (Procesamiento inteligente)
-    let suggestions = crate::ai::sugerir_arquitectura_inicial(project_info, ai_configs.clone())
-        .map_err(|e| miette::miette!("Error consultando la IA: {}", e))?;
-
-    // 3. UI (Wizard de confirmación)
-    let (selected_rules, max_lines) = crate::ui::ask_user_to_confirm_rules(suggestions)?;
+    // 2. IA (Procesamiento inteligente) y 3. UI (Wizard de confirmación).
+    // Si la IA falla, se continúa con una configuración manual sin reglas sugeridas.
+    let (selected_rules, max_lines) =
+        match crate::ai::sugerir_arquitectura_inicial(project_info, ai_configs.clone(), ai_race) {
+            Ok(suggestions) if non_interactive => (
+                auto_accepted_rules(suggestions.rules, min_confidence),
+                suggestions.suggested_max_lines,
+            ),
+            Ok(suggestions) => crate::ui::ask_user_to_confirm_rules(suggestions, min_confidence)?,
+            Err(e) if non_interactive => {
+                eprintln!("\n⚠️  Error consultando la IA: {}", e);
+                (Vec::new(), DEFAULT_MANUAL_MAX_LINES)
+            }
+            Err(e) => {
+                eprintln!("\n⚠️  Error consultando la IA: {}", e);
+                println!("📝 Continuando con la configuración manual...\n");
+                (
+                    Vec::new(),
+                    crate::ui::ask_max_lines(DEFAULT_MANUAL_MAX_LINES)?,
+                )
+            }
+        };
 
-    // 4. Config (Persistencia)
-    let final_ctx = save_config_from_wizard(
+    // 4. Config (Persistencia). La configuración tomada del entorno no se escribe
+    // en .architect.ai.json: la API key seguiría en disco después del job de CI.
+    let saved_ai_configs = if non_interactive {
+        Vec::new()
+    } else {
+        ai_configs.clone()
+    };
+    let mut final_ctx = save_config_from_wizard(
         root,
         selected_rules,
         max_lines,
-        ai_configs,
+        saved_ai_configs,
         ai_config.name.clone(),
     )?;
+    final_ctx.ai_configs = ai_configs;
 
     println!("✅ Configuración guardada exitosamente.\n");
     Ok(Arc::new(final_ctx))
 }
 
+/// Reglas de la IA que se aplican sin preguntar: las que alcanzan `min_confidence`.
+/// Las demás se listan para que se puedan agregar a mano.
+fn auto_accepted_rules(
+    rules: Vec<SuggestedRule>,
+    min_confidence: Option<f32>,
+) -> Vec<SuggestedRule> {
+    let (accepted, skipped): (Vec<_>, Vec<_>) = rules
+        .into_iter()
+        .partition(|rule| rule.meets_confidence(min_confidence));
+
+    if let Some(min) = min_confidence.filter(|_| !skipped.is_empty()) {
+        println!(
+            "⚠️  Reglas sugeridas con confianza menor a {:.2} (no se aplican):",
+            min
+        );
+        for rule in &skipped {
+            let confidence = rule
+                .confidence
+                .map_or_else(|| "sin confianza".to_string(), |c| format!("{:.2}", c));
+            println!("   {} -> {} ({})", rule.from, rule.to, confidence);
+        }
+    }
+    accepted
+}
+
+/// Escribe un architect.json mínimo sin preguntar nada: el preset del framework
+/// detectado, sin imports prohibidos ni patrón de arquitectura
+fn create_default_config(root: &Path) -> Result<()> {
+    let framework = crate::detector::detect_framework(root);
+    let preset = crate::detector::get_framework_preset(&framework);
+    let settings: RuleSettings =
+        serde_json::from_value(serde_json::Value::Object(preset)).unwrap_or_default();
+
+    let config = ConfigFile {
+        max_lines_per_function: crate::detector::get_loc_suggestion(&framework),
+        architecture_pattern: ArchPattern::Ninguno,
+        forbidden_imports: Vec::new(),
+        ignored_paths: get_framework_ignored_paths(&framework),
+        settings,
+    };
+
+    let json = serde_json::to_string_pretty(&config).into_diagnostic()?;
+    fs::write(root.join("architect.json"), json).into_diagnostic()
+}
+
+/// Agrega reglas a `forbidden_imports` de architect.json conservando el resto del
+/// archivo tal como está (sin el preset del framework)
+pub fn append_forbidden_imports(root: &Path, rules: &[ForbiddenRule]) -> Result<()> {
+    let config_path = root.join("architect.json");
+    let content = fs::read_to_string(&config_path).into_diagnostic()?;
+    let mut json: serde_json::Value = serde_json::from_str(&content).into_diagnostic()?;
+
+    let forbidden = json
+        .as_object_mut()
+        .and_then(|obj| obj.get_mut("forbidden_imports"))
+        .and_then(|value| value.as_array_mut())
+        .ok_or_else(|| {
+            ConfigError::new(
+                "architect.json no tiene un array 'forbidden_imports'".to_string(),
+                "Agrega \"forbidden_imports\": [] al archivo o copia el fragmento a mano."
+                    .to_string(),
+            )
+        })?;
+    for rule in rules {
+        forbidden.push(serde_json::to_value(rule).into_diagnostic()?);
+    }
+
+    let json = serde_json::to_string_pretty(&json).into_diagnostic()?;
+    fs::write(&config_path, json).into_diagnostic()
+}
+
 /// PERSISTENCIA: Guarda las reglas de la IA y devuelve el contexto nuevo
 pub fn save_config_from_wizard(
     root: &Path,
@@ -464,12 +1694,18 @@ pub fn save_config_from_wizard(
     // Obtener ignored_paths según el framework
     let ignored_paths = get_framework_ignored_paths(&framework);
 
+    // Las reglas adicionales parten del preset del framework
+    let preset = crate::detector::get_framework_preset(&framework);
+    let settings: RuleSettings =
+        serde_json::from_value(serde_json::Value::Object(preset)).unwrap_or_default();
+
     // Valores por defecto para el primer architect.json
     let config = ConfigFile {
         max_lines_per_function: max_lines,
         architecture_pattern: ArchPattern::MVC, // O el que detecte la IA
         forbidden_imports: forbidden_imports.clone(),
         ignored_paths: ignored_paths.clone(),
+        settings,
     };
 
     let json = serde_json::to_string_pretty(&config).into_diagnostic()?;
@@ -503,7 +1739,9 @@ pub fn save_config_from_wizard(
         pattern: config.architecture_pattern,
         forbidden_imports,
         ignored_paths,
+        settings: config.settings,
         ai_configs,
+        cascade: None,
     })
 }
 
@@ -704,6 +1942,23 @@ pub struct ArchError {
     #[label("{message}")]
     pub span: SourceSpan,
     pub message: String,
+    /// Sugerencia de corrección de la regla (ver `rules::help_for`)
+    #[help]
+    pub help: Option<String>,
+}
+
+#[derive(Error, Debug, Diagnostic)]
+#[error("Advertencia de Arquitectura")]
+#[diagnostic(code(arch::warning), severity(warning))]
+pub struct ArchWarning {
+    #[source_code]
+    pub src: String,
+    #[label("{message}")]
+    pub span: SourceSpan,
+    pub message: String,
+    /// Sugerencia de corrección de la regla (ver `rules::help_for`)
+    #[help]
+    pub help: Option<String>,
 }
 
 #[derive(Error, Debug, Diagnostic)]
@@ -720,3 +1975,317 @@ impl ConfigError {
         Self { details, help }
     }
 }
+
+#[cfg(test)]
+impl LinterContext {
+    /// Contexto sin framework ni patrón, con `settings`, para los tests de las reglas
+    pub fn for_tests(settings: RuleSettings) -> Self {
+        Self {
+            max_lines: 60,
+            framework: Framework::Unknown,
+            pattern: ArchPattern::Ninguno,
+            forbidden_imports: Vec::new(),
+            ignored_paths: Vec::new(),
+            settings,
+            ai_configs: Vec::new(),
+            cascade: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_api_url_recognizes_official_hosts() {
+        let cases = [
+            ("https://api.anthropic.com", AIProvider::Claude),
+            (
+                "https://generativelanguage.googleapis.com/v1beta",
+                AIProvider::Gemini,
+            ),
+            ("https://api.openai.com/v1", AIProvider::OpenAI),
+            ("https://api.groq.com/openai/v1", AIProvider::Groq),
+            ("https://api.moonshot.ai/v1", AIProvider::Kimi),
+            ("https://api.deepseek.com", AIProvider::DeepSeek),
+            ("http://localhost:11434/v1", AIProvider::Ollama),
+            ("http://192.168.0.10:11434", AIProvider::Ollama),
+        ];
+        for (url, provider) in cases {
+            assert_eq!(AIProvider::from_api_url(url), Some(provider), "{}", url);
+        }
+    }
+
+    #[test]
+    fn from_api_url_does_not_guess_unknown_hosts() {
+        // Ni un host propio ni "ollama" fuera del host identifican al proveedor
+        assert_eq!(AIProvider::from_api_url("https://llm.example.com/v1"), None);
+        assert_eq!(
+            AIProvider::from_api_url("https://gateway.example.com/ollama/v1"),
+            None
+        );
+        assert_eq!(
+            AIProvider::from_api_url("https://anthropic.com.evil.example"),
+            None
+        );
+        assert_eq!(AIProvider::from_api_url(""), None);
+    }
+
+    #[test]
+    fn parse_ai_config_file_infers_a_missing_provider_from_the_url() {
+        let content = r#"{
+            "selected_name": "local",
+            "configs": [
+                { "name": "local", "api_url": "http://localhost:11434/v1", "api_key": "", "model": "llama3" },
+                { "name": "gpt", "provider": "OpenAI", "api_url": "http://localhost:11434/v1", "api_key": "k", "model": "gpt-4o" }
+            ]
+        }"#;
+        let file = parse_ai_config_file(content).unwrap();
+        assert_eq!(file.configs[0].provider, AIProvider::Ollama);
+        // Un `provider` explícito manda sobre la URL
+        assert_eq!(file.configs[1].provider, AIProvider::OpenAI);
+    }
+
+    #[test]
+    fn parse_ai_config_file_rejects_an_unknown_url_without_provider() {
+        let content = r#"{
+            "selected_name": "proxy",
+            "configs": [
+                { "name": "proxy", "api_url": "https://llm.example.com/v1", "api_key": "k", "model": "m" }
+            ]
+        }"#;
+        let error = parse_ai_config_file(content).unwrap_err().to_string();
+        assert!(error.contains("proxy"), "{}", error);
+        assert!(error.contains("provider"), "{}", error);
+    }
+
+    #[test]
+    fn config_json_schema_validates_architect_json() {
+        let schema = jsonschema::JSONSchema::compile(&config_json_schema()).unwrap();
+
+        let good = serde_json::json!({
+            "max_lines_per_function": 40,
+            "architecture_pattern": "Hexagonal",
+            "forbidden_imports": [{ "from": "src/domain/**", "to": "src/infrastructure/**" }],
+            "sealed_paths": ["src/core/**"],
+            "max_cognitive_complexity": 10
+        });
+        assert!(schema.is_valid(&good));
+
+        // Un patrón desconocido, un número como texto o una regla sin `to`
+        let bad = [
+            serde_json::json!({
+                "max_lines_per_function": 40,
+                "architecture_pattern": "Layered",
+                "forbidden_imports": []
+            }),
+            serde_json::json!({
+                "max_lines_per_function": "40",
+                "architecture_pattern": "Clean",
+                "forbidden_imports": []
+            }),
+            serde_json::json!({
+                "max_lines_per_function": 40,
+                "architecture_pattern": "MVC",
+                "forbidden_imports": [{ "from": "src/controllers/**" }]
+            }),
+        ];
+        for config in &bad {
+            assert!(!schema.is_valid(config), "{}", config);
+        }
+    }
+
+    #[test]
+    fn a_nestjs_project_gets_the_nestjs_preset() {
+        let content = r#"{
+            "max_lines_per_function": 40,
+            "architecture_pattern": "Hexagonal",
+            "forbidden_imports": []
+        }"#;
+        let config = parse_config_file(content, &Framework::NestJS).unwrap();
+        assert_eq!(config.settings.max_cognitive_complexity, 20);
+        assert_eq!(config.settings.max_decorators, 7);
+        assert_eq!(config.settings.max_imports, 20);
+        assert!(config.settings.no_manual_di_instantiation.is_some());
+        assert!(config.ignored_paths.contains(&"dist/".to_string()));
+
+        // Sin framework rigen los valores por defecto
+        let config = parse_config_file(content, &Framework::Unknown).unwrap();
+        assert_eq!(config.settings.max_decorators, 5);
+        assert!(config.settings.no_manual_di_instantiation.is_none());
+    }
+
+    #[test]
+    fn an_explicit_key_wins_over_the_preset() {
+        let content = r#"{
+            "max_lines_per_function": 40,
+            "architecture_pattern": "Hexagonal",
+            "forbidden_imports": [],
+            "max_decorators": 3,
+            "max_cognitive_complexity": 8
+        }"#;
+        let config = parse_config_file(content, &Framework::NestJS).unwrap();
+        assert_eq!(config.settings.max_decorators, 3);
+        assert_eq!(config.settings.max_cognitive_complexity, 8);
+        // Las claves que el usuario no definió siguen viniendo del preset
+        assert_eq!(config.settings.max_imports, 20);
+    }
+
+    #[test]
+    fn the_most_specific_pattern_scope_governs_each_file() {
+        let content = r#"{
+            "max_lines_per_function": 40,
+            "architecture_pattern": "MVC",
+            "forbidden_imports": [],
+            "pattern_scopes": [
+                { "pattern": "Clean", "paths": ["src/core/**"] },
+                { "pattern": "MVC", "paths": ["src/core/web/**"] }
+            ]
+        }"#;
+        let config = parse_config_file(content, &Framework::Unknown).unwrap();
+        let mut ctx = LinterContext::for_tests(config.settings);
+        ctx.pattern = config.architecture_pattern;
+
+        let cases = [
+            (
+                "src/core/users/users.controller.ts",
+                Some(ArchPattern::Clean),
+            ),
+            ("src/core/web/users.controller.ts", Some(ArchPattern::MVC)),
+            ("src/delivery/users.controller.ts", None),
+        ];
+        for (file, pattern) in cases {
+            assert_eq!(
+                ctx.scoped_pattern(Path::new(file)),
+                pattern.as_ref(),
+                "{}",
+                file
+            );
+        }
+
+        // La regla MVC solo corre donde gobierna MVC (el alcance o el patrón global)
+        let enabled = |file| ctx.is_rule_enabled("mvc-controller-repository", Path::new(file));
+        assert!(!enabled("src/core/users/users.controller.ts"));
+        assert!(enabled("src/core/web/users.controller.ts"));
+        assert!(enabled("src/delivery/users.controller.ts"));
+    }
+
+    #[test]
+    fn a_nested_architect_json_layers_onto_the_root_one() {
+        let root = crate::rules::testing::temp_project(
+            "config-cascade",
+            &[
+                (
+                    "architect.json",
+                    r#"{
+                        "max_lines_per_function": 60,
+                        "architecture_pattern": "Hexagonal",
+                        "forbidden_imports": [{ "from": "src/**", "to": "lodash" }],
+                        "cascade": true
+                    }"#,
+                ),
+                (
+                    "src/domain/architect.json",
+                    r#"{
+                        "max_lines_per_function": 20,
+                        "forbidden_imports": [{ "from": "src/domain/**", "to": "src/infrastructure/**" }]
+                    }"#,
+                ),
+                ("src/domain/user.ts", ""),
+                ("src/domain/model/order.ts", ""),
+                ("src/app/main.ts", ""),
+            ],
+        );
+        let ctx = load_config(&root).unwrap();
+
+        // Fuera de src/domain rige la raíz
+        assert!(ctx.cascaded_for(&root.join("src/app/main.ts")).is_none());
+
+        // En src/domain y sus subcarpetas manda el más cercano y los arrays se concatenan
+        for file in ["src/domain/user.ts", "src/domain/model/order.ts"] {
+            let domain = ctx.cascaded_for(&root.join(file)).unwrap();
+            assert_eq!(domain.max_lines, 20, "{}", file);
+            assert!(matches!(domain.pattern, ArchPattern::Hexagonal));
+            let targets: Vec<&str> = domain
+                .forbidden_imports
+                .iter()
+                .map(|rule| rule.to.as_str())
+                .collect();
+            assert_eq!(targets, vec!["lodash", "src/infrastructure/**"], "{}", file);
+        }
+
+        // Sin `cascade` el architect.json anidado se ignora
+        let flat = root.join("architect.json");
+        let content = fs::read_to_string(&flat).unwrap();
+        fs::write(
+            &flat,
+            content.replace("\"cascade\": true", "\"cascade\": false"),
+        )
+        .unwrap();
+        let ctx = load_config(&root).unwrap();
+        assert!(ctx.cascaded_for(&root.join("src/domain/user.ts")).is_none());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn only_rules_at_or_above_the_min_confidence_are_auto_applied() {
+        let rule = |to: &str, confidence| SuggestedRule {
+            from: "src/domain/**".to_string(),
+            to: to.to_string(),
+            reason: String::new(),
+            confidence,
+        };
+        let suggested = || {
+            vec![
+                rule("src/infrastructure/**", Some(0.95)),
+                rule("src/application/**", Some(0.7)),
+                rule("src/ui/**", Some(0.69)),
+                rule("src/shared/**", None),
+            ]
+        };
+        let targets = |rules: Vec<SuggestedRule>| -> Vec<String> {
+            rules.into_iter().map(|rule| rule.to).collect()
+        };
+
+        // El umbral es inclusivo y una regla sin confianza declarada no lo alcanza
+        assert_eq!(
+            targets(auto_accepted_rules(suggested(), Some(0.7))),
+            vec!["src/infrastructure/**", "src/application/**"]
+        );
+        assert_eq!(
+            targets(auto_accepted_rules(suggested(), Some(0.99))),
+            Vec::<String>::new()
+        );
+        // Sin --ai-min-confidence se aplican todas
+        assert_eq!(auto_accepted_rules(suggested(), None).len(), 4);
+    }
+
+    #[test]
+    fn sampling_values_are_validated_per_provider() {
+        let config = |provider, temperature, max_tokens| AIConfig {
+            provider,
+            temperature,
+            max_tokens,
+            ..AIConfig::default()
+        };
+
+        // Claude acepta hasta 1; los demás hasta 2
+        assert!(config(AIProvider::Claude, Some(1.5), None)
+            .validate()
+            .is_err());
+        assert!(config(AIProvider::Gemini, Some(1.5), None)
+            .validate()
+            .is_ok());
+        assert!(config(AIProvider::OpenAI, Some(-0.1), None)
+            .validate()
+            .is_err());
+        assert!(config(AIProvider::OpenAI, None, Some(0))
+            .validate()
+            .is_err());
+        assert!(config(AIProvider::OpenAI, None, Some(512))
+            .validate()
+            .is_ok());
+    }
+}
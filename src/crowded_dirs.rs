@@ -0,0 +1,131 @@
+//! Carpetas con demasiados archivos (max-files-per-dir)
+//!
+//! Una carpeta con decenas de archivos sueltos suele pedir subcarpetas por feature
+//! o responsabilidad. Se cuentan los archivos analizados que están directamente en
+//! cada carpeta (no en sus subcarpetas), a partir del listado ya recolectado.
+
+use crate::analyzer;
+use crate::config::RuleSettings;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+pub const RULE_ID: &str = "max-files-per-dir";
+
+/// Carpetas planas por convención, exentas por defecto
+pub fn default_exempt_dirs() -> Vec<String> {
+    ["migrations/", "seeds/", "__generated__/"]
+        .iter()
+        .map(|pattern| pattern.to_string())
+        .collect()
+}
+
+/// Carpeta que supera `max_files_per_dir`
+#[derive(Debug, Clone)]
+pub struct CrowdedDir {
+    /// Ruta relativa a la raíz del proyecto, terminada en `/`
+    pub dir: String,
+    pub files: usize,
+}
+
+/// Carpetas con más archivos directos que el máximo, de más a menos archivos.
+/// Con `max_files_per_dir` en 0 no hace nada.
+pub fn find_crowded_dirs(
+    files: &[PathBuf],
+    project_root: &Path,
+    settings: &RuleSettings,
+) -> Vec<CrowdedDir> {
+    let max = settings.max_files_per_dir;
+    if max == 0 {
+        return Vec::new();
+    }
+
+    let mut counts: BTreeMap<&Path, usize> = BTreeMap::new();
+    for file in files {
+        if let Some(dir) = file.parent() {
+            *counts.entry(dir).or_insert(0) += 1;
+        }
+    }
+
+    let mut crowded: Vec<CrowdedDir> = counts
+        .into_iter()
+        .filter(|(_, count)| *count > max)
+        .map(|(dir, count)| CrowdedDir {
+            dir: format!(
+                "{}/",
+                dir.strip_prefix(project_root)
+                    .unwrap_or(dir)
+                    .to_string_lossy()
+                    .replace('\\', "/")
+            ),
+            files: count,
+        })
+        .filter(|crowded| !is_exempt(&crowded.dir, &settings.max_files_per_dir_exempt))
+        .collect();
+    crowded.sort_by(|a, b| b.files.cmp(&a.files).then_with(|| a.dir.cmp(&b.dir)));
+    crowded
+}
+
+/// Imprime una línea por carpeta que supera el máximo
+pub fn print_crowded_dirs_report(crowded: &[CrowdedDir], max: usize) {
+    if crowded.is_empty() {
+        return;
+    }
+
+    println!(
+        "\n📂 CARPETAS CON MÁS DE {} ARCHIVOS ({})\n",
+        max,
+        crowded.len()
+    );
+    for dir in crowded {
+        println!("  {:>4} archivos  {}", dir.files, dir.dir);
+    }
+    println!("\n💡 Agrupa los archivos en subcarpetas por feature o responsabilidad.");
+}
+
+fn is_exempt(dir: &str, exempt: &[String]) -> bool {
+    exempt
+        .iter()
+        .any(|pattern| analyzer::matches_pattern(dir, &analyzer::normalize_pattern(pattern)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::LinterContext;
+    use crate::discovery;
+    use crate::rules::testing::temp_project;
+    use std::fs;
+
+    #[test]
+    fn only_directories_with_too_many_direct_files_are_reported() {
+        let mut files = Vec::new();
+        for index in 0..4 {
+            files.push((format!("src/utils/util{}.ts", index), ""));
+            files.push((format!("src/migrations/{}-init.ts", index), ""));
+        }
+        // Las subcarpetas no suman a su padre
+        for index in 0..3 {
+            files.push((format!("src/utils/dates/date{}.ts", index), ""));
+        }
+        let files: Vec<(&str, &str)> = files
+            .iter()
+            .map(|(path, content)| (path.as_str(), *content))
+            .collect();
+        let root = temp_project("crowded-dirs", &files);
+        let settings = RuleSettings {
+            max_files_per_dir: 3,
+            ..RuleSettings::default()
+        };
+        let files = discovery::collect_files(&root, &LinterContext::for_tests(settings.clone()));
+
+        let crowded = find_crowded_dirs(&files, &root, &settings);
+
+        let found: Vec<(&str, usize)> = crowded
+            .iter()
+            .map(|crowded| (crowded.dir.as_str(), crowded.files))
+            .collect();
+        assert_eq!(found, vec![("src/utils/", 4)]);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,128 @@
+//! Exportación de las violaciones en CSV (--format csv)
+//!
+//! Pensado para hojas de cálculo: una fila por violación con cabecera fija.
+//! Los campos se entrecomillan según RFC 4180 cuando contienen comas, comillas
+//! o saltos de línea.
+
+use crate::compare::ReportEntry;
+use crate::rules::Severity;
+use std::io::{self, Write};
+
+/// Cabecera del CSV. El final del código señalado va después de `message` para que
+/// las seis primeras columnas sigan siendo las de siempre
+const HEADER: &str = "file,line,column,rule_id,severity,message,end_line,end_column";
+
+/// Escribe las violaciones en CSV en stdout
+pub fn print_csv(entries: &[ReportEntry]) -> io::Result<()> {
+    write_csv(&mut io::stdout().lock(), entries)
+}
+
+/// Escribe la cabecera y una fila por violación
+fn write_csv(out: &mut impl Write, entries: &[ReportEntry]) -> io::Result<()> {
+    writeln!(out, "{}", HEADER)?;
+    for entry in entries {
+        let severity = match entry.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{},{}",
+            escape(&entry.file),
+            entry.line,
+            entry.column,
+            escape(&entry.rule_id),
+            severity,
+            escape(&entry.message),
+            entry.end_line,
+            entry.end_column
+        )?;
+    }
+    out.flush()
+}
+
+/// Entrecomilla el campo si hace falta, duplicando las comillas internas
+fn escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::circular::CircularDependency;
+
+    /// Lector RFC 4180 mínimo: separa filas y campos respetando las comillas
+    fn parse(csv: &str) -> Vec<Vec<String>> {
+        let mut rows = Vec::new();
+        let mut row = Vec::new();
+        let mut field = String::new();
+        let mut quoted = false;
+        let mut chars = csv.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '"' if quoted && chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                '"' => quoted = !quoted,
+                ',' if !quoted => row.push(std::mem::take(&mut field)),
+                '\n' if !quoted => {
+                    row.push(std::mem::take(&mut field));
+                    rows.push(std::mem::take(&mut row));
+                }
+                _ => field.push(c),
+            }
+        }
+        rows
+    }
+
+    fn render(entries: &[ReportEntry]) -> String {
+        let mut out = Vec::new();
+        write_csv(&mut out, entries).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn quotes_fields_with_commas_quotes_and_newlines() {
+        let mut entry = ReportEntry::from_unclassified("src/a, b.ts");
+        entry.line = 3;
+        entry.column = 5;
+        entry.end_line = 4;
+        entry.end_column = 12;
+        entry.message = "Usa \"UserRepository\", no el ORM\ndirectamente".to_string();
+
+        let rows = parse(&render(&[entry.clone()]));
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].join(","), HEADER);
+        let fields = &rows[1];
+        assert_eq!(fields.len(), HEADER.split(',').count());
+        assert_eq!(fields[0], "src/a, b.ts");
+        assert_eq!(fields[1], "3");
+        assert_eq!(fields[2], "5");
+        assert_eq!(fields[3], "require-layer");
+        assert_eq!(fields[4], "error");
+        assert_eq!(fields[5], entry.message);
+        assert_eq!(fields[6], "4");
+        assert_eq!(fields[7], "12");
+    }
+
+    #[test]
+    fn cycles_are_rows_with_the_joined_path() {
+        let cycle = CircularDependency {
+            cycle: vec!["src/a.ts".to_string(), "src/b.ts".to_string()],
+            description: String::new(),
+            via_barrel: false,
+        };
+
+        let rows = parse(&render(&[ReportEntry::from_cycle(&cycle)]));
+        let fields = &rows[1];
+        assert_eq!(fields[0], "src/a.ts");
+        assert_eq!(fields[3], "circular-dependency");
+        assert_eq!(fields[4], "error");
+        assert_eq!(fields[5], "src/a.ts → src/b.ts");
+    }
+}
@@ -1,4 +1,5 @@
 use crate::config::Framework;
+use serde_json::{json, Map, Value};
 use std::fs;
 use std::path::Path;
 
@@ -42,3 +43,51 @@ pub fn get_loc_suggestion(framework: &Framework) -> usize {
         Framework::Unknown => 50, // Estándar general
     }
 }
+
+/// Preset de reglas por defecto según el framework detectado.
+/// Se aplica por debajo de architect.json: cualquier clave explícita del usuario gana.
+pub fn get_framework_preset(framework: &Framework) -> Map<String, Value> {
+    let mut preset = Map::new();
+
+    if *framework == Framework::Unknown {
+        return preset;
+    }
+
+    preset.insert(
+        "ignored_paths".to_string(),
+        json!(crate::config::get_framework_ignored_paths(framework)),
+    );
+
+    let max_cognitive_complexity = match framework {
+        Framework::NestJS => 20, // Servicios que orquestan varios casos de uso
+        Framework::React => 10,  // Componentes pequeños y declarativos
+        _ => 15,
+    };
+    preset.insert(
+        "max_cognitive_complexity".to_string(),
+        json!(max_cognitive_complexity),
+    );
+
+    match framework {
+        // Controladores con rutas, guards y Swagger; módulos que cablean muchos providers
+        Framework::NestJS | Framework::Angular => {
+            let max_decorators = if *framework == Framework::NestJS {
+                7
+            } else {
+                6
+            };
+            preset.insert("max_decorators".to_string(), json!(max_decorators));
+            preset.insert("max_imports".to_string(), json!(20));
+            // Los providers (`@Injectable()`) los crea el contenedor de DI
+            preset.insert("no_manual_di_instantiation".to_string(), json!({}));
+        }
+        _ => {}
+    }
+
+    // La guía de estilo de Angular desaconseja el prefijo `I` en las interfaces
+    if *framework == Framework::Angular {
+        preset.insert("interface_naming".to_string(), json!("no-i-prefix"));
+    }
+
+    preset
+}
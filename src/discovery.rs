@@ -1,8 +1,15 @@
+use crate::config::{DeclarationFiles, LinterContext};
 use crate::detector;
 use crate::parsers;
+use dialoguer::Confirm;
+use ignore::WalkBuilder;
+use miette::{IntoDiagnostic, Result};
 use serde::Serialize;
 use std::fs;
+use std::io::IsTerminal;
 use std::path::{Path, PathBuf};
+use std::sync::{mpsc, Arc};
+use std::thread;
 use walkdir::{DirEntry, WalkDir};
 
 #[derive(Serialize)]
@@ -15,28 +22,263 @@ pub struct ProjectContext {
 
 /// Recolecta todos los archivos soportados que el linter debe analizar.
 /// Incluye: TypeScript (.ts, .tsx), JavaScript (.js, .jsx), Python (.py), Go (.go), PHP (.php), Java (.java)
-/// Respeta los patrones de exclusión definidos en ignored_paths.
-pub fn collect_files(root: &Path, ignored_paths: &[String]) -> Vec<PathBuf> {
-    let supported_exts = parsers::supported_extensions();
+/// Respeta los patrones de exclusión definidos en ignored_paths y, si `respect_gitignore`
+/// está activo, los archivos ignorados por git (.gitignore anidados y .git/info/exclude).
+pub fn collect_files(root: &Path, ctx: &LinterContext) -> Vec<PathBuf> {
+    walk_files(root, ctx).collect()
+}
 
-    WalkDir::new(root)
-        .into_iter()
-        .filter_entry(|e| is_not_ignored_with_patterns(e, root, ignored_paths))
+/// Como `collect_files`, pero se detiene al superar `max_files` para no recorrer árboles
+/// enormes por error (ej: apuntar al home o a `/`). En una terminal pregunta si continuar;
+/// en modo no interactivo devuelve un error.
+pub fn collect_files_guarded(
+    root: &Path,
+    ctx: &LinterContext,
+    max_files: usize,
+) -> Result<Vec<PathBuf>> {
+    collect_files_limited(root, ctx, max_files, std::io::stdin().is_terminal())
+}
+
+fn collect_files_limited(
+    root: &Path,
+    ctx: &LinterContext,
+    max_files: usize,
+    interactive: bool,
+) -> Result<Vec<PathBuf>> {
+    let files: Vec<PathBuf> = walk_files(root, ctx).take(max_files + 1).collect();
+    if files.len() <= max_files {
+        return Ok(files);
+    }
+
+    confirm_or_fail(root, max_files, interactive)?;
+    Ok(collect_files(root, ctx))
+}
+
+/// Tras superar `max_files`: en una terminal pregunta si analizar todo de todos
+/// modos; en modo no interactivo (o si se rechaza) devuelve un error
+pub fn confirm_or_fail(root: &Path, max_files: usize, interactive: bool) -> Result<()> {
+    let message = too_many_files_message(root, max_files);
+    if !interactive {
+        return Err(miette::miette!("{}", message));
+    }
+
+    eprintln!("⚠️  {}", message);
+    let proceed = Confirm::new()
+        .with_prompt("¿Analizar todos los archivos de todos modos?")
+        .default(false)
+        .interact()
+        .into_diagnostic()?;
+
+    if proceed {
+        Ok(())
+    } else {
+        Err(miette::miette!("Análisis cancelado: demasiados archivos."))
+    }
+}
+
+/// Aviso de --max-files superado
+fn too_many_files_message(root: &Path, max_files: usize) -> String {
+    format!(
+        "Se encontraron más de {} archivos en {}. Acota la ruta o usa --max-files para subir el límite.",
+        max_files,
+        root.display()
+    )
+}
+
+/// Rutas descubiertas que pueden esperar en el canal a ser analizadas. Acota la
+/// memoria del recorrido en repositorios enormes.
+const STREAM_BUFFER: usize = 1024;
+
+/// Recorre el proyecto en un hilo aparte y entrega los archivos por un canal acotado,
+/// para que el análisis empiece sin esperar al recorrido completo. `max_files` actúa
+/// como tope: el hilo devuelve `true` si se detuvo por superarlo.
+pub fn stream_files(
+    root: &Path,
+    ctx: Arc<LinterContext>,
+    max_files: usize,
+) -> (mpsc::Receiver<PathBuf>, thread::JoinHandle<bool>) {
+    let (sender, receiver) = mpsc::sync_channel(STREAM_BUFFER);
+    let root = root.to_path_buf();
+
+    let walker = thread::spawn(move || {
+        for (count, path) in walk_files(&root, &ctx).enumerate() {
+            if count == max_files {
+                return true;
+            }
+            // El canal se cierra si el análisis termina antes (ej: un error)
+            if sender.send(path).is_err() {
+                break;
+            }
+        }
+        false
+    });
+
+    (receiver, walker)
+}
+
+/// Recorre el proyecto produciendo los archivos a analizar
+fn walk_files(root: &Path, ctx: &LinterContext) -> impl Iterator<Item = PathBuf> {
+    let supported_exts = analyzed_extensions(ctx);
+    let respect_gitignore = ctx.settings.respect_gitignore;
+    let declaration_files = ctx.settings.declaration_files;
+
+    let filter_root = root.to_path_buf();
+    let ignored_paths = ctx.ignored_paths.clone();
+
+    WalkBuilder::new(root)
+        .standard_filters(false)
+        .git_ignore(respect_gitignore)
+        .git_exclude(respect_gitignore)
+        .parents(respect_gitignore)
+        .require_git(false)
+        .filter_entry(move |e| is_not_ignored_with_patterns(e.path(), &filter_root, &ignored_paths))
+        .build()
         .filter_map(|e| e.ok())
-        .filter(|e| {
-            let path_str = e.path().to_string_lossy();
+        .filter(|e| e.file_type().is_some_and(|ft| ft.is_file()))
+        .filter(move |e| is_analyzable(e.path(), &supported_exts, declaration_files))
+        .map(|e| e.path().to_path_buf())
+}
 
-            // Ignorar archivos de definición TypeScript
-            if path_str.ends_with(".d.ts") {
-                return false;
+/// Indica si el archivo tiene una extensión a analizar
+pub(crate) fn is_analyzable(
+    path: &Path,
+    supported_exts: &[String],
+    declaration_files: DeclarationFiles,
+) -> bool {
+    // Los archivos de definición TypeScript solo se analizan en modo "structural"
+    if is_declaration_file(path) && declaration_files == DeclarationFiles::Skip {
+        return false;
+    }
+
+    // Verificar si la extensión está en la lista de soportadas
+    path.extension().is_some_and(|ext| {
+        supported_exts
+            .iter()
+            .any(|supported| ext == supported.as_str())
+    })
+}
+
+/// Archivo de declaración de tipos de TypeScript (`.d.ts`)
+pub(crate) fn is_declaration_file(path: &Path) -> bool {
+    path.to_string_lossy().ends_with(".d.ts")
+}
+
+/// Indica si la ruta posicional es un patrón glob (ej: `src/**/*.controller.ts`)
+pub fn is_glob_pattern(path: &str) -> bool {
+    path.chars().any(|c| matches!(c, '*' | '?' | '['))
+}
+
+/// Raíz del proyecto para un glob: el ancestro más cercano de su parte literal que
+/// tenga architect.json o package.json. Sin ninguno, la propia parte literal.
+pub fn glob_project_root(pattern: &str) -> Result<PathBuf> {
+    let literal: PathBuf = Path::new(pattern)
+        .components()
+        .take_while(|c| !is_glob_pattern(&c.as_os_str().to_string_lossy()))
+        .collect();
+    let base = if literal.as_os_str().is_empty() {
+        PathBuf::from(".")
+    } else {
+        literal
+    };
+    let base = base.canonicalize().into_diagnostic()?;
+
+    let root = base
+        .ancestors()
+        .find(|dir| dir.join("architect.json").exists() || dir.join("package.json").exists())
+        .unwrap_or(&base)
+        .to_path_buf();
+    Ok(root)
+}
+
+/// Expande un glob a los archivos analizables, sin recorrer el resto del proyecto.
+/// Se aplican igualmente `ignored_paths` y el filtro de extensiones.
+pub fn expand_glob(pattern: &str, root: &Path, ctx: &LinterContext) -> Result<Vec<PathBuf>> {
+    let supported_exts = analyzed_extensions(ctx);
+
+    let mut files: Vec<PathBuf> = glob::glob(pattern)
+        .into_diagnostic()?
+        .filter_map(|entry| entry.ok())
+        .filter(|path| path.is_file())
+        .filter_map(|path| path.canonicalize().ok())
+        .filter(|path| is_analyzable(path, &supported_exts, ctx.settings.declaration_files))
+        .filter(|path| passes_ignored_paths(path, root, ctx))
+        .collect();
+
+    files.sort();
+    files.dedup();
+    Ok(files)
+}
+
+/// Lee la lista de archivos a analizar de un archivo (una ruta por línea) o de
+/// stdin con `-` (--files-from). Las rutas relativas se resuelven desde el
+/// directorio actual y, si no existen ahí, desde la raíz del proyecto.
+/// Las rutas que ya no existen (archivos borrados en el cambio) se omiten.
+pub fn read_files_from(source: &str, root: &Path, ctx: &LinterContext) -> Result<Vec<PathBuf>> {
+    let content = if source == "-" {
+        std::io::read_to_string(std::io::stdin()).into_diagnostic()?
+    } else {
+        fs::read_to_string(source).into_diagnostic()?
+    };
+    let supported_exts = analyzed_extensions(ctx);
+
+    let mut missing = 0;
+    let mut files: Vec<PathBuf> = content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .filter_map(|line| {
+            let path = Path::new(line);
+            let resolved = [path.to_path_buf(), root.join(path)]
+                .into_iter()
+                .find(|candidate| candidate.is_file())
+                .and_then(|candidate| candidate.canonicalize().ok());
+            if resolved.is_none() {
+                missing += 1;
             }
+            resolved
+        })
+        .filter(|path| is_analyzable(path, &supported_exts, ctx.settings.declaration_files))
+        .filter(|path| passes_ignored_paths(path, root, ctx))
+        .collect();
+
+    if missing > 0 {
+        // A stderr: con --format csv/sarif stdout solo lleva el documento
+        eprintln!("⏭️  {} ruta(s) de la lista no existen y se omiten", missing);
+    }
 
-            // Verificar si la extensión está en la lista de soportadas
-            e.path().extension().map_or(false, |ext| {
-                supported_exts.iter().any(|&supported| ext == supported)
-            })
+    files.sort();
+    files.dedup();
+    Ok(files)
+}
+
+/// Cada directorio entre la raíz y el archivo debe pasar los ignored_paths
+pub(crate) fn passes_ignored_paths(path: &Path, root: &Path, ctx: &LinterContext) -> bool {
+    path.ancestors()
+        .take_while(|dir| *dir != root && dir.starts_with(root))
+        .all(|dir| is_not_ignored_with_patterns(dir, root, &ctx.ignored_paths))
+}
+
+/// Extensiones a analizar: las configuradas en `extensions` o, si no hay, todas las soportadas.
+/// Las extensiones configuradas sin parser disponible se avisan y se omiten.
+pub(crate) fn analyzed_extensions(ctx: &LinterContext) -> Vec<String> {
+    if ctx.settings.extensions.is_empty() {
+        return parsers::supported_extensions()
+            .into_iter()
+            .map(String::from)
+            .collect();
+    }
+
+    ctx.settings
+        .extensions
+        .iter()
+        .map(|ext| ext.trim_start_matches('.').to_lowercase())
+        .filter(|ext| {
+            let supported = parsers::Language::from_extension(ext).is_some();
+            if !supported {
+                eprintln!("⚠️  Extensión sin soporte, se omite: .{}", ext);
+            }
+            supported
         })
-        .map(|e| e.path().to_path_buf())
         .collect()
 }
 
@@ -58,7 +300,7 @@ pub fn get_architecture_snapshot(root: &Path) -> ProjectContext {
 
     let walker = WalkDir::new(scan_path)
         .into_iter()
-        .filter_entry(|e| is_not_ignored(e));
+        .filter_entry(is_not_ignored);
 
     for entry in walker.filter_map(|e| e.ok()) {
         let path = entry.path();
@@ -111,9 +353,8 @@ fn is_not_ignored(entry: &DirEntry) -> bool {
 }
 
 /// Verifica si una entrada debe ser ignorada según los patrones configurados
-fn is_not_ignored_with_patterns(entry: &DirEntry, root: &Path, ignored_paths: &[String]) -> bool {
+fn is_not_ignored_with_patterns(entry_path: &Path, root: &Path, ignored_paths: &[String]) -> bool {
     // Obtener la ruta relativa al root del proyecto
-    let entry_path = entry.path();
     let relative_path = entry_path
         .strip_prefix(root)
         .unwrap_or(entry_path)
@@ -133,7 +374,7 @@ fn is_not_ignored_with_patterns(entry: &DirEntry, root: &Path, ignored_paths: &[
         }
 
         // También verificar el nombre del directorio/archivo directamente
-        if let Some(file_name) = entry.file_name().to_str() {
+        if let Some(file_name) = entry_path.file_name().and_then(|n| n.to_str()) {
             if file_name == normalized_pattern.trim_end_matches('/')
                 || format!("{}/", file_name) == normalized_pattern
             {
@@ -150,41 +391,59 @@ fn is_architectural_file(path: &Path) -> bool {
 
     // TypeScript/JavaScript patterns
     let ts_js_patterns = [
-        ".controller.ts", ".controller.js",
-        ".service.ts", ".service.js",
-        ".entity.ts", ".entity.js",
-        ".repository.ts", ".repository.js",
-        ".dto.ts", ".dto.js",
-        ".module.ts", ".module.js",
-        ".handler.ts", ".handler.js",
+        ".controller.ts",
+        ".controller.js",
+        ".service.ts",
+        ".service.js",
+        ".entity.ts",
+        ".entity.js",
+        ".repository.ts",
+        ".repository.js",
+        ".dto.ts",
+        ".dto.js",
+        ".module.ts",
+        ".module.js",
+        ".handler.ts",
+        ".handler.js",
     ];
 
     // Python patterns
     let py_patterns = [
-        "_controller.py", "_service.py",
-        "_repository.py", "_model.py",
-        "_view.py", "_handler.py",
+        "_controller.py",
+        "_service.py",
+        "_repository.py",
+        "_model.py",
+        "_view.py",
+        "_handler.py",
     ];
 
     // Go patterns
     let go_patterns = [
-        "_controller.go", "_service.go",
-        "_repository.go", "_handler.go",
+        "_controller.go",
+        "_service.go",
+        "_repository.go",
+        "_handler.go",
         "_model.go",
     ];
 
     // PHP patterns
     let php_patterns = [
-        "controller.php", "service.php",
-        "repository.php", "model.php",
-        "handler.php", "entity.php",
+        "controller.php",
+        "service.php",
+        "repository.php",
+        "model.php",
+        "handler.php",
+        "entity.php",
     ];
 
     // Java patterns
     let java_patterns = [
-        "controller.java", "service.java",
-        "repository.java", "model.java",
-        "handler.java", "entity.java",
+        "controller.java",
+        "service.java",
+        "repository.java",
+        "model.java",
+        "handler.java",
+        "entity.java",
         "dao.java",
     ];
 
@@ -194,3 +453,165 @@ fn is_architectural_file(path: &Path) -> bool {
         || php_patterns.iter().any(|&pattern| s.ends_with(pattern))
         || java_patterns.iter().any(|&pattern| s.ends_with(pattern))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer;
+    use crate::config::RuleSettings;
+    use crate::rules::testing::temp_project;
+
+    #[test]
+    fn gitignored_paths_are_skipped_only_when_respected() {
+        let root = temp_project(
+            "gitignore",
+            &[
+                (".gitignore", "generated/\n"),
+                ("src/app.ts", ""),
+                ("generated/client.ts", ""),
+                ("generated/nested/api.ts", ""),
+            ],
+        );
+        let mut settings = RuleSettings {
+            respect_gitignore: true,
+            ..RuleSettings::default()
+        };
+        let ctx = LinterContext::for_tests(settings.clone());
+        assert_eq!(collect_files(&root, &ctx), vec![root.join("src/app.ts")]);
+
+        settings.respect_gitignore = false;
+        let ctx = LinterContext::for_tests(settings);
+        assert_eq!(collect_files(&root, &ctx).len(), 3);
+    }
+
+    #[test]
+    fn a_glob_selects_a_subset_and_finds_its_project_root() {
+        let root = temp_project(
+            "glob",
+            &[
+                ("architect.json", "{}"),
+                ("src/users/users.controller.ts", ""),
+                ("src/users/users.service.ts", ""),
+                ("src/orders/http/orders.controller.ts", ""),
+                ("dist/users.controller.ts", ""),
+            ],
+        );
+        let mut ctx = LinterContext::for_tests(RuleSettings::default());
+        ctx.ignored_paths = vec!["dist/".to_string()];
+        let pattern = format!("{}/src/**/*.controller.ts", root.display());
+        assert_eq!(glob_project_root(&pattern).unwrap(), root);
+
+        // ignored_paths sigue aplicando a lo que selecciona el glob
+        let pattern = format!("{}/**/*.controller.ts", root.display());
+        assert_eq!(
+            expand_glob(&pattern, &root, &ctx).unwrap(),
+            [
+                root.join("src/orders/http/orders.controller.ts"),
+                root.join("src/users/users.controller.ts"),
+            ]
+        );
+    }
+
+    #[test]
+    fn declaration_files_skip_max_lines_by_default() {
+        // Un .d.ts escrito a mano con implementación: en un .ts sería un método largo
+        let declaration = "import { Db } from '../infrastructure/db';
+class Api {
+  request(method: string, url: string): string {
+    const target = method + ' ' + url;
+    const trimmed = target.trim();
+    return trimmed;
+  }
+}
+";
+        let root = temp_project(
+            "declaration-files",
+            &[
+                ("src/domain/api.d.ts", declaration),
+                ("src/domain/user.ts", ""),
+            ],
+        );
+        let mut ctx = LinterContext::for_tests(RuleSettings::default());
+        ctx.max_lines = 2;
+        ctx.forbidden_imports = vec![crate::config::ForbiddenRule {
+            from: "src/domain/**".to_string(),
+            to: "src/infrastructure/**".to_string(),
+        }];
+        let declaration = root.join("src/domain/api.d.ts");
+
+        // Por defecto no se recolecta
+        assert_eq!(
+            collect_files(&root, &ctx),
+            vec![root.join("src/domain/user.ts")]
+        );
+
+        // En modo estructural se analiza, pero solo con las reglas de imports
+        ctx.settings.declaration_files = DeclarationFiles::Structural;
+        assert_eq!(collect_files(&root, &ctx).len(), 2);
+        let rule_ids = |path: &Path| -> Vec<&str> {
+            analyzer::analyze_file(&swc_common::SourceMap::default(), path, &ctx)
+                .unwrap()
+                .iter()
+                .map(|finding| finding.rule_id)
+                .collect()
+        };
+        assert_eq!(rule_ids(&declaration), vec!["forbidden-imports"]);
+
+        // El mismo contenido en un .ts sí pasa por max-lines
+        let source = root.join("src/domain/api.ts");
+        fs::copy(&declaration, &source).unwrap();
+        assert_eq!(rule_ids(&source), vec!["forbidden-imports", "max-lines"]);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn files_from_analyzes_only_the_listed_files() {
+        let root = temp_project(
+            "files-from",
+            &[
+                ("src/a.ts", ""),
+                ("src/b.ts", ""),
+                ("src/c.ts", ""),
+                ("README.md", ""),
+            ],
+        );
+        let list = root.join("changed.txt");
+        fs::write(
+            &list,
+            format!(
+                "src/a.ts\n{}\n\nsrc/deleted.ts\nREADME.md\nsrc/a.ts\n",
+                root.join("src/c.ts").display()
+            ),
+        )
+        .unwrap();
+        let ctx = LinterContext::for_tests(RuleSettings::default());
+
+        // Relativas a la raíz o absolutas; sin borrados, duplicados ni no analizables
+        assert_eq!(
+            read_files_from(list.to_str().unwrap(), &root, &ctx).unwrap(),
+            [root.join("src/a.ts"), root.join("src/c.ts")]
+        );
+    }
+
+    #[test]
+    fn max_files_guard_fails_without_a_terminal() {
+        let root = temp_project(
+            "max-files",
+            &[("src/a.ts", ""), ("src/b.ts", ""), ("src/c.ts", "")],
+        );
+        let ctx = LinterContext::for_tests(RuleSettings::default());
+
+        assert_eq!(
+            collect_files_limited(&root, &ctx, 3, false).unwrap().len(),
+            3
+        );
+        let error = collect_files_limited(&root, &ctx, 2, false).unwrap_err();
+        assert!(error.to_string().contains("más de 2 archivos"));
+
+        // El recorrido en streaming se detiene en el mismo límite
+        let (paths, walker) = stream_files(&root, Arc::new(ctx), 2);
+        assert_eq!(paths.iter().count(), 2);
+        assert!(walker.join().unwrap());
+    }
+}
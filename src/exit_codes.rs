@@ -0,0 +1,55 @@
+//! Códigos de salida del proceso (--list-exit-codes)
+//!
+//! Sin --detailed-exit-codes se conserva el comportamiento de siempre: 1 si hay
+//! errores y 0 en cualquier otro caso, para no romper los pipelines que tratan
+//! cualquier código distinto de 0 como fallo. Con el flag, los scripts de CI
+//! pueden distinguir un proyecto con solo advertencias de un análisis incompleto.
+//! Los argumentos inválidos salen siempre con su propio código, distinto del 1,
+//! para no confundirlos con violaciones. El código es el mismo con cualquier --format.
+
+/// Sin errores (ni advertencias, con --detailed-exit-codes)
+pub const CLEAN: i32 = 0;
+/// Hay violaciones con severidad error (o nuevas, con --compare-to)
+pub const ERRORS: i32 = 1;
+/// Solo advertencias (--detailed-exit-codes)
+pub const WARNINGS_ONLY: i32 = 2;
+/// El análisis no se completó: archivos con errores de sintaxis o un fallo interno
+/// (configuración inválida, error de E/S...) (--detailed-exit-codes)
+pub const INCOMPLETE: i32 = 3;
+/// Argumentos de línea de comandos inválidos: no se llegó a analizar nada
+pub const USAGE: i32 = 4;
+
+/// Código de salida de un análisis. Los errores tienen prioridad: un script que
+/// solo mira el 1 sigue detectando las violaciones aunque algún archivo no parsee.
+pub fn for_run(failed: bool, warnings: usize, parse_errors: usize, detailed: bool) -> i32 {
+    if failed {
+        ERRORS
+    } else if !detailed {
+        CLEAN
+    } else if parse_errors > 0 {
+        INCOMPLETE
+    } else if warnings > 0 {
+        WARNINGS_ONLY
+    } else {
+        CLEAN
+    }
+}
+
+/// Imprime la tabla de códigos de salida
+pub fn print_exit_codes() {
+    println!("CÓDIGOS DE SALIDA:");
+    println!("  {}  Sin errores", CLEAN);
+    println!(
+        "  {}  Hay errores (con --compare-to, según --compare-fail-on)",
+        ERRORS
+    );
+    println!("  {}  Argumentos inválidos", USAGE);
+    println!("  Con --detailed-exit-codes, además:");
+    println!("  {}  Solo advertencias, sin errores", WARNINGS_ONLY);
+    println!(
+        "  {}  Análisis incompleto: archivos con errores de sintaxis o un fallo interno",
+        INCOMPLETE
+    );
+    println!();
+    println!("Si aplica más de uno, gana el primero de la lista: 1, 3, 2.");
+}
@@ -0,0 +1,95 @@
+//! Integración con git: archivos cambiados desde una referencia y checkout temporal
+//! de revisiones anteriores para comparar la deuda arquitectónica.
+
+use miette::{IntoDiagnostic, Result};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Ejecuta git en el directorio indicado y devuelve stdout
+fn run_git(dir: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .into_diagnostic()?;
+
+    if !output.status.success() {
+        return Err(miette::miette!(
+            "git {} falló: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Archivos añadidos o modificados desde `git_ref` (incluye cambios sin commitear
+/// y archivos nuevos sin trackear). Rutas absolutas bajo `root`.
+pub fn changed_files_since(root: &Path, git_ref: &str) -> Result<Vec<PathBuf>> {
+    let diff = run_git(
+        root,
+        &[
+            "diff",
+            "--name-only",
+            "--relative",
+            "--diff-filter=ACMR",
+            git_ref,
+            "--",
+        ],
+    )?;
+    let untracked = run_git(root, &["ls-files", "--others", "--exclude-standard"])?;
+
+    let mut files: Vec<PathBuf> = diff
+        .lines()
+        .chain(untracked.lines())
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| root.join(line.trim()))
+        .collect();
+    files.sort();
+    files.dedup();
+    Ok(files)
+}
+
+/// Checkout temporal (git worktree) de una referencia; se elimina al salir de alcance
+pub struct Worktree {
+    repo_dir: PathBuf,
+    path: PathBuf,
+    /// Directorio equivalente al root del proyecto dentro del worktree
+    pub project_root: PathBuf,
+}
+
+impl Worktree {
+    pub fn checkout(root: &Path, git_ref: &str) -> Result<Self> {
+        // Un directorio por checkout: --since y --compare-to pueden convivir
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+        let path = std::env::temp_dir().join(format!(
+            "architect-since-{}-{}",
+            std::process::id(),
+            NEXT.fetch_add(1, Ordering::Relaxed)
+        ));
+        let path_str = path.to_string_lossy().into_owned();
+
+        // El proyecto puede ser un subdirectorio del repositorio
+        let prefix = run_git(root, &["rev-parse", "--show-prefix"])?;
+        run_git(root, &["worktree", "add", "--detach", &path_str, git_ref])?;
+
+        Ok(Self {
+            repo_dir: root.to_path_buf(),
+            project_root: path.join(prefix.trim()),
+            path,
+        })
+    }
+}
+
+impl Drop for Worktree {
+    fn drop(&mut self) {
+        let path_str = self.path.to_string_lossy().into_owned();
+        let _ = run_git(
+            &self.repo_dir,
+            &["worktree", "remove", "--force", &path_str],
+        );
+    }
+}
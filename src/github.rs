@@ -0,0 +1,86 @@
+//! Anotaciones de GitHub Actions (--format github)
+//!
+//! Cada violación se emite como un comando `::error` o `::warning` que GitHub
+//! muestra en la línea correspondiente del PR. Con --compare-to solo se anotan las
+//! violaciones nuevas: el PR muestra lo que introduce y no la deuda que ya existía.
+//! Las rutas son relativas a la raíz analizada, que debe ser la del repositorio
+//! para que GitHub ubique los archivos.
+
+use crate::compare::ReportEntry;
+use crate::rules::Severity;
+use std::io::{self, Write};
+
+/// Escribe una anotación por violación en stdout
+pub fn print_annotations(entries: &[ReportEntry]) -> io::Result<()> {
+    write_annotations(&mut io::stdout().lock(), entries)
+}
+
+fn write_annotations(out: &mut impl Write, entries: &[ReportEntry]) -> io::Result<()> {
+    for entry in entries {
+        let command = match entry.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        let mut properties = vec![format!("file={}", escape_property(&entry.file))];
+        // Los ciclos no señalan una línea: la anotación queda a nivel de archivo
+        if entry.line > 0 {
+            properties.push(format!("line={}", entry.line));
+        }
+        if entry.column > 0 {
+            properties.push(format!("col={}", entry.column));
+        }
+        if entry.end_line > 0 {
+            properties.push(format!("endLine={}", entry.end_line));
+        }
+        if entry.end_column > 0 {
+            properties.push(format!("endColumn={}", entry.end_column));
+        }
+        properties.push(format!("title={}", escape_property(&entry.rule_id)));
+        writeln!(
+            out,
+            "::{} {}::{}",
+            command,
+            properties.join(","),
+            escape_data(&entry.message)
+        )?;
+    }
+    out.flush()
+}
+
+/// Codifica los caracteres que cortarían el mensaje del comando
+fn escape_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Como `escape_data`, más los separadores de las propiedades
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compare;
+
+    #[test]
+    fn annotates_only_violations_missing_from_the_baseline() {
+        let existing = ReportEntry::from_unclassified("src/helpers/a.ts");
+        let mut introduced = ReportEntry::from_unclassified("src/helpers/b.ts");
+        introduced.line = 1;
+        introduced.column = 1;
+        let baseline = vec![existing.clone()];
+
+        let comparison = compare::compare(baseline, vec![existing, introduced]);
+        let mut out = Vec::new();
+        write_annotations(&mut out, &comparison.added).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "::error file=src/helpers/b.ts,line=1,col=1,title=require-layer::\
+             Archivo sin clasificar: no está en ninguna capa\n"
+        );
+    }
+}
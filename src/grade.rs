@@ -0,0 +1,181 @@
+//! Nota global del proyecto de A a F (--grade)
+//!
+//! Resume las métricas de la ejecución en una sola letra. Cada factor aporta una
+//! penalización de 0 a 1 ponderada; la nota parte de 100 y se le restan. Es una
+//! vista para seguir la tendencia, no un umbral de CI: para eso están las reglas.
+
+use crate::metrics::RunMetrics;
+
+/// Violaciones (más medio punto por advertencia) por cada 1000 líneas que
+/// agotan la penalización de densidad
+const DENSITY_LIMIT_PER_KLOC: f64 = 10.0;
+
+/// Ciclos que agotan su penalización
+const CYCLES_LIMIT: f64 = 5.0;
+
+/// Factor de la nota: su peso y su penalización (0..1)
+struct Factor {
+    weight: f64,
+    penalty: f64,
+    detail: String,
+}
+
+impl Factor {
+    fn points(&self) -> f64 {
+        self.weight * self.penalty * 100.0
+    }
+}
+
+/// Imprime la nota y los tres factores que más la bajan
+pub fn print_grade(metrics: &RunMetrics, max_lines: usize) {
+    let factors = factors(metrics, max_lines);
+    let score = score(&factors);
+
+    println!(
+        "\n🎓 NOTA DEL PROYECTO: {} ({:.0}/100)",
+        letter(score),
+        score
+    );
+
+    let dragging = dragging(&factors);
+    if dragging.is_empty() {
+        println!("   Ningún factor resta puntos.");
+        return;
+    }
+    println!("   Lo que más la baja:");
+    for factor in dragging.iter().take(3) {
+        println!("   -{:>4.0}  {}", factor.points(), factor.detail);
+    }
+}
+
+/// Nota de 0 a 100: lo que restan los factores
+fn score(factors: &[Factor]) -> f64 {
+    100.0 - factors.iter().map(Factor::points).sum::<f64>()
+}
+
+/// Factores que restan algún punto, de más a menos
+fn dragging(factors: &[Factor]) -> Vec<&Factor> {
+    let mut dragging: Vec<&Factor> = factors.iter().filter(|f| f.points() >= 0.5).collect();
+    dragging.sort_by(|a, b| b.points().total_cmp(&a.points()));
+    dragging
+}
+
+fn letter(score: f64) -> char {
+    match score {
+        s if s >= 90.0 => 'A',
+        s if s >= 80.0 => 'B',
+        s if s >= 70.0 => 'C',
+        s if s >= 60.0 => 'D',
+        _ => 'F',
+    }
+}
+
+fn factors(metrics: &RunMetrics, max_lines: usize) -> Vec<Factor> {
+    let errors = metrics.total_violations as f64;
+    let warnings = metrics.total_warnings as f64;
+
+    let kloc = metrics.total_lines as f64 / 1000.0;
+    let density = if kloc > 0.0 {
+        (errors + warnings * 0.5) / kloc
+    } else {
+        0.0
+    };
+    let density_penalty = (density / DENSITY_LIMIT_PER_KLOC).min(1.0);
+
+    // A la mitad del máximo no penaliza; en el máximo, del todo
+    let method_ratio = if max_lines > 0 {
+        metrics.avg_method_length / max_lines as f64
+    } else {
+        0.0
+    };
+
+    let error_share = if errors + warnings > 0.0 {
+        errors / (errors + warnings)
+    } else {
+        0.0
+    };
+
+    vec![
+        Factor {
+            weight: 0.4,
+            penalty: density_penalty,
+            detail: format!("{:.1} violaciones por cada 1000 líneas", density),
+        },
+        Factor {
+            weight: 0.25,
+            penalty: (metrics.cycles as f64 / CYCLES_LIMIT).min(1.0),
+            detail: format!("{} dependencias cíclicas", metrics.cycles),
+        },
+        Factor {
+            weight: 0.15,
+            penalty: ((method_ratio - 0.5) / 0.5).clamp(0.0, 1.0),
+            detail: format!(
+                "métodos de {:.0} líneas en promedio (máximo {})",
+                metrics.avg_method_length, max_lines
+            ),
+        },
+        Factor {
+            weight: 0.2,
+            // La proporción de errores solo pesa en la medida en que hay hallazgos
+            penalty: error_share * density_penalty,
+            detail: format!(
+                "{:.0}% de los hallazgos son errores, no advertencias",
+                error_share * 100.0
+            ),
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_clean_project_grades_higher_than_a_messy_one() {
+        let clean = RunMetrics {
+            total_files: 20,
+            total_lines: 2000,
+            avg_method_length: 12.0,
+            max_method_length: 30,
+            ..RunMetrics::default()
+        };
+        let messy = RunMetrics {
+            total_files: 5,
+            total_lines: 800,
+            total_violations: 12,
+            total_warnings: 2,
+            cycles: 3,
+            avg_method_length: 36.0,
+            max_method_length: 90,
+            ..RunMetrics::default()
+        };
+
+        let clean_factors = factors(&clean, 40);
+        let messy_factors = factors(&messy, 40);
+        let (clean_score, messy_score) = (score(&clean_factors), score(&messy_factors));
+        assert!(
+            clean_score > messy_score,
+            "{} <= {}",
+            clean_score,
+            messy_score
+        );
+        assert_eq!(letter(clean_score), 'A');
+        assert_eq!(letter(messy_score), 'F');
+
+        // Ordenados por los puntos que restan
+        let details: Vec<&str> = dragging(&messy_factors)
+            .iter()
+            .map(|factor| factor.detail.as_str())
+            .collect();
+        assert_eq!(
+            details,
+            vec![
+                "16.2 violaciones por cada 1000 líneas",
+                "86% de los hallazgos son errores, no advertencias",
+                "3 dependencias cíclicas",
+                "métodos de 36 líneas en promedio (máximo 40)",
+            ]
+        );
+        assert!(dragging(&clean_factors).is_empty());
+    }
+}
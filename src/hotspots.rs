@@ -0,0 +1,228 @@
+//! Hotspots: archivos candidatos a "god module" (--hotspots)
+//!
+//! En lugar de un umbral por métrica, combina en una sola puntuación la longitud del
+//! archivo, la complejidad cognitiva de su función más compleja, el fan-out (módulos
+//! distintos importados) y la cantidad de exports. Cada factor se normaliza contra el
+//! máximo del proyecto (0..1) y la puntuación es su promedio sobre 100: sirve para
+//! priorizar refactors, no para fallar la ejecución.
+//!
+//! Las métricas se toman del módulo que la fase de reglas ya parseó: solo puntúan
+//! los archivos JS/TS analizados en esta ejecución.
+
+use crate::rules::cognitive_complexity;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use swc_ecma_ast::{Decl, Module, ModuleDecl, ModuleItem};
+
+/// Hotspots listados si --hotspots no indica otro número
+pub const DEFAULT_HOTSPOTS_LISTED: usize = 10;
+
+/// Nombres de los factores, en el orden de `Hotspot::factors`
+const FACTOR_NAMES: [&str; 4] = ["líneas", "complejidad", "fan-out", "exports"];
+
+/// Métricas crudas de un archivo
+#[derive(Debug, Clone, Copy)]
+struct FileStats {
+    lines: usize,
+    complexity: usize,
+    fan_out: usize,
+    exports: usize,
+}
+
+impl FileStats {
+    fn values(&self) -> [usize; 4] {
+        [self.lines, self.complexity, self.fan_out, self.exports]
+    }
+}
+
+/// Archivo con su puntuación compuesta
+#[derive(Debug, Clone)]
+pub struct Hotspot {
+    /// Ruta relativa a la raíz del proyecto
+    pub file: String,
+    /// Promedio de los factores normalizados, de 0 a 100
+    pub score: f64,
+    /// Valor crudo y normalizado (0..1) de cada factor de `FACTOR_NAMES`
+    pub factors: [(usize, f64); 4],
+}
+
+/// Acumula las métricas de cada archivo desde los hilos de la fase de reglas
+#[derive(Default)]
+pub struct HotspotCollector {
+    stats: Mutex<Vec<(PathBuf, FileStats)>>,
+}
+
+impl HotspotCollector {
+    /// Registra las métricas de un módulo ya parseado
+    pub fn record_module(&self, path: &Path, lines: usize, module: &Module) {
+        let stats = file_stats(lines, module);
+        self.stats.lock().unwrap().push((path.to_path_buf(), stats));
+    }
+
+    /// Calcula la puntuación de cada archivo, ordenados de mayor a menor
+    pub fn ranked(self, project_root: &Path) -> Vec<Hotspot> {
+        let stats = self.stats.into_inner().unwrap();
+
+        let mut max = [0; 4];
+        for (_, file) in &stats {
+            for (limit, value) in max.iter_mut().zip(file.values()) {
+                *limit = (*limit).max(value);
+            }
+        }
+
+        let mut hotspots: Vec<Hotspot> = stats
+            .into_iter()
+            .map(|(path, file)| {
+                let mut factors = [(0, 0.0); 4];
+                for ((factor, value), max) in factors.iter_mut().zip(file.values()).zip(max) {
+                    let normalized = if max > 0 {
+                        value as f64 / max as f64
+                    } else {
+                        0.0
+                    };
+                    *factor = (value, normalized);
+                }
+                let score = factors
+                    .iter()
+                    .map(|(_, normalized)| normalized)
+                    .sum::<f64>()
+                    / factors.len() as f64
+                    * 100.0;
+                Hotspot {
+                    file: path
+                        .strip_prefix(project_root)
+                        .unwrap_or(&path)
+                        .to_string_lossy()
+                        .replace('\\', "/"),
+                    score,
+                    factors,
+                }
+            })
+            .collect();
+
+        hotspots.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| a.file.cmp(&b.file))
+        });
+        hotspots
+    }
+}
+
+/// Imprime los `limit` archivos con mayor puntuación y lo que aporta cada factor
+pub fn print_hotspots_report(hotspots: &[Hotspot], limit: usize) {
+    if hotspots.is_empty() {
+        println!("\n🔥 No hay archivos que puntuar.");
+        return;
+    }
+
+    println!(
+        "\n🔥 HOTSPOTS ({} de {} archivos)\n",
+        limit.min(hotspots.len()),
+        hotspots.len()
+    );
+    for (rank, hotspot) in hotspots.iter().take(limit).enumerate() {
+        println!(
+            "  {:>2}. {:>5.1}  {}",
+            rank + 1,
+            hotspot.score,
+            hotspot.file
+        );
+        let factors: Vec<String> = FACTOR_NAMES
+            .iter()
+            .zip(&hotspot.factors)
+            .map(|(name, (value, normalized))| format!("{} {} ({:.2})", name, value, normalized))
+            .collect();
+        println!("             {}", factors.join(" · "));
+    }
+
+    println!();
+    println!("💡 Cada factor se normaliza contra el máximo del proyecto (1.00 = el peor).");
+    println!("   Empieza por los archivos donde varios factores están cerca de 1.");
+}
+
+fn file_stats(lines: usize, module: &Module) -> FileStats {
+    let mut sources = HashSet::new();
+    let mut exports = 0;
+
+    for item in &module.body {
+        let decl = match item {
+            ModuleItem::ModuleDecl(decl) => decl,
+            ModuleItem::Stmt(_) => continue,
+        };
+        match decl {
+            ModuleDecl::Import(import) => {
+                sources.insert(import.src.value.to_string());
+            }
+            ModuleDecl::ExportAll(export) => {
+                sources.insert(export.src.value.to_string());
+                exports += 1;
+            }
+            ModuleDecl::ExportNamed(export) => {
+                if let Some(src) = &export.src {
+                    sources.insert(src.value.to_string());
+                }
+                exports += export.specifiers.len();
+            }
+            // `export const a = 1, b = 2` declara dos exports
+            ModuleDecl::ExportDecl(export) => {
+                exports += match &export.decl {
+                    Decl::Var(var) => var.decls.len(),
+                    _ => 1,
+                }
+            }
+            ModuleDecl::ExportDefaultDecl(_) | ModuleDecl::ExportDefaultExpr(_) => exports += 1,
+            _ => {}
+        }
+    }
+
+    FileStats {
+        lines,
+        complexity: cognitive_complexity::max_function_complexity(module),
+        fan_out: sources.len(),
+        exports,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer;
+    use crate::config::{LinterContext, RuleSettings};
+    use crate::rules::testing::temp_project;
+    use std::fs;
+    use swc_common::SourceMap;
+
+    #[test]
+    fn a_god_module_ranks_above_a_clean_file() {
+        let god = "import { a } from './a';\nimport { b } from './b';\nimport { c } from './c';\n\
+export function route(kind: string, items: number[]) {\n  for (const item of items) {\n    if (item > 0) {\n      if (kind === 'a' && item > 10) {\n        a(item);\n      } else if (kind === 'b') {\n        b(item);\n      } else {\n        c(item);\n      }\n    }\n  }\n}\n\
+export const first = 1, second = 2;\nexport default route;\n";
+        let clean = "export const sum = (x: number, y: number) => x + y;\n";
+        let root = temp_project("hotspots", &[("src/god.ts", god), ("src/clean.ts", clean)]);
+        let ctx = LinterContext::for_tests(RuleSettings::default());
+        let cm = SourceMap::default();
+        let collector = HotspotCollector::default();
+        for file in ["src/clean.ts", "src/god.ts"] {
+            analyzer::analyze_file_recording(&cm, &root.join(file), &ctx, Some(&collector))
+                .unwrap();
+        }
+
+        let hotspots = collector.ranked(&root);
+
+        let ranking: Vec<&str> = hotspots
+            .iter()
+            .map(|hotspot| hotspot.file.as_str())
+            .collect();
+        assert_eq!(ranking, vec!["src/god.ts", "src/clean.ts"]);
+        // El peor archivo del proyecto marca el máximo de cada factor
+        let god = &hotspots[0];
+        assert_eq!(god.score, 100.0);
+        let raw: Vec<usize> = god.factors.iter().map(|(value, _)| *value).collect();
+        assert_eq!(raw[2..], [3, 4]);
+        assert!(hotspots[1].score < 50.0, "{:?}", hotspots[1]);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,106 @@
+//! Reporte HTML de las violaciones (report.html de --output-dir)
+//!
+//! Una sola página autocontenida, sin scripts ni recursos externos, para adjuntar
+//! como artefacto de CI o abrir en el navegador.
+
+use crate::compare::ReportEntry;
+use crate::rules::Severity;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+const STYLE: &str = "body{font-family:sans-serif;margin:2rem}\
+table{border-collapse:collapse;width:100%}\
+th,td{border:1px solid #ddd;padding:.4rem;text-align:left;vertical-align:top}\
+th{background:#f4f4f4}.error{color:#b00020}.warning{color:#a15c00}";
+
+/// Escribe el reporte en `path`
+pub fn write_html_file(path: &Path, entries: &[ReportEntry]) -> io::Result<()> {
+    let mut out = BufWriter::new(File::create(path)?);
+    write_html(&mut out, entries)?;
+    out.flush()
+}
+
+/// Escribe una página con el resumen y una fila por violación
+fn write_html(out: &mut impl Write, entries: &[ReportEntry]) -> io::Result<()> {
+    let errors = entries
+        .iter()
+        .filter(|entry| entry.severity == Severity::Error)
+        .count();
+
+    writeln!(out, "<!DOCTYPE html>")?;
+    writeln!(out, "<html lang=\"es\">")?;
+    writeln!(out, "<head>")?;
+    writeln!(out, "<meta charset=\"utf-8\">")?;
+    writeln!(out, "<title>architect-linter</title>")?;
+    writeln!(out, "<style>{}</style>", STYLE)?;
+    writeln!(out, "</head>")?;
+    writeln!(out, "<body>")?;
+    writeln!(
+        out,
+        "<h1>architect-linter {}</h1>",
+        env!("CARGO_PKG_VERSION")
+    )?;
+    writeln!(
+        out,
+        "<p>{} errores, {} advertencias</p>",
+        errors,
+        entries.len() - errors
+    )?;
+    writeln!(out, "<table>")?;
+    writeln!(
+        out,
+        "<tr><th>Archivo</th><th>Línea</th><th>Regla</th><th>Severidad</th><th>Mensaje</th></tr>"
+    )?;
+    for entry in entries {
+        let severity = match entry.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        // Los ciclos y las carpetas no señalan una línea
+        let line = if entry.line > 0 {
+            entry.line.to_string()
+        } else {
+            String::new()
+        };
+        writeln!(
+            out,
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td class=\"{}\">{}</td><td>{}</td></tr>",
+            escape(&entry.file),
+            line,
+            escape(&entry.rule_id),
+            severity,
+            severity,
+            escape(&entry.message)
+        )?;
+    }
+    writeln!(out, "</table>")?;
+    writeln!(out, "</body>")?;
+    writeln!(out, "</html>")
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn messages_are_escaped_inside_their_row() {
+        let mut entry = ReportEntry::from_unclassified("src/helpers/<hash>.ts");
+        entry.message = "Usa \"Map<K, V>\" & no any".to_string();
+        let mut out = Vec::new();
+        write_html(&mut out, &[entry]).unwrap();
+        let html = String::from_utf8(out).unwrap();
+
+        assert!(html.contains("<p>1 errores, 0 advertencias</p>"));
+        assert!(html.contains(
+            "<tr><td>src/helpers/&lt;hash&gt;.ts</td><td></td><td>require-layer</td><td class=\"error\">error</td><td>Usa &quot;Map&lt;K, V&gt;&quot; &amp; no any</td></tr>"
+        ));
+    }
+}
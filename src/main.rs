@@ -1,20 +1,44 @@
 use indicatif::{ProgressBar, ProgressStyle};
 use miette::{GraphicalReportHandler, IntoDiagnostic, Result};
 use rayon::prelude::*;
-use std::path::PathBuf;
+use std::collections::{HashMap, HashSet};
+use std::io::{IsTerminal, Write};
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
+use std::time::Instant;
 use swc_common::SourceMap;
 
 mod ai;
 mod analyzer;
+mod archive;
+mod audit;
 mod autofix;
+mod checkpoint;
 mod circular;
 mod cli;
+mod compare;
 mod config;
+mod crowded_dirs;
+mod csv;
 mod detector;
 mod discovery;
+mod exit_codes;
+mod git;
+mod github;
+mod grade;
+mod hotspots;
+mod html;
+mod metrics;
 mod parsers;
+mod profile;
+mod resolver;
+mod rules;
+mod sarif;
+mod suggest;
+mod tui;
 mod ui;
+mod unclassified;
+mod unused_exports;
 mod watch;
 
 fn main() -> Result<()> {
@@ -24,107 +48,1526 @@ fn main() -> Result<()> {
         None => return Ok(()), // Se procesó --help o --version
     };
 
-    ui::print_banner();
+    let detailed_exit_codes = cli_args.detailed_exit_codes;
+    if let Err(report) = run(cli_args) {
+        eprintln!("Error: {:?}", report);
+        std::process::exit(error_exit_code(&report, detailed_exit_codes));
+    }
+    Ok(())
+}
+
+/// Código de salida de una ejecución que terminó con error
+fn error_exit_code(report: &miette::Report, detailed_exit_codes: bool) -> i32 {
+    if report.downcast_ref::<cli::UnknownRule>().is_some() {
+        // Argumentos que no se pueden atender: no se llegó a analizar nada
+        exit_codes::USAGE
+    } else if detailed_exit_codes {
+        // El análisis no pudo completarse (configuración inválida, E/S...)
+        exit_codes::INCOMPLETE
+    } else {
+        exit_codes::ERRORS
+    }
+}
+
+fn run(cli_args: cli::CliArgs) -> Result<()> {
+    if let Some(rule_id) = &cli_args.explain {
+        return cli::print_explain(rule_id);
+    }
+
+    // 2. Obtener la ruta del proyecto
+    if cli_args.print_config {
+        // Salida JSON limpia, sin banner
+        let path = cli_args.project_path.as_deref().unwrap_or(".");
+        let project_root = PathBuf::from(path).canonicalize().into_diagnostic()?;
+        return config::print_effective_config(&project_root);
+    }
+
+    // Con --format csv/sarif o --fix-dry-run, stdout solo lleva el documento o el diff
+    if !cli_args.is_machine_output() && !cli_args.fix_dry_run {
+        ui::print_banner();
+    }
+
+    ai::set_offline(cli_args.offline);
+    if cli_args.offline && cli_args.fix_mode {
+        return Err(miette::miette!(
+            "--fix necesita consultar la IA y no está disponible en modo offline (--offline o CI)."
+        ));
+    }
+
+    if let Some(dir) = &cli_args.output_dir {
+        std::fs::create_dir_all(dir).into_diagnostic()?;
+    }
+
+    if let Some(archive_path) = &cli_args.archive {
+        return run_archive_mode(Path::new(archive_path), &cli_args);
+    }
+
+    let glob_pattern = cli_args
+        .project_path
+        .as_deref()
+        .filter(|path| discovery::is_glob_pattern(path));
+
+    let project_root = if let Some(pattern) = glob_pattern {
+        // La configuración se carga desde la raíz del proyecto que contiene al glob
+        discovery::glob_project_root(pattern)?
+    } else if let Some(path) = &cli_args.project_path {
+        PathBuf::from(path).canonicalize().into_diagnostic()?
+    } else if cli_args.files_from.is_some() || cli_args.non_interactive {
+        // La lista suele venir de un pipeline, sin terminal para el modo interactivo
+        PathBuf::from(".").canonicalize().into_diagnostic()?
+    } else {
+        ui::get_interactive_path()?
+    };
+
+    // 3. Cargar o crear configuración asistida por IA
+    let mut ctx = config::setup_or_load_config(
+        &project_root,
+        config::SetupOptions {
+            ai_race: cli_args.ai_race,
+            force_ai: cli_args.ai_init,
+            offline: cli_args.offline,
+            non_interactive: cli_args.non_interactive,
+            min_confidence: cli_args.ai_min_confidence,
+        },
+    )?;
+
+    // Los flags de CLI tienen prioridad sobre architect.json
+    if let Some(context) = Arc::get_mut(&mut ctx) {
+        apply_cli_overrides(context, &cli_args);
+    }
+
+    // 4. Decidir entre modo normal, watch o fix
+    if cli_args.fix_mode {
+        run_fix_mode(
+            &project_root,
+            Arc::clone(&ctx),
+            cli_args.max_files,
+            cli_args.ai_race,
+            cli_args.absolute_paths,
+            cli_args.fix_dry_run,
+        )?;
+    } else if cli_args.audit_config {
+        run_audit_mode(&project_root, &ctx, cli_args.max_files)?;
+    } else if cli_args.suggest_rules {
+        run_suggest_mode(&project_root, &ctx, cli_args.max_files)?;
+    } else if cli_args.watch_mode {
+        run_watch_mode(
+            &project_root,
+            Arc::clone(&ctx),
+            cli_args.max_files,
+            cli_args.absolute_paths,
+        )?;
+    } else {
+        run_normal_mode(&project_root, Arc::clone(&ctx), &cli_args)?;
+    }
+
+    Ok(())
+}
+
+/// Aplica los flags de CLI que tienen prioridad sobre architect.json
+fn apply_cli_overrides(ctx: &mut config::LinterContext, cli_args: &cli::CliArgs) {
+    if let Some(extensions) = &cli_args.extensions {
+        ctx.settings.extensions = extensions.clone();
+    }
+    if cli_args.require_layer {
+        ctx.settings.require_layer = true;
+    }
+    for (rule_id, level) in &cli_args.rule_overrides {
+        if !rules::RULES.iter().any(|rule| rule.id == rule_id) {
+            eprintln!("⚠️  --rule: regla desconocida '{}'", rule_id);
+        }
+        ctx.settings.rules.insert(rule_id.clone(), *level);
+    }
+    // Los flags también mandan sobre los architect.json de las subcarpetas
+    if let Some(cascade) = &mut ctx.cascade {
+        cascade.extensions = cli_args.extensions.clone();
+        cascade.rule_overrides = cli_args.rule_overrides.clone();
+    }
+}
+
+/// Analiza un proyecto empaquetado (--archive) sin extraerlo: las reglas por archivo
+/// y las dependencias cíclicas se ejecutan sobre el contenido en memoria. Las reglas
+/// que consultan el disco (ej: resolución de imports para allow-lists) no ven sus archivos.
+fn run_archive_mode(archive_path: &Path, cli_args: &cli::CliArgs) -> Result<()> {
+    // Sin el repositorio no hay referencia git que analizar: solo un reporte de --report
+    if let Some(target) = &cli_args.compare_to {
+        if !Path::new(target).is_file() {
+            return Err(miette::miette!(
+                "Con --archive, --compare-to requiere un reporte de --report (no existe '{}').",
+                target
+            ));
+        }
+    }
+    let mut project = archive::read_archive(archive_path)?;
+    if project.config.is_none() && !cli_args.is_machine_output() {
+        println!("⚠️  El archivo no tiene architect.json en su raíz: se usa la configuración por defecto.");
+    }
+    let mut ctx = config::context_from_archive(project.config.as_deref())?;
+    apply_cli_overrides(&mut ctx, cli_args);
+
+    project.retain_analyzed(&ctx);
+    if project.files.is_empty() {
+        return print_no_files(cli_args);
+    }
+    if !cli_args.is_machine_output() {
+        println!(
+            "📦 Analizando {} archivos de {}",
+            project.files.len(),
+            archive_path.display()
+        );
+    }
+
+    let cm = SourceMap::default();
+    let mut total = 0;
+    let mut warnings = 0;
+    let mut parse_errors = 0;
+    let mut report_entries = Vec::new();
+
+    // Fase 1: reglas por archivo
+    if cli_args.runs_phase(cli::Phase::Rules) {
+        let collector = FindingCollector::with_limit(cli_args.max_violations_per_file);
+        let counts: Vec<(usize, usize, usize)> = project
+            .files
+            .par_iter()
+            .map(|file| {
+                let display = file.path.to_string_lossy().replace('\\', "/");
+                match analyzer::analyze_source(&cm, &file.path, file.source.clone(), &ctx) {
+                    Ok(findings) => emit_findings(&file.path, &display, findings, Some(&collector)),
+                    Err(e) => {
+                        print_analysis_error(&display, &e);
+                        (1, 0, 0)
+                    }
+                }
+            })
+            .collect();
+        for (errors, file_warnings, file_parse_errors) in counts {
+            total += errors;
+            warnings += file_warnings;
+            parse_errors += file_parse_errors;
+        }
+
+        let (mut collected, suppressed) = collector.into_findings();
+        sort_collected(&mut collected);
+        if cli_args.wants_report() {
+            report_entries = collected
+                .iter()
+                .map(|entry| compare::ReportEntry::from_finding(&entry.display, &entry.finding))
+                .collect();
+        }
+        if cli_args.is_machine_output() {
+            // Las filas se emiten al final, junto con los ciclos
+        } else if cli_args.dedupe {
+            print_deduped(collected).into_diagnostic()?;
+        } else {
+            print_collected(&collected);
+        }
+        print_suppressed(&suppressed, cli_args);
+    }
+
+    // Fase 2: dependencias cíclicas entre las entradas del archivo
+    if cli_args.runs_phase(cli::Phase::Circular) {
+        let sources: Vec<(PathBuf, &str)> = project
+            .files
+            .iter()
+            .map(|file| (file.path.clone(), file.source.as_str()))
+            .collect();
+        let mut graph = circular::CircularDependencyAnalyzer::in_memory();
+        let skipped = graph.build_graph_in_memory(&sources, &cm);
+        // Las rutas del archivo ya son relativas a su raíz
+        print_skipped_files(&skipped, Path::new(""), cli_args);
+        let detected_cycles = circular::filter_by_length(graph.detect_cycles(), &ctx.settings);
+        if !detected_cycles.is_empty() && !cli_args.is_machine_output() {
+            circular::print_circular_dependency_report(&detected_cycles);
+        }
+        total += detected_cycles.len();
+        if cli_args.wants_report() {
+            report_entries.extend(detected_cycles.iter().map(compare::ReportEntry::from_cycle));
+        }
+        write_dependency_graph(cli_args, &graph, &detected_cycles)?;
+    }
+
+    write_report_artifacts(cli_args, &report_entries)?;
+    if cli_args.is_csv() {
+        csv::print_csv(&report_entries).into_diagnostic()?;
+    }
+    let comparison = match &cli_args.compare_to {
+        Some(target) => {
+            let previous = compare::load_report(Path::new(target))?;
+            let comparison = compare::compare(previous, report_entries);
+            if !cli_args.is_machine_output() {
+                compare::print_comparison(&comparison, target);
+            }
+            // Solo lo que introduce la rama: la deuda previa no se anota en el PR
+            print_annotations(cli_args, &comparison.added)?;
+            Some(comparison)
+        }
+        None => {
+            print_annotations(cli_args, &report_entries)?;
+            None
+        }
+    };
+    if !cli_args.is_machine_output() {
+        print_summary(total, warnings, parse_errors);
+    }
+
+    let failed = match (&comparison, cli_args.compare_fail_on) {
+        (Some(comparison), cli::CompareFailOn::New) => comparison.added_errors() > 0,
+        (Some(_), cli::CompareFailOn::Never) => false,
+        _ => total > 0,
+    };
+    std::process::exit(exit_codes::for_run(
+        failed,
+        warnings,
+        parse_errors,
+        cli_args.detailed_exit_codes,
+    ));
+}
+
+/// Ejecuta el análisis en modo normal (una sola vez)
+fn run_normal_mode(
+    project_root: &PathBuf,
+    ctx: Arc<config::LinterContext>,
+    cli_args: &cli::CliArgs,
+) -> Result<()> {
+    let profiler = cli_args.profile.then(profile::Profiler::default);
+
+    // Archivos listados explícitamente (--files-from o glob)
+    let listed = match (&cli_args.files_from, cli_args.project_path.as_deref()) {
+        (Some(source), _) => Some(discovery::read_files_from(source, project_root, &ctx)?),
+        (None, Some(pattern)) if discovery::is_glob_pattern(pattern) => {
+            Some(discovery::expand_glob(pattern, project_root, &ctx)?)
+        }
+        _ => None,
+    };
+
+    // Sin lista explícita ni --since, la fase de reglas analiza los archivos a medida
+    // que se descubren en lugar de esperar al recorrido completo del proyecto
+    let streaming =
+        listed.is_none() && cli_args.since.is_none() && cli_args.runs_phase(cli::Phase::Rules);
+    let mut files = match listed {
+        Some(files) => files,
+        None if streaming => Vec::new(),
+        None => timed(profiler.as_ref(), "descubrimiento", || {
+            discovery::collect_files_guarded(project_root, &ctx, cli_args.max_files)
+        })?,
+    };
+
+    // Mostrar información de directorios ignorados
+    if !ctx.ignored_paths.is_empty() && !cli_args.is_machine_output() {
+        println!("📂 Ignorando directorios: {}", ctx.ignored_paths.join(", "));
+    }
+
+    if files.is_empty() && !streaming {
+        print_no_files(cli_args)?;
+        return Ok(());
+    }
+
+    // Con --since, las reglas solo se aplican a los archivos cambiados desde la referencia
+    let mut rule_files = match &cli_args.since {
+        Some(git_ref) => {
+            let changed = git::changed_files_since(project_root, git_ref)?;
+            let changed: Vec<PathBuf> = files
+                .iter()
+                .filter(|f| changed.contains(f))
+                .cloned()
+                .collect();
+            if !cli_args.is_machine_output() {
+                println!(
+                    "🏷️  {} archivos cambiados desde '{}'",
+                    changed.len(),
+                    git_ref
+                );
+            }
+            changed
+        }
+        None => files.clone(),
+    };
+
+    let counters = Counters::default();
+    let cm = Arc::new(SourceMap::default());
+    // --grade se calcula sobre las mismas métricas que --metrics
+    let metrics = (cli_args.metrics_path.is_some() || cli_args.grade)
+        .then(metrics::MetricsCollector::default);
+    let hotspot_collector = cli_args
+        .hotspots
+        .filter(|_| !cli_args.is_machine_output())
+        .map(|_| hotspots::HotspotCollector::default());
+    let mut report_entries = Vec::new();
+    let checkpoint = cli_args.checkpoint.as_ref().map(|path| {
+        let config_hash = checkpoint::config_hash(project_root, cli_args);
+        checkpoint::Checkpoint::load(Path::new(path), project_root, &config_hash)
+    });
+
+    let phase = RulesPhaseOptions {
+        project_root,
+        cm: &cm,
+        ctx: &ctx,
+        cli_args,
+        metrics: metrics.as_ref(),
+        checkpoint: checkpoint.as_ref(),
+        profiler: profiler.as_ref(),
+        hotspots: hotspot_collector.as_ref(),
+    };
+
+    // Fase 1: reglas por archivo (Barra de progreso y Análisis Paralelo con Rayon)
+    if streaming {
+        let (paths, walker) =
+            discovery::stream_files(project_root, Arc::clone(&ctx), cli_args.max_files);
+        // El recorrido y las reglas corren a la vez: se miden juntos
+        let mut outcome = timed(profiler.as_ref(), "descubrimiento + reglas", || {
+            run_rules_phase(&phase, paths.into_iter(), None, &counters)
+        })?;
+
+        // El recorrido se detuvo en --max-files. Todavía no se mostró ningún hallazgo:
+        // se pregunta (o se falla) como en el recorrido completo, y si se continúa se
+        // analizan los archivos que faltan
+        if walker.join().unwrap_or(false) {
+            timed(profiler.as_ref(), "reglas", || {
+                continue_past_limit(
+                    &phase,
+                    &mut outcome,
+                    &counters,
+                    std::io::stdin().is_terminal(),
+                )
+            })?;
+        }
+        let (analyzed, entries) = finish_rules_phase(&phase, outcome)?;
+        report_entries = entries;
+
+        if analyzed.is_empty() {
+            print_no_files(cli_args)?;
+            return Ok(());
+        }
+
+        // El resto de fases usan el listado completo, ordenado para que sea determinista
+        files = analyzed;
+        files.sort();
+        rule_files = files.clone();
+    } else if cli_args.runs_phase(cli::Phase::Rules) {
+        let outcome = timed(profiler.as_ref(), "reglas", || {
+            run_rules_phase(
+                &phase,
+                rule_files.iter().cloned(),
+                Some(rule_files.len()),
+                &counters,
+            )
+        })?;
+        report_entries = finish_rules_phase(&phase, outcome)?.1;
+
+        if let Some(git_ref) = cli_args
+            .since
+            .as_ref()
+            .filter(|_| !cli_args.is_machine_output())
+        {
+            let (errors, warnings, _) = counters.totals();
+            let current = errors + warnings;
+            print_since_summary(project_root, git_ref, &rule_files, current, &ctx, cli_args)?;
+        }
+    }
+
+    if let Some(checkpoint) = &checkpoint {
+        checkpoint.save()?;
+    }
+
+    // Fase 2: Análisis de Dependencias Cíclicas
+    if cli_args.runs_phase(cli::Phase::Circular) {
+        if !cli_args.is_machine_output() {
+            println!("\n🔍 Analizando dependencias cíclicas...");
+        }
+        let mut graph = circular::CircularDependencyAnalyzer::new(project_root);
+
+        let skipped = timed(profiler.as_ref(), "grafo de dependencias", || {
+            graph.build_graph(&files, &cm)
+        });
+        print_skipped_files(&skipped, project_root, cli_args);
+        let detected_cycles = timed(profiler.as_ref(), "detección de ciclos", || {
+            circular::filter_by_length(graph.detect_cycles(), &ctx.settings)
+        });
+        if !detected_cycles.is_empty() {
+            if !cli_args.is_machine_output() {
+                circular::print_circular_dependency_report(&detected_cycles);
+                println!("\n⚠️  Se encontraron dependencias cíclicas que deben ser resueltas.");
+            }
+            counters.add((detected_cycles.len(), 0, 0));
+        }
+        if let Some(metrics) = &metrics {
+            metrics.record_cycles(detected_cycles.len());
+        }
+        if cli_args.wants_report() {
+            report_entries.extend(detected_cycles.iter().map(compare::ReportEntry::from_cycle));
+        }
+        if cli_args.graph_metrics {
+            circular::print_coupling_report(&graph.coupling_metrics());
+        }
+        write_dependency_graph(cli_args, &graph, &detected_cycles)?;
+    }
+
+    // Comprobaciones sobre el listado completo del proyecto (no solo los archivos de --since)
+    if cli_args.runs_phase(cli::Phase::Rules) {
+        if ctx.settings.no_unused_exports.is_some() && !cli_args.is_machine_output() {
+            println!("\n🔍 Buscando exports sin uso...");
+        }
+        let checks = project_checks(&files, project_root, &cm, &ctx, profiler.as_ref());
+        // Carpetas con demasiados archivos
+        if ctx.settings.max_files_per_dir > 0 && !cli_args.is_machine_output() {
+            crowded_dirs::print_crowded_dirs_report(
+                &checks.crowded,
+                ctx.settings.max_files_per_dir,
+            );
+        }
+        counters.add((0, checks.crowded.len(), 0));
+        // Archivos fuera de todas las capas del patrón
+        if let Some(stray) = &checks.unclassified {
+            if !cli_args.is_machine_output() {
+                let layers = unclassified::layer_patterns(&ctx.settings, &ctx.pattern);
+                unclassified::print_unclassified_report(stray, &layers);
+            }
+            counters.add((stray.len(), 0, 0));
+        } else if ctx.settings.require_layer && !cli_args.is_machine_output() {
+            println!(
+                "⚠️  require_layer solo aplica a los patrones Hexagonal y Clean (patrón actual: {:?}).",
+                ctx.pattern
+            );
+        }
+        // Exports sin uso
+        if ctx.settings.no_unused_exports.is_some() && !cli_args.is_machine_output() {
+            unused_exports::print_unused_exports_report(&checks.unused_exports);
+        }
+        counters.add((0, checks.unused_exports.len(), 0));
+        if cli_args.wants_report() {
+            report_entries.extend(checks.report_entries());
+        }
+    }
+
+    // Hotspots: puntuación compuesta por archivo para priorizar refactors
+    if let (Some(limit), Some(collector)) = (cli_args.hotspots, hotspot_collector) {
+        let ranked = timed(profiler.as_ref(), "hotspots", || {
+            collector.ranked(project_root)
+        });
+        hotspots::print_hotspots_report(&ranked, limit);
+    }
+
+    if let Some(metrics) = metrics
+        .as_ref()
+        .filter(|_| cli_args.grade && !cli_args.is_machine_output())
+    {
+        grade::print_grade(&metrics.summary(rule_files.len()), ctx.max_lines);
+    }
+
+    // Registro de métricas para seguimiento histórico
+    if let (Some(metrics), Some(path)) = (&metrics, &cli_args.metrics_path) {
+        metrics.write(path.as_ref(), rule_files.len())?;
+        if !cli_args.is_machine_output() {
+            println!("📈 Métricas guardadas en: {}", path);
+        }
+    }
+
+    // Reporte de violaciones y comparación con una ejecución anterior
+    write_report_artifacts(cli_args, &report_entries)?;
+    if cli_args.is_csv() {
+        csv::print_csv(&report_entries).into_diagnostic()?;
+    }
+    let comparison = match &cli_args.compare_to {
+        Some(target) => {
+            let previous = load_compare_baseline(project_root, target, &ctx, cli_args)?;
+            let comparison = compare::compare(previous, report_entries);
+            if !cli_args.is_machine_output() {
+                compare::print_comparison(&comparison, target);
+            }
+            // Solo lo que introduce la rama: la deuda previa no se anota en el PR
+            print_annotations(cli_args, &comparison.added)?;
+            Some(comparison)
+        }
+        None => {
+            print_annotations(cli_args, &report_entries)?;
+            None
+        }
+    };
+
+    // Resultado final
+    let (total, warnings, parse_errors) = counters.totals();
+    if let Some(profiler) = &profiler {
+        // Con --format csv/sarif stdout solo lleva el documento
+        if cli_args.is_machine_output() {
+            eprint!("{}", profiler.render());
+        } else {
+            print!("{}", profiler.render());
+        }
+    }
+    if !cli_args.is_machine_output() {
+        print_summary(total, warnings, parse_errors);
+    }
+
+    // Con --compare-to solo fallan las violaciones nuevas (salvo --compare-fail-on)
+    let failed = match (&comparison, cli_args.compare_fail_on) {
+        (Some(comparison), cli::CompareFailOn::New) => comparison.added_errors() > 0,
+        (Some(_), cli::CompareFailOn::Never) => false,
+        _ => total > 0,
+    };
+    std::process::exit(exit_codes::for_run(
+        failed,
+        warnings,
+        parse_errors,
+        cli_args.detailed_exit_codes,
+    ));
+}
+
+/// Ejecuta una fase midiendo su duración si se pidió --profile
+fn timed<T>(
+    profiler: Option<&profile::Profiler>,
+    name: &'static str,
+    run: impl FnOnce() -> T,
+) -> T {
+    match profiler {
+        Some(profiler) => profiler.phase(name, run),
+        None => run(),
+    }
+}
+
+/// Resumen final de la ejecución
+fn print_summary(total: usize, warnings: usize, parse_errors: usize) {
+    if parse_errors > 0 {
+        println!(
+            "🧩 {} archivo(s) con errores de sintaxis: se reportaron solo sus hallazgos parciales.",
+            parse_errors
+        );
+    }
+    if warnings > 0 {
+        println!("⚠️  Se encontraron {} advertencias.", warnings);
+    }
+    if total > 0 {
+        println!("❌ Se encontraron {} violaciones arquitectónicas.", total);
+    } else {
+        println!("✨ ¡Proyecto impecable! La arquitectura se respeta.");
+    }
+}
+
+/// Anotaciones de --format github o documento de --format sarif
+fn print_annotations(cli_args: &cli::CliArgs, entries: &[compare::ReportEntry]) -> Result<()> {
+    match cli_args.format {
+        cli::OutputFormat::Github => github::print_annotations(entries).into_diagnostic(),
+        cli::OutputFormat::Sarif => sarif::print_sarif(entries).into_diagnostic(),
+        cli::OutputFormat::Text | cli::OutputFormat::Csv => Ok(()),
+    }
+}
+
+/// Guarda las violaciones en los archivos pedidos (--report, --output-dir)
+fn write_report_artifacts(
+    cli_args: &cli::CliArgs,
+    report_entries: &[compare::ReportEntry],
+) -> Result<()> {
+    if let Some(path) = &cli_args.report_path {
+        compare::write_report(Path::new(path), report_entries)?;
+        if !cli_args.is_machine_output() {
+            println!("🧾 Reporte de violaciones guardado en: {}", path);
+        }
+    }
+    if let Some(path) = &cli_args.sarif_path {
+        let mut file = std::io::BufWriter::new(std::fs::File::create(path).into_diagnostic()?);
+        sarif::write_sarif(&mut file, report_entries).into_diagnostic()?;
+        if !cli_args.is_machine_output() {
+            println!("🧾 Reporte SARIF guardado en: {}", path);
+        }
+    }
+    if let Some(path) = &cli_args.html_path {
+        html::write_html_file(Path::new(path), report_entries).into_diagnostic()?;
+        if !cli_args.is_machine_output() {
+            println!("🧾 Reporte HTML guardado en: {}", path);
+        }
+    }
+    Ok(())
+}
+
+/// Guarda el grafo de dependencias en DOT (graph.dot de --output-dir)
+fn write_dependency_graph(
+    cli_args: &cli::CliArgs,
+    graph: &circular::CircularDependencyAnalyzer,
+    cycles: &[circular::CircularDependency],
+) -> Result<()> {
+    if let Some(path) = &cli_args.graph_path {
+        let mut file = std::io::BufWriter::new(std::fs::File::create(path).into_diagnostic()?);
+        graph.write_dot(&mut file, cycles).into_diagnostic()?;
+        file.flush().into_diagnostic()?;
+        if !cli_args.is_machine_output() {
+            println!("🕸️  Grafo de dependencias guardado en: {}", path);
+        }
+    }
+    Ok(())
+}
+
+/// Sin archivos que analizar: el mensaje habitual, o un CSV/SARIF vacío
+fn print_no_files(cli_args: &cli::CliArgs) -> Result<()> {
+    if cli_args.is_csv() {
+        return csv::print_csv(&[]).into_diagnostic();
+    }
+    if cli_args.format == cli::OutputFormat::Sarif {
+        return sarif::print_sarif(&[]).into_diagnostic();
+    }
+    println!("✅ No se encontraron archivos para analizar (TypeScript, JavaScript, Python, Go, PHP, Java).");
+    Ok(())
+}
+
+/// Violaciones de referencia para --compare-to: un reporte de --report o el
+/// análisis de una referencia git en un worktree temporal (con la configuración actual)
+fn load_compare_baseline(
+    project_root: &Path,
+    target: &str,
+    ctx: &config::LinterContext,
+    cli_args: &cli::CliArgs,
+) -> Result<Vec<compare::ReportEntry>> {
+    let report = Path::new(target);
+    if report.is_file() {
+        return compare::load_report(report);
+    }
+
+    if !cli_args.is_machine_output() {
+        println!("\n🏷️  Analizando '{}' para comparar...", target);
+    }
+    let worktree = git::Worktree::checkout(project_root, target)?;
+    let root = &worktree.project_root;
+    let files = discovery::collect_files_guarded(root, ctx, cli_args.max_files)?;
+    Ok(phase_entries(root, project_root, &files, ctx, cli_args))
+}
+
+/// Violaciones de las fases seleccionadas con --only/--skip, sin imprimir nada.
+/// `root` puede ser un worktree: los architect.json en cascada se buscan en la
+/// carpeta equivalente de `config_root`, como en la ejecución actual
+fn phase_entries(
+    root: &Path,
+    config_root: &Path,
+    files: &[PathBuf],
+    ctx: &config::LinterContext,
+    cli_args: &cli::CliArgs,
+) -> Vec<compare::ReportEntry> {
+    let cm = SourceMap::default();
+    let mut entries = Vec::new();
+
+    if cli_args.runs_phase(cli::Phase::Rules) {
+        entries.par_extend(files.par_iter().flat_map_iter(|file| {
+            let display = display_path(file, root, false);
+            let config_path = file
+                .strip_prefix(root)
+                .map_or_else(|_| file.clone(), |relative| config_root.join(relative));
+            let cascaded = ctx.cascaded_for(&config_path);
+            let file_ctx = cascaded.as_deref().unwrap_or(ctx);
+            let mut findings = analyzer::analyze_file(&cm, file, file_ctx).unwrap_or_default();
+            if cli_args.report_unresolved {
+                findings.extend(
+                    analyzer::find_unresolved_imports(&cm, file, file_ctx).unwrap_or_default(),
+                );
+            }
+            findings
+                .iter()
+                .map(|finding| compare::ReportEntry::from_finding(&display, finding))
+                .collect::<Vec<_>>()
+        }));
+        entries.extend(project_checks(files, root, &cm, ctx, None).report_entries());
+    }
+
+    if cli_args.runs_phase(cli::Phase::Circular) {
+        let cycles = circular::filter_by_length(
+            circular::analyze_circular_dependencies(files, root, &cm),
+            &ctx.settings,
+        );
+        entries.extend(cycles.iter().map(compare::ReportEntry::from_cycle));
+    }
+
+    entries
+}
+
+/// Hallazgos sobre el listado completo del proyecto en lugar de archivo por archivo.
+/// Se calculan igual en la ejecución y en la referencia de --compare-to
+struct ProjectChecks {
+    /// Carpetas con más archivos que max_files_per_dir (vacío si es 0)
+    crowded: Vec<crowded_dirs::CrowdedDir>,
+    /// Archivos fuera de todas las capas (`None` sin require_layer o con un patrón
+    /// que no es por capas)
+    unclassified: Option<Vec<String>>,
+    /// Exports que nadie importa (vacío sin no_unused_exports)
+    unused_exports: Vec<unused_exports::UnusedExport>,
+}
+
+fn project_checks(
+    files: &[PathBuf],
+    project_root: &Path,
+    cm: &SourceMap,
+    ctx: &config::LinterContext,
+    profiler: Option<&profile::Profiler>,
+) -> ProjectChecks {
+    let unclassified =
+        (ctx.settings.require_layer && unclassified::applies_to(&ctx.pattern)).then(|| {
+            unclassified::find_unclassified_files(files, project_root, &ctx.settings, &ctx.pattern)
+        });
+
+    let unused_exports = match ctx.settings.no_unused_exports {
+        Some(_) => timed(profiler, "exports sin uso", || {
+            unused_exports::find_unused_exports(files, project_root, cm, ctx)
+        }),
+        None => Vec::new(),
+    };
+
+    ProjectChecks {
+        crowded: crowded_dirs::find_crowded_dirs(files, project_root, &ctx.settings),
+        unclassified,
+        unused_exports,
+    }
+}
+
+impl ProjectChecks {
+    fn report_entries(&self) -> Vec<compare::ReportEntry> {
+        let crowded = self
+            .crowded
+            .iter()
+            .map(compare::ReportEntry::from_crowded_dir);
+        let unclassified = self
+            .unclassified
+            .iter()
+            .flatten()
+            .map(|file| compare::ReportEntry::from_unclassified(file));
+        let unused = self
+            .unused_exports
+            .iter()
+            .map(compare::ReportEntry::from_unused_export);
+        crowded.chain(unclassified).chain(unused).collect()
+    }
+}
+
+/// Lo que la fase de reglas comparte entre todos los archivos
+#[derive(Clone, Copy)]
+struct RulesPhaseOptions<'a> {
+    project_root: &'a Path,
+    cm: &'a SourceMap,
+    ctx: &'a config::LinterContext,
+    cli_args: &'a cli::CliArgs,
+    metrics: Option<&'a metrics::MetricsCollector>,
+    checkpoint: Option<&'a checkpoint::Checkpoint>,
+    profiler: Option<&'a profile::Profiler>,
+    /// Métricas de cada módulo para --hotspots
+    hotspots: Option<&'a hotspots::HotspotCollector>,
+}
+
+/// Errores, advertencias y errores de sintaxis de la ejecución. Los hilos de la fase
+/// de reglas suman a la vez, por eso cada contador tiene su lock.
+#[derive(Default)]
+struct Counters {
+    errors: Mutex<usize>,
+    warnings: Mutex<usize>,
+    parse_errors: Mutex<usize>,
+}
+
+impl Counters {
+    /// Suma (errores, advertencias, errores de sintaxis), como los devuelve `count_findings`
+    fn add(&self, (errors, warnings, parse_errors): (usize, usize, usize)) {
+        *self.errors.lock().unwrap() += errors;
+        *self.warnings.lock().unwrap() += warnings;
+        *self.parse_errors.lock().unwrap() += parse_errors;
+    }
+
+    /// (errores, advertencias, errores de sintaxis) acumulados
+    fn totals(&self) -> (usize, usize, usize) {
+        (
+            *self.errors.lock().unwrap(),
+            *self.warnings.lock().unwrap(),
+            *self.parse_errors.lock().unwrap(),
+        )
+    }
+}
+
+/// Hallazgos de la fase de reglas, todavía sin mostrar
+struct RulesOutcome {
+    analyzed: Vec<PathBuf>,
+    collected: Vec<tui::CollectedFinding>,
+    /// Archivos con hallazgos omitidos por --max-violations-per-file
+    suppressed: Vec<(String, usize)>,
+    /// Hallazgos retomados del checkpoint
+    resumed: Vec<compare::ReportEntry>,
+}
+
+impl RulesOutcome {
+    /// Agrega los hallazgos de otra pasada sobre archivos distintos
+    fn merge(&mut self, other: RulesOutcome) {
+        self.analyzed.extend(other.analyzed);
+        self.collected.extend(other.collected);
+        self.suppressed.extend(other.suppressed);
+        self.suppressed.sort();
+        self.resumed.extend(other.resumed);
+    }
+}
+
+/// El recorrido en streaming se detuvo en --max-files: pregunta (o falla) como el
+/// recorrido completo y, si se continúa, analiza los archivos que faltan
+fn continue_past_limit(
+    phase: &RulesPhaseOptions,
+    outcome: &mut RulesOutcome,
+    counters: &Counters,
+    interactive: bool,
+) -> Result<()> {
+    discovery::confirm_or_fail(phase.project_root, phase.cli_args.max_files, interactive)?;
+    analyze_rest(phase, outcome, counters)
+}
+
+/// Analiza los archivos del proyecto que la pasada anterior no llegó a ver
+fn analyze_rest(
+    phase: &RulesPhaseOptions,
+    outcome: &mut RulesOutcome,
+    counters: &Counters,
+) -> Result<()> {
+    let seen: HashSet<PathBuf> = outcome.analyzed.iter().cloned().collect();
+    let rest = discovery::collect_files(phase.project_root, phase.ctx)
+        .into_iter()
+        .filter(|file| !seen.contains(file));
+    outcome.merge(run_rules_phase(phase, rest, None, counters)?);
+    Ok(())
+}
+
+/// Fase de reglas: analiza cada archivo en paralelo y acumula errores y advertencias.
+/// Los archivos pueden llegar mientras se recorre el proyecto (`total` desconocido).
+/// No imprime nada: los hallazgos se muestran con `finish_rules_phase`.
+fn run_rules_phase(
+    phase: &RulesPhaseOptions,
+    files: impl Iterator<Item = PathBuf> + Send,
+    total: Option<usize>,
+    counters: &Counters,
+) -> Result<RulesOutcome> {
+    let RulesPhaseOptions {
+        project_root,
+        cm,
+        ctx,
+        cli_args,
+        metrics,
+        checkpoint,
+        profiler,
+        hotspots,
+    } = *phase;
+    // Sin el total (recorrido en curso) se muestra un spinner con el contador
+    let pb = match total {
+        Some(total) => {
+            let pb = ProgressBar::new(total as u64);
+            pb.set_style(
+                ProgressStyle::default_bar()
+                    .template("{spinner:.green} [{bar:40.cyan/blue}] {pos}/{len} {msg}")
+                    .into_diagnostic()?,
+            );
+            pb
+        }
+        None => {
+            let pb = ProgressBar::new_spinner();
+            pb.set_style(
+                ProgressStyle::default_spinner()
+                    .template("{spinner:.green} {pos} archivos analizados {msg}")
+                    .into_diagnostic()?,
+            );
+            pb
+        }
+    };
+    let analyzed = Mutex::new(Vec::new());
+
+    // Los hallazgos se acumulan y se muestran al final, ordenados, para que la salida
+    // no dependa del orden en que terminan los hilos
+    let collector = FindingCollector::with_limit(cli_args.max_violations_per_file);
+    let resumed = Mutex::new(Vec::new());
+
+    files.par_bridge().for_each(|path| {
+        let file_path = &path;
+
+        // Ya analizado (y sin cambios) en una ejecución anterior con --checkpoint
+        if let Some(entries) = checkpoint.and_then(|checkpoint| checkpoint.completed(file_path)) {
+            counters.add(count_entries(&entries));
+            resumed.lock().unwrap().extend(entries);
+            analyzed.lock().unwrap().push(file_path.clone());
+            pb.inc(1);
+            return;
+        }
+
+        let started = Instant::now();
+        let display = display_path(file_path, project_root, cli_args.absolute_paths);
+        // Con `cascade`, el architect.json más cercano al archivo
+        let cascaded = ctx.cascaded_for(file_path);
+        let file_ctx = cascaded.as_deref().unwrap_or(ctx);
+        let (mut errors, warnings, parse_errors) = report_file(
+            analyzer::analyze_file_recording(cm, file_path, file_ctx, hotspots),
+            file_path,
+            &display,
+            metrics,
+            Some(&collector),
+            checkpoint,
+        );
+
+        // Imports relativos rotos (solo con --report-unresolved)
+        if cli_args.report_unresolved {
+            match analyzer::find_unresolved_imports(cm, file_path, file_ctx) {
+                Ok(findings) => {
+                    if let Some(metrics) = metrics {
+                        metrics.record_findings(&findings);
+                    }
+                    if let Some(checkpoint) = checkpoint {
+                        checkpoint.append(file_path, &findings);
+                    }
+                    errors += emit_findings(file_path, &display, findings, Some(&collector)).0
+                }
+                Err(e) => eprintln!("⚠️  Error analizando {}: {}", display, e),
+            }
+        }
+
+        if let Some(metrics) = metrics {
+            if let Ok((lines, lengths)) = analyzer::collect_line_metrics(cm, file_path) {
+                metrics.record_lines(lines);
+                metrics.record_method_lengths(&lengths);
+            }
+        }
+
+        if let Some(profiler) = profiler {
+            profiler.record_file(&display, started.elapsed());
+        }
+
+        counters.add((errors, warnings, parse_errors));
+        analyzed.lock().unwrap().push(file_path.clone());
+        pb.inc(1);
+    });
+
+    pb.finish_and_clear();
+    let (collected, suppressed) = collector.into_findings();
+    Ok(RulesOutcome {
+        analyzed: analyzed.into_inner().unwrap(),
+        collected,
+        suppressed,
+        resumed: resumed.into_inner().unwrap(),
+    })
+}
+
+/// Muestra los hallazgos de la fase de reglas. Devuelve los archivos analizados y
+/// las violaciones para --report/--compare-to.
+fn finish_rules_phase(
+    phase: &RulesPhaseOptions,
+    outcome: RulesOutcome,
+) -> Result<(Vec<PathBuf>, Vec<compare::ReportEntry>)> {
+    let RulesPhaseOptions {
+        project_root,
+        cli_args,
+        ..
+    } = *phase;
+    let RulesOutcome {
+        analyzed,
+        mut collected,
+        suppressed,
+        mut resumed,
+    } = outcome;
+    sort_collected(&mut collected);
+
+    let mut report_entries = Vec::new();
+    if cli_args.wants_report() {
+        // Rutas siempre relativas para poder comparar entre máquinas y worktrees
+        report_entries = collected
+            .iter()
+            .map(|entry| {
+                let file = display_path(&entry.path, project_root, false);
+                compare::ReportEntry::from_finding(&file, &entry.finding)
+            })
+            .collect();
+    }
+
+    // Los hallazgos retomados del checkpoint ya no tienen el código fuente para el
+    // diagnóstico completo: se listan en una línea cada uno
+    resumed.sort_by(|a, b| a.file.cmp(&b.file).then(a.line.cmp(&b.line)));
+    if !resumed.is_empty() && !cli_args.is_machine_output() {
+        println!(
+            "\n♻️  Hallazgos retomados del checkpoint ({}):",
+            resumed.len()
+        );
+        for entry in &resumed {
+            println!(
+                "   {}:{} [{}] {}",
+                entry.file, entry.line, entry.rule_id, entry.message
+            );
+        }
+    }
+    if cli_args.wants_report() {
+        report_entries.extend(resumed);
+    }
+
+    // Sin una terminal interactiva, --tui degrada a la salida normal
+    let tui = cli_args.tui && std::io::stdout().is_terminal();
+    if cli_args.tui && !tui {
+        eprintln!("⚠️  --tui requiere una terminal interactiva. Se usa la salida normal.");
+    }
+    if tui {
+        tui::browse(collected)?;
+    } else if cli_args.is_machine_output() {
+        // Las filas se emiten al final, junto con los ciclos
+    } else if cli_args.dedupe {
+        print_deduped(collected).into_diagnostic()?;
+    } else {
+        print_collected(&collected);
+    }
+    print_suppressed(&suppressed, cli_args);
+    Ok((analyzed, report_entries))
+}
+
+/// Compara los hallazgos actuales de los archivos cambiados con los de la referencia.
+/// Solo se re-analizan esos archivos en un worktree temporal, el resto no cambió.
+/// `current` son los errores y advertencias de la fase de reglas: la versión anterior
+/// se cuenta igual, con `file_debt` y la configuración que rige cada archivo hoy.
+fn print_since_summary(
+    project_root: &Path,
+    git_ref: &str,
+    changed_files: &[PathBuf],
+    current: usize,
+    ctx: &config::LinterContext,
+    cli_args: &cli::CliArgs,
+) -> Result<()> {
+    let previous = debt_at_ref(
+        project_root,
+        git_ref,
+        changed_files,
+        ctx,
+        cli_args.report_unresolved,
+    )?;
+
+    let delta = current as i64 - previous as i64;
+    println!("\n🏷️  Deuda arquitectónica desde '{}':", git_ref);
+    println!(
+        "   Antes: {} | Ahora: {} | Cambio neto: {:+}",
+        previous, current, delta
+    );
+    Ok(())
+}
+
+/// Violaciones que tenían en `git_ref` los archivos cambiados desde entonces. Los
+/// archivos que no existían en esa revisión no suman
+fn debt_at_ref(
+    project_root: &Path,
+    git_ref: &str,
+    changed_files: &[PathBuf],
+    ctx: &config::LinterContext,
+    report_unresolved: bool,
+) -> Result<usize> {
+    let worktree = git::Worktree::checkout(project_root, git_ref)?;
+    let cm = SourceMap::default();
+
+    Ok(changed_files
+        .iter()
+        .filter_map(|file| {
+            let relative = file.strip_prefix(project_root).ok()?;
+            let old_file = worktree.project_root.join(relative);
+            old_file.exists().then_some((file, old_file))
+        })
+        .map(|(file, old_file)| {
+            let cascaded = ctx.cascaded_for(file);
+            let file_ctx = cascaded.as_deref().unwrap_or(ctx);
+            file_debt(&cm, &old_file, file_ctx, report_unresolved)
+        })
+        .sum())
+}
+
+/// Ruta de un archivo tal como se muestra en la salida: relativa a la raíz del
+/// proyecto para que los logs sean portables entre máquinas, salvo con --absolute-paths
+fn display_path(file_path: &Path, project_root: &Path, absolute: bool) -> String {
+    if !absolute {
+        if let Ok(relative) = file_path.strip_prefix(project_root) {
+            return relative.to_string_lossy().replace('\\', "/");
+        }
+    }
+    file_path.display().to_string()
+}
+
+/// Registra e imprime los hallazgos del análisis de un archivo.
+/// Devuelve (errores, advertencias, errores de sintaxis).
+fn report_file(
+    analysis: Result<Vec<rules::Finding>>,
+    file_path: &Path,
+    display: &str,
+    metrics: Option<&metrics::MetricsCollector>,
+    collector: Option<&FindingCollector>,
+    checkpoint: Option<&checkpoint::Checkpoint>,
+) -> (usize, usize, usize) {
+    match analysis {
+        Ok(findings) => {
+            if let Some(metrics) = metrics {
+                metrics.record_findings(&findings);
+            }
+            if let Some(checkpoint) = checkpoint {
+                checkpoint.record(file_path, &findings);
+            }
+            emit_findings(file_path, display, findings, collector)
+        }
+        Err(e) => {
+            if let Some(metrics) = metrics {
+                metrics.record_error("analysis-error");
+            }
+            print_analysis_error(display, &e);
+            (1, 0, 0)
+        }
+    }
+}
+
+/// Imprime los hallazgos de un archivo, o los guarda para mostrarlos al final. Devuelve (errores, advertencias, errores de sintaxis).
+fn emit_findings(
+    file_path: &Path,
+    display: &str,
+    findings: Vec<rules::Finding>,
+    collector: Option<&FindingCollector>,
+) -> (usize, usize, usize) {
+    let counts = count_findings(&findings);
+    match collector {
+        Some(collector) => collector.add(file_path, display, findings),
+        None => print_findings(display, &findings),
+    }
+    counts
+}
+
+/// Deuda de un archivo para --since: errores más advertencias, como los contadores
+/// de la fase de reglas. Los errores de sintaxis no cuentan y un archivo que no se
+/// puede leer aporta 0.
+fn file_debt(
+    cm: &SourceMap,
+    file: &Path,
+    ctx: &config::LinterContext,
+    report_unresolved: bool,
+) -> usize {
+    let mut findings = analyzer::analyze_file(cm, file, ctx).unwrap_or_default();
+    if report_unresolved {
+        findings.extend(analyzer::find_unresolved_imports(cm, file, ctx).unwrap_or_default());
+    }
+    let (errors, warnings, _) = count_findings(&findings);
+    errors + warnings
+}
+
+/// Cuenta (errores, advertencias, errores de sintaxis). Los errores de sintaxis
+/// no son violaciones de arquitectura y se cuentan aparte.
+fn count_findings(findings: &[rules::Finding]) -> (usize, usize, usize) {
+    findings
+        .iter()
+        .fold((0, 0, 0), |(errors, warnings, parse_errors), finding| {
+            if finding.rule_id == rules::PARSE_ERROR_ID {
+                (errors, warnings, parse_errors + 1)
+            } else if finding.severity == rules::Severity::Error {
+                (errors + 1, warnings, parse_errors)
+            } else {
+                (errors, warnings + 1, parse_errors)
+            }
+        })
+}
+
+/// Como `count_findings`, para los hallazgos guardados en un checkpoint
+fn count_entries(entries: &[compare::ReportEntry]) -> (usize, usize, usize) {
+    entries
+        .iter()
+        .fold((0, 0, 0), |(errors, warnings, parse_errors), entry| {
+            if entry.rule_id == rules::PARSE_ERROR_ID {
+                (errors, warnings, parse_errors + 1)
+            } else if entry.severity == rules::Severity::Error {
+                (errors + 1, warnings, parse_errors)
+            } else {
+                (errors, warnings + 1, parse_errors)
+            }
+        })
+}
+
+/// Máximo de archivos listados por grupo en la salida de --dedupe
+const DEDUPE_MAX_LISTED: usize = 5;
+
+/// Acumula los hallazgos de todos los archivos para mostrarlos al final
+#[derive(Default)]
+struct FindingCollector {
+    findings: Mutex<Vec<tui::CollectedFinding>>,
+    /// Máximo de hallazgos guardados por archivo (--max-violations-per-file)
+    max_per_file: Option<usize>,
+    /// Por archivo: ruta a mostrar, hallazgos guardados y omitidos
+    per_file: Mutex<HashMap<PathBuf, (String, usize, usize)>>,
+}
+
+impl FindingCollector {
+    fn with_limit(max_per_file: Option<usize>) -> Self {
+        Self {
+            max_per_file,
+            ..Default::default()
+        }
+    }
+
+    fn add(&self, file_path: &Path, display: &str, mut findings: Vec<rules::Finding>) {
+        // Al llegar al máximo el resto se descarta (ya se contó para el resultado):
+        // un archivo generado con miles de hallazgos no satura la salida ni la memoria
+        if let Some(max) = self.max_per_file {
+            let mut per_file = self.per_file.lock().unwrap();
+            let (_, kept, suppressed) = per_file
+                .entry(file_path.to_path_buf())
+                .or_insert_with(|| (display.to_string(), 0, 0));
+            let room = max.saturating_sub(*kept);
+            if findings.len() > room {
+                // Se conservan los errores antes que las advertencias, en orden de línea
+                findings.sort_by(|a, b| {
+                    a.severity
+                        .cmp(&b.severity)
+                        .then(a.location.start_line.cmp(&b.location.start_line))
+                });
+                *suppressed += findings.len() - room;
+                findings.truncate(room);
+            }
+            *kept += findings.len();
+        }
+
+        let mut stored = self.findings.lock().unwrap();
+        stored.extend(findings.into_iter().map(|finding| tui::CollectedFinding {
+            path: file_path.to_path_buf(),
+            display: display.to_string(),
+            finding,
+        }));
+    }
+
+    /// Hallazgos guardados y, ordenados por ruta, los archivos con hallazgos omitidos
+    fn into_findings(self) -> (Vec<tui::CollectedFinding>, Vec<(String, usize)>) {
+        let mut suppressed: Vec<(String, usize)> = self
+            .per_file
+            .into_inner()
+            .unwrap()
+            .into_values()
+            .filter(|(_, _, suppressed)| *suppressed > 0)
+            .map(|(display, _, suppressed)| (display, suppressed))
+            .collect();
+        suppressed.sort();
+        (self.findings.into_inner().unwrap(), suppressed)
+    }
+}
+
+/// Archivos que quedaron fuera del grafo de dependencias. El error de sintaxis ya
+/// cuenta en la fase de reglas (parse-error): aquí solo se avisa de que sus imports
+/// no participan en la detección de ciclos.
+fn print_skipped_files(
+    skipped: &[circular::SkippedFile],
+    project_root: &Path,
+    cli_args: &cli::CliArgs,
+) {
+    for file in skipped {
+        let line = format!(
+            "⚠️  {} queda fuera del análisis de ciclos: {}",
+            display_path(&file.path, project_root, cli_args.absolute_paths),
+            file.error
+        );
+        if cli_args.is_machine_output() {
+            eprintln!("{}", line);
+        } else {
+            println!("{}", line);
+        }
+    }
+}
 
-    // 2. Obtener la ruta del proyecto
-    let project_root = if let Some(path) = cli_args.project_path {
-        PathBuf::from(&path).canonicalize().into_diagnostic()?
-    } else {
-        ui::get_interactive_path()?
-    };
+/// Una línea por archivo que superó --max-violations-per-file. Con --format csv/sarif
+/// va a stderr para no mezclarse con el documento
+fn print_suppressed(suppressed: &[(String, usize)], cli_args: &cli::CliArgs) {
+    for (display, count) in suppressed {
+        let line = format!(
+            "✂️  {}: +{} hallazgos más omitidos (--max-violations-per-file)",
+            display, count
+        );
+        if cli_args.is_machine_output() {
+            eprintln!("{}", line);
+        } else {
+            println!("\n{}", line);
+        }
+    }
+}
 
-    // 3. Cargar o crear configuración asistida por IA
-    let ctx = Arc::new(config::setup_or_load_config(&project_root)?);
+/// Ordena los hallazgos: primero los errores, luego por archivo, línea, columna y regla
+fn sort_collected(entries: &mut [tui::CollectedFinding]) {
+    entries.sort_by(|a, b| {
+        let (a_location, b_location) = (&a.finding.location, &b.finding.location);
+        a.finding
+            .severity
+            .cmp(&b.finding.severity)
+            .then_with(|| a.display.cmp(&b.display))
+            .then(a_location.start_line.cmp(&b_location.start_line))
+            .then(a_location.start_col.cmp(&b_location.start_col))
+            .then(a.finding.rule_id.cmp(b.finding.rule_id))
+    });
+}
 
-    // 4. Decidir entre modo normal, watch o fix
-    if cli_args.fix_mode {
-        run_fix_mode(&project_root, Arc::clone(&ctx))?;
-    } else if cli_args.watch_mode {
-        run_watch_mode(&project_root, Arc::clone(&ctx))?;
-    } else {
-        run_normal_mode(&project_root, Arc::clone(&ctx))?;
+/// Imprime los hallazgos acumulados (ya ordenados)
+fn print_collected(entries: &[tui::CollectedFinding]) {
+    for entry in entries {
+        print_findings(&entry.display, std::slice::from_ref(&entry.finding));
     }
+}
 
-    Ok(())
+/// Imprime una sola vez los hallazgos con la misma regla y mensaje, con el
+/// total de apariciones y los archivos afectados (--dedupe). Los grupos siguen
+/// el orden de `sort_collected` según su primera aparición.
+fn print_deduped(entries: Vec<tui::CollectedFinding>) -> std::io::Result<()> {
+    write_deduped(&mut std::io::stdout().lock(), entries)
 }
 
-/// Ejecuta el análisis en modo normal (una sola vez)
-fn run_normal_mode(project_root: &PathBuf, ctx: Arc<config::LinterContext>) -> Result<()> {
-    // Recolectar archivos de todos los lenguajes soportados
-    let files = discovery::collect_files(project_root, &ctx.ignored_paths);
+fn write_deduped(out: &mut impl Write, entries: Vec<tui::CollectedFinding>) -> std::io::Result<()> {
+    let mut groups: Vec<(rules::Finding, Vec<String>)> = Vec::new();
+    let mut index: HashMap<(&'static str, String), usize> = HashMap::new();
+    for tui::CollectedFinding {
+        display, finding, ..
+    } in entries
+    {
+        let key = (finding.rule_id, finding.message.clone());
+        match index.get(&key) {
+            Some(&i) => groups[i].1.push(display),
+            None => {
+                index.insert(key, groups.len());
+                groups.push((finding, vec![display]));
+            }
+        }
+    }
 
-    // Mostrar información de directorios ignorados
-    if !ctx.ignored_paths.is_empty() {
-        println!("📂 Ignorando directorios: {}", ctx.ignored_paths.join(", "));
+    for (finding, files) in groups {
+        let count = if files.len() > 1 {
+            format!(" (×{})", files.len())
+        } else {
+            String::new()
+        };
+        match finding.severity {
+            rules::Severity::Error => writeln!(
+                out,
+                "\n📌 Violación en: {} [{}]{}",
+                files[0], finding.rule_id, count
+            )?,
+            rules::Severity::Warning => writeln!(
+                out,
+                "\n⚠️  Advertencia en: {} [{}]{}",
+                files[0], finding.rule_id, count
+            )?,
+        }
+        writeln!(out, "{}", render_report(&finding.report))?;
+
+        if files.len() > 1 {
+            let others = &files[1..];
+            let listed: Vec<&str> = others
+                .iter()
+                .take(DEDUPE_MAX_LISTED)
+                .map(|f| f.as_str())
+                .collect();
+            writeln!(out, "   🔁 También en: {}", listed.join(", "))?;
+            if others.len() > DEDUPE_MAX_LISTED {
+                writeln!(
+                    out,
+                    "   ... y {} archivos más",
+                    others.len() - DEDUPE_MAX_LISTED
+                )?;
+            }
+        }
     }
+    Ok(())
+}
 
-    if files.is_empty() {
-        println!("✅ No se encontraron archivos para analizar (TypeScript, JavaScript, Python, Go, PHP, Java).");
-        return Ok(());
+/// Imprime los hallazgos de un archivo
+fn print_findings(display: &str, findings: &[rules::Finding]) {
+    for finding in findings {
+        if finding.rule_id == rules::PARSE_ERROR_ID {
+            println!("\n🧩 Error de sintaxis en: {}", display);
+        } else {
+            match finding.severity {
+                rules::Severity::Error => {
+                    println!("\n📌 Violación en: {} [{}]", display, finding.rule_id);
+                }
+                rules::Severity::Warning => {
+                    println!("\n⚠️  Advertencia en: {} [{}]", display, finding.rule_id);
+                }
+            }
+        }
+        println!("{}", render_report(&finding.report));
     }
+}
 
-    // Barra de progreso y Análisis Paralelo con Rayon
-    let pb = ProgressBar::new(files.len() as u64);
-    pb.set_style(
-        ProgressStyle::default_bar()
-            .template("{spinner:.green} [{bar:40.cyan/blue}] {pos}/{len} {msg}")
-            .into_diagnostic()?,
-    );
+/// Imprime en stderr el error con el que se cortó el análisis de un archivo: stdout
+/// queda solo para el reporte, que con --format csv o sarif debe seguir siendo válido
+fn print_analysis_error(display: &str, report: &miette::Report) {
+    eprintln!("\n📌 Violación en: {}", display);
+    eprintln!("{}", render_report(report));
+}
 
-    let error_count = Arc::new(Mutex::new(0));
-    let cm = Arc::new(SourceMap::default());
+/// Renderiza un diagnóstico de miette con el formato gráfico
+fn render_report(report: &miette::Report) -> String {
+    let mut out = String::new();
+    let _ = GraphicalReportHandler::new().render_report(&mut out, report.as_ref());
+    out
+}
 
-    files.par_iter().for_each(|file_path| {
-        if let Err(e) = analyzer::analyze_file(&cm, file_path, &ctx) {
-            let mut count = error_count.lock().unwrap();
-            *count += 1;
+/// Audita architect.json: reglas de forbidden_imports cuyo origen o destino no existen
+fn run_audit_mode(
+    project_root: &Path,
+    ctx: &config::LinterContext,
+    max_files: usize,
+) -> Result<()> {
+    let files = discovery::collect_files_guarded(project_root, ctx, max_files)?;
+    println!(
+        "📊 Auditando {} reglas sobre {} archivos...",
+        ctx.forbidden_imports.len(),
+        files.len()
+    );
 
-            let mut out = String::new();
-            let _ = GraphicalReportHandler::new().render_report(&mut out, e.as_ref());
+    let cm = SourceMap::default();
+    let usages = audit::audit_forbidden_rules(&cm, project_root, &files, ctx);
+    let dead = audit::print_audit_report(&usages);
 
-            println!("\n📌 Violación en: {}", file_path.display());
-            println!("{}", out);
-        }
-        pb.inc(1);
-    });
+    if dead > 0 {
+        println!("\n❌ {} regla(s) muerta(s) en forbidden_imports.", dead);
+        std::process::exit(exit_codes::ERRORS);
+    }
+    Ok(())
+}
 
-    pb.finish_and_clear();
+/// Propone forbidden_imports a partir de los imports que hoy cruzan las capas del
+/// patrón (--suggest-rules) y, en una terminal, ofrece agregarlos a architect.json
+fn run_suggest_mode(
+    project_root: &Path,
+    ctx: &config::LinterContext,
+    max_files: usize,
+) -> Result<()> {
+    if ctx.pattern == config::ArchPattern::Ninguno {
+        return Err(miette::miette!(
+            "--suggest-rules necesita un architecture_pattern (Hexagonal, Clean o MVC) en architect.json."
+        ));
+    }
 
-    // Análisis de Dependencias Cíclicas
-    println!("\n🔍 Analizando dependencias cíclicas...");
-    let cycles = circular::analyze_circular_dependencies(&files, project_root, &cm);
+    let files = discovery::collect_files_guarded(project_root, ctx, max_files)?;
+    println!(
+        "📊 Buscando cruces de capas ({:?}) en {} archivos...",
+        ctx.pattern,
+        files.len()
+    );
 
-    match cycles {
-        Ok(detected_cycles) => {
-            if !detected_cycles.is_empty() {
-                circular::print_circular_dependency_report(&detected_cycles);
-                println!("\n⚠️  Se encontraron dependencias cíclicas que deben ser resueltas.");
-                std::process::exit(1);
-            }
-        }
-        Err(e) => {
-            println!("⚠️  No se pudo analizar dependencias cíclicas: {}", e);
-            println!("💡 Continuando con el resto del análisis...");
-        }
+    let cm = SourceMap::default();
+    let mut graph = circular::CircularDependencyAnalyzer::new(project_root);
+    for skipped in graph.build_graph(&files, &cm) {
+        eprintln!("⚠️  {}: {}", skipped.path.display(), skipped.error);
     }
+    let suggestions =
+        suggest::suggest_forbidden_rules(&graph, &ctx.pattern, &ctx.forbidden_imports);
+    suggest::print_suggestions(&suggestions, &ctx.pattern);
 
-    // Resultado final
-    let total = *error_count.lock().unwrap();
-    if total > 0 {
-        println!("❌ Se encontraron {} violaciones arquitectónicas.", total);
-        std::process::exit(1);
-    } else {
-        println!("✨ ¡Proyecto impecable! La arquitectura se respeta.");
-        std::process::exit(0);
+    if suggestions.is_empty() || !std::io::stdout().is_terminal() {
+        return Ok(());
     }
+    let accept = dialoguer::Confirm::with_theme(&dialoguer::theme::ColorfulTheme::default())
+        .with_prompt("¿Agregar estas reglas a architect.json?")
+        .default(false)
+        .interact()
+        .into_diagnostic()?;
+    if accept {
+        let rules: Vec<config::ForbiddenRule> = suggestions
+            .into_iter()
+            .map(|suggestion| suggestion.rule)
+            .collect();
+        config::append_forbidden_imports(project_root, &rules)?;
+        println!("✅ {} regla(s) agregadas a forbidden_imports.", rules.len());
+    }
+    Ok(())
 }
 
 /// Ejecuta el análisis en modo watch (observación continua)
-fn run_watch_mode(project_root: &PathBuf, ctx: Arc<config::LinterContext>) -> Result<()> {
+fn run_watch_mode(
+    project_root: &Path,
+    ctx: Arc<config::LinterContext>,
+    max_files: usize,
+    absolute_paths: bool,
+) -> Result<()> {
     println!("🚀 Iniciando modo watch...\n");
 
     // Análisis inicial completo
-    let files = discovery::collect_files(project_root, &ctx.ignored_paths);
+    let files = discovery::collect_files_guarded(project_root, &ctx, max_files)?;
 
     // Mostrar información de directorios ignorados
     if !ctx.ignored_paths.is_empty() {
@@ -141,22 +1584,32 @@ fn run_watch_mode(project_root: &PathBuf, ctx: Arc<config::LinterContext>) -> Re
 
     // Construir grafo de dependencias inicial
     let mut analyzer = circular::CircularDependencyAnalyzer::new(project_root);
-    analyzer.build_graph(&files, &cm)?;
+    for skipped in analyzer.build_graph(&files, &cm) {
+        eprintln!("⚠️  {}: {}", skipped.path.display(), skipped.error);
+    }
 
     // Análisis inicial de violaciones
     let mut error_count = 0;
+    let mut warning_count = 0;
     for file_path in &files {
-        if let Err(e) = analyzer::analyze_file(&cm, file_path, &ctx) {
-            error_count += 1;
-            let mut out = String::new();
-            let _ = GraphicalReportHandler::new().render_report(&mut out, e.as_ref());
-            println!("\n📌 Violación en: {}", file_path.display());
-            println!("{}", out);
-        }
+        let display = display_path(file_path, project_root, absolute_paths);
+        let file_ctx = ctx
+            .cascaded_for(file_path)
+            .unwrap_or_else(|| Arc::clone(&ctx));
+        let (errors, warnings, _) = report_file(
+            analyzer::analyze_file(&cm, file_path, &file_ctx),
+            file_path,
+            &display,
+            None,
+            None,
+            None,
+        );
+        error_count += errors;
+        warning_count += warnings;
     }
 
     // Análisis de ciclos inicial
-    let cycles = analyzer.detect_cycles();
+    let cycles = circular::filter_by_length(analyzer.detect_cycles(), &ctx.settings);
     if !cycles.is_empty() {
         circular::print_circular_dependency_report(&cycles);
         println!(
@@ -165,6 +1618,10 @@ fn run_watch_mode(project_root: &PathBuf, ctx: Arc<config::LinterContext>) -> Re
         );
     }
 
+    if warning_count > 0 {
+        println!("\n⚠️  Se encontraron {} advertencias.", warning_count);
+    }
+
     if error_count > 0 {
         println!(
             "\n❌ Se encontraron {} violaciones arquitectónicas.",
@@ -176,7 +1633,7 @@ fn run_watch_mode(project_root: &PathBuf, ctx: Arc<config::LinterContext>) -> Re
 
     // Iniciar observación de archivos
     let analyzer = Arc::new(Mutex::new(analyzer));
-    let project_root_arc = Arc::new(project_root.clone());
+    let project_root_arc = Arc::new(project_root.to_path_buf());
     let ignored_paths = ctx.ignored_paths.clone();
 
     watch::start_watch_mode(project_root_arc.as_ref(), ignored_paths, |changed_files| {
@@ -189,13 +1646,19 @@ fn run_watch_mode(project_root: &PathBuf, ctx: Arc<config::LinterContext>) -> Re
         let mut error_count = 0;
         for file_path in changed_files {
             // Validar reglas arquitectónicas
-            if let Err(e) = analyzer::analyze_file(&cm, file_path, &ctx) {
-                error_count += 1;
-                let mut out = String::new();
-                let _ = GraphicalReportHandler::new().render_report(&mut out, e.as_ref());
-                println!("\n📌 Violación en: {}", file_path.display());
-                println!("{}", out);
-            }
+            let display = display_path(file_path, &project_root, absolute_paths);
+            let file_ctx = ctx
+                .cascaded_for(file_path)
+                .unwrap_or_else(|| Arc::clone(&ctx));
+            let (errors, _, _) = report_file(
+                analyzer::analyze_file(&cm, file_path, &file_ctx),
+                file_path,
+                &display,
+                None,
+                None,
+                None,
+            );
+            error_count += errors;
 
             // Actualizar grafo de dependencias
             let mut analyzer = analyzer.lock().unwrap();
@@ -219,7 +1682,10 @@ fn run_watch_mode(project_root: &PathBuf, ctx: Arc<config::LinterContext>) -> Re
             let affected_nodes = analyzer.get_affected_nodes(&normalized_path);
 
             if !affected_nodes.is_empty() {
-                let cycles = analyzer.detect_cycles_in_subgraph(&affected_nodes);
+                let cycles = circular::filter_by_length(
+                    analyzer.detect_cycles_in_subgraph(&affected_nodes),
+                    &ctx.settings,
+                );
                 if !cycles.is_empty() {
                     circular::print_circular_dependency_report(&cycles);
                     println!(
@@ -245,11 +1711,34 @@ fn run_watch_mode(project_root: &PathBuf, ctx: Arc<config::LinterContext>) -> Re
     Ok(())
 }
 
+/// Mensaje de progreso de --fix. Con --fix-dry-run stdout solo lleva el diff unificado
+/// (`> fixes.diff` se puede aplicar con `git apply`): el resto va a stderr
+fn fix_message(message: &str, dry_run: bool) {
+    if dry_run {
+        eprintln!("{}", message);
+    } else {
+        println!("{}", message);
+    }
+}
+
 /// Ejecuta el análisis en modo fix (auto-reparación con IA)
-fn run_fix_mode(project_root: &PathBuf, ctx: Arc<config::LinterContext>) -> Result<()> {
+fn run_fix_mode(
+    project_root: &Path,
+    ctx: Arc<config::LinterContext>,
+    max_files: usize,
+    ai_race: bool,
+    absolute_paths: bool,
+    dry_run: bool,
+) -> Result<()> {
     use dialoguer::Confirm;
 
-    println!("🔧 Modo Fix: Auto-reparación con IA\n");
+    let say = |message: &str| fix_message(message, dry_run);
+
+    if dry_run {
+        say("🔧 Modo Fix (simulación): se muestran los cambios sin escribir ningún archivo\n");
+    } else {
+        say("🔧 Modo Fix: Auto-reparación con IA\n");
+    }
 
     // Verificar que hay configuración de IA
     if ctx.ai_configs.is_empty() {
@@ -260,18 +1749,21 @@ fn run_fix_mode(project_root: &PathBuf, ctx: Arc<config::LinterContext>) -> Resu
     }
 
     // Recolectar archivos
-    let files = discovery::collect_files(project_root, &ctx.ignored_paths);
+    let files = discovery::collect_files_guarded(project_root, &ctx, max_files)?;
 
     if !ctx.ignored_paths.is_empty() {
-        println!("📂 Ignorando directorios: {}", ctx.ignored_paths.join(", "));
+        say(&format!(
+            "📂 Ignorando directorios: {}",
+            ctx.ignored_paths.join(", ")
+        ));
     }
 
     if files.is_empty() {
-        println!("✅ No se encontraron archivos para analizar (TypeScript, JavaScript, Python, Go, PHP, Java).");
+        say("✅ No se encontraron archivos para analizar (TypeScript, JavaScript, Python, Go, PHP, Java).");
         return Ok(());
     }
 
-    println!("📊 Analizando {} archivos...\n", files.len());
+    say(&format!("📊 Analizando {} archivos...\n", files.len()));
 
     // Recolectar todas las violaciones
     let cm = Arc::new(SourceMap::default());
@@ -283,91 +1775,124 @@ fn run_fix_mode(project_root: &PathBuf, ctx: Arc<config::LinterContext>) -> Resu
                 all_violations.extend(violations);
             }
             Err(e) => {
-                eprintln!("⚠️  Error analizando {}: {}", file_path.display(), e);
+                eprintln!(
+                    "⚠️  Error analizando {}: {}",
+                    display_path(file_path, project_root, absolute_paths),
+                    e
+                );
             }
         }
     }
 
     if all_violations.is_empty() {
-        println!("✨ ¡No se encontraron violaciones! Tu código está perfecto.");
+        say("✨ ¡No se encontraron violaciones! Tu código está perfecto.");
         return Ok(());
     }
 
-    println!(
+    say(&format!(
         "🔍 Encontradas {} violación(es) arquitectónicas\n",
         all_violations.len()
-    );
+    ));
 
     // Procesar cada violación
+    let mut preview = autofix::FixPreview::default();
     let mut fixed_count = 0;
     let mut skipped_count = 0;
 
     for (index, violation) in all_violations.iter().enumerate() {
-        println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-        println!("Violación #{}/{}", index + 1, all_violations.len());
-        println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-        println!("📄 Archivo: {}", violation.file_path.display());
-        println!("📍 Línea: {}", violation.line_number);
-        println!(
+        say("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        say(&format!(
+            "Violación #{}/{}",
+            index + 1,
+            all_violations.len()
+        ));
+        say("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        say(&format!(
+            "📄 Archivo: {}",
+            display_path(&violation.file_path, project_root, absolute_paths)
+        ));
+        say(&format!("📍 Línea: {}", violation.line_number));
+        say(&format!(
             "🚫 Regla violada: '{}' no puede importar de '{}'",
             violation.rule.from, violation.rule.to
-        );
-        println!("💥 Import ofensivo: {}", violation.offensive_import);
-        println!();
+        ));
+        say(&format!(
+            "💥 Import ofensivo: {}",
+            violation.offensive_import
+        ));
+        say("");
 
         // Consultar a la IA con fallback
-        println!("🤖 Consultando sugerencia de fix (usando sistema de fallback multimodelo)...");
+        say("🤖 Consultando sugerencia de fix (usando sistema de fallback multimodelo)...");
 
-        let runtime = tokio::runtime::Runtime::new().into_diagnostic()?;
-        let suggestion = match runtime.block_on(autofix::suggest_fix(
+        let suggestion = match ai::runtime().block_on(autofix::suggest_fix(
             violation,
             project_root,
             &ctx.ai_configs,
+            ai_race,
         )) {
             Ok(s) => s,
             Err(_e) => {
                 eprintln!("❌ No se pudo obtener ninguna sugerencia de los modelos configurados.");
-                println!("⏭️  Saltando esta violación...\n");
+                say("⏭️  Saltando esta violación...\n");
                 skipped_count += 1;
                 continue;
             }
         };
 
         // Mostrar la sugerencia
-        println!();
-        println!(
+        say("");
+        say(&format!(
             "💡 Sugerencia de la IA (confianza: {}):",
             suggestion.confidence
-        );
-        println!("{}", suggestion.explanation);
-        println!();
+        ));
+        say(&suggestion.explanation);
+        say("");
 
         match &suggestion.fix_type {
             autofix::FixType::Refactor { old_code, new_code } => {
-                println!("📝 Tipo: Refactorización de código");
-                println!("Cambiar:");
-                println!("  ❌ {}", old_code);
-                println!("Por:");
-                println!("  ✅ {}", new_code);
+                say("📝 Tipo: Refactorización de código");
+                say("Cambiar:");
+                say(&format!("  ❌ {}", old_code));
+                say("Por:");
+                say(&format!("  ✅ {}", new_code));
             }
             autofix::FixType::MoveFile { from, to } => {
-                println!("📦 Tipo: Mover archivo");
-                println!("  De: {}", from);
-                println!("  A:  {}", to);
+                say("📦 Tipo: Mover archivo");
+                say(&format!("  De: {}", from));
+                say(&format!("  A:  {}", to));
             }
             autofix::FixType::CreateInterface {
                 interface_path,
                 interface_code,
                 updated_import,
             } => {
-                println!("🎯 Tipo: Crear interfaz");
-                println!("  Nueva interfaz: {}", interface_path);
-                println!("  Código: {} líneas", interface_code.lines().count());
-                println!("  Nuevo import: {}", updated_import);
+                say("🎯 Tipo: Crear interfaz");
+                say(&format!("  Nueva interfaz: {}", interface_path));
+                say(&format!(
+                    "  Código: {} líneas",
+                    interface_code.lines().count()
+                ));
+                say(&format!("  Nuevo import: {}", updated_import));
             }
         }
 
-        println!();
+        say("");
+
+        // --fix-dry-run: los fixes se acumulan en memoria y el diff sale al final
+        if dry_run {
+            match autofix::preview_fix(&mut preview, &suggestion, violation, project_root) {
+                Ok(()) => {
+                    fixed_count += 1;
+                }
+                Err(e) => {
+                    eprintln!("❌ No se pudo calcular el fix: {}", e);
+                    skipped_count += 1;
+                }
+            }
+            say("");
+            continue;
+        }
 
         // Pedir confirmación
         let should_apply = Confirm::new()
@@ -379,7 +1904,7 @@ fn run_fix_mode(project_root: &PathBuf, ctx: Arc<config::LinterContext>) -> Resu
         if should_apply {
             match autofix::apply_fix(&suggestion, violation, project_root) {
                 Ok(message) => {
-                    println!("{}", message);
+                    say(&message);
                     fixed_count += 1;
                 }
                 Err(e) => {
@@ -388,26 +1913,867 @@ fn run_fix_mode(project_root: &PathBuf, ctx: Arc<config::LinterContext>) -> Resu
                 }
             }
         } else {
-            println!("⏭️  Fix omitido");
+            say("⏭️  Fix omitido");
             skipped_count += 1;
         }
 
-        println!();
+        say("");
+    }
+
+    // Un diff por archivo, con todos sus fixes aplicados
+    if dry_run {
+        print!("{}", autofix::render_diff(preview.changes(), project_root));
     }
 
     // Resumen final
-    println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-    println!("📊 RESUMEN");
-    println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-    println!("✅ Fixes aplicados: {}", fixed_count);
-    println!("⏭️  Omitidos: {}", skipped_count);
-    println!("📝 Total violaciones: {}", all_violations.len());
-    println!();
+    say("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+    say("📊 RESUMEN");
+    say("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+    if dry_run {
+        say(&format!("📝 Fixes simulados: {}", fixed_count));
+    } else {
+        say(&format!("✅ Fixes aplicados: {}", fixed_count));
+    }
+    say(&format!("⏭️  Omitidos: {}", skipped_count));
+    say(&format!("📝 Total violaciones: {}", all_violations.len()));
+    say("");
 
-    if fixed_count > 0 {
-        println!("🎉 ¡Se aplicaron {} fix(es) exitosamente!", fixed_count);
-        println!("💡 Tip: Ejecuta el linter nuevamente para verificar que todo esté correcto.");
+    if fixed_count > 0 && !dry_run {
+        say(&format!(
+            "🎉 ¡Se aplicaron {} fix(es) exitosamente!",
+            fixed_count
+        ));
+        say("💡 Tip: Ejecuta el linter nuevamente para verificar que todo esté correcto.");
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::testing::temp_project;
+    use std::fs;
+
+    #[test]
+    fn file_debt_counts_errors_and_warnings_but_not_syntax_errors() {
+        // Un método de 4 líneas con max_lines = 2: un error de max-lines
+        let dir = temp_project(
+            "since-debt",
+            &[
+                (
+                    "orders.ts",
+                    "import { x } from './missing';\n\nclass Orders {\n  total() {\n    const a = x;\n    const b = a;\n    return b;\n  }\n}\n",
+                ),
+                ("broken.ts", "const = ;\n"),
+            ],
+        );
+        let long_method = dir.join("orders.ts");
+        let broken = dir.join("broken.ts");
+
+        let mut ctx = config::LinterContext::for_tests(config::RuleSettings::default());
+        ctx.max_lines = 2;
+        let cm = SourceMap::default();
+
+        assert_eq!(file_debt(&cm, &long_method, &ctx, false), 1);
+        // Con --report-unresolved también cuenta el import roto, como en la fase de reglas
+        assert_eq!(file_debt(&cm, &long_method, &ctx, true), 2);
+        assert_eq!(file_debt(&cm, &broken, &ctx, false), 0);
+        assert_eq!(file_debt(&cm, &dir.join("deleted.ts"), &ctx, false), 0);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    /// Ejecuta git en `dir` con un autor fijo
+    fn git(dir: &Path, args: &[&str]) {
+        let status = std::process::Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .output()
+            .unwrap()
+            .status;
+        assert!(status.success(), "git {:?}", args);
+    }
+
+    #[test]
+    fn since_compares_the_changed_files_with_a_tagged_revision() {
+        let root = temp_project("since-tags", &[]);
+        let git = |args: &[&str]| git(&root, args);
+        let long_method = |name: &str| {
+            format!(
+                "class {} {{\n  total() {{\n    const a = 1;\n    const b = a;\n    return b;\n  }}\n}}\n",
+                name
+            )
+        };
+        let short_method = "class Orders {\n  total() {\n    return 1;\n  }\n}\n";
+
+        // v1: orders.ts ya tenía deuda; users.ts y payments.ts estaban limpios
+        git(&["init", "-q"]);
+        fs::write(root.join("orders.ts"), long_method("Orders")).unwrap();
+        fs::write(root.join("users.ts"), "export const users = [];\n").unwrap();
+        fs::write(root.join("stable.ts"), long_method("Stable")).unwrap();
+        git(&["add", "-A"]);
+        git(&["commit", "-q", "-m", "v1"]);
+        git(&["tag", "v1"]);
+
+        // v2: se corrige orders.ts, users.ts gana deuda y aparece payments.ts
+        fs::write(root.join("orders.ts"), short_method).unwrap();
+        fs::write(root.join("users.ts"), long_method("Users")).unwrap();
+        fs::write(root.join("payments.ts"), long_method("Payments")).unwrap();
+        git(&["add", "-A"]);
+        git(&["commit", "-q", "-m", "v2"]);
+        git(&["tag", "v2"]);
+
+        let mut ctx = config::LinterContext::for_tests(config::RuleSettings::default());
+        ctx.max_lines = 2;
+        let cm = SourceMap::default();
+
+        // Solo los archivos cambiados; stable.ts no se vuelve a analizar
+        let changed = git::changed_files_since(&root, "v1").unwrap();
+        let names: Vec<_> = changed
+            .iter()
+            .map(|file| file.file_name().unwrap())
+            .collect();
+        assert_eq!(names, ["orders.ts", "payments.ts", "users.ts"]);
+
+        let current: usize = changed
+            .iter()
+            .map(|file| file_debt(&cm, file, &ctx, false))
+            .sum();
+        assert_eq!(current, 2);
+        assert_eq!(debt_at_ref(&root, "v1", &changed, &ctx, false).unwrap(), 1);
+        // Contra v2 no cambió nada
+        assert!(git::changed_files_since(&root, "v2").unwrap().is_empty());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    /// Violaciones de la ejecución sobre `root`, como las reúne `run_normal_mode`
+    fn current_entries(root: &Path, ctx: &config::LinterContext) -> Vec<compare::ReportEntry> {
+        let files = discovery::collect_files(root, ctx);
+        let mut entries = rule_entries(root, ctx, files.iter().cloned());
+        let cm = SourceMap::default();
+        entries.extend(project_checks(&files, root, &cm, ctx, None).report_entries());
+        entries
+    }
+
+    #[test]
+    fn comparing_an_unchanged_repo_with_head_adds_nothing() {
+        let root = temp_project(
+            "compare-head",
+            &[
+                ("src/domain/user.ts", "export class User {}\n"),
+                ("src/helpers/hash.ts", "export const hash = 1;\n"),
+                ("src/domain/order.ts", "export class Order {}\n"),
+                (
+                    "src/main.ts",
+                    "import { User } from './domain/user';\nnew User();\n",
+                ),
+            ],
+        );
+        git(&root, &["init", "-q"]);
+        git(&root, &["add", "-A"]);
+        git(&root, &["commit", "-q", "-m", "base"]);
+
+        let mut ctx = config::LinterContext::for_tests(config::RuleSettings {
+            require_layer: true,
+            max_files_per_dir: 1,
+            no_unused_exports: Some(config::NoUnusedExportsRule {
+                entry_points: Vec::new(),
+                tests: Vec::new(),
+            }),
+            ..config::RuleSettings::default()
+        });
+        ctx.pattern = config::ArchPattern::Hexagonal;
+        let cli_args = cli::CliArgs::for_tests(&["--format", "csv", "--compare-to", "HEAD"]);
+
+        let current = current_entries(&root, &ctx);
+        let rule_ids: Vec<&str> = current.iter().map(|entry| entry.rule_id.as_str()).collect();
+        assert_eq!(
+            rule_ids,
+            [
+                crowded_dirs::RULE_ID,
+                unclassified::RULE_ID,
+                unused_exports::RULE_ID,
+                unused_exports::RULE_ID,
+            ]
+        );
+        let baseline = load_compare_baseline(&root, "HEAD", &ctx, &cli_args).unwrap();
+        let comparison = compare::compare(baseline, current);
+        assert!(comparison.added.is_empty(), "{:?}", comparison.added);
+        assert_eq!(comparison.unchanged.len(), 4);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn the_git_baseline_applies_the_cascaded_configs() {
+        let long_method = "class Orders {\n  total() {\n    const a = 1;\n    const b = a;\n    return b;\n  }\n}\n";
+        let root = temp_project(
+            "compare-cascade",
+            &[
+                (
+                    "architect.json",
+                    r#"{ "max_lines_per_function": 60, "architecture_pattern": "Ninguno", "forbidden_imports": [], "cascade": true }"#,
+                ),
+                (
+                    "src/domain/architect.json",
+                    r#"{ "max_lines_per_function": 2 }"#,
+                ),
+                ("src/domain/orders.ts", long_method),
+                ("src/app/orders.ts", long_method),
+            ],
+        );
+        git(&root, &["init", "-q"]);
+        git(&root, &["add", "-A"]);
+        git(&root, &["commit", "-q", "-m", "base"]);
+        let ctx = config::load_config(&root).unwrap();
+        let cli_args = cli::CliArgs::for_tests(&["--format", "csv", "--compare-to", "HEAD"]);
+
+        // Solo src/domain tiene el límite estricto
+        let current = current_entries(&root, &ctx);
+        let files: Vec<&str> = current.iter().map(|entry| entry.file.as_str()).collect();
+        assert_eq!(files, ["src/domain/orders.ts"]);
+        let baseline = load_compare_baseline(&root, "HEAD", &ctx, &cli_args).unwrap();
+        let comparison = compare::compare(baseline, current);
+        assert!(comparison.added.is_empty(), "{:?}", comparison.added);
+        assert!(comparison.removed.is_empty(), "{:?}", comparison.removed);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    /// Fase de reglas sobre `files`: archivos analizados, cuántos se retomaron del
+    /// checkpoint y las violaciones
+    fn rules_outcome(
+        root: &Path,
+        ctx: &config::LinterContext,
+        files: impl Iterator<Item = PathBuf> + Send,
+        checkpoint: Option<&checkpoint::Checkpoint>,
+    ) -> (usize, usize, Vec<compare::ReportEntry>) {
+        let cli_args = cli::CliArgs::for_tests(&["--format", "csv"]);
+        let cm = SourceMap::default();
+        let phase = RulesPhaseOptions {
+            project_root: root,
+            cm: &cm,
+            ctx,
+            cli_args: &cli_args,
+            metrics: None,
+            checkpoint,
+            profiler: None,
+            hotspots: None,
+        };
+        let outcome = run_rules_phase(&phase, files, None, &Counters::default()).unwrap();
+        let resumed = outcome.resumed.len();
+        let (analyzed, entries) = finish_rules_phase(&phase, outcome).unwrap();
+        (analyzed.len(), resumed, entries)
+    }
+
+    /// Report entries de la fase de reglas sobre `files`
+    fn rule_entries(
+        root: &Path,
+        ctx: &config::LinterContext,
+        files: impl Iterator<Item = PathBuf> + Send,
+    ) -> Vec<compare::ReportEntry> {
+        rules_outcome(root, ctx, files, None).2
+    }
+
+    /// Proyecto con un método de 4 líneas en cada servicio
+    fn long_method_project(name: &str, services: &[&str]) -> PathBuf {
+        let long_method = "class Orders {\n  total() {\n    const a = 1;\n    const b = a;\n    return b;\n  }\n}\n";
+        let paths: Vec<String> = services
+            .iter()
+            .map(|service| format!("src/{}/{}.service.ts", service, service))
+            .collect();
+        let files: Vec<(&str, &str)> = paths
+            .iter()
+            .map(|path| (path.as_str(), long_method))
+            .collect();
+        temp_project(name, &files)
+    }
+
+    fn fingerprints(entries: &[compare::ReportEntry]) -> Vec<String> {
+        let mut fingerprints: Vec<String> = entries
+            .iter()
+            .map(|entry| entry.fingerprint.clone())
+            .collect();
+        fingerprints.sort();
+        fingerprints
+    }
+
+    /// Código de salida del análisis de `root`, como al final de `run`
+    fn exit_code(root: &Path, ctx: &config::LinterContext, detailed: bool) -> i32 {
+        let cli_args = cli::CliArgs::for_tests(&["--format", "csv"]);
+        let cm = SourceMap::default();
+        let phase = RulesPhaseOptions {
+            project_root: root,
+            cm: &cm,
+            ctx,
+            cli_args: &cli_args,
+            metrics: None,
+            checkpoint: None,
+            profiler: None,
+            hotspots: None,
+        };
+        let counters = Counters::default();
+        let files = discovery::collect_files(root, ctx);
+        let outcome = run_rules_phase(&phase, files.into_iter(), None, &counters).unwrap();
+        finish_rules_phase(&phase, outcome).unwrap();
+        let (total, warnings, parse_errors) = counters.totals();
+        exit_codes::for_run(total > 0, warnings, parse_errors, detailed)
+    }
+
+    #[test]
+    fn each_scenario_has_its_exit_code() {
+        let root = long_method_project("exit-codes", &["orders"]);
+        let strict = || {
+            let mut ctx = config::LinterContext::for_tests(config::RuleSettings::default());
+            ctx.max_lines = 2;
+            ctx
+        };
+        let lenient = config::LinterContext::for_tests(config::RuleSettings::default());
+        let mut warnings_only = strict();
+        warnings_only
+            .settings
+            .rules
+            .insert("max-lines".to_string(), config::RuleLevel::Warn);
+
+        // 0: limpio; 1: errores; 2: solo advertencias
+        assert_eq!(exit_code(&root, &lenient, true), exit_codes::CLEAN);
+        assert_eq!(exit_code(&root, &strict(), true), exit_codes::ERRORS);
+        assert_eq!(
+            exit_code(&root, &warnings_only, true),
+            exit_codes::WARNINGS_ONLY
+        );
+        // Sin --detailed-exit-codes las advertencias no cambian el código
+        assert_eq!(exit_code(&root, &warnings_only, false), exit_codes::CLEAN);
+        assert_eq!(exit_code(&root, &strict(), false), exit_codes::ERRORS);
+
+        // 3: un archivo que no parsea deja el análisis incompleto...
+        fs::write(root.join("src/broken.ts"), "const = ;\n").unwrap();
+        assert_eq!(exit_code(&root, &lenient, true), exit_codes::INCOMPLETE);
+        assert_eq!(exit_code(&root, &lenient, false), exit_codes::CLEAN);
+        // ...pero los errores tienen prioridad
+        assert_eq!(exit_code(&root, &strict(), true), exit_codes::ERRORS);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn a_rule_flag_overrides_the_configured_level() {
+        let root = long_method_project("rule-override", &["orders"]);
+        let mut ctx = config::LinterContext::for_tests(config::RuleSettings::default());
+        ctx.max_lines = 2;
+        ctx.settings
+            .rules
+            .insert("max-lines".to_string(), config::RuleLevel::Error);
+        assert_eq!(exit_code(&root, &ctx, true), exit_codes::ERRORS);
+
+        apply_cli_overrides(
+            &mut ctx,
+            &cli::CliArgs::for_tests(&["--rule", "max-lines=warn"]),
+        );
+        assert_eq!(exit_code(&root, &ctx, true), exit_codes::WARNINGS_ONLY);
+        apply_cli_overrides(
+            &mut ctx,
+            &cli::CliArgs::for_tests(&["--rule", "max-lines=off"]),
+        );
+        assert_eq!(exit_code(&root, &ctx, true), exit_codes::CLEAN);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn the_per_file_cap_suppresses_findings_but_still_counts_them() {
+        let method = "  total() {\n    const a = 1;\n    const b = a;\n    return b;\n  }\n";
+        let generated = format!("class Generated {{\n{}}}\n", method.repeat(5));
+        let root = temp_project(
+            "max-violations-per-file",
+            &[
+                ("src/generated.ts", generated.as_str()),
+                ("src/clean.ts", "export const a = 1;\n"),
+            ],
+        );
+        let mut ctx = config::LinterContext::for_tests(config::RuleSettings::default());
+        ctx.max_lines = 2;
+        let cli_args =
+            cli::CliArgs::for_tests(&["--format", "csv", "--max-violations-per-file", "2"]);
+        let cm = SourceMap::default();
+        let phase = RulesPhaseOptions {
+            project_root: &root,
+            cm: &cm,
+            ctx: &ctx,
+            cli_args: &cli_args,
+            metrics: None,
+            checkpoint: None,
+            profiler: None,
+            hotspots: None,
+        };
+        let counters = Counters::default();
+        let files = discovery::collect_files(&root, &ctx);
+        let outcome = run_rules_phase(&phase, files.into_iter(), None, &counters).unwrap();
+
+        // Se guardan los dos primeros y el resto queda como "+3 más"
+        assert_eq!(
+            outcome.suppressed,
+            vec![("src/generated.ts".to_string(), 3)]
+        );
+        let lines: Vec<usize> = outcome
+            .collected
+            .iter()
+            .map(|collected| collected.finding.location.start_line)
+            .collect();
+        assert_eq!(lines, vec![2, 7]);
+        // El resultado cuenta los cinco
+        assert_eq!(counters.totals(), (5, 0, 0));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn failed_runs_exit_with_usage_or_incomplete() {
+        // 4: una regla desconocida en `explain`, con o sin --detailed-exit-codes
+        let mut out = Vec::new();
+        let unknown = cli::write_explain(&mut out, "no-such-rule").unwrap_err();
+        assert_eq!(error_exit_code(&unknown, false), exit_codes::USAGE);
+        assert_eq!(error_exit_code(&unknown, true), exit_codes::USAGE);
+
+        // 3: un fallo interno solo se distingue de las violaciones con el flag
+        let internal = miette::miette!("architect.json inválido");
+        assert_eq!(error_exit_code(&internal, true), exit_codes::INCOMPLETE);
+        assert_eq!(error_exit_code(&internal, false), exit_codes::ERRORS);
+    }
+
+    #[test]
+    fn output_dir_writes_every_artifact_with_its_conventional_name() {
+        let root = long_method_project("output-dir", &["a", "b"]);
+        fs::write(root.join("src/a/cycle.ts"), "import './other';\n").unwrap();
+        fs::write(root.join("src/a/other.ts"), "import './cycle';\n").unwrap();
+        let mut ctx = config::LinterContext::for_tests(config::RuleSettings::default());
+        ctx.max_lines = 2;
+        let out = root.join("artifacts");
+        fs::create_dir_all(&out).unwrap();
+        let cli_args = cli::CliArgs::for_tests(&["--output-dir", out.to_str().unwrap()]);
+
+        let files = discovery::collect_files(&root, &ctx);
+        let mut entries = rule_entries(&root, &ctx, files.iter().cloned());
+        let mut graph = circular::CircularDependencyAnalyzer::new(&root);
+        graph.build_graph(&files, &SourceMap::default());
+        let cycles = graph.detect_cycles();
+        entries.extend(cycles.iter().map(compare::ReportEntry::from_cycle));
+        write_report_artifacts(&cli_args, &entries).unwrap();
+        write_dependency_graph(&cli_args, &graph, &cycles).unwrap();
+
+        for name in ["report.json", "report.sarif", "report.html", "graph.dot"] {
+            assert!(out.join(name).is_file(), "{}", name);
+        }
+        let sarif: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(out.join("report.sarif")).unwrap()).unwrap();
+        assert_eq!(sarif["runs"][0]["results"].as_array().unwrap().len(), 3);
+        let dot = fs::read_to_string(out.join("graph.dot")).unwrap();
+        assert!(
+            dot.contains("\"src/a/cycle.ts\" -> \"src/a/other.ts\" [color=red];"),
+            "{}",
+            dot
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn streaming_and_eager_collection_report_the_same_violations() {
+        let root = long_method_project("streaming", &["a", "b", "c", "d"]);
+        let mut ctx = config::LinterContext::for_tests(config::RuleSettings::default());
+        ctx.max_lines = 2;
+        let ctx = Arc::new(ctx);
+
+        let eager = discovery::collect_files(&root, &ctx);
+        let eager = rule_entries(&root, &ctx, eager.into_iter());
+        let (paths, walker) = discovery::stream_files(&root, Arc::clone(&ctx), 100);
+        let streamed = rule_entries(&root, &ctx, paths.into_iter());
+        assert!(!walker.join().unwrap());
+
+        assert_eq!(eager.len(), 4);
+        assert_eq!(fingerprints(&eager), fingerprints(&streamed));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn a_streamed_walk_past_max_files_asks_before_analyzing_the_rest() {
+        let root = long_method_project("streaming-limit", &["a", "b", "c", "d"]);
+        let mut ctx = config::LinterContext::for_tests(config::RuleSettings::default());
+        ctx.max_lines = 2;
+        let ctx = Arc::new(ctx);
+        let cli_args = cli::CliArgs::for_tests(&["--format", "csv", "--max-files", "2"]);
+        let cm = SourceMap::default();
+        let phase = RulesPhaseOptions {
+            project_root: &root,
+            cm: &cm,
+            ctx: &ctx,
+            cli_args: &cli_args,
+            metrics: None,
+            checkpoint: None,
+            profiler: None,
+            hotspots: None,
+        };
+        let counters = Counters::default();
+        let streamed = |counters: &Counters| {
+            let (paths, walker) = discovery::stream_files(&root, Arc::clone(&ctx), 2);
+            let outcome = run_rules_phase(&phase, paths.into_iter(), None, counters).unwrap();
+            assert!(walker.join().unwrap());
+            outcome
+        };
+
+        // Sin terminal, el límite es un error con el recuento
+        let mut outcome = streamed(&counters);
+        assert_eq!(outcome.analyzed.len(), 2);
+        let error = continue_past_limit(&phase, &mut outcome, &counters, false).unwrap_err();
+        assert!(error.to_string().contains("más de 2 archivos"));
+        assert_eq!(outcome.analyzed.len(), 2);
+
+        // Si se continúa, se analizan los que faltan sin repetir ninguno
+        let mut outcome = streamed(&counters);
+        analyze_rest(&phase, &mut outcome, &counters).unwrap();
+        let (analyzed, entries) = finish_rules_phase(&phase, outcome).unwrap();
+        assert_eq!(analyzed.len(), 4);
+        let eager = rule_entries(
+            &root,
+            &ctx,
+            discovery::collect_files(&root, &ctx).into_iter(),
+        );
+        assert_eq!(fingerprints(&entries), fingerprints(&eager));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn only_and_skip_select_the_circular_phase() {
+        let root = long_method_project("only-circular", &["orders", "users"]);
+        fs::write(
+            root.join("src/orders/cycle.ts"),
+            "import { u } from '../users/cycle';\nexport const o = 1;\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("src/users/cycle.ts"),
+            "import { o } from '../orders/cycle';\nexport const u = 1;\n",
+        )
+        .unwrap();
+        let mut ctx = config::LinterContext::for_tests(config::RuleSettings::default());
+        ctx.max_lines = 2;
+        let files = discovery::collect_files(&root, &ctx);
+        let rule_ids = |args: &[&str]| {
+            let cli_args = cli::CliArgs::for_tests(args);
+            let mut ids: Vec<String> = phase_entries(&root, &root, &files, &ctx, &cli_args)
+                .into_iter()
+                .map(|entry| entry.rule_id)
+                .collect();
+            ids.sort();
+            ids
+        };
+
+        assert_eq!(rule_ids(&["--only", "circular"]), ["circular-dependency"]);
+        assert_eq!(
+            rule_ids(&["--skip", "circular"]),
+            ["max-lines", "max-lines"]
+        );
+        assert_eq!(
+            rule_ids(&[]),
+            ["circular-dependency", "max-lines", "max-lines"]
+        );
+
+        // El ciclo de 2 módulos queda fuera de min_cycle_length, como en la ejecución
+        ctx.settings.min_cycle_length = 3;
+        let cli_args = cli::CliArgs::for_tests(&["--only", "circular"]);
+        assert!(phase_entries(&root, &root, &files, &ctx, &cli_args).is_empty());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn extensions_restrict_the_analyzed_files() {
+        let root = temp_project(
+            "extensions",
+            &[
+                ("src/app.tsx", "export const a = 1;\n"),
+                ("src/app.ts", "export const a = 1;\n"),
+                ("src/legacy.js", "export const a = 1;\n"),
+                ("src/view.vue", "export const a = 1;\n"),
+            ],
+        );
+        let names = |ctx: &config::LinterContext| {
+            let mut names: Vec<String> = discovery::collect_files(&root, ctx)
+                .iter()
+                .map(|file| file.file_name().unwrap().to_string_lossy().into_owned())
+                .collect();
+            names.sort();
+            names
+        };
+
+        let mut ctx = config::LinterContext::for_tests(config::RuleSettings {
+            extensions: vec!["ts".to_string(), "tsx".to_string()],
+            ..config::RuleSettings::default()
+        });
+        assert_eq!(names(&ctx), ["app.ts", "app.tsx"]);
+
+        // --extensions reemplaza la lista de architect.json
+        apply_cli_overrides(&mut ctx, &cli::CliArgs::for_tests(&["--extensions", "tsx"]));
+        assert_eq!(names(&ctx), ["app.tsx"]);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn metrics_aggregate_the_whole_run() {
+        let root = long_method_project("metrics", &["orders", "users"]);
+        let mut ctx = config::LinterContext::for_tests(config::RuleSettings::default());
+        ctx.max_lines = 2;
+        let cli_args = cli::CliArgs::for_tests(&["--format", "csv"]);
+        let cm = SourceMap::default();
+        let collector = metrics::MetricsCollector::default();
+        let phase = RulesPhaseOptions {
+            project_root: &root,
+            cm: &cm,
+            ctx: &ctx,
+            cli_args: &cli_args,
+            metrics: Some(&collector),
+            checkpoint: None,
+            profiler: None,
+            hotspots: None,
+        };
+        let files = discovery::collect_files(&root, &ctx);
+        let outcome =
+            run_rules_phase(&phase, files.iter().cloned(), None, &Counters::default()).unwrap();
+        finish_rules_phase(&phase, outcome).unwrap();
+        collector.record_cycles(1);
+
+        let path = root.join("metrics.json");
+        collector.write(&path, files.len()).unwrap();
+        let metrics: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(metrics["total_files"], 2);
+        assert_eq!(metrics["total_violations"], 3);
+        assert_eq!(metrics["by_rule"]["max-lines"], 2);
+        assert_eq!(metrics["by_rule"]["circular-dependency"], 1);
+        assert_eq!(metrics["cycles"], 1);
+        assert_eq!(metrics["total_lines"], 14);
+        assert_eq!(metrics["max_method_length"], 4);
+        assert_eq!(metrics["avg_method_length"], 4.0);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn the_profile_lists_every_phase_and_the_slowest_files() {
+        let root = long_method_project("profile", &["orders", "users"]);
+        let ctx = config::LinterContext::for_tests(config::RuleSettings::default());
+        let cli_args = cli::CliArgs::for_tests(&["--format", "csv", "--profile"]);
+        let cm = SourceMap::default();
+        let profiler = profile::Profiler::default();
+        let phase = RulesPhaseOptions {
+            project_root: &root,
+            cm: &cm,
+            ctx: &ctx,
+            cli_args: &cli_args,
+            metrics: None,
+            checkpoint: None,
+            profiler: Some(&profiler),
+            hotspots: None,
+        };
+        let files = timed(Some(&profiler), "descubrimiento", || {
+            discovery::collect_files(&root, &ctx)
+        });
+        let outcome = timed(Some(&profiler), "reglas", || {
+            run_rules_phase(&phase, files.iter().cloned(), None, &Counters::default())
+        })
+        .unwrap();
+        finish_rules_phase(&phase, outcome).unwrap();
+        let mut graph = circular::CircularDependencyAnalyzer::new(&root);
+        timed(Some(&profiler), "grafo de dependencias", || {
+            graph.build_graph(&files, &cm)
+        });
+        timed(Some(&profiler), "detección de ciclos", || {
+            graph.detect_cycles()
+        });
+
+        let report = profiler.render();
+        for name in [
+            "descubrimiento",
+            "reglas",
+            "grafo de dependencias",
+            "detección de ciclos",
+            "total",
+        ] {
+            assert!(report.contains(&format!("    {:<22} ", name)), "{}", report);
+        }
+        assert!(report.contains("Archivos: 2 "), "{}", report);
+        let slowest = report.split("Archivos más lentos:").nth(1).unwrap();
+        assert!(slowest.contains("orders.service.ts"), "{}", report);
+        assert!(slowest.contains("users.service.ts"), "{}", report);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn output_paths_are_relative_unless_absolute_paths() {
+        let root = long_method_project("relative-paths", &["orders"]);
+        let mut ctx = config::LinterContext::for_tests(config::RuleSettings::default());
+        ctx.max_lines = 2;
+        let file = root.join("src/orders/orders.service.ts");
+        let run = |args: &[&str]| {
+            let cli_args = cli::CliArgs::for_tests(args);
+            let cm = SourceMap::default();
+            let phase = RulesPhaseOptions {
+                project_root: &root,
+                cm: &cm,
+                ctx: &ctx,
+                cli_args: &cli_args,
+                metrics: None,
+                checkpoint: None,
+                profiler: None,
+                hotspots: None,
+            };
+            let outcome = run_rules_phase(
+                &phase,
+                std::iter::once(file.clone()),
+                None,
+                &Counters::default(),
+            )
+            .unwrap();
+            let display = outcome.collected[0].display.clone();
+            let (_, entries) = finish_rules_phase(&phase, outcome).unwrap();
+            (display, entries)
+        };
+
+        let (display, entries) = run(&["--format", "csv"]);
+        assert_eq!(display, "src/orders/orders.service.ts");
+        assert_eq!(entries[0].file, "src/orders/orders.service.ts");
+
+        // --absolute-paths cambia la salida de texto, no los reportes
+        let (display, entries) = run(&["--format", "csv", "--absolute-paths"]);
+        assert_eq!(display, file.display().to_string());
+        assert_eq!(entries[0].file, "src/orders/orders.service.ts");
+        let mut sarif = Vec::new();
+        sarif::write_sarif(&mut sarif, &entries).unwrap();
+        let sarif: serde_json::Value = serde_json::from_slice(&sarif).unwrap();
+        assert_eq!(
+            sarif["runs"][0]["results"][0]["locations"][0]["physicalLocation"]["artifactLocation"]
+                ["uri"],
+            "src/orders/orders.service.ts"
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn dedupe_collapses_identical_findings() {
+        let services = ["a", "b", "c", "d", "e", "f", "g", "h"];
+        let root = long_method_project("dedupe", &services);
+        let mut ctx = config::LinterContext::for_tests(config::RuleSettings::default());
+        ctx.max_lines = 2;
+        let cli_args = cli::CliArgs::for_tests(&["--dedupe"]);
+        let cm = SourceMap::default();
+        let phase = RulesPhaseOptions {
+            project_root: &root,
+            cm: &cm,
+            ctx: &ctx,
+            cli_args: &cli_args,
+            metrics: None,
+            checkpoint: None,
+            profiler: None,
+            hotspots: None,
+        };
+        let files = discovery::collect_files(&root, &ctx);
+        let mut outcome =
+            run_rules_phase(&phase, files.into_iter(), None, &Counters::default()).unwrap();
+        sort_collected(&mut outcome.collected);
+
+        let mut out = Vec::new();
+        write_deduped(&mut out, outcome.collected).unwrap();
+        let out = String::from_utf8(out).unwrap();
+
+        // Un representante con el total y cinco de los otros siete archivos
+        assert_eq!(out.matches("📌 Violación en:").count(), 1, "{}", out);
+        assert!(out.contains("📌 Violación en: src/a/a.service.ts [max-lines] (×8)"));
+        assert!(out.contains(
+            "🔁 También en: src/b/b.service.ts, src/c/c.service.ts, src/d/d.service.ts, src/e/e.service.ts, src/f/f.service.ts"
+        ));
+        assert!(out.contains("... y 2 archivos más"));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn findings_are_sorted_by_severity_file_line_and_column() {
+        let source = "class Orders {\n  total(x?: X) {\n    const a = x!.y;\n    const b = x!.z + x!.w;\n    return b;\n  }\n}\n";
+        let root = temp_project(
+            "sorted-findings",
+            &[("src/b.ts", source), ("src/a.ts", source)],
+        );
+        let mut settings = config::RuleSettings {
+            no_non_null_assertion: Some(config::NoNonNullAssertionRule {
+                paths: Vec::new(),
+                allow: Vec::new(),
+            }),
+            ..config::RuleSettings::default()
+        };
+        settings
+            .rules
+            .insert("no-non-null-assertion".to_string(), config::RuleLevel::Warn);
+        let mut ctx = config::LinterContext::for_tests(settings);
+        ctx.max_lines = 2;
+
+        // Los archivos llegan en cualquier orden; la salida es siempre la misma
+        let files = [root.join("src/b.ts"), root.join("src/a.ts")];
+        let order: Vec<(rules::Severity, String, usize, usize)> =
+            rule_entries(&root, &ctx, files.into_iter())
+                .into_iter()
+                .map(|entry| (entry.severity, entry.file, entry.line, entry.column))
+                .collect();
+        let (error, warning) = (rules::Severity::Error, rules::Severity::Warning);
+        let row = |severity, file: &str, line, column| (severity, file.to_string(), line, column);
+        assert_eq!(
+            order,
+            [
+                row(error, "src/a.ts", 2, 3),
+                row(error, "src/b.ts", 2, 3),
+                row(warning, "src/a.ts", 3, 15),
+                row(warning, "src/a.ts", 4, 15),
+                row(warning, "src/a.ts", 4, 22),
+                row(warning, "src/b.ts", 3, 15),
+                row(warning, "src/b.ts", 4, 15),
+                row(warning, "src/b.ts", 4, 22),
+            ]
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn an_interrupted_run_resumes_from_its_checkpoint() {
+        let root = long_method_project("checkpoint-resume", &["a", "b", "c"]);
+        let mut ctx = config::LinterContext::for_tests(config::RuleSettings::default());
+        ctx.max_lines = 2;
+        let files = discovery::collect_files(&root, &ctx);
+        let path = root.join("checkpoint.json");
+
+        // Primera ejecución: se interrumpe tras analizar un archivo
+        let checkpoint = checkpoint::Checkpoint::load(&path, &root, "config");
+        rules_outcome(&root, &ctx, files[..1].iter().cloned(), Some(&checkpoint));
+        checkpoint.save().unwrap();
+
+        // La reanudación retoma ese archivo y analiza los otros dos
+        let checkpoint = checkpoint::Checkpoint::load(&path, &root, "config");
+        let (analyzed, resumed, entries) =
+            rules_outcome(&root, &ctx, files.iter().cloned(), Some(&checkpoint));
+        assert_eq!((analyzed, resumed), (3, 1));
+
+        let fresh = rule_entries(&root, &ctx, files.iter().cloned());
+        assert_eq!(fresh.len(), 3);
+        assert_eq!(fingerprints(&entries), fingerprints(&fresh));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,113 @@
+//! Métricas compactas de cada ejecución (--metrics) para seguir la salud
+//! arquitectónica en el tiempo. No reemplaza al reporte de violaciones.
+
+use crate::rules::{Finding, Severity, PARSE_ERROR_ID};
+use miette::{IntoDiagnostic, Result};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Registro de métricas de una ejecución, tal como se escribe en disco
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RunMetrics {
+    /// Segundos desde UNIX epoch
+    pub timestamp: u64,
+    pub total_files: usize,
+    /// Líneas de los archivos JS/TS analizados
+    pub total_lines: usize,
+    pub total_violations: usize,
+    pub total_warnings: usize,
+    /// Hallazgos por id de regla
+    pub by_rule: BTreeMap<String, usize>,
+    pub cycles: usize,
+    pub avg_method_length: f64,
+    pub max_method_length: usize,
+}
+
+/// Acumula métricas desde los hilos del análisis
+#[derive(Default)]
+pub struct MetricsCollector {
+    metrics: Mutex<RunMetrics>,
+    method_lines_total: Mutex<(usize, usize)>,
+}
+
+impl MetricsCollector {
+    pub fn record_findings(&self, findings: &[Finding]) {
+        let mut metrics = self.metrics.lock().unwrap();
+        for finding in findings {
+            // Los errores de sintaxis solo cuentan en su propia categoría
+            match finding.severity {
+                _ if finding.rule_id == PARSE_ERROR_ID => {}
+                Severity::Error => metrics.total_violations += 1,
+                Severity::Warning => metrics.total_warnings += 1,
+            }
+            *metrics
+                .by_rule
+                .entry(finding.rule_id.to_string())
+                .or_insert(0) += 1;
+        }
+    }
+
+    /// Errores que no provienen de una regla (ej: archivo que no se pudo analizar)
+    pub fn record_error(&self, category: &str) {
+        let mut metrics = self.metrics.lock().unwrap();
+        metrics.total_violations += 1;
+        *metrics.by_rule.entry(category.to_string()).or_insert(0) += 1;
+    }
+
+    pub fn record_method_lengths(&self, lengths: &[usize]) {
+        if lengths.is_empty() {
+            return;
+        }
+        {
+            let mut totals = self.method_lines_total.lock().unwrap();
+            totals.0 += lengths.iter().sum::<usize>();
+            totals.1 += lengths.len();
+        }
+        let mut metrics = self.metrics.lock().unwrap();
+        let max = lengths.iter().copied().max().unwrap_or(0);
+        metrics.max_method_length = metrics.max_method_length.max(max);
+    }
+
+    pub fn record_lines(&self, lines: usize) {
+        self.metrics.lock().unwrap().total_lines += lines;
+    }
+
+    pub fn record_cycles(&self, cycles: usize) {
+        let mut metrics = self.metrics.lock().unwrap();
+        metrics.cycles += cycles;
+        metrics.total_violations += cycles;
+        *metrics
+            .by_rule
+            .entry("circular-dependency".to_string())
+            .or_insert(0) += cycles;
+    }
+
+    /// Escribe el registro JSON en `path`
+    pub fn write(&self, path: &Path, total_files: usize) -> Result<()> {
+        let json = serde_json::to_string_pretty(&self.summary(total_files)).into_diagnostic()?;
+        fs::write(path, json).into_diagnostic()?;
+        Ok(())
+    }
+
+    /// Métricas acumuladas con los valores derivados (promedios, marca de tiempo)
+    pub fn summary(&self, total_files: usize) -> RunMetrics {
+        let mut metrics = self.metrics.lock().unwrap().clone();
+        let (lines, methods) = *self.method_lines_total.lock().unwrap();
+
+        metrics.timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        metrics.total_files = total_files;
+        metrics.avg_method_length = if methods > 0 {
+            lines as f64 / methods as f64
+        } else {
+            0.0
+        };
+        metrics
+    }
+}
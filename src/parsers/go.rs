@@ -39,8 +39,8 @@ impl GoParser {
 
         // Go imports are package paths: github.com/user/repo/pkg
         // Check if import ends with pattern folder
-        if let Some(last_segment) = normalized_path.split('/').last() {
-            if let Some(pattern_last) = normalized_pattern.split('/').last() {
+        if let Some(last_segment) = normalized_path.split('/').next_back() {
+            if let Some(pattern_last) = normalized_pattern.split('/').next_back() {
                 if last_segment.contains(pattern_last) {
                     return true;
                 }
@@ -141,6 +141,7 @@ impl ArchitectParser for GoParser {
                             to: rule.to.clone(),
                         },
                         line_number: import.line_number,
+                        rule_id: "forbidden-imports",
                     });
                 }
             }
@@ -157,6 +158,7 @@ impl ArchitectParser for GoParser {
                         to: "database".to_string(),
                     },
                     line_number: import.line_number,
+                    rule_id: "forbidden-imports",
                 });
             }
         }
@@ -47,8 +47,8 @@ impl JavaParser {
         }
 
         // Check if import ends with pattern folder
-        if let Some(last_segment) = path_with_dots.split('.').last() {
-            if let Some(pattern_last) = pattern_with_dots.split('.').last() {
+        if let Some(last_segment) = path_with_dots.split('.').next_back() {
+            if let Some(pattern_last) = pattern_with_dots.split('.').next_back() {
                 if last_segment.contains(pattern_last) {
                     return true;
                 }
@@ -146,6 +146,7 @@ impl ArchitectParser for JavaParser {
                             to: rule.to.clone(),
                         },
                         line_number: import.line_number,
+                        rule_id: "forbidden-imports",
                     });
                 }
             }
@@ -165,6 +166,7 @@ impl ArchitectParser for JavaParser {
                         to: "repository".to_string(),
                     },
                     line_number: import.line_number,
+                    rule_id: "forbidden-imports",
                 });
             }
         }
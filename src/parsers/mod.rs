@@ -8,11 +8,11 @@ use crate::config::LinterContext;
 use miette::Result;
 use std::path::Path;
 
-pub mod typescript;
-pub mod python;
 pub mod go;
-pub mod php;
 pub mod java;
+pub mod php;
+pub mod python;
+pub mod typescript;
 
 /// Represents an import statement extracted from source code
 #[derive(Debug, Clone)]
@@ -41,8 +41,8 @@ impl Language {
     /// Get language from file extension
     pub fn from_extension(ext: &str) -> Option<Self> {
         match ext {
-            "ts" | "tsx" => Some(Language::TypeScript),
-            "js" | "jsx" => Some(Language::JavaScript),
+            "ts" | "tsx" | "mts" | "cts" => Some(Language::TypeScript),
+            "js" | "jsx" | "mjs" | "cjs" => Some(Language::JavaScript),
             "py" => Some(Language::Python),
             "go" => Some(Language::Go),
             "rs" => Some(Language::Rust),
@@ -92,18 +92,10 @@ pub fn get_parser_for_file(file_path: &Path) -> Option<Box<dyn ArchitectParser>>
         Language::TypeScript | Language::JavaScript => {
             Some(Box::new(typescript::TypeScriptParser::new()))
         }
-        Language::Python => {
-            Some(Box::new(python::PythonParser::new()))
-        }
-        Language::Go => {
-            Some(Box::new(go::GoParser::new()))
-        }
-        Language::Php => {
-            Some(Box::new(php::PhpParser::new()))
-        }
-        Language::Java => {
-            Some(Box::new(java::JavaParser::new()))
-        }
+        Language::Python => Some(Box::new(python::PythonParser::new())),
+        Language::Go => Some(Box::new(go::GoParser::new())),
+        Language::Php => Some(Box::new(php::PhpParser::new())),
+        Language::Java => Some(Box::new(java::JavaParser::new())),
         Language::Rust => {
             // Rust support coming soon
             None
@@ -45,8 +45,8 @@ impl PhpParser {
         }
 
         // Check if import contains pattern folder
-        if let Some(last_segment) = normalized_path.split(&['/', '\\']).last() {
-            if let Some(pattern_last) = normalized_pattern.split(&['/', '\\']).last() {
+        if let Some(last_segment) = normalized_path.split(&['/', '\\']).next_back() {
+            if let Some(pattern_last) = normalized_pattern.split(&['/', '\\']).next_back() {
                 if last_segment.contains(pattern_last) {
                     return true;
                 }
@@ -159,13 +159,16 @@ impl ArchitectParser for PhpParser {
                             to: rule.to.clone(),
                         },
                         line_number: import.line_number,
+                        rule_id: "forbidden-imports",
                     });
                 }
             }
 
             // PHP-specific rules
             // Example: Controllers shouldn't import Models directly
-            if file_path_str.contains("controller") && import.source.to_lowercase().contains("model") {
+            if file_path_str.contains("controller")
+                && import.source.to_lowercase().contains("model")
+            {
                 violations.push(Violation {
                     file_path: file_path.to_path_buf(),
                     file_content: source_code.to_string(),
@@ -175,6 +178,7 @@ impl ArchitectParser for PhpParser {
                         to: "model".to_string(),
                     },
                     line_number: import.line_number,
+                    rule_id: "forbidden-imports",
                 });
             }
         }
@@ -38,7 +38,8 @@ impl PythonParser {
         let path_as_dots = normalized_path.replace('/', ".");
         let pattern_as_dots = normalized_pattern.replace('/', ".");
 
-        if path_as_dots.contains(&pattern_as_dots) || normalized_path.contains(&normalized_pattern) {
+        if path_as_dots.contains(&pattern_as_dots) || normalized_path.contains(&normalized_pattern)
+        {
             return true;
         }
 
@@ -145,6 +146,7 @@ impl ArchitectParser for PythonParser {
                             to: rule.to.clone(),
                         },
                         line_number: import.line_number,
+                        rule_id: "forbidden-imports",
                     });
                 }
             }
@@ -161,6 +163,7 @@ impl ArchitectParser for PythonParser {
                         to: "models".to_string(),
                     },
                     line_number: import.line_number,
+                    rule_id: "forbidden-imports",
                 });
             }
         }
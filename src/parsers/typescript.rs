@@ -56,6 +56,31 @@ impl TypeScriptParser {
 
         false
     }
+
+    /// Check if a package name matches a forbidden pattern exactly (`typeorm`, `@nestjs/*`)
+    fn matches_package(package: &str, pattern: &str) -> bool {
+        let normalized_pattern = pattern
+            .to_lowercase()
+            .trim_end_matches(['*', '/'])
+            .to_string();
+        let package = package.to_lowercase();
+
+        package == normalized_pattern
+            || (normalized_pattern.starts_with('@')
+                && !normalized_pattern.contains('/')
+                && package.starts_with(&format!("{}/", normalized_pattern)))
+    }
+
+    /// Internal imports are relative paths, project aliases (`@/`, `~/`) or
+    /// package.json subpath imports (`#domain/user`).
+    /// Bare package imports are never subject to allow lists.
+    fn is_internal_import(source: &str) -> bool {
+        source.starts_with('.')
+            || source.starts_with('/')
+            || source.starts_with("@/")
+            || source.starts_with("~/")
+            || source.starts_with('#')
+    }
 }
 
 impl ArchitectParser for TypeScriptParser {
@@ -70,20 +95,35 @@ impl ArchitectParser for TypeScriptParser {
             .parse(source_code, None)
             .ok_or_else(|| miette::miette!("Failed to parse TypeScript"))?;
 
-        // Query for import declarations
+        // Query for import declarations, plus import('x') / require('x') calls at any depth
+        // (inside functions, conditionals or try blocks)
         let query_source = r#"
             (import_statement
               source: (string (string_fragment) @import_path))
+            (call_expression
+              function: (import)
+              arguments: (arguments . (string (string_fragment) @import_path)))
+            ((call_expression
+              function: (identifier) @callee
+              arguments: (arguments . (string (string_fragment) @import_path)))
+             (#eq? @callee "require"))
         "#;
 
         let query = Query::new(&tree_sitter_typescript::language_typescript(), query_source)
             .into_diagnostic()?;
+        let import_path_index = query
+            .capture_index_for_name("import_path")
+            .ok_or_else(|| miette::miette!("Missing import_path capture"))?;
 
         let mut cursor = QueryCursor::new();
         let matches = cursor.matches(&query, tree.root_node(), source_code.as_bytes());
 
         for match_ in matches {
-            for capture in match_.captures {
+            let paths = match_
+                .captures
+                .iter()
+                .filter(|c| c.index == import_path_index);
+            for capture in paths {
                 let node = capture.node;
                 let import_path = node.utf8_text(source_code.as_bytes()).into_diagnostic()?;
                 let line_number = node.start_position().row + 1;
@@ -120,10 +160,28 @@ impl ArchitectParser for TypeScriptParser {
         let file_path_str = file_path.to_string_lossy().to_lowercase();
 
         for import in imports {
+            // Bare imports resolved to their real package (subpaths, npm aliases)
+            let package =
+                if context.settings.resolve_packages && !Self::is_internal_import(&import.source) {
+                    crate::resolver::resolve_package_name(file_path, &import.source)
+                } else {
+                    None
+                };
+
+            // Subpath imports (`#domain/user`) resolved through package.json "imports"
+            let subpath_target = crate::resolver::resolve_subpath_import(file_path, &import.source)
+                .map(|resolved| resolved.to_string_lossy().to_lowercase());
+
             // Check against forbidden rules
             for rule in &context.forbidden_imports {
                 let file_matches = Self::matches_pattern(&file_path_str, &rule.from);
-                let import_matches = Self::matches_pattern(&import.source.to_lowercase(), &rule.to);
+                let import_matches = Self::matches_pattern(&import.source.to_lowercase(), &rule.to)
+                    || package
+                        .as_deref()
+                        .is_some_and(|name| Self::matches_package(name, &rule.to))
+                    || subpath_target
+                        .as_deref()
+                        .is_some_and(|target| Self::matches_pattern(target, &rule.to));
 
                 if file_matches && import_matches {
                     violations.push(Violation {
@@ -135,12 +193,48 @@ impl ArchitectParser for TypeScriptParser {
                             to: rule.to.clone(),
                         },
                         line_number: import.line_number,
+                        rule_id: "forbidden-imports",
                     });
                 }
             }
 
-            // Extra rule: Controller → Repository
-            if file_path_str.contains("controller") && import.source.to_lowercase().contains(".repository") {
+            // Allow lists: internal imports must target one of the allowed folders
+            if Self::is_internal_import(&import.source) {
+                let target = crate::circular::resolve_import_path(file_path, &import.source)
+                    .map(|resolved| resolved.to_string_lossy().to_lowercase())
+                    .unwrap_or_else(|| import.source.to_lowercase());
+
+                for rule in &context.settings.allowed_imports {
+                    if !Self::matches_pattern(&file_path_str, &rule.from) {
+                        continue;
+                    }
+
+                    let allowed = rule
+                        .allow
+                        .iter()
+                        .any(|pattern| Self::matches_pattern(&target, pattern));
+
+                    if !allowed {
+                        violations.push(Violation {
+                            file_path: file_path.to_path_buf(),
+                            file_content: source_code.to_string(),
+                            offensive_import: import.raw_statement.clone(),
+                            rule: ForbiddenRule {
+                                from: rule.from.clone(),
+                                to: import.source.clone(),
+                            },
+                            line_number: import.line_number,
+                            rule_id: "allowed-imports",
+                        });
+                    }
+                }
+            }
+
+            // Controller → Repository (NestJS/MVC, configurable via the rules map)
+            if context.is_rule_enabled("mvc-controller-repository", file_path)
+                && file_path_str.contains("controller")
+                && import.source.to_lowercase().contains(".repository")
+            {
                 violations.push(Violation {
                     file_path: file_path.to_path_buf(),
                     file_content: source_code.to_string(),
@@ -150,6 +244,7 @@ impl ArchitectParser for TypeScriptParser {
                         to: ".repository".to_string(),
                     },
                     line_number: import.line_number,
+                    rule_id: "mvc-controller-repository",
                 });
             }
         }
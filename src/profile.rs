@@ -0,0 +1,165 @@
+//! Perfil de tiempos de una ejecución (--profile)
+//!
+//! Mide el tiempo de pared de cada fase y el de cada archivo de la fase de reglas
+//! (lectura, parseo y reglas juntos). Los archivos se cuentan en un histograma
+//! atómico desde los hilos de rayon; solo los más lentos pasan por el mutex.
+
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Archivos más lentos listados al final
+const SLOWEST_LISTED: usize = 10;
+
+/// Límites superiores (en milisegundos) de los tramos del histograma; el último
+/// tramo recoge todo lo que los supera
+const BUCKET_LIMITS_MS: [u64; 4] = [1, 10, 100, 1000];
+
+/// Tiempos acumulados durante la ejecución
+#[derive(Default)]
+pub struct Profiler {
+    /// Fases en el orden en que terminaron
+    phases: Mutex<Vec<(&'static str, Duration)>>,
+    /// Archivos por tramo de duración
+    histogram: [AtomicUsize; BUCKET_LIMITS_MS.len() + 1],
+    /// Suma de las duraciones por archivo, en microsegundos
+    files_total_us: AtomicU64,
+    /// Los `SLOWEST_LISTED` archivos más lentos, de más a menos lento
+    slowest: Mutex<Vec<(String, Duration)>>,
+    /// Duración (en nanosegundos) del más rápido de `slowest` cuando está lleno; lo
+    /// que no la supera se descarta sin tomar el mutex
+    slowest_floor_ns: AtomicU64,
+}
+
+impl Profiler {
+    /// Ejecuta una fase midiendo su duración
+    pub fn phase<T>(&self, name: &'static str, run: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let result = run();
+        self.phases.lock().unwrap().push((name, start.elapsed()));
+        result
+    }
+
+    /// Registra la duración del análisis de un archivo
+    pub fn record_file(&self, display: &str, elapsed: Duration) {
+        let millis = elapsed.as_millis() as u64;
+        let bucket = BUCKET_LIMITS_MS
+            .iter()
+            .position(|limit| millis < *limit)
+            .unwrap_or(BUCKET_LIMITS_MS.len());
+        self.histogram[bucket].fetch_add(1, Ordering::Relaxed);
+        self.files_total_us
+            .fetch_add(elapsed.as_micros() as u64, Ordering::Relaxed);
+
+        if (elapsed.as_nanos() as u64) <= self.slowest_floor_ns.load(Ordering::Relaxed) {
+            return;
+        }
+        let mut slowest = self.slowest.lock().unwrap();
+        // Otro hilo pudo subir el mínimo mientras se esperaba el mutex
+        if slowest.len() == SLOWEST_LISTED
+            && slowest.last().is_some_and(|(_, last)| elapsed <= *last)
+        {
+            return;
+        }
+        let index = slowest.partition_point(|(_, duration)| *duration >= elapsed);
+        slowest.insert(index, (display.to_string(), elapsed));
+        slowest.truncate(SLOWEST_LISTED);
+        if slowest.len() == SLOWEST_LISTED {
+            let floor = slowest[SLOWEST_LISTED - 1].1.as_nanos() as u64;
+            self.slowest_floor_ns.store(floor, Ordering::Relaxed);
+        }
+    }
+
+    /// Resumen legible de los tiempos
+    pub fn render(&self) -> String {
+        let mut out = String::from("\n⏱️  PERFIL DE TIEMPOS\n\n  Fases:\n");
+
+        let phases = self.phases.lock().unwrap();
+        let total: Duration = phases.iter().map(|(_, duration)| *duration).sum();
+        for (name, duration) in phases.iter() {
+            out.push_str(&format!(
+                "    {:<22} {:>10}\n",
+                name,
+                format_duration(*duration)
+            ));
+        }
+        out.push_str(&format!(
+            "    {:<22} {:>10}\n",
+            "total",
+            format_duration(total)
+        ));
+
+        let counts: Vec<usize> = self
+            .histogram
+            .iter()
+            .map(|bucket| bucket.load(Ordering::Relaxed))
+            .collect();
+        let files: usize = counts.iter().sum();
+        if files == 0 {
+            return out;
+        }
+
+        let files_total = Duration::from_micros(self.files_total_us.load(Ordering::Relaxed));
+        out.push_str(&format!(
+            "\n  Archivos: {} (suma {}, promedio {})\n",
+            files,
+            format_duration(files_total),
+            format_duration(files_total / files as u32)
+        ));
+        let mut lower = 0;
+        for (limit, count) in BUCKET_LIMITS_MS.iter().zip(&counts) {
+            out.push_str(&format!("    {:>5}-{:<5} ms  {}\n", lower, limit, count));
+            lower = *limit;
+        }
+        out.push_str(&format!(
+            "    {:>5}+      ms  {}\n",
+            lower,
+            counts[BUCKET_LIMITS_MS.len()]
+        ));
+
+        out.push_str("\n  Archivos más lentos:\n");
+        for (display, duration) in self.slowest.lock().unwrap().iter() {
+            out.push_str(&format!(
+                "    {:>10}  {}\n",
+                format_duration(*duration),
+                display
+            ));
+        }
+        out
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    if duration.as_secs() > 0 {
+        format!("{:.2} s", duration.as_secs_f64())
+    } else {
+        format!("{:.1} ms", duration.as_secs_f64() * 1000.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_the_slowest_files_are_kept_in_order() {
+        let profiler = Profiler::default();
+        for millis in 1..=SLOWEST_LISTED as u64 + 5 {
+            profiler.record_file(&format!("{}.ts", millis), Duration::from_millis(millis));
+        }
+        // Por debajo del mínimo de la lista llena: no entra
+        profiler.record_file("fast.ts", Duration::from_millis(3));
+
+        let slowest = profiler.slowest.lock().unwrap();
+        let names: Vec<&str> = slowest.iter().map(|(name, _)| name.as_str()).collect();
+        let expected: Vec<String> = (6..=15)
+            .rev()
+            .map(|millis| format!("{}.ts", millis))
+            .collect();
+        assert_eq!(names, expected);
+        assert_eq!(
+            profiler.slowest_floor_ns.load(Ordering::Relaxed),
+            Duration::from_millis(6).as_nanos() as u64
+        );
+    }
+}
@@ -0,0 +1,383 @@
+//! Resolución de imports más allá de las rutas relativas
+//!
+//! - Paquetes (bare specifiers) contra node_modules: `typeorm/repository` o un alias
+//!   instalado como `"orm": "npm:typeorm@..."` se atribuyen al paquete real leyendo
+//!   el `name` de su package.json.
+//! - Project references de TypeScript: los imports hacia el `outDir` o hacia el nombre
+//!   de paquete de un proyecto referenciado se mapean a su código fuente.
+//! - Subpath imports de Node (`#domain/user`) con el campo `imports` del package.json.
+
+use crate::circular::resolve_candidate;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// Condiciones de `imports` que se prueban, en orden. Se prefieren las que apuntan
+/// a código fuente antes que `types` (un `.d.ts` no tiene imports que seguir).
+const IMPORT_CONDITIONS: [&str; 5] = ["import", "node", "default", "require", "types"];
+
+/// Nombre del paquete al que pertenece un bare specifier (`@scope/pkg/sub` -> `@scope/pkg`)
+pub fn package_specifier_name(specifier: &str) -> Option<&str> {
+    if specifier.is_empty() || specifier.starts_with('.') || specifier.starts_with('/') {
+        return None;
+    }
+
+    let mut end = specifier.len();
+    let mut slashes = specifier.match_indices('/');
+    let segments = if specifier.starts_with('@') { 2 } else { 1 };
+    if let Some((index, _)) = slashes.nth(segments - 1) {
+        end = index;
+    }
+
+    let name = &specifier[..end];
+    // `@/` y `~/` son alias del proyecto, no paquetes
+    if name == "@" || name.starts_with("@/") || name == "~" {
+        return None;
+    }
+    Some(name)
+}
+
+/// Busca el paquete en los node_modules de los directorios ancestros y devuelve
+/// el `name` declarado en su package.json
+pub fn resolve_package_name(current_file: &Path, specifier: &str) -> Option<String> {
+    let dir_name = package_specifier_name(specifier)?;
+
+    for dir in current_file.ancestors().skip(1) {
+        let manifest = dir.join("node_modules").join(dir_name).join("package.json");
+        if !manifest.exists() {
+            continue;
+        }
+
+        let content = fs::read_to_string(&manifest).ok()?;
+        let json: serde_json::Value = serde_json::from_str(&content).ok()?;
+        return Some(
+            json.get("name")
+                .and_then(|n| n.as_str())
+                .unwrap_or(dir_name)
+                .to_string(),
+        );
+    }
+
+    None
+}
+
+/// `name` del package.json más cercano al archivo: el paquete al que pertenece
+pub fn own_package_name(current_file: &Path) -> Option<Arc<str>> {
+    current_file
+        .ancestors()
+        .skip(1)
+        .find_map(package_name)
+        .flatten()
+}
+
+/// `name` del package.json de un directorio (cacheado por directorio). `None` si no
+/// hay package.json y `Some(None)` si lo hay pero no declara `name`.
+fn package_name(dir: &Path) -> Option<Option<Arc<str>>> {
+    type Cache = Mutex<HashMap<PathBuf, Option<Option<Arc<str>>>>>;
+    static CACHE: OnceLock<Cache> = OnceLock::new();
+
+    let mut cache = CACHE.get_or_init(Default::default).lock().unwrap();
+    cache
+        .entry(dir.to_path_buf())
+        .or_insert_with(|| {
+            let content = fs::read_to_string(dir.join("package.json")).ok()?;
+            let json: serde_json::Value = serde_json::from_str(&content).ok()?;
+            let name = json.get("name").and_then(|name| name.as_str());
+            Some(name.map(Arc::from))
+        })
+        .clone()
+}
+
+/// Resuelve un subpath import (`#domain/user`) con el campo `imports` del package.json
+/// más cercano que lo declare. Admite el comodín `*` y targets condicionales.
+pub fn resolve_subpath_import(current_file: &Path, specifier: &str) -> Option<PathBuf> {
+    if !specifier.starts_with('#') {
+        return None;
+    }
+
+    let (package_dir, imports) = current_file
+        .ancestors()
+        .skip(1)
+        .find_map(|dir| Some((dir, package_imports(dir)?)))?;
+
+    let (target, matched) = match imports.get(specifier) {
+        Some(target) => (target, None),
+        None => match_imports_pattern(&imports, specifier)?,
+    };
+    let target = import_target(target)?;
+    let target = match matched {
+        Some(matched) => target.replace('*', matched),
+        None => target.to_string(),
+    };
+    resolve_candidate(&normalize_lexically(&package_dir.join(target)))
+}
+
+/// Campo `imports` del package.json de un directorio (cacheado por directorio)
+fn package_imports(dir: &Path) -> Option<Arc<serde_json::Map<String, serde_json::Value>>> {
+    type Cache = Mutex<HashMap<PathBuf, Option<Arc<serde_json::Map<String, serde_json::Value>>>>>;
+    static CACHE: OnceLock<Cache> = OnceLock::new();
+
+    let mut cache = CACHE.get_or_init(Default::default).lock().unwrap();
+    cache
+        .entry(dir.to_path_buf())
+        .or_insert_with(|| {
+            let content = fs::read_to_string(dir.join("package.json")).ok()?;
+            let json: serde_json::Value = serde_json::from_str(&content).ok()?;
+            json.get("imports")?.as_object().cloned().map(Arc::new)
+        })
+        .clone()
+}
+
+/// Clave con `*` que coincide con el especificador; gana el prefijo más largo, como en Node.
+/// Devuelve el target y el fragmento que sustituye al `*`.
+fn match_imports_pattern<'a, 'b>(
+    imports: &'a serde_json::Map<String, serde_json::Value>,
+    specifier: &'b str,
+) -> Option<(&'a serde_json::Value, Option<&'b str>)> {
+    imports
+        .iter()
+        .filter_map(|(key, target)| {
+            let (prefix, suffix) = key.split_once('*')?;
+            let matched = specifier.strip_prefix(prefix)?.strip_suffix(suffix)?;
+            Some((prefix.len(), target, matched))
+        })
+        .max_by_key(|(prefix_len, _, _)| *prefix_len)
+        .map(|(_, target, matched)| (target, Some(matched)))
+}
+
+/// Ruta de un target de `imports`: un string, el primero válido de un array o
+/// la primera condición conocida de un objeto
+fn import_target(target: &serde_json::Value) -> Option<&str> {
+    match target {
+        serde_json::Value::String(path) => Some(path.as_str()),
+        serde_json::Value::Array(targets) => targets.iter().find_map(import_target),
+        serde_json::Value::Object(conditions) => IMPORT_CONDITIONS
+            .iter()
+            .find_map(|condition| import_target(conditions.get(*condition)?)),
+        _ => None,
+    }
+}
+
+/// Proyecto referenciado desde un tsconfig.json (`references: [{ "path": ... }]`)
+#[derive(Debug)]
+struct ReferencedProject {
+    /// Directorio de código fuente (`rootDir`, o `src/` si existe)
+    root_dir: PathBuf,
+    /// Directorio de salida compilada (`outDir`)
+    out_dir: Option<PathBuf>,
+    /// `name` del package.json del proyecto, si tiene
+    package_name: Option<String>,
+}
+
+/// Proyectos referenciados (directa o transitivamente) desde el tsconfig.json raíz
+#[derive(Debug, Default)]
+pub struct ProjectReferences {
+    projects: Vec<ReferencedProject>,
+}
+
+impl ProjectReferences {
+    /// Lee `references` del tsconfig.json del proyecto. Sin tsconfig o sin
+    /// referencias, el resultado está vacío y no altera la resolución.
+    pub fn load(root: &Path) -> Self {
+        let mut projects = Vec::new();
+        let mut visited = HashSet::new();
+        collect_references(&root.join("tsconfig.json"), &mut visited, &mut projects);
+        Self { projects }
+    }
+
+    /// Resuelve un import que cruza a un proyecto referenciado. `None` si no aplica.
+    pub fn resolve(&self, current_file: &Path, specifier: &str) -> Option<PathBuf> {
+        if self.projects.is_empty() {
+            return None;
+        }
+
+        if specifier.starts_with('.') {
+            // `../core/dist/user` -> `../core/src/user.ts`
+            let target = normalize_lexically(&current_file.parent()?.join(specifier));
+            return self.projects.iter().find_map(|project| {
+                let out_dir = project.out_dir.as_ref()?;
+                let relative = target.strip_prefix(out_dir).ok()?;
+                resolve_candidate(&project.root_dir.join(strip_output_extension(relative)))
+            });
+        }
+
+        // `@org/core/user` -> `<core>/src/user.ts`
+        let name = package_specifier_name(specifier)?;
+        let project = self
+            .projects
+            .iter()
+            .find(|project| project.package_name.as_deref() == Some(name))?;
+        let subpath = specifier[name.len()..].trim_start_matches('/');
+        if subpath.is_empty() {
+            resolve_candidate(&project.root_dir.join("index"))
+        } else {
+            resolve_candidate(&project.root_dir.join(subpath))
+        }
+    }
+}
+
+/// Recorre recursivamente las referencias de un tsconfig
+fn collect_references(
+    tsconfig_path: &Path,
+    visited: &mut HashSet<PathBuf>,
+    projects: &mut Vec<ReferencedProject>,
+) {
+    let tsconfig = match read_jsonc(tsconfig_path) {
+        Some(json) => json,
+        None => return,
+    };
+    let base_dir = match tsconfig_path.parent() {
+        Some(dir) => dir,
+        None => return,
+    };
+
+    let references = match tsconfig.get("references").and_then(|r| r.as_array()) {
+        Some(references) => references,
+        None => return,
+    };
+
+    for reference in references {
+        let path = match reference.get("path").and_then(|p| p.as_str()) {
+            Some(path) => normalize_lexically(&base_dir.join(path)),
+            None => continue,
+        };
+
+        // `path` puede apuntar al directorio o directamente al tsconfig
+        let referenced_tsconfig = if path.extension().is_some_and(|ext| ext == "json") {
+            path
+        } else {
+            path.join("tsconfig.json")
+        };
+
+        if !visited.insert(referenced_tsconfig.clone()) {
+            continue;
+        }
+
+        if let Some(project) = load_referenced_project(&referenced_tsconfig) {
+            projects.push(project);
+        }
+        collect_references(&referenced_tsconfig, visited, projects);
+    }
+}
+
+fn load_referenced_project(tsconfig_path: &Path) -> Option<ReferencedProject> {
+    let tsconfig = read_jsonc(tsconfig_path)?;
+    let dir = tsconfig_path.parent()?.to_path_buf();
+    let options = tsconfig.get("compilerOptions");
+    let option_dir = |key: &str| {
+        options
+            .and_then(|o| o.get(key))
+            .and_then(|v| v.as_str())
+            .map(|v| normalize_lexically(&dir.join(v)))
+    };
+
+    let root_dir = option_dir("rootDir").unwrap_or_else(|| {
+        let src = dir.join("src");
+        if src.is_dir() {
+            src
+        } else {
+            dir.clone()
+        }
+    });
+
+    let package_name = fs::read_to_string(dir.join("package.json"))
+        .ok()
+        .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+        .and_then(|json| json.get("name").and_then(|n| n.as_str()).map(String::from));
+
+    Some(ReferencedProject {
+        root_dir,
+        out_dir: option_dir("outDir"),
+        package_name,
+    })
+}
+
+/// Lee un JSON con comentarios y comas finales (formato de tsconfig.json)
+fn read_jsonc(path: &Path) -> Option<serde_json::Value> {
+    let content = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&strip_jsonc(&content)).ok()
+}
+
+/// Elimina comentarios `//` y `/* */` y comas finales fuera de strings
+fn strip_jsonc(content: &str) -> String {
+    let mut out = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            out.push(c);
+            if c == '\\' {
+                if let Some(escaped) = chars.next() {
+                    out.push(escaped);
+                }
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        match (c, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                out.push(c);
+            }
+            ('/', Some('/')) => {
+                while let Some(&next) = chars.peek() {
+                    if next == '\n' {
+                        break;
+                    }
+                    chars.next();
+                }
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                let mut prev = '\0';
+                for next in chars.by_ref() {
+                    if prev == '*' && next == '/' {
+                        break;
+                    }
+                    prev = next;
+                }
+            }
+            (']', _) | ('}', _) => {
+                // Quitar una coma final pendiente: `[1, 2,]`
+                let trimmed_len = out.trim_end().len();
+                if out[..trimmed_len].ends_with(',') {
+                    out.truncate(trimmed_len - 1);
+                }
+                out.push(c);
+            }
+            _ => out.push(c),
+        }
+    }
+
+    out
+}
+
+/// Resuelve `.` y `..` sin tocar el sistema de archivos
+pub(crate) fn normalize_lexically(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other.as_os_str()),
+        }
+    }
+    normalized
+}
+
+/// `user.js` / `user.d.ts` compilados -> `user` (la extensión fuente se prueba después)
+fn strip_output_extension(relative: &Path) -> PathBuf {
+    let as_str = relative.to_string_lossy();
+    for suffix in [".d.ts", ".js", ".jsx", ".mjs", ".cjs"] {
+        if let Some(stem) = as_str.strip_suffix(suffix) {
+            return PathBuf::from(stem);
+        }
+    }
+    relative.to_path_buf()
+}
@@ -0,0 +1,180 @@
+//! Handlers async de controladores que no manejan errores
+//!
+//! En Express, un handler `async` cuya promesa se rechaza no llega al middleware de
+//! errores: la petición queda colgada o el proceso cae por `unhandledRejection`.
+//! Es una heurística: se señala el primer `await` fuera de un `try/catch` que no es
+//! el valor de retorno ni termina en `.catch(...)`. Un wrapper (`asyncHandler`) o un
+//! filtro de excepciones de NestJS también lo resuelven; en ese caso desactiva la regla.
+
+use super::{prop_name_to_string, Finding, RuleContext, Severity};
+use swc_common::Span;
+use swc_ecma_ast::{
+    ArrowExpr, AwaitExpr, Callee, ClassMethod, Expr, Function, MemberProp, ReturnStmt, TryStmt,
+};
+use swc_ecma_visit::{Visit, VisitWith};
+
+pub const RULE_ID: &str = "async-controller-error-handling";
+
+/// Archivos de controladores por defecto
+pub fn default_controller_files() -> Vec<String> {
+    ["*.controller.ts", "*.controller.js"]
+        .iter()
+        .map(|pattern| pattern.to_string())
+        .collect()
+}
+
+/// Advierte sobre los métodos async de un controlador con un `await` sin manejo de errores
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    let rule = match &rc.ctx.settings.async_controller_error_handling {
+        Some(rule) => rule,
+        None => return Vec::new(),
+    };
+    if !rc.path_matches(&rule.files) {
+        return Vec::new();
+    }
+
+    let mut visitor = ControllerVisitor {
+        rc,
+        findings: Vec::new(),
+    };
+    rc.module.visit_with(&mut visitor);
+    visitor.findings
+}
+
+struct ControllerVisitor<'a, 'b> {
+    rc: &'a RuleContext<'b>,
+    findings: Vec<Finding>,
+}
+
+impl Visit for ControllerVisitor<'_, '_> {
+    fn visit_class_method(&mut self, method: &ClassMethod) {
+        let body = match &method.function.body {
+            Some(body) if method.function.is_async => body,
+            _ => return,
+        };
+
+        let mut awaits = AwaitVisitor::default();
+        body.visit_with(&mut awaits);
+        if let Some(span) = awaits.unhandled {
+            self.findings.push(self.rc.finding(
+                RULE_ID,
+                Severity::Warning,
+                span,
+                format!(
+                    "El handler async '{}' hace await sin try/catch: si la promesa se rechaza, la petición no llega al manejo de errores. Envuélvelo en try/catch o pásalo por un wrapper (ej: asyncHandler).",
+                    prop_name_to_string(&method.key)
+                ),
+            ));
+        }
+    }
+}
+
+/// Busca el primer `await` sin manejo de errores dentro del cuerpo de un método
+#[derive(Default)]
+struct AwaitVisitor {
+    /// Profundidad de bloques `try` con `catch` que rodean al nodo actual
+    try_depth: usize,
+    unhandled: Option<Span>,
+}
+
+impl Visit for AwaitVisitor {
+    fn visit_try_stmt(&mut self, n: &TryStmt) {
+        if n.handler.is_some() {
+            self.try_depth += 1;
+            n.block.visit_with(self);
+            self.try_depth -= 1;
+        } else {
+            n.block.visit_with(self);
+        }
+        n.handler.visit_with(self);
+        n.finalizer.visit_with(self);
+    }
+
+    fn visit_return_stmt(&mut self, n: &ReturnStmt) {
+        // `return await x`: el rechazo se propaga igual que con `return x`
+        match n.arg.as_deref() {
+            Some(Expr::Await(await_expr)) => await_expr.arg.visit_with(self),
+            _ => n.visit_children_with(self),
+        }
+    }
+
+    fn visit_await_expr(&mut self, n: &AwaitExpr) {
+        if self.try_depth == 0 && self.unhandled.is_none() && !ends_in_catch(&n.arg) {
+            self.unhandled = Some(n.span);
+        }
+        n.visit_children_with(self);
+    }
+
+    // Las funciones anidadas (callbacks) tienen su propio manejo de errores
+    fn visit_function(&mut self, _: &Function) {}
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+}
+
+/// `await promesa.catch(...)`: el rechazo ya se maneja en la cadena
+fn ends_in_catch(expr: &Expr) -> bool {
+    let call = match expr {
+        Expr::Call(call) => call,
+        _ => return false,
+    };
+    match &call.callee {
+        Callee::Expr(callee) => matches!(
+            &**callee,
+            Expr::Member(member)
+                if matches!(&member.prop, MemberProp::Ident(prop) if &*prop.sym == "catch")
+        ),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{AsyncControllerErrorHandlingRule, RuleSettings};
+    use crate::rules::testing::check;
+
+    const SOURCE: &str = "\
+export class OrdersController {
+    async create(req: Request, res: Response) {
+        const order = await this.service.create(req.body);
+        res.json(order);
+    }
+    async find(req: Request, res: Response) {
+        try {
+            res.json(await this.service.find(req.params.id));
+        } catch (error) {
+            res.status(500).end();
+        }
+    }
+    async list() {
+        await this.service.warmup().catch(() => undefined);
+        return await this.service.list();
+    }
+}
+";
+
+    fn settings() -> RuleSettings {
+        RuleSettings {
+            async_controller_error_handling: Some(AsyncControllerErrorHandlingRule {
+                files: default_controller_files(),
+            }),
+            ..RuleSettings::default()
+        }
+    }
+
+    #[test]
+    fn reports_awaits_without_error_handling() {
+        let findings = check(RULE_ID, "src/orders.controller.ts", SOURCE, settings());
+        // try/catch, `.catch(...)` y `return await` manejan o propagan el rechazo
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].location.start_line, 3);
+        assert!(findings[0]
+            .message
+            .starts_with("El handler async 'create' hace await sin try/catch"));
+    }
+
+    #[test]
+    fn only_applies_to_controllers() {
+        assert!(check(RULE_ID, "src/orders.service.ts", SOURCE, settings()).is_empty());
+    }
+}
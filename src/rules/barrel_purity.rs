@@ -0,0 +1,133 @@
+//! Los barrels (`index.ts`) solo agregan: imports y re-exports, nunca lógica
+//!
+//! Una función, una clase o una llamada con efectos dentro de un barrel se
+//! ejecuta en cuanto alguien importa cualquier cosa de la carpeta, y suele ser
+//! el origen de ciclos e inicializaciones inesperadas.
+
+use super::{Finding, RuleContext, Severity};
+use swc_common::Spanned;
+use swc_ecma_ast::{Decl, ModuleDecl, ModuleItem, Stmt};
+
+pub const RULE_ID: &str = "barrel-purity";
+
+/// Nombres de archivo que se consideran barrels
+pub fn default_barrel_files() -> Vec<String> {
+    ["index.ts", "index.js"]
+        .iter()
+        .map(|name| name.to_string())
+        .collect()
+}
+
+/// Reporta cada sentencia de un barrel que no es un import o un re-export
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    let rule = match &rc.ctx.settings.barrel_purity {
+        Some(rule) => rule,
+        None => return Vec::new(),
+    };
+
+    let file_name = match rc.path.file_name().and_then(|name| name.to_str()) {
+        Some(name) => name.to_lowercase(),
+        None => return Vec::new(),
+    };
+    if !rule
+        .files
+        .iter()
+        .any(|barrel| barrel.to_lowercase() == file_name)
+    {
+        return Vec::new();
+    }
+
+    rc.module
+        .body
+        .iter()
+        .filter_map(|item| impurity(item).map(|kind| (item.span(), kind)))
+        .map(|(span, kind)| {
+            rc.finding(
+                RULE_ID,
+                Severity::Warning,
+                span,
+                format!(
+                    "El barrel '{}' contiene {}. Un barrel solo debe re-exportar: mueve el código a su propio módulo y re-expórtalo.",
+                    file_name, kind
+                ),
+            )
+        })
+        .collect()
+}
+
+/// Descripción de la sentencia si no es un import o un re-export
+fn impurity(item: &ModuleItem) -> Option<&'static str> {
+    match item {
+        ModuleItem::ModuleDecl(decl) => match decl {
+            ModuleDecl::Import(_)
+            | ModuleDecl::ExportAll(_)
+            | ModuleDecl::ExportNamed(_)
+            | ModuleDecl::TsImportEquals(_) => None,
+            ModuleDecl::ExportDecl(export) => Some(describe_decl(&export.decl)),
+            ModuleDecl::ExportDefaultDecl(_) | ModuleDecl::ExportDefaultExpr(_) => {
+                Some("un export default con código propio")
+            }
+            _ => Some("una sentencia que no es un re-export"),
+        },
+        ModuleItem::Stmt(Stmt::Empty(_)) => None,
+        ModuleItem::Stmt(Stmt::Decl(decl)) => Some(describe_decl(decl)),
+        ModuleItem::Stmt(Stmt::Expr(_)) => Some("una expresión con efectos (ej: una llamada)"),
+        ModuleItem::Stmt(_) => Some("lógica (sentencias de control)"),
+    }
+}
+
+fn describe_decl(decl: &Decl) -> &'static str {
+    match decl {
+        Decl::Fn(_) => "una función",
+        Decl::Class(_) => "una clase",
+        Decl::TsEnum(_) => "un enum",
+        Decl::TsInterface(_) | Decl::TsTypeAlias(_) => "una declaración de tipo",
+        Decl::TsModule(_) => "un namespace",
+        _ => "una variable",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{BarrelPurityRule, RuleSettings};
+    use crate::rules::testing::check;
+
+    const SOURCE: &str = "\
+import { setup } from './setup';
+export * from './order';
+export { Invoice } from './invoice';
+export function createOrder() {}
+setup();
+";
+
+    fn settings() -> RuleSettings {
+        RuleSettings {
+            barrel_purity: Some(BarrelPurityRule {
+                files: default_barrel_files(),
+            }),
+            ..RuleSettings::default()
+        }
+    }
+
+    #[test]
+    fn reports_code_inside_barrels() {
+        let findings = check(RULE_ID, "src/orders/index.ts", SOURCE, settings());
+        let found: Vec<(usize, &str)> = findings
+            .iter()
+            .map(|f| (f.location.start_line, f.message.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (4, "El barrel 'index.ts' contiene una función. Un barrel solo debe re-exportar: mueve el código a su propio módulo y re-expórtalo."),
+                (5, "El barrel 'index.ts' contiene una expresión con efectos (ej: una llamada). Un barrel solo debe re-exportar: mueve el código a su propio módulo y re-expórtalo."),
+            ]
+        );
+    }
+
+    #[test]
+    fn ignores_files_that_are_not_barrels() {
+        assert!(check(RULE_ID, "src/orders/orders.ts", SOURCE, settings()).is_empty());
+    }
+}
@@ -0,0 +1,393 @@
+//! Complejidad cognitiva (definición de SonarSource)
+//!
+//! A diferencia de la complejidad ciclomática, penaliza el anidamiento:
+//! - +1 por cada estructura que rompe el flujo (if, else, loops, switch, catch, ternario,
+//!   break/continue con etiqueta y cada secuencia de operadores lógicos)
+//! - +N adicional según el nivel de anidamiento de esa estructura
+//! - `else if` y `else` no reciben penalización por anidamiento
+
+use super::{prop_name_to_string, Finding, RuleContext, Severity};
+use swc_common::Span;
+use swc_ecma_ast::{
+    ArrowExpr, BinExpr, BinaryOp, BreakStmt, CatchClause, ClassMethod, CondExpr, ContinueStmt,
+    DoWhileStmt, Expr, FnDecl, FnExpr, ForInStmt, ForOfStmt, ForStmt, Function, IfStmt, Module,
+    Pat, PrivateMethod, Stmt, SwitchStmt, VarDeclarator, WhileStmt,
+};
+use swc_ecma_visit::{Visit, VisitWith};
+
+pub const RULE_ID: &str = "cognitive-complexity";
+
+/// Reporta las funciones cuya complejidad cognitiva supera el máximo configurado
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    let max = rc.ctx.settings.max_cognitive_complexity;
+    if max == 0 {
+        return Vec::new();
+    }
+
+    let mut collector = FunctionCollector {
+        functions: Vec::new(),
+    };
+    rc.module.visit_with(&mut collector);
+
+    collector
+        .functions
+        .into_iter()
+        .filter(|f| f.complexity > max)
+        .map(|f| {
+            rc.finding(
+                RULE_ID,
+                Severity::Warning,
+                f.span,
+                format!(
+                    "'{}' tiene complejidad cognitiva {}. Máximo: {}.",
+                    f.name, f.complexity, max
+                ),
+            )
+        })
+        .collect()
+}
+
+/// Complejidad de la función más compleja del módulo (0 si no tiene funciones)
+pub fn max_function_complexity(module: &Module) -> usize {
+    let mut collector = FunctionCollector {
+        functions: Vec::new(),
+    };
+    module.visit_with(&mut collector);
+    collector
+        .functions
+        .iter()
+        .map(|f| f.complexity)
+        .max()
+        .unwrap_or(0)
+}
+
+/// Complejidad calculada para una función
+struct MeasuredFunction {
+    name: String,
+    span: Span,
+    complexity: usize,
+}
+
+/// Recorre el módulo buscando funciones de primer nivel (las anidadas suman a su padre)
+struct FunctionCollector {
+    functions: Vec<MeasuredFunction>,
+}
+
+impl FunctionCollector {
+    fn measure_function(&mut self, name: String, span: Span, function: &Function) {
+        let mut visitor = ComplexityVisitor::default();
+        if let Some(body) = &function.body {
+            body.visit_with(&mut visitor);
+        }
+        self.functions.push(MeasuredFunction {
+            name,
+            span,
+            complexity: visitor.complexity,
+        });
+    }
+
+    fn measure_arrow(&mut self, name: String, arrow: &ArrowExpr) {
+        let mut visitor = ComplexityVisitor::default();
+        arrow.body.visit_with(&mut visitor);
+        self.functions.push(MeasuredFunction {
+            name,
+            span: arrow.span,
+            complexity: visitor.complexity,
+        });
+    }
+}
+
+impl Visit for FunctionCollector {
+    fn visit_fn_decl(&mut self, n: &FnDecl) {
+        self.measure_function(n.ident.sym.to_string(), n.function.span, &n.function);
+    }
+
+    fn visit_class_method(&mut self, n: &ClassMethod) {
+        self.measure_function(prop_name_to_string(&n.key), n.span, &n.function);
+    }
+
+    fn visit_private_method(&mut self, n: &PrivateMethod) {
+        self.measure_function(format!("#{}", n.key.id.sym), n.span, &n.function);
+    }
+
+    fn visit_var_declarator(&mut self, n: &VarDeclarator) {
+        // `const handler = () => {...}` usa el nombre de la variable
+        if let (Pat::Ident(binding), Some(init)) = (&n.name, &n.init) {
+            let name = binding.id.sym.to_string();
+            match &**init {
+                Expr::Arrow(arrow) => return self.measure_arrow(name, arrow),
+                Expr::Fn(fn_expr) => {
+                    return self.measure_function(name, fn_expr.function.span, &fn_expr.function)
+                }
+                _ => {}
+            }
+        }
+        n.visit_children_with(self);
+    }
+
+    fn visit_fn_expr(&mut self, n: &FnExpr) {
+        let name = n
+            .ident
+            .as_ref()
+            .map(|ident| ident.sym.to_string())
+            .unwrap_or_else(|| "(anónima)".to_string());
+        self.measure_function(name, n.function.span, &n.function);
+    }
+
+    fn visit_arrow_expr(&mut self, n: &ArrowExpr) {
+        self.measure_arrow("(anónima)".to_string(), n);
+    }
+}
+
+/// Calcula la complejidad cognitiva del cuerpo de una función
+#[derive(Default)]
+struct ComplexityVisitor {
+    complexity: usize,
+    nesting: usize,
+}
+
+impl ComplexityVisitor {
+    /// Estructura que incrementa la complejidad y el anidamiento de su cuerpo
+    fn nested_structure<N: VisitWith<Self>>(&mut self, node: &N) {
+        self.complexity += 1 + self.nesting;
+        self.nesting += 1;
+        node.visit_children_with(self);
+        self.nesting -= 1;
+    }
+
+    fn visit_if_chain(&mut self, n: &IfStmt, is_else_if: bool) {
+        // `else if` suma 1 sin penalización por anidamiento
+        self.complexity += if is_else_if { 1 } else { 1 + self.nesting };
+        n.test.visit_with(self);
+
+        self.nesting += 1;
+        n.cons.visit_with(self);
+        self.nesting -= 1;
+
+        if let Some(alt) = &n.alt {
+            match &**alt {
+                Stmt::If(else_if) => self.visit_if_chain(else_if, true),
+                other => {
+                    self.complexity += 1;
+                    self.nesting += 1;
+                    other.visit_with(self);
+                    self.nesting -= 1;
+                }
+            }
+        }
+    }
+}
+
+fn is_logical(op: BinaryOp) -> bool {
+    matches!(
+        op,
+        BinaryOp::LogicalAnd | BinaryOp::LogicalOr | BinaryOp::NullishCoalescing
+    )
+}
+
+/// Aplana una cadena de operadores lógicos (a && b || c) en orden de lectura
+fn collect_logical_chain<'a>(
+    expr: &'a Expr,
+    ops: &mut Vec<BinaryOp>,
+    operands: &mut Vec<&'a Expr>,
+) {
+    match expr {
+        Expr::Bin(bin) if is_logical(bin.op) => {
+            collect_logical_chain(&bin.left, ops, operands);
+            ops.push(bin.op);
+            collect_logical_chain(&bin.right, ops, operands);
+        }
+        other => operands.push(other),
+    }
+}
+
+impl Visit for ComplexityVisitor {
+    fn visit_if_stmt(&mut self, n: &IfStmt) {
+        self.visit_if_chain(n, false);
+    }
+
+    fn visit_switch_stmt(&mut self, n: &SwitchStmt) {
+        self.nested_structure(n);
+    }
+
+    fn visit_for_stmt(&mut self, n: &ForStmt) {
+        self.nested_structure(n);
+    }
+
+    fn visit_for_in_stmt(&mut self, n: &ForInStmt) {
+        self.nested_structure(n);
+    }
+
+    fn visit_for_of_stmt(&mut self, n: &ForOfStmt) {
+        self.nested_structure(n);
+    }
+
+    fn visit_while_stmt(&mut self, n: &WhileStmt) {
+        self.nested_structure(n);
+    }
+
+    fn visit_do_while_stmt(&mut self, n: &DoWhileStmt) {
+        self.nested_structure(n);
+    }
+
+    fn visit_catch_clause(&mut self, n: &CatchClause) {
+        self.nested_structure(n);
+    }
+
+    fn visit_cond_expr(&mut self, n: &CondExpr) {
+        self.nested_structure(n);
+    }
+
+    fn visit_break_stmt(&mut self, n: &BreakStmt) {
+        if n.label.is_some() {
+            self.complexity += 1;
+        }
+    }
+
+    fn visit_continue_stmt(&mut self, n: &ContinueStmt) {
+        if n.label.is_some() {
+            self.complexity += 1;
+        }
+    }
+
+    fn visit_bin_expr(&mut self, n: &BinExpr) {
+        if !is_logical(n.op) {
+            n.visit_children_with(self);
+            return;
+        }
+
+        // Cada secuencia de operadores lógicos iguales suma 1
+        let mut ops = Vec::new();
+        let mut operands = Vec::new();
+        collect_logical_chain(&n.left, &mut ops, &mut operands);
+        ops.push(n.op);
+        collect_logical_chain(&n.right, &mut ops, &mut operands);
+
+        self.complexity += 1 + ops.windows(2).filter(|pair| pair[0] != pair[1]).count();
+
+        for operand in operands {
+            operand.visit_with(self);
+        }
+    }
+
+    // Las funciones anidadas (callbacks, lambdas) incrementan el anidamiento
+    fn visit_function(&mut self, n: &Function) {
+        self.nesting += 1;
+        n.visit_children_with(self);
+        self.nesting -= 1;
+    }
+
+    fn visit_arrow_expr(&mut self, n: &ArrowExpr) {
+        self.nesting += 1;
+        n.visit_children_with(self);
+        self.nesting -= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RuleSettings;
+    use crate::rules::testing::check;
+
+    // for (+1), if anidado en el for (+1 +1 de anidamiento) y la secuencia && (+1)
+    const NESTED: &str = "\
+function process(items: number[]) {
+    for (const item of items) {
+        if (item > 0 && item < 10) {
+            console.log(item);
+        }
+    }
+}
+";
+
+    fn settings(max: usize) -> RuleSettings {
+        RuleSettings {
+            max_cognitive_complexity: max,
+            ..RuleSettings::default()
+        }
+    }
+
+    #[test]
+    fn reports_functions_above_the_maximum() {
+        let findings = check(RULE_ID, "src/orders.ts", NESTED, settings(3));
+        assert_eq!(findings.len(), 1);
+        assert_eq!(
+            findings[0].message,
+            "'process' tiene complejidad cognitiva 4. Máximo: 3."
+        );
+        assert_eq!(findings[0].location.start_line, 1);
+    }
+
+    #[test]
+    fn allows_functions_at_the_maximum() {
+        assert!(check(RULE_ID, "src/orders.ts", NESTED, settings(4)).is_empty());
+        assert!(check(RULE_ID, "src/orders.ts", NESTED, settings(0)).is_empty());
+    }
+
+    #[test]
+    fn else_branches_are_not_penalized_for_nesting() {
+        // if (+1), else if (+1) y else (+1), sin anidamiento
+        let source = "\
+const label = (n: number) => {
+    if (n > 0) {
+        return 'positivo';
+    } else if (n < 0) {
+        return 'negativo';
+    } else {
+        return 'cero';
+    }
+};
+";
+        let findings = check(RULE_ID, "src/label.ts", source, settings(2));
+        assert_eq!(findings.len(), 1);
+        assert_eq!(
+            findings[0].message,
+            "'label' tiene complejidad cognitiva 3. Máximo: 2."
+        );
+        assert!(check(RULE_ID, "src/label.ts", source, settings(3)).is_empty());
+    }
+
+    #[test]
+    fn nesting_separates_functions_with_the_same_branches() {
+        // Tres if en ambos casos (complejidad ciclomática 4): en secuencia suman 1 + 1 + 1
+        let flat = "\
+function flat(a: boolean, b: boolean, c: boolean) {
+    if (a) {
+        console.log('a');
+    }
+    if (b) {
+        console.log('b');
+    }
+    if (c) {
+        console.log('c');
+    }
+}
+";
+        // anidados suman 1 + 2 + 3
+        let nested = "\
+function nested(a: boolean, b: boolean, c: boolean) {
+    if (a) {
+        if (b) {
+            if (c) {
+                console.log('abc');
+            }
+        }
+    }
+}
+";
+        let score = |source: &str| {
+            let findings = check(RULE_ID, "src/branches.ts", source, settings(1));
+            assert_eq!(findings.len(), 1);
+            findings[0].message.clone()
+        };
+        assert_eq!(
+            score(flat),
+            "'flat' tiene complejidad cognitiva 3. Máximo: 1."
+        );
+        assert_eq!(
+            score(nested),
+            "'nested' tiene complejidad cognitiva 6. Máximo: 1."
+        );
+    }
+}
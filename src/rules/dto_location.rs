@@ -0,0 +1,174 @@
+//! DTOs y clases de validación fuera de su capa
+//!
+//! Los DTOs de request/response y los esquemas de class-validator pertenecen a la
+//! capa de presentación: un DTO en el dominio lo acopla al formato de la API.
+//! Una clase cuenta como DTO si su nombre termina en `Dto` o si alguna de sus
+//! propiedades usa un decorador de validación.
+
+use super::{Finding, RuleContext, Severity};
+use swc_common::Span;
+use swc_ecma_ast::{Callee, Class, ClassDecl, ClassMember, Decorator, Expr};
+use swc_ecma_visit::{Visit, VisitWith};
+
+pub const RULE_ID: &str = "dto-location";
+
+/// Decoradores de class-validator más habituales
+pub fn default_validation_decorators() -> Vec<String> {
+    [
+        "IsString",
+        "IsNumber",
+        "IsInt",
+        "IsBoolean",
+        "IsEmail",
+        "IsEnum",
+        "IsDate",
+        "IsArray",
+        "IsUUID",
+        "IsOptional",
+        "IsNotEmpty",
+        "IsDefined",
+        "Length",
+        "MinLength",
+        "MaxLength",
+        "Min",
+        "Max",
+        "Matches",
+        "ValidateNested",
+    ]
+    .iter()
+    .map(|d| d.to_string())
+    .collect()
+}
+
+/// Reporta los DTOs declarados fuera de las carpetas configuradas
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    let rule = match &rc.ctx.settings.dto_location {
+        Some(rule) => rule,
+        None => return Vec::new(),
+    };
+
+    if rc.path_matches(&rule.paths) {
+        return Vec::new();
+    }
+
+    let mut visitor = DtoVisitor {
+        rc,
+        validation_decorators: &rule.validation_decorators,
+        paths: &rule.paths,
+        findings: Vec::new(),
+    };
+    rc.module.visit_with(&mut visitor);
+    visitor.findings
+}
+
+struct DtoVisitor<'a, 'b> {
+    rc: &'a RuleContext<'b>,
+    validation_decorators: &'a [String],
+    paths: &'a [String],
+    findings: Vec<Finding>,
+}
+
+impl DtoVisitor<'_, '_> {
+    fn check_class(&mut self, name: &str, class: &Class, span: Span) {
+        let validated = class.body.iter().any(|member| {
+            let decorators = match member {
+                ClassMember::ClassProp(prop) => &prop.decorators,
+                ClassMember::PrivateProp(prop) => &prop.decorators,
+                _ => return false,
+            };
+            decorators
+                .iter()
+                .filter_map(decorator_name)
+                .any(|decorator| {
+                    self.validation_decorators
+                        .iter()
+                        .any(|validation| validation == decorator)
+                })
+        });
+        if !name.ends_with("Dto") && !validated {
+            return;
+        }
+
+        self.findings.push(self.rc.finding(
+            RULE_ID,
+            Severity::Error,
+            span,
+            format!(
+                "El DTO '{}' está fuera de su capa. Muévelo a: {}.",
+                name,
+                self.paths.join(", ")
+            ),
+        ));
+    }
+}
+
+impl Visit for DtoVisitor<'_, '_> {
+    fn visit_class_decl(&mut self, n: &ClassDecl) {
+        self.check_class(&n.ident.sym, &n.class, n.ident.span);
+        n.visit_children_with(self);
+    }
+}
+
+/// Nombre de un decorador: `@IsString()`, `@IsString` o `@validator.IsString()`
+pub(super) fn decorator_name(decorator: &Decorator) -> Option<&str> {
+    let expr = match &*decorator.expr {
+        Expr::Call(call) => match &call.callee {
+            Callee::Expr(callee) => &**callee,
+            _ => return None,
+        },
+        expr => expr,
+    };
+    match expr {
+        Expr::Ident(ident) => Some(&*ident.sym),
+        Expr::Member(member) => member.prop.as_ident().map(|ident| &*ident.sym),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{DtoLocationRule, RuleSettings};
+    use crate::rules::testing::check;
+
+    const SOURCE: &str = "\
+export class CreateOrderDto {
+    items: string[];
+}
+export class OrderFilter {
+    @IsOptional()
+    @validator.IsString()
+    status?: string;
+}
+export class Order {
+    @Column()
+    id: string;
+}
+";
+
+    fn settings() -> RuleSettings {
+        RuleSettings {
+            dto_location: Some(DtoLocationRule {
+                paths: vec!["src/presentation/dto/".to_string()],
+                validation_decorators: default_validation_decorators(),
+            }),
+            ..RuleSettings::default()
+        }
+    }
+
+    #[test]
+    fn reports_dtos_by_name_or_validation_decorators() {
+        let findings = check(RULE_ID, "src/domain/order.ts", SOURCE, settings());
+        let lines: Vec<usize> = findings.iter().map(|f| f.location.start_line).collect();
+        assert_eq!(lines, vec![1, 4]);
+        assert_eq!(
+            findings[0].message,
+            "El DTO 'CreateOrderDto' está fuera de su capa. Muévelo a: src/presentation/dto/."
+        );
+    }
+
+    #[test]
+    fn allows_dtos_in_their_layer() {
+        assert!(check(RULE_ID, "src/presentation/dto/order.ts", SOURCE, settings()).is_empty());
+    }
+}
@@ -0,0 +1,99 @@
+//! Enums declarados fuera de su ubicación central
+//!
+//! Un enum es vocabulario compartido: si cada capa declara el suyo (`OrderStatus`
+//! en el servicio, otro en el controlador) los valores terminan divergiendo. Los
+//! `declare enum` de archivos de tipos ambientales no cuentan.
+
+use super::{Finding, RuleContext, Severity};
+use swc_ecma_ast::TsEnumDecl;
+use swc_ecma_visit::{Visit, VisitWith};
+
+pub const RULE_ID: &str = "enum-location";
+
+/// Reporta los enums declarados fuera de las carpetas configuradas
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    let rule = match &rc.ctx.settings.enum_location {
+        Some(rule) => rule,
+        None => return Vec::new(),
+    };
+
+    if rc.path_matches(&rule.paths) {
+        return Vec::new();
+    }
+
+    let mut visitor = EnumVisitor {
+        rc,
+        paths: &rule.paths,
+        findings: Vec::new(),
+    };
+    rc.module.visit_with(&mut visitor);
+    visitor.findings
+}
+
+struct EnumVisitor<'a, 'b> {
+    rc: &'a RuleContext<'b>,
+    paths: &'a [String],
+    findings: Vec<Finding>,
+}
+
+impl Visit for EnumVisitor<'_, '_> {
+    fn visit_ts_enum_decl(&mut self, n: &TsEnumDecl) {
+        if !n.declare {
+            self.findings.push(self.rc.finding(
+                RULE_ID,
+                Severity::Error,
+                n.span,
+                format!(
+                    "El enum '{}' debe declararse en {}. Muévelo allí e impórtalo desde esta capa.",
+                    n.id.sym,
+                    self.paths.join(", ")
+                ),
+            ));
+        }
+        n.visit_children_with(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{EnumLocationRule, RuleSettings};
+    use crate::rules::testing::check;
+
+    const SOURCE: &str = "\
+export enum OrderStatus {
+    New,
+    Paid,
+}
+declare enum LegacyStatus {
+    Open,
+}
+";
+
+    fn settings() -> RuleSettings {
+        RuleSettings {
+            enum_location: Some(EnumLocationRule {
+                paths: vec!["src/domain/enums/".to_string(), "shared/".to_string()],
+            }),
+            ..RuleSettings::default()
+        }
+    }
+
+    #[test]
+    fn reports_enums_outside_the_configured_folders() {
+        let findings = check(RULE_ID, "src/orders/orders.service.ts", SOURCE, settings());
+        // `declare enum` no declara valores propios
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].location.start_line, 1);
+        assert_eq!(
+            findings[0].message,
+            "El enum 'OrderStatus' debe declararse en src/domain/enums/, shared/. Muévelo allí e impórtalo desde esta capa."
+        );
+    }
+
+    #[test]
+    fn allows_enums_in_the_configured_folders() {
+        assert!(check(RULE_ID, "src/domain/enums/order.ts", SOURCE, settings()).is_empty());
+        assert!(check(RULE_ID, "libs/shared/status.ts", SOURCE, settings()).is_empty());
+    }
+}
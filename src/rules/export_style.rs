@@ -0,0 +1,149 @@
+//! Estilo de exports por archivo: solo default, solo nombrados o sin mezclar
+//!
+//! Los exports de solo tipos (`export interface`, `export type`) no cuentan:
+//! acompañan a cualquier estilo sin afectar al tree-shaking.
+
+use super::{Finding, RuleContext, Severity};
+use crate::config::ExportStyle;
+use swc_common::Span;
+use swc_ecma_ast::{Decl, ExportSpecifier, ModuleDecl, ModuleExportName, ModuleItem};
+
+pub const RULE_ID: &str = "export-style";
+
+/// Reporta cada export que no respeta el estilo configurado
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    let style = match rc.ctx.settings.export_style {
+        Some(style) => style,
+        None => return Vec::new(),
+    };
+
+    let exports = collect_exports(rc);
+    let has_named = exports.iter().any(|e| !e.is_default);
+
+    exports
+        .into_iter()
+        .filter_map(|export| {
+            let message = match style {
+                ExportStyle::DefaultOnly if !export.is_default => {
+                    "Este archivo solo debe tener un export default. Mueve los exports nombrados a su propio módulo."
+                }
+                ExportStyle::NamedOnly if export.is_default => {
+                    "No uses export default. Exporta por nombre (export class/function/const ...)."
+                }
+                ExportStyle::NoMixing if export.is_default && has_named => {
+                    "No mezcles export default con exports nombrados en el mismo archivo."
+                }
+                _ => return None,
+            };
+            Some(rc.finding(RULE_ID, Severity::Warning, export.span, message.to_string()))
+        })
+        .collect()
+}
+
+/// Export de valor encontrado en el nivel superior del módulo
+struct ExportEntry {
+    span: Span,
+    is_default: bool,
+}
+
+fn collect_exports(rc: &RuleContext) -> Vec<ExportEntry> {
+    let mut exports = Vec::new();
+
+    for item in &rc.module.body {
+        let decl = match item {
+            ModuleItem::ModuleDecl(decl) => decl,
+            _ => continue,
+        };
+
+        match decl {
+            ModuleDecl::ExportDefaultDecl(n) => exports.push(ExportEntry {
+                span: n.span,
+                is_default: true,
+            }),
+            ModuleDecl::ExportDefaultExpr(n) => exports.push(ExportEntry {
+                span: n.span,
+                is_default: true,
+            }),
+            // Las interfaces y los alias de tipo no existen en runtime
+            ModuleDecl::ExportDecl(n)
+                if !matches!(n.decl, Decl::TsInterface(_) | Decl::TsTypeAlias(_)) =>
+            {
+                exports.push(ExportEntry {
+                    span: n.span,
+                    is_default: false,
+                })
+            }
+            ModuleDecl::ExportAll(n) if !n.type_only => exports.push(ExportEntry {
+                span: n.span,
+                is_default: false,
+            }),
+            ModuleDecl::ExportNamed(n) if !n.type_only => {
+                // `export { x as default }` y `export { default } from './x'` son defaults
+                for specifier in &n.specifiers {
+                    let is_default = match specifier {
+                        ExportSpecifier::Named(named) => {
+                            if named.is_type_only {
+                                continue;
+                            }
+                            let exported = named.exported.as_ref().unwrap_or(&named.orig);
+                            matches!(exported, ModuleExportName::Ident(ident) if &*ident.sym == "default")
+                        }
+                        ExportSpecifier::Default(_) => true,
+                        ExportSpecifier::Namespace(_) => false,
+                    };
+                    exports.push(ExportEntry {
+                        span: n.span,
+                        is_default,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    exports
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RuleSettings;
+    use crate::rules::testing::check;
+
+    // La interfaz no cuenta: los exports de solo tipos acompañan a cualquier estilo
+    const MIXED: &str = "\
+export interface Options {
+    id: string;
+}
+export const limit = 10;
+export default function list() {}
+";
+
+    fn lines(style: ExportStyle, source: &str) -> Vec<usize> {
+        let settings = RuleSettings {
+            export_style: Some(style),
+            ..RuleSettings::default()
+        };
+        check(RULE_ID, "src/list.ts", source, settings)
+            .iter()
+            .map(|f| f.location.start_line)
+            .collect()
+    }
+
+    #[test]
+    fn reports_the_exports_that_break_the_style() {
+        assert_eq!(lines(ExportStyle::DefaultOnly, MIXED), vec![4]);
+        assert_eq!(lines(ExportStyle::NamedOnly, MIXED), vec![5]);
+        assert_eq!(lines(ExportStyle::NoMixing, MIXED), vec![5]);
+    }
+
+    #[test]
+    fn treats_renamed_exports_as_defaults() {
+        let source = "\
+const list = () => [];
+export { list as default };
+";
+        assert_eq!(lines(ExportStyle::NamedOnly, source), vec![2]);
+        assert!(lines(ExportStyle::NoMixing, source).is_empty());
+    }
+}
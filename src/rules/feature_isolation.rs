@@ -0,0 +1,169 @@
+//! Aislamiento entre features (organización vertical por carpeta)
+//!
+//! Cada carpeta de primer nivel bajo `features_root` es una feature. Desde otra
+//! feature solo se puede importar su API pública (el `index` de la carpeta), igual
+//! que con los directorios de `sealed_paths`.
+
+use super::{Finding, RuleContext, Severity};
+use crate::analyzer;
+use crate::circular::resolve_import_path;
+use std::path::Path;
+use swc_common::Span;
+use swc_ecma_ast::{ModuleDecl, ModuleItem};
+
+pub const RULE_ID: &str = "feature-isolation";
+
+/// Reporta los imports de internals de otra feature
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    let rule = match &rc.ctx.settings.feature_isolation {
+        Some(rule) => rule,
+        None => return Vec::new(),
+    };
+
+    let mut root = analyzer::normalize_pattern(&rule.features_root);
+    if root.is_empty() {
+        return Vec::new();
+    }
+    if !root.ends_with('/') {
+        root.push('/');
+    }
+
+    let current = match feature_of(rc.path, &root) {
+        Some((feature, _)) => feature,
+        None => return Vec::new(),
+    };
+
+    rc.module
+        .body
+        .iter()
+        .filter_map(import_source)
+        .filter_map(|(source, span)| {
+            // Canonicalizar para que los `..` del import no confundan la feature destino
+            let resolved = resolve_import_path(rc.path, source)?.canonicalize().ok()?;
+            let (feature, internal_path) = feature_of(&resolved, &root)?;
+            if feature == current || is_public_api(&internal_path) {
+                return None;
+            }
+            Some(rc.finding(
+                RULE_ID,
+                Severity::Error,
+                span,
+                format!(
+                    "La feature '{}' importa internals de la feature '{}' ('{}'). Importa desde su API pública (index).",
+                    current, feature, source
+                ),
+            ))
+        })
+        .collect()
+}
+
+/// Especificador y span de los imports y re-exports con origen
+fn import_source(item: &ModuleItem) -> Option<(&str, Span)> {
+    match item {
+        ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+            Some((&*import.src.value, import.span))
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(export)) => {
+            export.src.as_ref().map(|src| (&*src.value, export.span))
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportAll(export)) => {
+            Some((&*export.src.value, export.span))
+        }
+        _ => None,
+    }
+}
+
+/// Feature a la que pertenece un archivo y su ruta dentro de la feature.
+/// Los archivos sueltos directamente en `features_root` no pertenecen a ninguna.
+pub(super) fn feature_of(path: &Path, root: &str) -> Option<(String, String)> {
+    let path = path.to_string_lossy().to_lowercase().replace('\\', "/");
+    let start = path.find(root)? + root.len();
+    let (feature, rest) = path[start..].split_once('/')?;
+    Some((feature.to_string(), rest.to_string()))
+}
+
+/// La API pública de una feature es el `index` de su carpeta raíz
+fn is_public_api(internal_path: &str) -> bool {
+    !internal_path.contains('/')
+        && Path::new(internal_path)
+            .file_stem()
+            .is_some_and(|stem| stem == "index")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{FeatureIsolationRule, RuleSettings};
+    use crate::rules::testing::{check, temp_project};
+
+    const SOURCE: &str = "\
+import { Invoice } from '../billing/invoice';
+import { Invoice as PublicInvoice } from '../billing';
+import { format } from './helpers';
+export * from '../billing/invoice';
+";
+
+    fn settings() -> RuleSettings {
+        RuleSettings {
+            feature_isolation: Some(FeatureIsolationRule {
+                features_root: "src/features/".to_string(),
+            }),
+            ..RuleSettings::default()
+        }
+    }
+
+    #[test]
+    fn reports_imports_of_another_feature_internals() {
+        let root = temp_project(
+            "feature-isolation",
+            &[
+                (
+                    "src/features/billing/index.ts",
+                    "export * from './invoice';\n",
+                ),
+                (
+                    "src/features/billing/invoice.ts",
+                    "export class Invoice {}\n",
+                ),
+                (
+                    "src/features/orders/helpers.ts",
+                    "export const format = 1;\n",
+                ),
+                ("src/features/orders/order.service.ts", SOURCE),
+            ],
+        );
+        let file = root.join("src/features/orders/order.service.ts");
+
+        let findings = check(RULE_ID, file.to_str().unwrap(), SOURCE, settings());
+        // El index de billing y los archivos de la propia feature están permitidos
+        let lines: Vec<usize> = findings.iter().map(|f| f.location.start_line).collect();
+        assert_eq!(lines, vec![1, 4]);
+        assert_eq!(
+            findings[0].message,
+            "La feature 'orders' importa internals de la feature 'billing' ('../billing/invoice'). Importa desde su API pública (index)."
+        );
+
+        // Fuera de features_root la regla no aplica
+        let outside = root.join("src/main.ts");
+        let source = "import { Invoice } from './features/billing/invoice';\n";
+        assert!(check(RULE_ID, outside.to_str().unwrap(), source, settings()).is_empty());
+    }
+
+    #[test]
+    fn public_api_is_the_index_at_the_feature_root() {
+        assert!(is_public_api("index.ts"));
+        assert!(!is_public_api("invoice.ts"));
+        assert!(!is_public_api("internal/index.ts"));
+        assert_eq!(
+            feature_of(
+                Path::new("/app/src/features/orders/a/b.ts"),
+                "src/features/"
+            ),
+            Some(("orders".to_string(), "a/b.ts".to_string()))
+        );
+        assert_eq!(
+            feature_of(Path::new("/app/src/features/x.ts"), "src/features/"),
+            None
+        );
+    }
+}
@@ -0,0 +1,124 @@
+//! Imports de solo tipos desde una capa más externa (modo estricto)
+//!
+//! `import type` desaparece al compilar y por eso ni forbidden_imports ni el detector
+//! de ciclos lo consideran. Aun así, un dominio que tipa sus datos con la forma de una
+//! entidad de infraestructura queda acoplado a ella: cualquier cambio de esquema se
+//! propaga hacia adentro. Los imports de valores siguen a cargo de forbidden_imports.
+
+use super::{Finding, RuleContext, Severity};
+use crate::analyzer::{matches_pattern, normalize_pattern};
+use crate::circular::resolve_import_path;
+use swc_ecma_ast::{ImportDecl, ImportSpecifier, ModuleDecl, ModuleItem};
+
+pub const RULE_ID: &str = "forbid-cross-layer-types";
+
+/// Reporta los imports de solo tipos que apuntan a una capa más externa que la del archivo
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    let rule = match &rc.ctx.settings.forbid_cross_layer_types {
+        Some(rule) => rule,
+        None => return Vec::new(),
+    };
+
+    let file_path = rc.path.to_string_lossy().to_lowercase();
+    let current = match layer_index(&file_path, &rule.layers) {
+        Some(index) => index,
+        None => return Vec::new(),
+    };
+
+    rc.module
+        .body
+        .iter()
+        .filter_map(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(import)) if is_type_only(import) => {
+                Some(import)
+            }
+            _ => None,
+        })
+        .filter_map(|import| {
+            // Igual que no-cross-layer-new: los relativos se resuelven al archivo real
+            // y los alias se comparan tal cual con los patrones de capa
+            let source: &str = &import.src.value;
+            let target = resolve_import_path(rc.path, source)
+                .map(|resolved| resolved.to_string_lossy().to_lowercase())
+                .unwrap_or_else(|| source.to_lowercase());
+            let imported = layer_index(&target, &rule.layers)?;
+            if imported <= current {
+                return None;
+            }
+            Some(rc.finding(
+                RULE_ID,
+                Severity::Error,
+                import.span,
+                format!(
+                    "La capa '{}' importa tipos de '{}', una capa más externa. Define el tipo en '{}' (o en un puerto) y que la capa externa lo implemente.",
+                    rule.layers[current], rule.layers[imported], rule.layers[current]
+                ),
+            ))
+        })
+        .collect()
+}
+
+/// `import type { A }` o `import { type A, type B }`: no importa ningún valor
+fn is_type_only(import: &ImportDecl) -> bool {
+    import.type_only
+        || (!import.specifiers.is_empty()
+            && import.specifiers.iter().all(|specifier| {
+                matches!(specifier, ImportSpecifier::Named(named) if named.is_type_only)
+            }))
+}
+
+/// Posición de la primera capa configurada que coincide con la ruta
+fn layer_index(path: &str, layers: &[String]) -> Option<usize> {
+    layers
+        .iter()
+        .position(|layer| matches_pattern(path, &normalize_pattern(layer)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{ForbidCrossLayerTypesRule, RuleSettings};
+    use crate::rules::testing::check;
+
+    fn settings() -> RuleSettings {
+        RuleSettings {
+            forbid_cross_layer_types: Some(ForbidCrossLayerTypesRule {
+                layers: vec![
+                    "src/domain/".to_string(),
+                    "src/application/".to_string(),
+                    "src/infrastructure/".to_string(),
+                ],
+            }),
+            ..RuleSettings::default()
+        }
+    }
+
+    #[test]
+    fn reports_type_imports_from_outer_layers() {
+        let source = "\
+import type { OrderEntity } from '../infrastructure/order.entity';
+import { type OrderRow, type OrderKey } from '../infrastructure/order.row';
+import type { Money } from './money';
+import { OrderRepository } from '../infrastructure/order.repository';
+";
+        let findings = check(RULE_ID, "src/domain/order.ts", source, settings());
+        // Los imports de valores quedan a cargo de forbidden_imports
+        let lines: Vec<usize> = findings.iter().map(|f| f.location.start_line).collect();
+        assert_eq!(lines, vec![1, 2]);
+        assert!(findings[0]
+            .message
+            .starts_with("La capa 'src/domain/' importa tipos de 'src/infrastructure/'"));
+    }
+
+    #[test]
+    fn allows_type_imports_towards_inner_layers() {
+        let source = "import type { Order } from '../domain/order';\n";
+        assert!(check(
+            RULE_ID,
+            "src/infrastructure/order.entity.ts",
+            source,
+            settings()
+        )
+        .is_empty());
+    }
+}
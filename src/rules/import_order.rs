@@ -0,0 +1,127 @@
+//! Orden de imports por grupos: paquetes externos, alias internos y relativos
+//!
+//! El orden es configurable. Se reporta el primer import que aparece después de
+//! un grupo que debería ir detrás de él.
+
+use super::{Finding, RuleContext, Severity};
+use crate::config::ImportGroup;
+use swc_ecma_ast::{ModuleDecl, ModuleItem};
+
+pub const RULE_ID: &str = "import-order";
+
+/// Advierte sobre el primer import fuera del orden de grupos configurado
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    let order = match &rc.ctx.settings.import_order {
+        Some(order) if !order.is_empty() => order,
+        _ => return Vec::new(),
+    };
+
+    // Grupo de mayor rango visto hasta ahora
+    let mut highest: Option<(usize, ImportGroup)> = None;
+
+    for item in &rc.module.body {
+        let import = match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => import,
+            _ => continue,
+        };
+
+        let group = classify(&import.src.value);
+        let rank = match order.iter().position(|g| *g == group) {
+            Some(rank) => rank,
+            None => continue, // Grupos no listados no participan del orden
+        };
+
+        match highest {
+            Some((highest_rank, highest_group)) if rank < highest_rank => {
+                return vec![rc.finding(
+                    RULE_ID,
+                    Severity::Warning,
+                    import.span,
+                    format!(
+                        "Import '{}' ({}) debe ir antes de los imports {}. Orden esperado: {}.",
+                        import.src.value,
+                        group.as_str(),
+                        highest_group.as_str(),
+                        order
+                            .iter()
+                            .map(|g| g.as_str())
+                            .collect::<Vec<_>>()
+                            .join(" → ")
+                    ),
+                )];
+            }
+            Some((highest_rank, _)) if rank == highest_rank => {}
+            _ => highest = Some((rank, group)),
+        }
+    }
+
+    Vec::new()
+}
+
+/// Clasifica el origen de un import
+fn classify(source: &str) -> ImportGroup {
+    if source.starts_with('.') || source.starts_with('/') {
+        ImportGroup::Relative
+    } else if source.starts_with("@/") || source.starts_with("~/") || source.starts_with('#') {
+        ImportGroup::Alias
+    } else {
+        ImportGroup::External
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RuleSettings;
+    use crate::rules::testing::check;
+
+    fn settings() -> RuleSettings {
+        RuleSettings {
+            import_order: Some(vec![
+                ImportGroup::External,
+                ImportGroup::Alias,
+                ImportGroup::Relative,
+            ]),
+            ..RuleSettings::default()
+        }
+    }
+
+    #[test]
+    fn reports_the_first_import_out_of_order() {
+        let source = "\
+import { Injectable } from '@nestjs/common';
+import { Order } from './order';
+import { Config } from '@/config';
+import { Logger } from '@/logger';
+";
+        let findings = check(RULE_ID, "src/orders.ts", source, settings());
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].location.start_line, 3);
+        assert_eq!(
+            findings[0].message,
+            "Import '@/config' (alias) debe ir antes de los imports relative. Orden esperado: external → alias → relative."
+        );
+    }
+
+    #[test]
+    fn allows_imports_in_order() {
+        let source = "\
+import { Injectable } from '@nestjs/common';
+import { Observable } from 'rxjs';
+import { Config } from '@/config';
+import { Order } from './order';
+import { Item } from '../items/item';
+";
+        assert!(check(RULE_ID, "src/orders.ts", source, settings()).is_empty());
+        assert!(check(RULE_ID, "src/orders.ts", source, RuleSettings::default()).is_empty());
+    }
+
+    #[test]
+    fn classifies_sources_by_prefix() {
+        assert_eq!(classify("react"), ImportGroup::External);
+        assert_eq!(classify("@nestjs/core"), ImportGroup::External);
+        assert_eq!(classify("@/shared"), ImportGroup::Alias);
+        assert_eq!(classify("#config"), ImportGroup::Alias);
+        assert_eq!(classify("../order"), ImportGroup::Relative);
+    }
+}
@@ -0,0 +1,122 @@
+//! Convención de nombres de interfaces: con o sin prefijo `I`
+//!
+//! Un nombre tiene prefijo si empieza con `I` seguida de otra mayúscula
+//! (`IUserRepository`, no `Item`). Las interfaces dentro de `declare global` o
+//! `declare module` no se revisan: amplían una interfaz existente (`Window`,
+//! `Request`) y deben llamarse igual que ella.
+
+use super::{Finding, RuleContext, Severity};
+use crate::config::InterfaceNaming;
+use swc_ecma_ast::{TsInterfaceDecl, TsModuleDecl};
+use swc_ecma_visit::{Visit, VisitWith};
+
+pub const RULE_ID: &str = "interface-naming";
+
+/// Reporta cada interfaz cuyo nombre no respeta la convención configurada
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    let naming = match rc.ctx.settings.interface_naming {
+        Some(naming) => naming,
+        None => return Vec::new(),
+    };
+
+    let mut visitor = InterfaceNamingVisitor {
+        rc,
+        naming,
+        findings: Vec::new(),
+    };
+    rc.module.visit_with(&mut visitor);
+    visitor.findings
+}
+
+struct InterfaceNamingVisitor<'a, 'b> {
+    rc: &'a RuleContext<'b>,
+    naming: InterfaceNaming,
+    findings: Vec<Finding>,
+}
+
+impl Visit for InterfaceNamingVisitor<'_, '_> {
+    fn visit_ts_interface_decl(&mut self, n: &TsInterfaceDecl) {
+        let name = &*n.id.sym;
+        let message = match self.naming {
+            InterfaceNaming::NoIPrefix if has_i_prefix(name) => format!(
+                "La interfaz '{}' usa el prefijo I. Renómbrala a '{}'.",
+                name,
+                &name[1..]
+            ),
+            InterfaceNaming::RequireIPrefix if !has_i_prefix(name) => format!(
+                "La interfaz '{}' no usa el prefijo I. Renómbrala a 'I{}'.",
+                name, name
+            ),
+            _ => return,
+        };
+        self.findings
+            .push(self.rc.finding(RULE_ID, Severity::Warning, n.span, message));
+    }
+
+    fn visit_ts_module_decl(&mut self, n: &TsModuleDecl) {
+        if !n.declare {
+            n.visit_children_with(self);
+        }
+    }
+}
+
+/// `I` seguida de otra mayúscula
+fn has_i_prefix(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next() == Some('I') && chars.next().is_some_and(|c| c.is_ascii_uppercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RuleSettings;
+    use crate::rules::testing::check;
+
+    const SOURCE: &str = "\
+interface IUserRepository {}
+interface Item {}
+export interface OrderService {}
+declare global {
+    interface IWindowExtras {}
+}
+";
+
+    fn settings(naming: InterfaceNaming) -> RuleSettings {
+        RuleSettings {
+            interface_naming: Some(naming),
+            ..RuleSettings::default()
+        }
+    }
+
+    fn lines(naming: InterfaceNaming) -> Vec<usize> {
+        check(RULE_ID, "src/domain/types.ts", SOURCE, settings(naming))
+            .iter()
+            .map(|f| f.location.start_line)
+            .collect()
+    }
+
+    #[test]
+    fn reports_prefix_against_configured_convention() {
+        // `Item` no tiene prefijo y `declare global` no se revisa
+        assert_eq!(lines(InterfaceNaming::NoIPrefix), vec![1]);
+        assert_eq!(lines(InterfaceNaming::RequireIPrefix), vec![2, 3]);
+
+        let findings = check(
+            RULE_ID,
+            "src/domain/types.ts",
+            SOURCE,
+            settings(InterfaceNaming::NoIPrefix),
+        );
+        assert_eq!(
+            findings[0].message,
+            "La interfaz 'IUserRepository' usa el prefijo I. Renómbrala a 'UserRepository'."
+        );
+    }
+
+    #[test]
+    fn detects_i_prefix() {
+        assert!(has_i_prefix("IUser"));
+        assert!(!has_i_prefix("Item"));
+        assert!(!has_i_prefix("I"));
+    }
+}
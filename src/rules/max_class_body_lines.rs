@@ -0,0 +1,212 @@
+//! Máximo de líneas en el cuerpo de una clase, sin boilerplate
+//!
+//! En NestJS y Angular el constructor (inyección de dependencias) y los
+//! decoradores (`@Get()`, `@Column()`, `@Input()`...) inflan las clases sin
+//! agregar lógica. Se cuentan las líneas ocupadas por los miembros, sin las
+//! líneas en blanco entre ellos y sin las partes de `max_class_body_lines_exclude`:
+//! por defecto el constructor y las líneas que solo tienen decoradores.
+
+use super::{Finding, RuleContext, Severity};
+use crate::config::ClassBodyExclusion;
+use std::collections::BTreeSet;
+use swc_common::{BytePos, Span, Spanned};
+use swc_ecma_ast::{Class, ClassDecl, ClassExpr, ClassMember, Decorator};
+use swc_ecma_visit::{Visit, VisitWith};
+
+pub const RULE_ID: &str = "max-class-body-lines";
+
+/// Advierte sobre las clases cuyo cuerpo supera el máximo configurado
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    if rc.ctx.settings.max_class_body_lines == 0 {
+        return Vec::new();
+    }
+
+    let mut visitor = ClassBodyVisitor {
+        rc,
+        findings: Vec::new(),
+    };
+    rc.module.visit_with(&mut visitor);
+    visitor.findings
+}
+
+struct ClassBodyVisitor<'a, 'b> {
+    rc: &'a RuleContext<'b>,
+    findings: Vec<Finding>,
+}
+
+impl ClassBodyVisitor<'_, '_> {
+    fn line(&self, pos: BytePos) -> usize {
+        self.rc.cm.lookup_char_pos(pos).line
+    }
+
+    fn lines(&self, span: Span) -> std::ops::RangeInclusive<usize> {
+        self.line(span.lo)..=self.line(span.hi)
+    }
+
+    /// Líneas del cuerpo que cuentan para el máximo
+    fn body_lines(&self, class: &Class) -> usize {
+        let settings = &self.rc.ctx.settings;
+        let excludes = |exclusion| settings.max_class_body_lines_exclude.contains(&exclusion);
+
+        let mut counted = BTreeSet::new();
+        for member in &class.body {
+            if matches!(member, ClassMember::Constructor(_))
+                && excludes(ClassBodyExclusion::Constructor)
+            {
+                continue;
+            }
+            let (decorators, key_span) = decorators_and_key(member);
+            counted.extend(self.lines(member.span()));
+            // Los decoradores pueden quedar fuera del span del miembro
+            for decorator in decorators {
+                counted.extend(self.lines(decorator.span));
+            }
+            if excludes(ClassBodyExclusion::Decorators) {
+                // La línea del nombre cuenta aunque empiece con un decorador (`@Input() name`)
+                let key_line = key_span.map(|span| self.line(span.lo));
+                for decorator in decorators {
+                    for line in self.lines(decorator.span) {
+                        if Some(line) != key_line {
+                            counted.remove(&line);
+                        }
+                    }
+                }
+            }
+        }
+        counted.len()
+    }
+
+    fn check_class(&mut self, name: &str, span: Span, class: &Class) {
+        let max = self.rc.ctx.settings.max_class_body_lines;
+        let lines = self.body_lines(class);
+        if lines <= max {
+            return;
+        }
+
+        let excluded: Vec<&str> = self
+            .rc
+            .ctx
+            .settings
+            .max_class_body_lines_exclude
+            .iter()
+            .map(|exclusion| match exclusion {
+                ClassBodyExclusion::Constructor => "constructor",
+                ClassBodyExclusion::Decorators => "decoradores",
+            })
+            .collect();
+        let detail = if excluded.is_empty() {
+            String::new()
+        } else {
+            format!(" (sin {})", excluded.join(" ni "))
+        };
+        self.findings.push(self.rc.finding(
+            RULE_ID,
+            Severity::Warning,
+            span,
+            format!(
+                "La clase '{}' tiene {} líneas de cuerpo{}. Máximo: {}. Extrae responsabilidades a otras clases.",
+                name, lines, detail, max
+            ),
+        ));
+    }
+}
+
+impl Visit for ClassBodyVisitor<'_, '_> {
+    fn visit_class_decl(&mut self, n: &ClassDecl) {
+        self.check_class(&n.ident.sym, n.ident.span, &n.class);
+        n.visit_children_with(self);
+    }
+
+    fn visit_class_expr(&mut self, n: &ClassExpr) {
+        match &n.ident {
+            Some(ident) => self.check_class(&ident.sym, ident.span, &n.class),
+            None => self.check_class("(anónima)", n.class.span, &n.class),
+        }
+        n.visit_children_with(self);
+    }
+}
+
+/// Decoradores de un miembro y el span de su nombre
+fn decorators_and_key(member: &ClassMember) -> (&[Decorator], Option<Span>) {
+    match member {
+        ClassMember::Method(method) => (&method.function.decorators, Some(method.key.span())),
+        ClassMember::PrivateMethod(method) => (&method.function.decorators, Some(method.key.span)),
+        ClassMember::ClassProp(prop) => (&prop.decorators, Some(prop.key.span())),
+        ClassMember::PrivateProp(prop) => (&prop.decorators, Some(prop.key.span)),
+        _ => (&[], None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RuleSettings;
+    use crate::rules::testing::check;
+
+    const SOURCE: &str = "\
+export class OrdersController {
+    constructor(
+        private readonly service: OrdersService,
+    ) {}
+
+    @Get(':id')
+    find(id: string) {
+        return this.service.find(id);
+    }
+
+    @Input() name: string;
+}
+";
+
+    fn settings(max: usize, exclude: Vec<ClassBodyExclusion>) -> RuleSettings {
+        RuleSettings {
+            max_class_body_lines: max,
+            max_class_body_lines_exclude: exclude,
+            ..RuleSettings::default()
+        }
+    }
+
+    fn defaults() -> Vec<ClassBodyExclusion> {
+        RuleSettings::default().max_class_body_lines_exclude
+    }
+
+    #[test]
+    fn skips_the_constructor_and_decorator_lines_by_default() {
+        // find (3 líneas) y la propiedad, cuyo decorador comparte línea con el nombre
+        let findings = check(
+            RULE_ID,
+            "src/orders.controller.ts",
+            SOURCE,
+            settings(3, defaults()),
+        );
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].location.start_line, 1);
+        assert_eq!(
+            findings[0].message,
+            "La clase 'OrdersController' tiene 4 líneas de cuerpo (sin constructor ni decoradores). Máximo: 3. Extrae responsabilidades a otras clases."
+        );
+        assert!(check(
+            RULE_ID,
+            "src/orders.controller.ts",
+            SOURCE,
+            settings(4, defaults())
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn counts_every_member_line_without_exclusions() {
+        // Las líneas en blanco entre miembros nunca cuentan
+        let findings = check(
+            RULE_ID,
+            "src/orders.controller.ts",
+            SOURCE,
+            settings(7, Vec::new()),
+        );
+        assert_eq!(findings.len(), 1);
+        assert_eq!(
+            findings[0].message,
+            "La clase 'OrdersController' tiene 8 líneas de cuerpo. Máximo: 7. Extrae responsabilidades a otras clases."
+        );
+    }
+}
@@ -0,0 +1,134 @@
+//! Máximo de decoradores por clase, método o propiedad
+//!
+//! Una pila larga de decoradores (guards, interceptores, caché, validación...)
+//! suele indicar demasiadas responsabilidades transversales en un mismo punto.
+
+use super::{prop_name_to_string, Finding, RuleContext, Severity};
+use swc_ecma_ast::{
+    ClassDecl, ClassExpr, ClassMethod, ClassProp, Decorator, PrivateMethod, PrivateProp,
+};
+use swc_ecma_visit::{Visit, VisitWith};
+
+pub const RULE_ID: &str = "max-decorators";
+
+/// Advierte sobre los elementos con más decoradores que el máximo configurado
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    if rc.ctx.settings.max_decorators == 0 {
+        return Vec::new();
+    }
+
+    let mut visitor = DecoratorsVisitor {
+        rc,
+        findings: Vec::new(),
+    };
+    rc.module.visit_with(&mut visitor);
+    visitor.findings
+}
+
+struct DecoratorsVisitor<'a, 'b> {
+    rc: &'a RuleContext<'b>,
+    findings: Vec<Finding>,
+}
+
+impl DecoratorsVisitor<'_, '_> {
+    fn check_decorators(&mut self, name: &str, decorators: &[Decorator]) {
+        let max = self.rc.ctx.settings.max_decorators;
+        if decorators.len() <= max {
+            return;
+        }
+
+        // Se señala la pila completa de decoradores
+        let span = decorators[0].span.to(decorators[decorators.len() - 1].span);
+        self.findings.push(self.rc.finding(
+            RULE_ID,
+            Severity::Warning,
+            span,
+            format!(
+                "'{}' tiene {} decoradores. Máximo: {}.",
+                name,
+                decorators.len(),
+                max
+            ),
+        ));
+    }
+}
+
+impl Visit for DecoratorsVisitor<'_, '_> {
+    fn visit_class_decl(&mut self, n: &ClassDecl) {
+        self.check_decorators(&n.ident.sym, &n.class.decorators);
+        n.visit_children_with(self);
+    }
+
+    fn visit_class_expr(&mut self, n: &ClassExpr) {
+        let name = n.ident.as_ref().map_or("(anónima)", |ident| &*ident.sym);
+        self.check_decorators(name, &n.class.decorators);
+        n.visit_children_with(self);
+    }
+
+    fn visit_class_method(&mut self, n: &ClassMethod) {
+        let name = prop_name_to_string(&n.key);
+        self.check_decorators(&name, &n.function.decorators);
+        n.visit_children_with(self);
+    }
+
+    fn visit_private_method(&mut self, n: &PrivateMethod) {
+        let name = format!("#{}", n.key.id.sym);
+        self.check_decorators(&name, &n.function.decorators);
+        n.visit_children_with(self);
+    }
+
+    fn visit_class_prop(&mut self, n: &ClassProp) {
+        let name = prop_name_to_string(&n.key);
+        self.check_decorators(&name, &n.decorators);
+        n.visit_children_with(self);
+    }
+
+    fn visit_private_prop(&mut self, n: &PrivateProp) {
+        let name = format!("#{}", n.key.id.sym);
+        self.check_decorators(&name, &n.decorators);
+        n.visit_children_with(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RuleSettings;
+    use crate::rules::testing::check;
+
+    const SOURCE: &str = "\
+@Controller('orders')
+@UseGuards(AuthGuard)
+export class OrdersController {
+    @Get(':id')
+    @UseInterceptors(CacheInterceptor)
+    @Roles('admin')
+    find() {}
+}
+";
+
+    fn settings(max: usize) -> RuleSettings {
+        RuleSettings {
+            max_decorators: max,
+            ..RuleSettings::default()
+        }
+    }
+
+    #[test]
+    fn reports_the_whole_decorator_stack() {
+        let findings = check(RULE_ID, "src/orders.controller.ts", SOURCE, settings(2));
+        assert_eq!(findings.len(), 1);
+        assert_eq!(
+            findings[0].message,
+            "'find' tiene 3 decoradores. Máximo: 2."
+        );
+        assert_eq!(findings[0].location.start_line, 4);
+        assert_eq!(findings[0].location.end_line, 6);
+    }
+
+    #[test]
+    fn allows_stacks_within_the_maximum() {
+        assert!(check(RULE_ID, "src/orders.controller.ts", SOURCE, settings(3)).is_empty());
+        assert!(check(RULE_ID, "src/orders.controller.ts", SOURCE, settings(0)).is_empty());
+    }
+}
@@ -0,0 +1,112 @@
+//! Fan-out: número de módulos distintos de los que importa un archivo
+//!
+//! Un archivo que importa de muchos módulos suele tener demasiadas responsabilidades.
+//! Los barrels (`index.*`) pueden quedar exentos porque su trabajo es agregar.
+
+use super::{Finding, RuleContext, Severity};
+use std::collections::HashSet;
+use swc_ecma_ast::{ModuleDecl, ModuleItem};
+
+pub const RULE_ID: &str = "max-imports";
+
+/// Advierte cuando el archivo importa de más fuentes distintas que el máximo configurado
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    let max = rc.ctx.settings.max_imports;
+    if max == 0 {
+        return Vec::new();
+    }
+
+    if rc.ctx.settings.max_imports_exempt_barrels && is_barrel(rc) {
+        return Vec::new();
+    }
+
+    let mut sources = HashSet::new();
+    let mut first_excess = None;
+
+    for item in &rc.module.body {
+        let (src, span) = match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => (&import.src.value, import.span),
+            ModuleItem::ModuleDecl(ModuleDecl::ExportAll(export)) => {
+                (&export.src.value, export.span)
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(export)) => match &export.src {
+                Some(src) => (&src.value, export.span),
+                None => continue,
+            },
+            _ => continue,
+        };
+
+        if sources.insert(src.to_string()) && sources.len() == max + 1 {
+            first_excess = Some(span);
+        }
+    }
+
+    match first_excess {
+        Some(span) => vec![rc.finding(
+            RULE_ID,
+            Severity::Warning,
+            span,
+            format!(
+                "El archivo importa de {} módulos distintos. Máximo: {}.",
+                sources.len(),
+                max
+            ),
+        )],
+        None => Vec::new(),
+    }
+}
+
+fn is_barrel(rc: &RuleContext) -> bool {
+    rc.path
+        .file_stem()
+        .is_some_and(|stem| stem.eq_ignore_ascii_case("index"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RuleSettings;
+    use crate::rules::testing::check;
+
+    const SOURCE: &str = "\
+import { a } from './a';
+import { b } from './a';
+import { c } from './c';
+export * from './d';
+";
+
+    fn settings(max: usize) -> RuleSettings {
+        RuleSettings {
+            max_imports: max,
+            ..RuleSettings::default()
+        }
+    }
+
+    #[test]
+    fn reports_the_first_import_over_the_maximum() {
+        let findings = check(RULE_ID, "src/orders.ts", SOURCE, settings(2));
+        assert_eq!(findings.len(), 1);
+        // './a' se importa dos veces pero cuenta como un módulo
+        assert_eq!(
+            findings[0].message,
+            "El archivo importa de 3 módulos distintos. Máximo: 2."
+        );
+        assert_eq!(findings[0].location.start_line, 4);
+    }
+
+    #[test]
+    fn allows_files_within_the_maximum() {
+        assert!(check(RULE_ID, "src/orders.ts", SOURCE, settings(3)).is_empty());
+    }
+
+    #[test]
+    fn barrels_can_be_exempt() {
+        assert!(check(RULE_ID, "src/index.ts", SOURCE, settings(2)).is_empty());
+
+        let counted = RuleSettings {
+            max_imports_exempt_barrels: false,
+            ..settings(2)
+        };
+        assert_eq!(check(RULE_ID, "src/index.ts", SOURCE, counted).len(), 1);
+    }
+}
@@ -0,0 +1,108 @@
+//! Máximo de miembros por interfaz o type literal
+//!
+//! Una interfaz con decenas de miembros suele mezclar varios conceptos: quien la
+//! implementa o la consume depende de todo aunque use una parte (segregación de
+//! interfaces). Aplica a `interface` y a `type X = { ... }`.
+
+use super::{Finding, RuleContext, Severity};
+use swc_common::Span;
+use swc_ecma_ast::{TsInterfaceDecl, TsType, TsTypeAliasDecl};
+use swc_ecma_visit::{Visit, VisitWith};
+
+pub const RULE_ID: &str = "max-interface-members";
+
+/// Advierte sobre interfaces y type literals con más miembros que el máximo configurado
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    if rc.ctx.settings.max_interface_members == 0 {
+        return Vec::new();
+    }
+
+    let mut visitor = InterfaceMembersVisitor {
+        rc,
+        findings: Vec::new(),
+    };
+    rc.module.visit_with(&mut visitor);
+    visitor.findings
+}
+
+struct InterfaceMembersVisitor<'a, 'b> {
+    rc: &'a RuleContext<'b>,
+    findings: Vec<Finding>,
+}
+
+impl InterfaceMembersVisitor<'_, '_> {
+    fn check_members(&mut self, name: &str, members: usize, span: Span) {
+        let max = self.rc.ctx.settings.max_interface_members;
+        if members <= max {
+            return;
+        }
+
+        self.findings.push(self.rc.finding(
+            RULE_ID,
+            Severity::Warning,
+            span,
+            format!(
+                "'{}' declara {} miembros. Máximo: {}. Divídelo en interfaces más pequeñas y cohesivas.",
+                name, members, max
+            ),
+        ));
+    }
+}
+
+impl Visit for InterfaceMembersVisitor<'_, '_> {
+    fn visit_ts_interface_decl(&mut self, n: &TsInterfaceDecl) {
+        self.check_members(&n.id.sym, n.body.body.len(), n.span);
+        n.visit_children_with(self);
+    }
+
+    fn visit_ts_type_alias_decl(&mut self, n: &TsTypeAliasDecl) {
+        if let TsType::TsTypeLit(literal) = &*n.type_ann {
+            self.check_members(&n.id.sym, literal.members.len(), n.span);
+        }
+        n.visit_children_with(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RuleSettings;
+    use crate::rules::testing::check;
+
+    const SOURCE: &str = "\
+export interface OrderRepository {
+    find(id: string): Order;
+    save(order: Order): void;
+    remove(id: string): void;
+}
+export type Point = { x: number; y: number };
+export type Id = string;
+";
+
+    fn settings(max: usize) -> RuleSettings {
+        RuleSettings {
+            max_interface_members: max,
+            ..RuleSettings::default()
+        }
+    }
+
+    #[test]
+    fn reports_interfaces_and_type_literals_above_the_maximum() {
+        let findings = check(RULE_ID, "src/ports.ts", SOURCE, settings(1));
+        let messages: Vec<&str> = findings.iter().map(|f| f.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "'OrderRepository' declara 3 miembros. Máximo: 1. Divídelo en interfaces más pequeñas y cohesivas.",
+                "'Point' declara 2 miembros. Máximo: 1. Divídelo en interfaces más pequeñas y cohesivas.",
+            ]
+        );
+    }
+
+    #[test]
+    fn allows_interfaces_within_the_maximum() {
+        assert_eq!(check(RULE_ID, "src/ports.ts", SOURCE, settings(2)).len(), 1);
+        assert!(check(RULE_ID, "src/ports.ts", SOURCE, settings(3)).is_empty());
+        assert!(check(RULE_ID, "src/ports.ts", SOURCE, settings(0)).is_empty());
+    }
+}
@@ -0,0 +1,125 @@
+//! Literales de objeto/array demasiado grandes (datos embebidos en el código)
+//!
+//! Tablas de lookup o configuraciones gigantes conviene moverlas a archivos de datos.
+//! A diferencia de max-lines, aplica también a literales fuera de funciones.
+
+use super::{Finding, RuleContext, Severity};
+use swc_common::Span;
+use swc_ecma_ast::{ArrayLit, ObjectLit};
+use swc_ecma_visit::{Visit, VisitWith};
+
+pub const RULE_ID: &str = "max-literal-size";
+
+/// Advierte sobre literales con demasiadas entradas o demasiadas líneas
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    let settings = &rc.ctx.settings;
+    if settings.max_literal_size == 0 && settings.max_literal_lines == 0 {
+        return Vec::new();
+    }
+
+    let mut visitor = LiteralVisitor {
+        rc,
+        findings: Vec::new(),
+    };
+    rc.module.visit_with(&mut visitor);
+    visitor.findings
+}
+
+struct LiteralVisitor<'a, 'b> {
+    rc: &'a RuleContext<'b>,
+    findings: Vec<Finding>,
+}
+
+impl LiteralVisitor<'_, '_> {
+    /// Devuelve true si el literal se reportó (sus literales internos no se revisan)
+    fn check_literal(&mut self, kind: &str, span: Span, entries: usize) -> bool {
+        let settings = &self.rc.ctx.settings;
+        let lines =
+            self.rc.cm.lookup_char_pos(span.hi).line - self.rc.cm.lookup_char_pos(span.lo).line + 1;
+
+        let message = if settings.max_literal_size > 0 && entries > settings.max_literal_size {
+            format!(
+                "{} literal con {} entradas. Máximo: {}. Muévelo a un archivo de datos.",
+                kind, entries, settings.max_literal_size
+            )
+        } else if settings.max_literal_lines > 0 && lines > settings.max_literal_lines {
+            format!(
+                "{} literal de {} líneas. Máximo: {}. Muévelo a un archivo de datos.",
+                kind, lines, settings.max_literal_lines
+            )
+        } else {
+            return false;
+        };
+
+        self.findings
+            .push(self.rc.finding(RULE_ID, Severity::Warning, span, message));
+        true
+    }
+}
+
+impl Visit for LiteralVisitor<'_, '_> {
+    fn visit_object_lit(&mut self, n: &ObjectLit) {
+        if !self.check_literal("Objeto", n.span, n.props.len()) {
+            n.visit_children_with(self);
+        }
+    }
+
+    fn visit_array_lit(&mut self, n: &ArrayLit) {
+        if !self.check_literal("Array", n.span, n.elems.len()) {
+            n.visit_children_with(self);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RuleSettings;
+    use crate::rules::testing::check;
+
+    const SOURCE: &str = "\
+export const PRICES = {
+    basic: [1, 2, 3, 4],
+    pro: 20,
+    team: {
+        seats: 5,
+    },
+};
+";
+
+    fn settings(size: usize, lines: usize) -> RuleSettings {
+        RuleSettings {
+            max_literal_size: size,
+            max_literal_lines: lines,
+            ..RuleSettings::default()
+        }
+    }
+
+    #[test]
+    fn reports_literals_with_too_many_entries() {
+        let findings = check(RULE_ID, "src/prices.ts", SOURCE, settings(3, 0));
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].location.start_line, 2);
+        assert_eq!(
+            findings[0].message,
+            "Array literal con 4 entradas. Máximo: 3. Muévelo a un archivo de datos."
+        );
+    }
+
+    #[test]
+    fn reports_only_the_outer_literal_when_it_is_too_long() {
+        let findings = check(RULE_ID, "src/prices.ts", SOURCE, settings(0, 5));
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].location.start_line, 1);
+        assert_eq!(
+            findings[0].message,
+            "Objeto literal de 7 líneas. Máximo: 5. Muévelo a un archivo de datos."
+        );
+    }
+
+    #[test]
+    fn allows_literals_within_the_limits() {
+        assert!(check(RULE_ID, "src/prices.ts", SOURCE, settings(4, 7)).is_empty());
+        assert!(check(RULE_ID, "src/prices.ts", SOURCE, settings(0, 0)).is_empty());
+    }
+}
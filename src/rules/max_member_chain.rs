@@ -0,0 +1,125 @@
+//! Máximo de accesos encadenados a propiedades (ley de Demeter)
+//!
+//! `order.customer.address.city.name` atraviesa varios objetos y acopla el código
+//! a la estructura interna de cada uno. Las llamadas a métodos cortan la cadena
+//! por defecto, para no penalizar los builders fluidos (`qb.where().orderBy()`).
+
+use super::{Finding, RuleContext, Severity};
+use swc_common::{Span, Spanned};
+use swc_ecma_ast::{Callee, Expr, OptChainBase};
+use swc_ecma_visit::{Visit, VisitWith};
+
+pub const RULE_ID: &str = "max-member-chain";
+
+/// Advierte sobre las cadenas de accesos más largas que el máximo configurado
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    if rc.ctx.settings.max_member_chain == 0 {
+        return Vec::new();
+    }
+
+    let mut visitor = MemberChainVisitor {
+        rc,
+        reported: None,
+        findings: Vec::new(),
+    };
+    rc.module.visit_with(&mut visitor);
+    visitor.findings
+}
+
+struct MemberChainVisitor<'a, 'b> {
+    rc: &'a RuleContext<'b>,
+    /// Última cadena reportada: sus tramos interiores no se vuelven a reportar
+    reported: Option<Span>,
+    findings: Vec<Finding>,
+}
+
+impl MemberChainVisitor<'_, '_> {
+    /// Accesos consecutivos desde el final de la expresión hacia su objeto base
+    fn chain_len(&self, expr: &Expr) -> usize {
+        let allow_calls = self.rc.ctx.settings.max_member_chain_allow_calls;
+        match expr {
+            Expr::Member(member) => 1 + self.chain_len(&member.obj),
+            Expr::OptChain(chain) => match &*chain.base {
+                OptChainBase::Member(member) => 1 + self.chain_len(&member.obj),
+                OptChainBase::Call(call) if !allow_calls => self.chain_len(&call.callee),
+                OptChainBase::Call(_) => 0,
+            },
+            Expr::Call(call) if !allow_calls => match &call.callee {
+                Callee::Expr(callee) => self.chain_len(callee),
+                _ => 0,
+            },
+            // `a!.b` es el mismo acceso que `a.b`
+            Expr::TsNonNull(non_null) => self.chain_len(&non_null.expr),
+            _ => 0,
+        }
+    }
+
+    /// Tramo inicial de una cadena ya reportada (`a.b.c` dentro de `a.b.c.d.e`)
+    fn is_reported_prefix(&self, span: Span) -> bool {
+        self.reported
+            .is_some_and(|reported| reported.lo == span.lo && span.hi <= reported.hi)
+    }
+}
+
+impl Visit for MemberChainVisitor<'_, '_> {
+    fn visit_expr(&mut self, n: &Expr) {
+        let span = n.span();
+        if !self.is_reported_prefix(span) {
+            let max = self.rc.ctx.settings.max_member_chain;
+            let len = self.chain_len(n);
+            if len > max {
+                self.findings.push(self.rc.finding(
+                    RULE_ID,
+                    Severity::Warning,
+                    span,
+                    format!(
+                        "Cadena de {} accesos encadenados. Máximo: {}. Pide al objeto lo que necesitas en lugar de recorrer su estructura.",
+                        len, max
+                    ),
+                ));
+                self.reported = Some(span);
+            }
+        }
+        n.visit_children_with(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RuleSettings;
+    use crate::rules::testing::check;
+
+    const SOURCE: &str = "\
+export const city = order.customer.address.city.name;
+export const street = order.customer.address;
+export const page = qb.where(a).orderBy(b).limit(c).offset(d);
+";
+
+    fn settings(max: usize, allow_calls: bool) -> RuleSettings {
+        RuleSettings {
+            max_member_chain: max,
+            max_member_chain_allow_calls: allow_calls,
+            ..RuleSettings::default()
+        }
+    }
+
+    #[test]
+    fn reports_each_long_chain_once() {
+        let findings = check(RULE_ID, "src/orders.ts", SOURCE, settings(3, true));
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].location.start_line, 1);
+        assert_eq!(
+            findings[0].message,
+            "Cadena de 4 accesos encadenados. Máximo: 3. Pide al objeto lo que necesitas en lugar de recorrer su estructura."
+        );
+    }
+
+    #[test]
+    fn method_calls_cut_the_chain_unless_configured() {
+        let findings = check(RULE_ID, "src/orders.ts", SOURCE, settings(3, false));
+        let lines: Vec<usize> = findings.iter().map(|f| f.location.start_line).collect();
+        assert_eq!(lines, vec![1, 3]);
+        assert!(check(RULE_ID, "src/orders.ts", SOURCE, settings(4, true)).is_empty());
+    }
+}
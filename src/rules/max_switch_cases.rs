@@ -0,0 +1,107 @@
+//! Máximo de casos por `switch`
+//!
+//! Un `switch` con muchos casos suele indicar que falta polimorfismo o un patrón
+//! strategy: cada nuevo caso obliga a tocar el mismo bloque.
+
+use super::{Finding, RuleContext, Severity};
+use swc_ecma_ast::SwitchStmt;
+use swc_ecma_visit::{Visit, VisitWith};
+
+pub const RULE_ID: &str = "max-switch-cases";
+
+/// Advierte sobre los `switch` con más casos que el máximo configurado
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    if rc.ctx.settings.max_switch_cases == 0 {
+        return Vec::new();
+    }
+
+    let mut visitor = SwitchCasesVisitor {
+        rc,
+        findings: Vec::new(),
+    };
+    rc.module.visit_with(&mut visitor);
+    visitor.findings
+}
+
+struct SwitchCasesVisitor<'a, 'b> {
+    rc: &'a RuleContext<'b>,
+    findings: Vec<Finding>,
+}
+
+impl Visit for SwitchCasesVisitor<'_, '_> {
+    fn visit_switch_stmt(&mut self, n: &SwitchStmt) {
+        let settings = &self.rc.ctx.settings;
+        let max = settings.max_switch_cases;
+
+        // `default` (case sin test) cuenta solo si así se configura
+        let cases = n
+            .cases
+            .iter()
+            .filter(|case| case.test.is_some() || !settings.max_switch_cases_exclude_default)
+            .count();
+
+        if cases > max {
+            self.findings.push(self.rc.finding(
+                RULE_ID,
+                Severity::Warning,
+                n.span,
+                format!(
+                    "Este switch tiene {} casos. Máximo: {}. Considera un mapa de handlers o polimorfismo.",
+                    cases, max
+                ),
+            ));
+        }
+
+        n.visit_children_with(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RuleSettings;
+    use crate::rules::testing::check;
+
+    const SOURCE: &str = "\
+export function label(status: string) {
+    switch (status) {
+        case 'new':
+            return 'Nuevo';
+        case 'paid':
+            return 'Pagado';
+        default:
+            return 'Desconocido';
+    }
+}
+";
+
+    fn settings(max: usize, exclude_default: bool) -> RuleSettings {
+        RuleSettings {
+            max_switch_cases: max,
+            max_switch_cases_exclude_default: exclude_default,
+            ..RuleSettings::default()
+        }
+    }
+
+    #[test]
+    fn counts_default_only_when_configured() {
+        assert!(check(RULE_ID, "src/label.ts", SOURCE, settings(2, true)).is_empty());
+
+        let findings = check(RULE_ID, "src/label.ts", SOURCE, settings(2, false));
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].location.start_line, 2);
+        assert_eq!(
+            findings[0].message,
+            "Este switch tiene 3 casos. Máximo: 2. Considera un mapa de handlers o polimorfismo."
+        );
+    }
+
+    #[test]
+    fn reports_switches_above_the_maximum() {
+        assert_eq!(
+            check(RULE_ID, "src/label.ts", SOURCE, settings(1, true)).len(),
+            1
+        );
+        assert!(check(RULE_ID, "src/label.ts", SOURCE, settings(0, false)).is_empty());
+    }
+}
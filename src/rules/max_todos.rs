@@ -0,0 +1,129 @@
+//! Máximo de comentarios TODO/FIXME/HACK por archivo
+//!
+//! Cada marcador es deuda técnica anotada y pendiente. Acotarlos por archivo
+//! evita que se acumulen sin que nadie los atienda.
+
+use super::{Finding, RuleContext, Severity};
+use crate::analyzer;
+use swc_common::comments::{Comment, SingleThreadedComments};
+use swc_ecma_parser::{lexer::Lexer, StringInput};
+
+pub const RULE_ID: &str = "max-todos";
+
+/// Marcadores de deuda técnica por defecto
+pub fn default_todo_markers() -> Vec<String> {
+    ["TODO", "FIXME", "HACK"]
+        .iter()
+        .map(|marker| marker.to_string())
+        .collect()
+}
+
+/// Advierte sobre los archivos con más comentarios marcados que el máximo,
+/// señalando el primer comentario que lo supera
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    let settings = &rc.ctx.settings;
+    let max = settings.max_todos;
+    if max == 0 {
+        return Vec::new();
+    }
+
+    let mut marked: Vec<Comment> = collect_comments(rc)
+        .into_iter()
+        .filter(|comment| has_marker(&comment.text, &settings.todo_markers))
+        .collect();
+    if marked.len() <= max {
+        return Vec::new();
+    }
+    marked.sort_by_key(|comment| comment.span.lo);
+
+    vec![rc.finding(
+        RULE_ID,
+        Severity::Warning,
+        marked[max].span,
+        format!(
+            "El archivo tiene {} comentarios {} (máximo {}). Resuelve los pendientes o conviértelos en issues.",
+            marked.len(),
+            settings.todo_markers.join("/"),
+            max
+        ),
+    )]
+}
+
+/// El AST no conserva los comentarios: se vuelve a tokenizar el archivo con un
+/// almacén de comentarios (solo cuando la regla está activa)
+fn collect_comments(rc: &RuleContext) -> Vec<Comment> {
+    let extension = rc.path.extension().and_then(|e| e.to_str()).unwrap_or("");
+    let syntax = match analyzer::syntax_for_extension(extension) {
+        Some(syntax) => syntax,
+        None => return Vec::new(),
+    };
+
+    let comments = SingleThreadedComments::default();
+    let lexer = Lexer::new(
+        syntax,
+        Default::default(),
+        StringInput::from(rc.fm),
+        Some(&comments),
+    );
+    lexer.for_each(drop);
+
+    let (leading, trailing) = comments.take_all();
+    let leading = leading.borrow();
+    let trailing = trailing.borrow();
+    leading
+        .values()
+        .chain(trailing.values())
+        .flatten()
+        .cloned()
+        .collect()
+}
+
+/// El marcador debe aparecer como palabra completa (`TODO:` sí, `TODOS` no)
+fn has_marker(text: &str, markers: &[String]) -> bool {
+    text.split(|c: char| !c.is_alphanumeric() && c != '_')
+        .any(|word| markers.iter().any(|marker| marker == word))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RuleSettings;
+    use crate::rules::testing::check;
+
+    const SOURCE: &str = "\
+// TODO: validar el pedido
+export function save() {
+    /* FIXME revisar el total */
+    return 1; // HACK temporal
+}
+// Los TODOS del equipo no cuentan
+";
+
+    fn settings(max: usize) -> RuleSettings {
+        RuleSettings {
+            max_todos: max,
+            ..RuleSettings::default()
+        }
+    }
+
+    #[test]
+    fn reports_the_first_comment_over_the_maximum() {
+        let findings = check(RULE_ID, "src/save.ts", SOURCE, settings(2));
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].location.start_line, 4);
+        assert_eq!(
+            findings[0].message,
+            "El archivo tiene 3 comentarios TODO/FIXME/HACK (máximo 2). Resuelve los pendientes o conviértelos en issues."
+        );
+        assert!(check(RULE_ID, "src/save.ts", SOURCE, settings(3)).is_empty());
+    }
+
+    #[test]
+    fn markers_must_be_whole_words() {
+        let markers = default_todo_markers();
+        assert!(has_marker(" TODO: algo", &markers));
+        assert!(has_marker("FIXME(ana)", &markers));
+        assert!(!has_marker(" TODOS los casos", &markers));
+        assert!(!has_marker(" todo en minúsculas", &markers));
+    }
+}
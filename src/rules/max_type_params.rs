@@ -0,0 +1,143 @@
+//! Máximo de parámetros genéricos por declaración
+//!
+//! APIs como `function f<A, B, C, D, E>()` son difíciles de usar y suelen indicar
+//! una abstracción excesiva. Aplica a funciones, métodos, clases, interfaces y type aliases.
+
+use super::{prop_name_to_string, Finding, RuleContext, Severity};
+use swc_ecma_ast::{
+    ArrowExpr, ClassDecl, ClassExpr, ClassMethod, FnDecl, FnExpr, TsInterfaceDecl, TsTypeAliasDecl,
+    TsTypeParamDecl,
+};
+use swc_ecma_visit::{Visit, VisitWith};
+
+pub const RULE_ID: &str = "max-type-params";
+
+/// Advierte sobre declaraciones con más parámetros de tipo que el máximo configurado
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    if rc.ctx.settings.max_type_params == 0 {
+        return Vec::new();
+    }
+
+    let mut visitor = TypeParamsVisitor {
+        rc,
+        findings: Vec::new(),
+    };
+    rc.module.visit_with(&mut visitor);
+    visitor.findings
+}
+
+struct TypeParamsVisitor<'a, 'b> {
+    rc: &'a RuleContext<'b>,
+    findings: Vec<Finding>,
+}
+
+impl TypeParamsVisitor<'_, '_> {
+    fn check_params(&mut self, name: &str, type_params: Option<&TsTypeParamDecl>) {
+        let max = self.rc.ctx.settings.max_type_params;
+        let decl = match type_params {
+            Some(decl) if decl.params.len() > max => decl,
+            _ => return,
+        };
+
+        self.findings.push(self.rc.finding(
+            RULE_ID,
+            Severity::Warning,
+            decl.span,
+            format!(
+                "'{}' declara {} parámetros genéricos. Máximo: {}.",
+                name,
+                decl.params.len(),
+                max
+            ),
+        ));
+    }
+}
+
+impl Visit for TypeParamsVisitor<'_, '_> {
+    fn visit_fn_decl(&mut self, n: &FnDecl) {
+        self.check_params(&n.ident.sym, n.function.type_params.as_deref());
+        n.visit_children_with(self);
+    }
+
+    fn visit_fn_expr(&mut self, n: &FnExpr) {
+        let name = n.ident.as_ref().map_or("(anónima)", |ident| &*ident.sym);
+        self.check_params(name, n.function.type_params.as_deref());
+        n.visit_children_with(self);
+    }
+
+    fn visit_arrow_expr(&mut self, n: &ArrowExpr) {
+        self.check_params("(arrow)", n.type_params.as_deref());
+        n.visit_children_with(self);
+    }
+
+    fn visit_class_method(&mut self, n: &ClassMethod) {
+        let name = prop_name_to_string(&n.key);
+        self.check_params(&name, n.function.type_params.as_deref());
+        n.visit_children_with(self);
+    }
+
+    fn visit_class_decl(&mut self, n: &ClassDecl) {
+        self.check_params(&n.ident.sym, n.class.type_params.as_deref());
+        n.visit_children_with(self);
+    }
+
+    fn visit_class_expr(&mut self, n: &ClassExpr) {
+        let name = n.ident.as_ref().map_or("(anónima)", |ident| &*ident.sym);
+        self.check_params(name, n.class.type_params.as_deref());
+        n.visit_children_with(self);
+    }
+
+    fn visit_ts_interface_decl(&mut self, n: &TsInterfaceDecl) {
+        self.check_params(&n.id.sym, n.type_params.as_deref());
+        n.visit_children_with(self);
+    }
+
+    fn visit_ts_type_alias_decl(&mut self, n: &TsTypeAliasDecl) {
+        self.check_params(&n.id.sym, n.type_params.as_deref());
+        n.visit_children_with(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RuleSettings;
+    use crate::rules::testing::check;
+
+    const SOURCE: &str = "\
+export interface Mapper<A, B> {
+    map(value: A): B;
+}
+export type Join<A, B, C> = [A, B, C];
+export class Cache<K, V> {
+    merge<A, B, C>(a: A, b: B, c: C) {}
+}
+";
+
+    fn settings(max: usize) -> RuleSettings {
+        RuleSettings {
+            max_type_params: max,
+            ..RuleSettings::default()
+        }
+    }
+
+    #[test]
+    fn reports_declarations_above_the_maximum() {
+        let findings = check(RULE_ID, "src/types.ts", SOURCE, settings(2));
+        let messages: Vec<&str> = findings.iter().map(|f| f.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "'Join' declara 3 parámetros genéricos. Máximo: 2.",
+                "'merge' declara 3 parámetros genéricos. Máximo: 2.",
+            ]
+        );
+        assert_eq!(findings[1].location.start_line, 6);
+    }
+
+    #[test]
+    fn allows_declarations_within_the_maximum() {
+        assert!(check(RULE_ID, "src/types.ts", SOURCE, settings(3)).is_empty());
+        assert!(check(RULE_ID, "src/types.ts", SOURCE, settings(0)).is_empty());
+    }
+}
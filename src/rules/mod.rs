@@ -0,0 +1,986 @@
+//! Catálogo de reglas del linter
+//!
+//! Cada regla tiene un identificador estable que se usa en la salida, en la
+//! configuración y en las herramientas externas (extensiones de editor, generadores).
+
+use crate::analyzer;
+use crate::config::{ArchError, ArchWarning, LinterContext, RuleLevel};
+use miette::SourceSpan;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use swc_common::{SourceFile, SourceMap, Span};
+use swc_ecma_ast::{Module, PropName};
+
+pub mod async_controller_error_handling;
+pub mod barrel_purity;
+pub mod cognitive_complexity;
+pub mod dto_location;
+pub mod enum_location;
+pub mod export_style;
+pub mod feature_isolation;
+pub mod forbid_cross_layer_types;
+pub mod import_order;
+pub mod interface_naming;
+pub mod max_class_body_lines;
+pub mod max_decorators;
+pub mod max_imports;
+pub mod max_interface_members;
+pub mod max_literal_size;
+pub mod max_member_chain;
+pub mod max_switch_cases;
+pub mod max_todos;
+pub mod max_type_params;
+pub mod no_browser_globals;
+pub mod no_concrete_events;
+pub mod no_cross_layer_new;
+pub mod no_default_export;
+pub mod no_direct_logger;
+pub mod no_direct_orm;
+pub mod no_generic_error;
+pub mod no_magic_numbers;
+pub mod no_manual_di_instantiation;
+pub mod no_mutable_module_state;
+pub mod no_non_null_assertion;
+pub mod no_param_reassign;
+pub mod no_repository_to_service;
+pub mod no_self_package_import;
+pub mod no_service_to_service;
+pub mod no_sync_fs;
+pub mod no_weak_public_return;
+pub mod prefer_early_return;
+pub mod test_public_api_only;
+
+/// Categoría de los archivos con errores de sintaxis: se reportan aparte de las
+/// violaciones y no detienen el análisis del resto del proyecto
+pub const PARSE_ERROR_ID: &str = "parse-error";
+
+/// Metadatos de una regla disponible en el linter
+#[derive(Debug, Clone, Serialize)]
+pub struct RuleMeta {
+    /// Identificador estable de la regla (ej: "max-lines")
+    pub id: &'static str,
+    /// Descripción corta de lo que valida
+    pub description: &'static str,
+    /// Severidad por defecto ("error" o "warning")
+    pub severity: &'static str,
+    /// Campos de architect.json que controlan la regla
+    pub config_keys: &'static [&'static str],
+    /// Sugerencia concreta para corregir una violación
+    pub help: &'static str,
+    /// Por qué importa la regla (architect-linter explain)
+    pub rationale: &'static str,
+    /// Código que viola la regla
+    pub bad_example: &'static str,
+    /// El mismo código corregido
+    pub good_example: &'static str,
+}
+
+/// Registro de todas las reglas conocidas
+pub static RULES: &[RuleMeta] = &[
+    RuleMeta {
+        id: "forbidden-imports",
+        description: "Prohíbe imports entre capas según las reglas de forbidden_imports",
+        severity: "error",
+        config_keys: &["forbidden_imports"],
+        help: "Invierte la dependencia: define una abstracción (interfaz o puerto) en la capa que importa e inyecta la implementación",
+        rationale: "Las capas internas (dominio) no deben conocer a las externas (infraestructura, UI): si lo hacen, cualquier cambio técnico se propaga al núcleo del negocio.",
+        bad_example: "// src/domain/user.ts\nimport { UserRepository } from '../infrastructure/user.repository';",
+        good_example: "// src/domain/user.ts\nimport { UserRepositoryPort } from './ports/user-repository.port';",
+    },
+    RuleMeta {
+        id: "max-lines",
+        description: "Limita el número de líneas por método",
+        severity: "error",
+        config_keys: &["max_lines_per_function"],
+        help: "Extrae bloques del método (por ejemplo el cuerpo de un bucle) a métodos privados con nombres descriptivos",
+        rationale: "Los métodos largos mezclan varias responsabilidades y son difíciles de leer, probar y revisar.",
+        bad_example: "async register(dto) {\n  // ...80 líneas de validación, persistencia y envío de emails\n}",
+        good_example: "async register(dto) {\n  const user = this.validate(dto);\n  await this.users.save(user);\n  await this.notifyWelcome(user);\n}",
+    },
+    RuleMeta {
+        id: "mvc-controller-repository",
+        description: "Prohíbe importar repositorios directamente en controladores (activa en NestJS/MVC)",
+        severity: "error",
+        config_keys: &["rules", "pattern_scopes"],
+        help: "Mueve el acceso a datos a un servicio y haz que el controlador dependa del servicio",
+        rationale: "Un controlador que habla con el repositorio salta la capa de servicios y deja la lógica de negocio en la capa HTTP.",
+        bad_example: "@Controller('users')\nexport class UsersController {\n  constructor(private repo: UserRepository) {}\n}",
+        good_example: "@Controller('users')\nexport class UsersController {\n  constructor(private users: UsersService) {}\n}",
+    },
+    RuleMeta {
+        id: "no-repository-to-service",
+        description: "Prohíbe que un repositorio (*.repository.*) importe un servicio (*.service.*) (activa en NestJS o con un patrón de capas)",
+        severity: "error",
+        config_keys: &["rules", "pattern_scopes"],
+        help: "Invierte la dependencia: el servicio recibe el repositorio (o su interfaz del dominio) y el repositorio no conoce a ningún servicio",
+        rationale: "El repositorio es infraestructura al servicio del dominio: si importa servicios, la dependencia apunta al revés y aparecen ciclos servicio ↔ repositorio.",
+        bad_example: "// src/users/user.repository.ts\nimport { UsersService } from './users.service';",
+        good_example: "// src/users/users.service.ts\nimport { UserRepository } from './user.repository';",
+    },
+    RuleMeta {
+        id: PARSE_ERROR_ID,
+        description: "Archivo con errores de sintaxis: se omiten sus reglas de AST y se reportan los hallazgos parciales",
+        severity: "error",
+        config_keys: &[],
+        help: "Corrige la sintaxis o, si usa sintaxis no soportada, excluye el archivo con ignored_paths",
+        rationale: "Sin un AST válido no se pueden aplicar las reglas del archivo; el error se reporta aparte para no ocultarlo.",
+        bad_example: "export function total(items {\n  return items.length;\n}",
+        good_example: "export function total(items) {\n  return items.length;\n}",
+    },
+    RuleMeta {
+        id: "sealed-paths",
+        description: "Solo permite importar el index de un directorio sellado desde fuera",
+        severity: "error",
+        config_keys: &["sealed_paths"],
+        help: "Importa desde el index del directorio sellado; si necesitas algo interno, expórtalo en ese index",
+        rationale: "Un directorio sellado expone una API pública en su index; importar sus internals acopla el resto del código a detalles que pueden cambiar.",
+        bad_example: "import { hashPassword } from '@/auth/internal/crypto';",
+        good_example: "import { hashPassword } from '@/auth';",
+    },
+    RuleMeta {
+        id: "allowed-imports",
+        description: "Solo permite imports internos hacia las carpetas de la lista blanca",
+        severity: "error",
+        config_keys: &["allowed_imports"],
+        help: "Importa solo desde las carpetas permitidas o añade la carpeta a allowed_imports si la dependencia es intencional",
+        rationale: "Una lista blanca de dependencias hace explícito de qué puede depender cada capa.",
+        bad_example: "// src/domain/order.ts\nimport { Logger } from '../shared/logging/winston';",
+        good_example: "// src/domain/order.ts\nimport { Money } from './value-objects/money';",
+    },
+    RuleMeta {
+        id: "cognitive-complexity",
+        description: "Advierte sobre funciones con complejidad cognitiva excesiva",
+        severity: "warning",
+        config_keys: &["max_cognitive_complexity"],
+        help: "Usa retornos tempranos para reducir el anidamiento y extrae las condiciones complejas a funciones con nombre",
+        rationale: "El anidamiento y las condiciones encadenadas hacen que el código sea difícil de seguir aunque tenga pocas líneas.",
+        bad_example: "if (user) {\n  if (user.active) {\n    for (const o of user.orders) {\n      if (o.paid) { /* ... */ }\n    }\n  }\n}",
+        good_example: "if (!user?.active) return;\nfor (const o of user.orders.filter(isPaid)) { /* ... */ }",
+    },
+    RuleMeta {
+        id: "max-imports",
+        description: "Advierte sobre archivos que importan de demasiados módulos distintos",
+        severity: "warning",
+        config_keys: &["max_imports", "max_imports_exempt_barrels"],
+        help: "Divide el archivo por responsabilidades o agrupa dependencias relacionadas detrás de un módulo fachada",
+        rationale: "Un archivo que importa de muchos módulos suele tener demasiadas responsabilidades.",
+        bad_example: "import { a } from './a';\nimport { b } from './b';\n// ...20 imports más",
+        good_example: "import { billing } from './billing';\nimport { shipping } from './shipping';",
+    },
+    RuleMeta {
+        id: "no-generic-error",
+        description: "Prohíbe lanzar Error/TypeError genéricos en las capas configuradas",
+        severity: "warning",
+        config_keys: &["no_generic_error"],
+        help: "Lanza un error de dominio específico (ej: class UserNotFoundError extends Error) en lugar de Error genérico",
+        rationale: "Los errores genéricos no se pueden distinguir al capturarlos y ocultan el significado del fallo.",
+        bad_example: "throw new Error('User not found');",
+        good_example: "throw new UserNotFoundError(userId);",
+    },
+    RuleMeta {
+        id: "no-sync-fs",
+        description: "Prohíbe llamadas síncronas de fs (readFileSync...) en las capas configuradas",
+        severity: "warning",
+        config_keys: &["no_sync_fs"],
+        help: "Usa la variante asíncrona (fs.promises o readFile con await) para no bloquear el event loop",
+        rationale: "Las llamadas síncronas de fs bloquean el event loop y frenan todas las peticiones en curso.",
+        bad_example: "const config = fs.readFileSync('config.json', 'utf8');",
+        good_example: "const config = await fs.promises.readFile('config.json', 'utf8');",
+    },
+    RuleMeta {
+        id: "no-browser-globals",
+        description: "Prohíbe window/document/localStorage/navigator fuera de la capa de presentación",
+        severity: "warning",
+        config_keys: &["no_browser_globals"],
+        help: "Encapsula el acceso al navegador en un adaptador de la capa de presentación e inyéctalo donde se necesite",
+        rationale: "El dominio y los servicios que usan APIs del navegador no pueden ejecutarse ni probarse fuera de él (SSR, tests).",
+        bad_example: "// src/services/session.ts\nlocalStorage.setItem('token', token);",
+        good_example: "// src/services/session.ts\nthis.storage.set('token', token); // adaptador inyectado",
+    },
+    RuleMeta {
+        id: "no-concrete-events",
+        description: "Prohíbe instanciar EventEmitter o importar el bus de eventos del framework en el dominio",
+        severity: "error",
+        config_keys: &["no_concrete_events"],
+        help: "Define un puerto (ej: interface DomainEventPublisher) en el dominio e inyecta la implementación del framework",
+        rationale: "El dominio que crea su propio EventEmitter o usa el bus del framework queda atado a esa tecnología.",
+        bad_example: "// src/domain/order.ts\nconst bus = new EventEmitter();\nbus.emit('order.created', order);",
+        good_example: "// src/domain/order.ts\nthis.events.publish(new OrderCreated(order));",
+    },
+    RuleMeta {
+        id: "no-direct-orm",
+        description: "Prohíbe importar ORMs (typeorm, @prisma/client, mongoose...) en las capas de aplicación/dominio",
+        severity: "error",
+        config_keys: &["no_direct_orm"],
+        help: "Define una interfaz de repositorio en el dominio e impleméntala con el ORM en la capa de infraestructura",
+        rationale: "Con el ORM importado en el dominio, el modelo de negocio depende del esquema de la base de datos.",
+        bad_example: "// src/domain/user.ts\nimport { Entity, Column } from 'typeorm';",
+        good_example: "// src/domain/user.repository.ts\nexport interface UserRepository { findById(id: string): Promise<User>; }",
+    },
+    RuleMeta {
+        id: "no-direct-logger",
+        description: "Prohíbe importar librerías de logging (winston, pino, bunyan...) en las capas de aplicación/dominio",
+        severity: "error",
+        config_keys: &["no_direct_logger"],
+        help: "Define un puerto Logger en el dominio, recíbelo por constructor e impleméntalo con la librería en infraestructura",
+        rationale: "Con el logger importado en el dominio, cambiar de librería o silenciarlo en los tests obliga a tocar la lógica de negocio.",
+        bad_example: "// src/domain/order.service.ts\nimport pino from 'pino';\nconst log = pino();",
+        good_example: "// src/domain/order.service.ts\nexport class OrderService {\n  constructor(private logger: Logger) {}\n}",
+    },
+    RuleMeta {
+        id: "async-controller-error-handling",
+        description: "Advierte (heurística) sobre handlers async de controladores con await fuera de try/catch",
+        severity: "warning",
+        config_keys: &["async_controller_error_handling"],
+        help: "Envuelve los await en try/catch y delega el error (next(err)), o registra el handler con un wrapper como asyncHandler",
+        rationale: "En Express una promesa rechazada en un handler no llega al middleware de errores: la petición queda colgada o el proceso cae.",
+        bad_example: "async findAll(req, res) {\n  const users = await this.users.findAll();\n  res.json(users);\n}",
+        good_example: "async findAll(req, res, next) {\n  try {\n    res.json(await this.users.findAll());\n  } catch (err) {\n    next(err);\n  }\n}",
+    },
+    RuleMeta {
+        id: "forbid-cross-layer-types",
+        description: "Modo estricto: prohíbe los imports de solo tipos (import type) hacia capas más externas",
+        severity: "error",
+        config_keys: &["forbid_cross_layer_types"],
+        help: "Declara el tipo en la capa interna (o en un puerto) y haz que la capa externa lo implemente o lo mapee",
+        rationale: "Aunque desaparezca al compilar, un tipo de infraestructura usado en el dominio lo ata a la forma de esa capa: un cambio de esquema obliga a tocar el núcleo.",
+        bad_example: "// src/domain/order.ts\nimport type { OrderEntity } from '../infrastructure/order.entity';",
+        good_example: "// src/domain/order.ts\nexport interface Order { id: string; total: number; }",
+    },
+    RuleMeta {
+        id: "no-cross-layer-new",
+        description: "Prohíbe instanciar con new clases importadas de otra capa (activa en NestJS/Angular)",
+        severity: "error",
+        config_keys: &["no_cross_layer_new"],
+        help: "Declara la dependencia en el constructor y deja que el contenedor de inyección la provea",
+        rationale: "Instanciar dependencias de otra capa salta el contenedor de inyección y acopla las capas.",
+        bad_example: "export class UsersController {\n  private users = new UsersService();\n}",
+        good_example: "export class UsersController {\n  constructor(private users: UsersService) {}\n}",
+    },
+    RuleMeta {
+        id: "no-manual-di-instantiation",
+        description: "Prohíbe instanciar con new clases @Injectable() (providers) fuera de tests y factories",
+        severity: "error",
+        config_keys: &["no_manual_di_instantiation"],
+        help: "Declara el provider en el constructor y regístralo en el módulo; si necesitas construirlo a mano, hazlo en una factory (useFactory)",
+        rationale: "Un provider creado con new no recibe sus dependencias ni los hooks de ciclo de vida del contenedor, y los tests no pueden sustituirlo por un mock.",
+        bad_example: "export class OrdersController {\n  private mailer = new MailerService();\n}",
+        good_example: "export class OrdersController {\n  constructor(private mailer: MailerService) {}\n}",
+    },
+    RuleMeta {
+        id: "no-default-export",
+        description: "Prohíbe export default salvo en los archivos permitidos (páginas, configs, stories)",
+        severity: "error",
+        config_keys: &["no_default_export"],
+        help: "Exporta por nombre (export class/function/const) y actualiza los imports a la forma { Nombre }",
+        rationale: "Los exports nombrados mantienen el mismo nombre en todos los imports y funcionan mejor con los renombrados.",
+        bad_example: "export default class UsersService {}",
+        good_example: "export class UsersService {}",
+    },
+    RuleMeta {
+        id: "feature-isolation",
+        description: "Prohíbe importar internals de otra feature; solo su index (carpetas bajo features_root)",
+        severity: "error",
+        config_keys: &["feature_isolation"],
+        help: "Exporta lo necesario desde el index de la otra feature e importa desde ahí, o mueve el código compartido a un módulo común",
+        rationale: "Si una feature usa internals de otra, ya no se pueden cambiar ni extraer por separado.",
+        bad_example: "// src/features/cart/cart.ts\nimport { priceOf } from '../catalog/utils/pricing';",
+        good_example: "// src/features/cart/cart.ts\nimport { priceOf } from '../catalog';",
+    },
+    RuleMeta {
+        id: "test-public-api-only",
+        description: "Advierte sobre tests que importan archivos internos del módulo bajo prueba en vez de su index",
+        severity: "warning",
+        config_keys: &["test_public_api_only"],
+        help: "Importa desde el index del módulo; si el test necesita un internal, agrégalo a 'allow'",
+        rationale: "Los tests atados a la estructura interna de un módulo se rompen con cada refactor aunque su comportamiento no cambie.",
+        bad_example: "// test/users.spec.ts\nimport { hash } from '../src/users/internal/hash';",
+        good_example: "// test/users.spec.ts\nimport { UsersService } from '../src/users';",
+    },
+    RuleMeta {
+        id: "no-service-to-service",
+        description: "Prohíbe que un *.service importe servicios de otra feature",
+        severity: "error",
+        config_keys: &["no_service_to_service"],
+        help: "Publica un comando o evento en el mediador/bus y deja que el servicio de la otra feature lo maneje",
+        rationale: "Los servicios de features distintas que se llaman directamente forman una red de dependencias difícil de romper.",
+        bad_example: "// src/orders/orders.service.ts\nimport { BillingService } from '../billing/billing.service';",
+        good_example: "// src/orders/orders.service.ts\nthis.mediator.send(new ChargeOrder(order.id));",
+    },
+    RuleMeta {
+        id: "no-self-package-import",
+        description: "Prohíbe que un archivo importe su propio paquete (el name del package.json más cercano) por nombre",
+        severity: "error",
+        config_keys: &["no_self_package_import"],
+        help: "Reemplaza el import por la ruta relativa al archivo dentro del paquete",
+        rationale: "Importarse por el nombre publicado pasa por los exports o el build del paquete: el bundler puede duplicar módulos y aparecen ciclos ocultos.",
+        bad_example: "// packages/pkg/src/service.ts (package.json: \"@org/pkg\")\nimport { slugify } from '@org/pkg/util';",
+        good_example: "// packages/pkg/src/service.ts\nimport { slugify } from './util';",
+    },
+    RuleMeta {
+        id: "dto-location",
+        description: "Prohíbe declarar DTOs (*Dto o con decoradores de validación) fuera de las carpetas configuradas",
+        severity: "error",
+        config_keys: &["dto_location"],
+        help: "Mueve el DTO a la capa de presentación y mapea a entidades de dominio en el controlador o caso de uso",
+        rationale: "Un DTO en el dominio acopla el modelo de negocio al formato de la API.",
+        bad_example: "// src/domain/create-user.dto.ts\nexport class CreateUserDto {\n  @IsEmail() email: string;\n}",
+        good_example: "// src/presentation/dto/create-user.dto.ts\nexport class CreateUserDto {\n  @IsEmail() email: string;\n}",
+    },
+    RuleMeta {
+        id: "enum-location",
+        description: "Prohíbe declarar enums fuera de las carpetas configuradas (vocabulario compartido)",
+        severity: "error",
+        config_keys: &["enum_location"],
+        help: "Mueve el enum a la carpeta configurada (ej: src/domain/enums/) e impórtalo desde las capas que lo usan",
+        rationale: "Los enums duplicados en varias capas terminan con valores distintos para el mismo concepto.",
+        bad_example: "// src/services/order.service.ts\nenum OrderStatus { Pending, Paid }",
+        good_example: "// src/domain/enums/order-status.enum.ts\nexport enum OrderStatus { Pending, Paid }",
+    },
+    RuleMeta {
+        id: "barrel-purity",
+        description: "Advierte sobre barrels (index.ts/index.js) con sentencias que no son imports ni re-exports",
+        severity: "warning",
+        config_keys: &["barrel_purity"],
+        help: "Mueve la función, clase o llamada a su propio módulo y re-expórtala desde el barrel",
+        rationale: "La lógica de un barrel se ejecuta al importar cualquier cosa de la carpeta y suele provocar ciclos.",
+        bad_example: "// src/users/index.ts\nexport * from './users.service';\nexport function formatUser(u) {\n  return u.name;\n}",
+        good_example: "// src/users/index.ts\nexport * from './users.service';\nexport { formatUser } from './format-user';",
+    },
+    RuleMeta {
+        id: "no-magic-numbers",
+        description: "Advierte sobre literales numéricos (salvo 0, 1 y -1) en la lógica de las capas configuradas",
+        severity: "warning",
+        config_keys: &["no_magic_numbers"],
+        help: "Extrae el valor a una constante con nombre (const SESSION_TTL_SECONDS = 3600) o a la configuración",
+        rationale: "Un literal suelto no explica qué representa y obliga a buscarlo por todo el código para cambiarlo.",
+        bad_example: "setTimeout(retry, 3600000);",
+        good_example: "const ONE_HOUR_MS = 3_600_000;\nsetTimeout(retry, ONE_HOUR_MS);",
+    },
+    RuleMeta {
+        id: "no-non-null-assertion",
+        description: "Advierte sobre las aserciones non-null (valor!) de TypeScript, salvo en los archivos permitidos (tests)",
+        severity: "warning",
+        config_keys: &["no_non_null_assertion"],
+        help: "Comprueba el valor (if, optional chaining ?. o ??) o corrige el tipo para que no admita null",
+        rationale: "El operador ! silencia la comprobación de nulls del compilador: si la suposición falla, el error aparece en runtime lejos de su causa.",
+        bad_example: "const email = this.users.find(id)!.email;",
+        good_example: "const user = this.users.find(id);\nif (!user) throw new UserNotFoundError(id);\nconst email = user.email;",
+    },
+    RuleMeta {
+        id: "no-mutable-module-state",
+        description: "Advierte sobre let/var del nivel superior y constantes de módulo que el archivo modifica",
+        severity: "warning",
+        config_keys: &["no_mutable_module_state"],
+        help: "Mueve el estado a una clase o función que lo reciba como dependencia; si es un singleton intencional, agrégalo a 'allow'",
+        rationale: "El estado de módulo lo comparten todos los que importan el archivo: acopla partes del código sin que se vea en las firmas y hace que un test dependa de lo que dejó el anterior.",
+        bad_example: "let requests = 0;\nconst cache = {};\n\nexport function track(key, value) {\n  requests++;\n  cache[key] = value;\n}",
+        good_example: "export class RequestTracker {\n  private requests = 0;\n  private cache = new Map();\n\n  track(key, value) {\n    this.requests++;\n    this.cache.set(key, value);\n  }\n}",
+    },
+    RuleMeta {
+        id: "no-param-reassign",
+        description: "Advierte sobre funciones que reasignan sus parámetros o modifican sus propiedades (heurística, sin análisis de alias)",
+        severity: "warning",
+        config_keys: &["no_param_reassign"],
+        help: "Usa una variable local en lugar de reasignar el parámetro y trabaja sobre una copia ({ ...obj }, [...arr]) en lugar de modificarlo",
+        rationale: "Modificar un objeto recibido cambia también el de quien llamó a la función: el efecto aparece lejos de su causa y es difícil de rastrear.",
+        bad_example: "function applyDiscount(order, rate) {\n  order.total = order.total * (1 - rate);\n  return order;\n}",
+        good_example: "function applyDiscount(order, rate) {\n  return { ...order, total: order.total * (1 - rate) };\n}",
+    },
+    RuleMeta {
+        id: "no-weak-public-return",
+        description: "Advierte sobre funciones exportadas y métodos públicos que retornan any/unknown o, sin anotación, un valor any (JSON.parse, .json())",
+        severity: "warning",
+        config_keys: &["no_weak_public_return"],
+        help: "Declara el tipo de retorno con una interfaz o tipo concreto; si el valor viene de JSON, valídalo y conviértelo a ese tipo",
+        rationale: "Un any en el retorno de la API pública desactiva el tipado en todo el código que la usa, sin que el compilador lo señale.",
+        bad_example: "export class UsersClient {\n  async find(id: string): Promise<any> {\n    return (await fetch(`/users/${id}`)).json();\n  }\n}",
+        good_example: "export class UsersClient {\n  async find(id: string): Promise<User> {\n    return parseUser(await (await fetch(`/users/${id}`)).json());\n  }\n}",
+    },
+    RuleMeta {
+        id: "import-order",
+        description: "Exige agrupar los imports: externos, alias internos y relativos",
+        severity: "warning",
+        config_keys: &["import_order"],
+        help: "Reordena los imports: primero paquetes externos, luego alias internos y al final rutas relativas",
+        rationale: "Un orden de imports consistente hace evidente de qué depende cada archivo y reduce conflictos de merge.",
+        bad_example: "import { User } from './user';\nimport { Injectable } from '@nestjs/common';",
+        good_example: "import { Injectable } from '@nestjs/common';\n\nimport { User } from './user';",
+    },
+    RuleMeta {
+        id: "export-style",
+        description: "Exige un estilo de exports por archivo: default-only, named-only o no-mixing",
+        severity: "warning",
+        config_keys: &["export_style"],
+        help: "Separa el export conflictivo a su propio módulo o conviértelo al estilo configurado",
+        rationale: "Mezclar estilos de export obliga a recordar cómo se importa cada archivo.",
+        bad_example: "export default class Api {}\nexport const VERSION = 2;",
+        good_example: "export class Api {}\nexport const VERSION = 2;",
+    },
+    RuleMeta {
+        id: "interface-naming",
+        description: "Exige una convención para el prefijo I de las interfaces: no-i-prefix o require-i-prefix",
+        severity: "warning",
+        config_keys: &["interface_naming"],
+        help: "Renombra la interfaz según la convención configurada",
+        rationale: "Mezclar IUserRepository y UserRepository en un mismo proyecto obliga a recordar cómo se llama cada tipo.",
+        bad_example: "// interface_naming: \"no-i-prefix\"\nexport interface IUserRepository {\n  findById(id: string): Promise<User>;\n}",
+        good_example: "export interface UserRepository {\n  findById(id: string): Promise<User>;\n}",
+    },
+    RuleMeta {
+        id: "max-literal-size",
+        description: "Advierte sobre literales de objeto/array gigantes (datos embebidos en el código)",
+        severity: "warning",
+        config_keys: &["max_literal_size", "max_literal_lines"],
+        help: "Mueve los datos a un archivo JSON o a una fuente de datos externa y cárgalos en tiempo de ejecución",
+        rationale: "Los datos embebidos en el código inflan los archivos y mezclan datos con lógica.",
+        bad_example: "const COUNTRIES = [\n  { code: 'AR', name: 'Argentina' },\n  // ...200 entradas más\n];",
+        good_example: "import COUNTRIES from './countries.json';",
+    },
+    RuleMeta {
+        id: "max-type-params",
+        description: "Advierte sobre declaraciones con demasiados parámetros genéricos",
+        severity: "warning",
+        config_keys: &["max_type_params"],
+        help: "Agrupa parámetros genéricos relacionados en un tipo de opciones o divide la declaración",
+        rationale: "Muchos parámetros genéricos hacen que la API sea difícil de usar y de inferir.",
+        bad_example: "function request<TReq, TRes, TErr, TCtx, TMeta>() {}",
+        good_example: "function request<T extends RequestTypes>() {}",
+    },
+    RuleMeta {
+        id: "max-decorators",
+        description: "Advierte sobre clases, métodos o propiedades con demasiados decoradores",
+        severity: "warning",
+        config_keys: &["max_decorators"],
+        help: "Agrupa los decoradores relacionados en uno compuesto (ej: applyDecorators en NestJS) o reparte responsabilidades",
+        rationale: "Una pila larga de decoradores mezcla muchas responsabilidades transversales en un mismo elemento.",
+        bad_example: "@Get() @UseGuards(Auth) @Roles('admin') @ApiOkResponse() @ApiBearerAuth() @Throttle(10)\nfindAll() {}",
+        good_example: "@Get() @AdminEndpoint()\nfindAll() {}",
+    },
+    RuleMeta {
+        id: "max-interface-members",
+        description: "Advierte sobre interfaces o type literals con demasiados miembros",
+        severity: "warning",
+        config_keys: &["max_interface_members"],
+        help: "Segrega la interfaz en varias más pequeñas por responsabilidad y compónlas donde hagan falta (extends o &)",
+        rationale: "Una interfaz enorme obliga a implementadores y consumidores a depender de miembros que no usan.",
+        bad_example: "interface UserService {\n  create(): void;\n  sendEmail(): void;\n  exportCsv(): void;\n  // ...20 miembros más\n}",
+        good_example: "interface UserWriter { create(): void; }\ninterface UserNotifier { sendEmail(): void; }\ninterface UserExporter { exportCsv(): void; }",
+    },
+    RuleMeta {
+        id: "max-class-body-lines",
+        description: "Advierte sobre clases con demasiadas líneas de lógica, sin contar constructor ni decoradores (desactivada por defecto)",
+        severity: "warning",
+        config_keys: &["max_class_body_lines", "max_class_body_lines_exclude"],
+        help: "Extrae grupos de métodos relacionados a clases colaboradoras o servicios más pequeños",
+        rationale: "Una clase con cientos de líneas de lógica concentra demasiadas responsabilidades; el constructor y los decoradores de NestJS/Angular no cuentan porque son boilerplate inevitable.",
+        bad_example: "export class OrdersService {\n  create() { /* ... */ }\n  refund() { /* ... */ }\n  exportCsv() { /* ... */ }\n  // ...400 líneas más\n}",
+        good_example: "export class OrdersService {\n  constructor(private refunds: RefundsService, private exporter: OrdersExporter) {}\n  create() { /* ... */ }\n}",
+    },
+    RuleMeta {
+        id: "max-member-chain",
+        description: "Advierte sobre cadenas largas de accesos a propiedades (ley de Demeter)",
+        severity: "warning",
+        config_keys: &["max_member_chain", "max_member_chain_allow_calls"],
+        help: "Añade un método al objeto intermedio que devuelva lo que necesitas (order.shippingCity()) en lugar de recorrer su estructura",
+        rationale: "Recorrer varios objetos acopla el código a la estructura interna de cada uno: cualquier cambio intermedio lo rompe.",
+        bad_example: "const city = order.customer.address.city.name;",
+        good_example: "const city = order.shippingCityName();",
+    },
+    RuleMeta {
+        id: "max-switch-cases",
+        description: "Advierte sobre switch con demasiados casos",
+        severity: "warning",
+        config_keys: &["max_switch_cases", "max_switch_cases_exclude_default"],
+        help: "Sustituye el switch por un mapa de handlers (objeto o Map) o por polimorfismo",
+        rationale: "Un switch que crece con cada caso nuevo obliga a modificar el mismo bloque una y otra vez.",
+        bad_example: "switch (event.type) {\n  case 'created': /* ... */\n  // ...10 casos más\n}",
+        good_example: "const handlers = { created: onCreated, paid: onPaid };\nhandlers[event.type]?.(event);",
+    },
+    RuleMeta {
+        id: "max-todos",
+        description: "Advierte sobre archivos con más comentarios TODO/FIXME/HACK que el máximo (desactivada por defecto)",
+        severity: "warning",
+        config_keys: &["max_todos", "todo_markers"],
+        help: "Resuelve los pendientes o muévelos a issues del tracker y deja solo la referencia",
+        rationale: "Los marcadores acumulados son deuda técnica que nadie prioriza mientras viva solo en el código.",
+        bad_example: "// TODO: validar email\n// FIXME: race condition\n// HACK: reintento manual\n// TODO: paginar",
+        good_example: "// Ver #142: validación de email pendiente",
+    },
+    RuleMeta {
+        id: "prefer-early-return",
+        description: "Advierte sobre funciones cuyo cuerpo entero está envuelto en un if",
+        severity: "warning",
+        config_keys: &["prefer_early_return_min_statements"],
+        help: "Invierte la condición y retorna antes: if (!ok) return; y deja el resto sin anidar",
+        rationale: "Envolver toda la función en un if añade un nivel de anidamiento a toda su lógica.",
+        bad_example: "function save(user) {\n  if (user.valid) {\n    normalize(user);\n    store(user);\n    notify(user);\n  }\n}",
+        good_example: "function save(user) {\n  if (!user.valid) return;\n  normalize(user);\n  store(user);\n  notify(user);\n}",
+    },
+    RuleMeta {
+        id: "max-files-per-dir",
+        description: "Advierte sobre las carpetas con más de N archivos directos (sin contar subcarpetas)",
+        severity: "warning",
+        config_keys: &["max_files_per_dir", "max_files_per_dir_exempt"],
+        help: "Agrupa los archivos en subcarpetas por feature o responsabilidad; las carpetas planas por convención van en max_files_per_dir_exempt",
+        rationale: "Una carpeta con decenas de archivos sueltos no comunica su estructura y hace difícil encontrar qué depende de qué.",
+        bad_example: "src/services/\n  user.service.ts\n  order.service.ts\n  ... 40 archivos más",
+        good_example: "src/services/\n  users/user.service.ts\n  orders/order.service.ts",
+    },
+    RuleMeta {
+        id: "require-layer",
+        description: "Con patrón Hexagonal o Clean, reporta los archivos .ts que no están en ninguna carpeta de capa (require_layer o --require-layer)",
+        severity: "error",
+        config_keys: &["require_layer", "layer_paths", "require_layer_exempt"],
+        help: "Mueve el archivo a la capa que le corresponde; si la carpeta es una capa propia agrégala a layer_paths, y si no necesita capa a require_layer_exempt",
+        rationale: "Un archivo fuera de todas las capas escapa a las reglas entre capas: puede depender de cualquier cosa sin que el linter lo vea.",
+        bad_example: "src/domain/user.ts\nsrc/infrastructure/user.repository.ts\nsrc/helpers/hash.ts  // ¿qué capa?",
+        good_example: "src/domain/user.ts\nsrc/infrastructure/user.repository.ts\nsrc/infrastructure/crypto/hash.ts",
+    },
+    RuleMeta {
+        id: "no-unused-exports",
+        description: "Advierte sobre exports que ningún módulo del proyecto importa (excepto puntos de entrada y tests)",
+        severity: "warning",
+        config_keys: &["no_unused_exports"],
+        help: "Elimina el export o quita la palabra `export` si solo se usa dentro del archivo; si lo consume algo externo, agrega el archivo a 'entry_points'",
+        rationale: "Los exports muertos agrandan la API pública de cada módulo y hacen creer que hay dependientes que no existen.",
+        bad_example: "// src/users/format.ts\nexport function formatUser(u) { ... }\nexport function legacyFormat(u) { ... } // nadie lo importa",
+        good_example: "// src/users/format.ts\nexport function formatUser(u) { ... }",
+    },
+    RuleMeta {
+        id: "unresolved-import",
+        description: "Imports relativos que no resuelven a ningún archivo (--report-unresolved)",
+        severity: "error",
+        config_keys: &[],
+        help: "Corrige la ruta del import o crea el archivo que falta",
+        rationale: "Un import roto rompe la compilación o deja dependencias fuera del grafo de análisis.",
+        bad_example: "import { User } from './models/usr';",
+        good_example: "import { User } from './models/user';",
+    },
+    RuleMeta {
+        id: "circular-dependency",
+        description: "Detecta dependencias cíclicas entre módulos",
+        severity: "error",
+        config_keys: &["min_cycle_length", "max_cycle_length"],
+        help: "Extrae el código compartido a un módulo común o invierte una de las dependencias con una interfaz",
+        rationale: "Los ciclos impiden entender, probar o extraer los módulos por separado y causan errores de inicialización.",
+        bad_example: "// a.ts\nimport { b } from './b';\n// b.ts\nimport { a } from './a';",
+        good_example: "// a.ts y b.ts importan lo compartido desde shared.ts",
+    },
+];
+
+/// Sugerencia de corrección registrada para una regla
+pub fn help_for(rule_id: &str) -> Option<String> {
+    RULES
+        .iter()
+        .find(|rule| rule.id == rule_id)
+        .map(|rule| rule.help.to_string())
+}
+
+/// Severidad de un hallazgo, de mayor a menor (el orden de los variantes define
+/// el orden de la salida)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// Posición de un hallazgo: líneas y columnas (1-based) del inicio y el final del
+/// código señalado. Todo en 0 si no señala código (ej: ciclos).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Location {
+    pub start_line: usize,
+    pub start_col: usize,
+    pub end_line: usize,
+    pub end_col: usize,
+}
+
+impl Location {
+    /// Calcula la posición de un span una sola vez, al crear el hallazgo
+    pub fn from_span(cm: &SourceMap, span: Span) -> Self {
+        let start = cm.lookup_char_pos(span.lo);
+        let end = cm.lookup_char_pos(span.hi);
+        Self {
+            start_line: start.line,
+            start_col: start.col.0 + 1,
+            end_line: end.line,
+            end_col: end.col.0 + 1,
+        }
+    }
+
+    /// La línea completa (para los hallazgos que solo conocen su número de línea)
+    pub fn of_line(fm: &SourceFile, line: usize) -> Self {
+        let length = fm
+            .src
+            .lines()
+            .nth(line.saturating_sub(1))
+            .map_or(0, |content| content.chars().count());
+        Self {
+            start_line: line,
+            start_col: 1,
+            end_line: line,
+            end_col: length + 1,
+        }
+    }
+}
+
+/// Resultado de una regla sobre un archivo, listo para mostrarse
+pub struct Finding {
+    /// Identificador de la regla que lo produjo
+    pub rule_id: &'static str,
+    pub severity: Severity,
+    /// Dónde se reporta
+    pub location: Location,
+    /// Mensaje legible del problema
+    pub message: String,
+    /// Diagnóstico de miette con el código fuente señalado
+    pub report: miette::Report,
+}
+
+impl Finding {
+    /// Cambia la severidad reconstruyendo el diagnóstico con el mismo código señalado
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        if self.severity == severity {
+            return self;
+        }
+
+        let parts = self
+            .report
+            .downcast_ref::<ArchError>()
+            .map(|e| (e.src.clone(), e.span, e.message.clone(), e.help.clone()))
+            .or_else(|| {
+                self.report
+                    .downcast_ref::<ArchWarning>()
+                    .map(|w| (w.src.clone(), w.span, w.message.clone(), w.help.clone()))
+            });
+
+        if let Some((src, span, message, help)) = parts {
+            self.report = match severity {
+                Severity::Error => ArchError {
+                    src,
+                    span,
+                    message,
+                    help,
+                }
+                .into(),
+                Severity::Warning => ArchWarning {
+                    src,
+                    span,
+                    message,
+                    help,
+                }
+                .into(),
+            };
+        }
+        self.severity = severity;
+        self
+    }
+
+    /// Sugerencia de corrección del diagnóstico, si la tiene
+    pub fn help(&self) -> Option<&str> {
+        self.report
+            .downcast_ref::<ArchError>()
+            .map(|e| e.help.as_deref())
+            .or_else(|| {
+                self.report
+                    .downcast_ref::<ArchWarning>()
+                    .map(|w| w.help.as_deref())
+            })
+            .flatten()
+    }
+
+    /// Crea un hallazgo de severidad error a partir de un diagnóstico ya construido
+    pub fn error(
+        rule_id: &'static str,
+        location: Location,
+        message: String,
+        report: miette::Report,
+    ) -> Self {
+        Self {
+            rule_id,
+            severity: Severity::Error,
+            location,
+            message,
+            report,
+        }
+    }
+}
+
+/// Contexto compartido por las reglas que trabajan sobre el AST de swc
+pub struct RuleContext<'a> {
+    pub cm: &'a SourceMap,
+    pub fm: &'a SourceFile,
+    pub module: &'a Module,
+    pub path: &'a Path,
+    pub ctx: &'a LinterContext,
+}
+
+impl RuleContext<'_> {
+    /// Indica si el archivo actual coincide con alguno de los patrones de carpeta
+    pub fn path_matches(&self, patterns: &[String]) -> bool {
+        let file_path = self.path.to_string_lossy().to_lowercase();
+        patterns.iter().any(|pattern| {
+            analyzer::matches_pattern(&file_path, &analyzer::normalize_pattern(pattern))
+        })
+    }
+
+    /// Crea un hallazgo señalando un span del archivo
+    pub fn finding(
+        &self,
+        rule_id: &'static str,
+        severity: Severity,
+        span: Span,
+        message: String,
+    ) -> Finding {
+        let location = Location::from_span(self.cm, span);
+        let start = (span.lo.0 - self.fm.start_pos.0) as usize;
+        let end = (span.hi.0 - self.fm.start_pos.0) as usize;
+        let src = self.fm.src.to_string();
+        let source_span = SourceSpan::new(start.into(), end - start);
+        let help = help_for(rule_id);
+
+        let report = match severity {
+            Severity::Error => ArchError {
+                src,
+                span: source_span,
+                message: message.clone(),
+                help,
+            }
+            .into(),
+            Severity::Warning => ArchWarning {
+                src,
+                span: source_span,
+                message: message.clone(),
+                help,
+            }
+            .into(),
+        };
+
+        Finding {
+            rule_id,
+            severity,
+            location,
+            message,
+            report,
+        }
+    }
+}
+
+/// Aplica el mapa `rules` de la configuración: descarta las reglas en "off"
+/// y ajusta la severidad de las que tienen "warn" o "error"
+pub fn apply_rule_levels(findings: Vec<Finding>, ctx: &LinterContext) -> Vec<Finding> {
+    findings
+        .into_iter()
+        .filter_map(|finding| match ctx.rule_level(finding.rule_id) {
+            Some(RuleLevel::Off) => None,
+            Some(RuleLevel::Warn) => Some(finding.with_severity(Severity::Warning)),
+            Some(RuleLevel::Error) => Some(finding.with_severity(Severity::Error)),
+            None => Some(finding),
+        })
+        .collect()
+}
+
+/// Ejecuta todas las reglas basadas en AST sobre un módulo ya parseado
+pub fn run_ast_rules(rc: &RuleContext) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    findings.extend(cognitive_complexity::check(rc));
+    findings.extend(max_imports::check(rc));
+    findings.extend(no_generic_error::check(rc));
+    findings.extend(no_sync_fs::check(rc));
+    findings.extend(no_browser_globals::check(rc));
+    findings.extend(no_concrete_events::check(rc));
+    findings.extend(no_direct_orm::check(rc));
+    findings.extend(no_direct_logger::check(rc));
+    findings.extend(async_controller_error_handling::check(rc));
+    findings.extend(forbid_cross_layer_types::check(rc));
+    findings.extend(no_cross_layer_new::check(rc));
+    findings.extend(no_manual_di_instantiation::check(rc));
+    findings.extend(no_magic_numbers::check(rc));
+    findings.extend(no_non_null_assertion::check(rc));
+    findings.extend(no_weak_public_return::check(rc));
+    findings.extend(no_param_reassign::check(rc));
+    findings.extend(no_mutable_module_state::check(rc));
+    findings.extend(feature_isolation::check(rc));
+    findings.extend(test_public_api_only::check(rc));
+    findings.extend(no_service_to_service::check(rc));
+    findings.extend(no_repository_to_service::check(rc));
+    findings.extend(no_self_package_import::check(rc));
+    findings.extend(dto_location::check(rc));
+    findings.extend(enum_location::check(rc));
+    findings.extend(barrel_purity::check(rc));
+    findings.extend(import_order::check(rc));
+    findings.extend(export_style::check(rc));
+    findings.extend(interface_naming::check(rc));
+    findings.extend(no_default_export::check(rc));
+    findings.extend(max_literal_size::check(rc));
+    findings.extend(max_type_params::check(rc));
+    findings.extend(max_decorators::check(rc));
+    findings.extend(max_interface_members::check(rc));
+    findings.extend(max_class_body_lines::check(rc));
+    findings.extend(max_member_chain::check(rc));
+    findings.extend(max_switch_cases::check(rc));
+    findings.extend(max_todos::check(rc));
+    findings.extend(prefer_early_return::check(rc));
+    findings
+}
+
+/// Obtiene un nombre legible para la clave de un miembro de clase u objeto
+pub fn prop_name_to_string(key: &PropName) -> String {
+    match key {
+        PropName::Ident(ident) => ident.sym.to_string(),
+        PropName::Str(s) => s.value.to_string(),
+        PropName::Num(n) => n.value.to_string(),
+        _ => "[computed]".to_string(),
+    }
+}
+
+/// Utilidades para los tests de las reglas
+#[cfg(test)]
+pub(crate) mod testing {
+    use super::Finding;
+    use crate::analyzer;
+    use crate::config::{LinterContext, RuleSettings};
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use swc_common::SourceMap;
+
+    /// Hallazgos de `rule_id` al analizar `source` como si fuera el archivo `path`
+    pub fn findings_in(
+        rule_id: &str,
+        path: &str,
+        source: &str,
+        ctx: &LinterContext,
+    ) -> Vec<Finding> {
+        let cm = SourceMap::default();
+        analyzer::analyze_source(&cm, Path::new(path), source.to_string(), ctx)
+            .unwrap()
+            .into_iter()
+            .filter(|finding| finding.rule_id == rule_id)
+            .collect()
+    }
+
+    /// Como `findings_in`, con un proyecto sin framework ni patrón y `settings`
+    pub fn check(rule_id: &str, path: &str, source: &str, settings: RuleSettings) -> Vec<Finding> {
+        findings_in(rule_id, path, source, &LinterContext::for_tests(settings))
+    }
+
+    /// Proyecto temporal propio de cada test con `files` (ruta relativa, contenido),
+    /// para las reglas que resuelven imports en disco. Devuelve la raíz canonicalizada
+    pub fn temp_project(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("architect-linter-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        for (path, content) in files {
+            let path = dir.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        dir.canonicalize().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swc_common::{BytePos, FileName};
+
+    #[test]
+    fn location_from_span_reports_one_based_lines_and_columns() {
+        let cm = SourceMap::default();
+        let source = "const a = 1;\nconst total = a + 2;\n";
+        let fm = cm.new_source_file(FileName::Custom("a.ts".into()), source.to_string());
+        // `total = a + 2` en la segunda línea, hasta antes del `;`
+        let lo = source.find("total").unwrap() as u32;
+        let hi = source.rfind(';').unwrap() as u32;
+        let span = Span::new(
+            fm.start_pos + BytePos(lo),
+            fm.start_pos + BytePos(hi),
+            Default::default(),
+        );
+
+        let location = Location::from_span(&cm, span);
+        assert_eq!(
+            location,
+            Location {
+                start_line: 2,
+                start_col: 7,
+                end_line: 2,
+                end_col: 20,
+            }
+        );
+    }
+
+    #[test]
+    fn location_from_span_spans_several_lines() {
+        let cm = SourceMap::default();
+        let source = "function f() {\n  return 1;\n}\n";
+        let fm = cm.new_source_file(FileName::Custom("f.ts".into()), source.to_string());
+        let span = Span::new(
+            fm.start_pos,
+            fm.start_pos + BytePos(source.rfind('}').unwrap() as u32 + 1),
+            Default::default(),
+        );
+
+        let location = Location::from_span(&cm, span);
+        assert_eq!((location.start_line, location.start_col), (1, 1));
+        assert_eq!((location.end_line, location.end_col), (3, 2));
+    }
+
+    #[test]
+    fn location_of_line_covers_the_whole_line() {
+        let cm = SourceMap::default();
+        let fm = cm.new_source_file(
+            FileName::Custom("b.ts".into()),
+            "import a from './a';\nimport b from './b';\n".to_string(),
+        );
+        assert_eq!(
+            Location::of_line(&fm, 2),
+            Location {
+                start_line: 2,
+                start_col: 1,
+                end_line: 2,
+                end_col: 21,
+            }
+        );
+    }
+
+    #[test]
+    fn every_rule_renders_its_help() {
+        let cm = SourceMap::default();
+        let fm = cm.new_source_file(
+            FileName::Custom("a.ts".into()),
+            "export const a = 1;\n".to_string(),
+        );
+        let module = Module {
+            span: Default::default(),
+            body: Vec::new(),
+            shebang: None,
+        };
+        let ctx = LinterContext::for_tests(Default::default());
+        let rc = RuleContext {
+            cm: &cm,
+            fm: &fm,
+            module: &module,
+            path: Path::new("a.ts"),
+            ctx: &ctx,
+        };
+        let span = Span::new(fm.start_pos, fm.start_pos + BytePos(6), Default::default());
+
+        for rule in RULES {
+            assert!(!rule.help.is_empty(), "{} no tiene help", rule.id);
+            for severity in [Severity::Error, Severity::Warning] {
+                let finding = rc.finding(rule.id, severity, span, "mensaje".to_string());
+                assert_eq!(finding.help(), Some(rule.help), "{}", rule.id);
+
+                let mut rendered = String::new();
+                miette::GraphicalReportHandler::new_themed(miette::GraphicalTheme::none())
+                    .with_width(usize::MAX)
+                    .render_report(&mut rendered, finding.report.as_ref())
+                    .unwrap();
+                assert!(rendered.contains(rule.help), "{}: {}", rule.id, rendered);
+            }
+        }
+    }
+}
@@ -0,0 +1,157 @@
+//! Acceso directo a globals del navegador fuera de la capa de presentación
+//!
+//! Usar `window`, `document` o `localStorage` en servicios o dominio acopla la
+//! lógica al navegador y rompe el renderizado en servidor (SSR).
+
+use super::{Finding, RuleContext, Severity};
+use std::collections::HashSet;
+use swc_ecma_ast::{BindingIdent, Expr, ImportSpecifier, UnaryExpr, UnaryOp};
+use swc_ecma_visit::{Visit, VisitWith};
+
+pub const RULE_ID: &str = "no-browser-globals";
+
+/// Globals del navegador revisados por defecto
+pub fn default_browser_globals() -> Vec<String> {
+    [
+        "window",
+        "document",
+        "localStorage",
+        "sessionStorage",
+        "navigator",
+    ]
+    .iter()
+    .map(|g| g.to_string())
+    .collect()
+}
+
+/// Advierte sobre cada referencia a un global del navegador fuera de las capas permitidas
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    let rule = match &rc.ctx.settings.no_browser_globals {
+        Some(rule) => rule,
+        None => return Vec::new(),
+    };
+
+    if rc.path_matches(&rule.allowed_paths) {
+        return Vec::new();
+    }
+
+    // Un binding local con el mismo nombre (import, variable, parámetro) no es el global
+    let mut bindings = LocalBindings::default();
+    rc.module.visit_with(&mut bindings);
+
+    let mut visitor = BrowserGlobalVisitor {
+        rc,
+        globals: &rule.globals,
+        shadowed: bindings.names,
+        findings: Vec::new(),
+    };
+    rc.module.visit_with(&mut visitor);
+    visitor.findings
+}
+
+/// Nombres declarados en el módulo que ocultan a un global
+#[derive(Default)]
+struct LocalBindings {
+    names: HashSet<String>,
+}
+
+impl Visit for LocalBindings {
+    fn visit_binding_ident(&mut self, n: &BindingIdent) {
+        self.names.insert(n.id.sym.to_string());
+    }
+
+    fn visit_import_specifier(&mut self, n: &ImportSpecifier) {
+        let local = match n {
+            ImportSpecifier::Named(named) => &named.local,
+            ImportSpecifier::Default(default) => &default.local,
+            ImportSpecifier::Namespace(namespace) => &namespace.local,
+        };
+        self.names.insert(local.sym.to_string());
+    }
+}
+
+struct BrowserGlobalVisitor<'a, 'b> {
+    rc: &'a RuleContext<'b>,
+    globals: &'a [String],
+    shadowed: HashSet<String>,
+    findings: Vec<Finding>,
+}
+
+impl Visit for BrowserGlobalVisitor<'_, '_> {
+    fn visit_unary_expr(&mut self, n: &UnaryExpr) {
+        // `typeof window !== "undefined"` es justamente la guarda para SSR
+        if n.op == UnaryOp::TypeOf && matches!(&*n.arg, Expr::Ident(_)) {
+            return;
+        }
+        n.visit_children_with(self);
+    }
+
+    fn visit_expr(&mut self, n: &Expr) {
+        if let Expr::Ident(ident) = n {
+            let name: &str = &ident.sym;
+            if self.globals.iter().any(|g| g == name) && !self.shadowed.contains(name) {
+                self.findings.push(self.rc.finding(
+                    RULE_ID,
+                    Severity::Warning,
+                    ident.span,
+                    format!(
+                        "'{}' es un global del navegador. Accede a él solo desde la capa de presentación.",
+                        name
+                    ),
+                ));
+            }
+        }
+        n.visit_children_with(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{NoBrowserGlobalsRule, RuleSettings};
+    use crate::rules::testing::check;
+
+    const SOURCE: &str = "\
+export function saveToken(token: string) {
+    if (typeof window === 'undefined') {
+        return;
+    }
+    localStorage.setItem('token', token);
+    window.location.reload();
+}
+";
+
+    fn settings() -> RuleSettings {
+        RuleSettings {
+            no_browser_globals: Some(NoBrowserGlobalsRule {
+                allowed_paths: vec!["src/components/".to_string()],
+                globals: default_browser_globals(),
+            }),
+            ..RuleSettings::default()
+        }
+    }
+
+    #[test]
+    fn reports_globals_outside_the_presentation_layer() {
+        let findings = check(RULE_ID, "src/services/auth.ts", SOURCE, settings());
+        // La guarda `typeof window` no cuenta
+        let lines: Vec<usize> = findings.iter().map(|f| f.location.start_line).collect();
+        assert_eq!(lines, vec![5, 6]);
+        assert_eq!(
+            findings[0].message,
+            "'localStorage' es un global del navegador. Accede a él solo desde la capa de presentación."
+        );
+    }
+
+    #[test]
+    fn allows_presentation_layer_and_local_bindings() {
+        assert!(check(RULE_ID, "src/components/login.tsx", SOURCE, settings()).is_empty());
+
+        let shadowed = "\
+export function render(document: Doc) {
+    return document.title;
+}
+";
+        assert!(check(RULE_ID, "src/services/render.ts", shadowed, settings()).is_empty());
+    }
+}
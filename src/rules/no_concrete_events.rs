@@ -0,0 +1,171 @@
+//! Eventos concretos del framework dentro del dominio
+//!
+//! Instanciar `new EventEmitter()` o importar el bus de eventos del framework desde
+//! el dominio lo acopla a la infraestructura. El dominio debe publicar eventos a
+//! través de una abstracción inyectada (ej: un puerto `DomainEventPublisher`).
+
+use super::{Finding, RuleContext, Severity};
+use crate::config::Framework;
+use swc_ecma_ast::{Expr, ImportDecl, NewExpr};
+use swc_ecma_visit::{Visit, VisitWith};
+
+pub const RULE_ID: &str = "no-concrete-events";
+
+/// Clases de eventos que no deberían instanciarse en el dominio según el framework
+pub fn default_event_symbols(framework: &Framework) -> Vec<String> {
+    let symbols: &[&str] = match framework {
+        Framework::NestJS => &["EventEmitter", "EventEmitter2", "EventBus"],
+        _ => &["EventEmitter", "EventEmitter2"],
+    };
+    symbols.iter().map(|s| s.to_string()).collect()
+}
+
+/// Módulos de bus de eventos que no deberían importarse en el dominio según el framework
+pub fn default_event_modules(framework: &Framework) -> Vec<String> {
+    let mut modules = vec![
+        "events",
+        "node:events",
+        "eventemitter2",
+        "eventemitter3",
+        "mitt",
+    ];
+    if *framework == Framework::NestJS {
+        modules.extend(["@nestjs/event-emitter", "@nestjs/cqrs"]);
+    }
+    modules.into_iter().map(|m| m.to_string()).collect()
+}
+
+/// Reporta las instancias y los imports de eventos concretos en las capas configuradas
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    let rule = match &rc.ctx.settings.no_concrete_events {
+        Some(rule) => rule,
+        None => return Vec::new(),
+    };
+
+    if !rc.path_matches(&rule.paths) {
+        return Vec::new();
+    }
+
+    // Listas vacías = valores por defecto del framework detectado
+    let symbols = if rule.symbols.is_empty() {
+        default_event_symbols(&rc.ctx.framework)
+    } else {
+        rule.symbols.clone()
+    };
+    let modules = if rule.modules.is_empty() {
+        default_event_modules(&rc.ctx.framework)
+    } else {
+        rule.modules.clone()
+    };
+
+    let mut visitor = ConcreteEventVisitor {
+        rc,
+        symbols: &symbols,
+        modules: &modules,
+        findings: Vec::new(),
+    };
+    rc.module.visit_with(&mut visitor);
+    visitor.findings
+}
+
+struct ConcreteEventVisitor<'a, 'b> {
+    rc: &'a RuleContext<'b>,
+    symbols: &'a [String],
+    modules: &'a [String],
+    findings: Vec<Finding>,
+}
+
+impl Visit for ConcreteEventVisitor<'_, '_> {
+    fn visit_import_decl(&mut self, n: &ImportDecl) {
+        let source: &str = &n.src.value;
+        if self.modules.iter().any(|m| m == source) {
+            self.findings.push(self.rc.finding(
+                RULE_ID,
+                Severity::Error,
+                n.span,
+                format!(
+                    "El dominio no debe importar el bus de eventos '{}'. Inyecta una abstracción para publicar eventos.",
+                    source
+                ),
+            ));
+        }
+    }
+
+    fn visit_new_expr(&mut self, n: &NewExpr) {
+        if let Expr::Ident(ident) = &*n.callee {
+            let name: &str = &ident.sym;
+            if self.symbols.iter().any(|s| s == name) {
+                self.findings.push(self.rc.finding(
+                    RULE_ID,
+                    Severity::Error,
+                    n.span,
+                    format!(
+                        "El dominio no debe instanciar '{}'. Recibe un publicador de eventos inyectado.",
+                        name
+                    ),
+                ));
+            }
+        }
+        n.visit_children_with(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{NoConcreteEventsRule, RuleSettings};
+    use crate::rules::testing::check;
+
+    const SOURCE: &str = "\
+import { EventEmitter } from 'events';
+import { DomainEventPublisher } from './ports';
+
+export class Order {
+    private readonly events = new EventEmitter();
+    constructor(private readonly publisher: DomainEventPublisher) {}
+}
+";
+
+    fn settings(symbols: &[&str]) -> RuleSettings {
+        RuleSettings {
+            no_concrete_events: Some(NoConcreteEventsRule {
+                paths: vec!["src/domain/".to_string()],
+                symbols: symbols.iter().map(|s| s.to_string()).collect(),
+                modules: Vec::new(),
+            }),
+            ..RuleSettings::default()
+        }
+    }
+
+    #[test]
+    fn reports_event_bus_imports_and_instances() {
+        let findings = check(RULE_ID, "src/domain/order.ts", SOURCE, settings(&[]));
+        assert_eq!(findings.len(), 2);
+        assert!(findings.iter().all(|f| f.severity == Severity::Error));
+        assert_eq!(findings[0].location.start_line, 1);
+        assert_eq!(
+            findings[1].message,
+            "El dominio no debe instanciar 'EventEmitter'. Recibe un publicador de eventos inyectado."
+        );
+        assert!(check(RULE_ID, "src/infrastructure/bus.ts", SOURCE, settings(&[])).is_empty());
+    }
+
+    #[test]
+    fn configured_symbols_replace_the_defaults() {
+        let findings = check(
+            RULE_ID,
+            "src/domain/order.ts",
+            SOURCE,
+            settings(&["EventBus"]),
+        );
+        // Solo queda el import de 'events', que sigue en los módulos por defecto
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].location.start_line, 1);
+    }
+
+    #[test]
+    fn nestjs_also_forbids_its_event_modules() {
+        assert!(default_event_modules(&Framework::NestJS).contains(&"@nestjs/cqrs".to_string()));
+        assert!(!default_event_modules(&Framework::Unknown).contains(&"@nestjs/cqrs".to_string()));
+    }
+}
@@ -0,0 +1,174 @@
+//! Instanciación directa (`new`) de clases de otra capa
+//!
+//! En frameworks con inyección de dependencias (NestJS, Angular), `new UserService()`
+//! dentro de un controlador se salta el contenedor y acopla las capas: la
+//! dependencia debe recibirse por constructor.
+
+use super::{Finding, RuleContext, Severity};
+use crate::analyzer::{matches_pattern, normalize_pattern};
+use crate::circular::resolve_import_path;
+use std::collections::HashMap;
+use swc_ecma_ast::{Expr, ImportDecl, ImportSpecifier, NewExpr};
+use swc_ecma_visit::{Visit, VisitWith};
+
+pub const RULE_ID: &str = "no-cross-layer-new";
+
+/// Reporta cada `new X()` cuya clase se importa desde una capa distinta a la del archivo
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    let rule = match &rc.ctx.settings.no_cross_layer_new {
+        Some(rule) if rc.ctx.is_rule_enabled(RULE_ID, rc.path) => rule,
+        _ => return Vec::new(),
+    };
+
+    let file_path = rc.path.to_string_lossy().to_lowercase();
+    let current_layer = match layer_of(&file_path, &rule.layers) {
+        Some(layer) => layer,
+        None => return Vec::new(),
+    };
+
+    // Nombre local -> capa de la que se importa
+    let mut imports = ImportedLayers {
+        rc,
+        layers: &rule.layers,
+        by_name: HashMap::new(),
+    };
+    rc.module.visit_with(&mut imports);
+
+    let mut visitor = NewVisitor {
+        rc,
+        current_layer,
+        imported: imports.by_name,
+        findings: Vec::new(),
+    };
+    rc.module.visit_with(&mut visitor);
+    visitor.findings
+}
+
+/// Primera capa configurada que coincide con la ruta
+fn layer_of<'a>(path: &str, layers: &'a [String]) -> Option<&'a str> {
+    layers
+        .iter()
+        .find(|layer| matches_pattern(path, &normalize_pattern(layer)))
+        .map(|layer| layer.as_str())
+}
+
+struct ImportedLayers<'a, 'b> {
+    rc: &'a RuleContext<'b>,
+    layers: &'a [String],
+    by_name: HashMap<String, &'a str>,
+}
+
+impl Visit for ImportedLayers<'_, '_> {
+    fn visit_import_decl(&mut self, n: &ImportDecl) {
+        if n.type_only {
+            return;
+        }
+
+        // Los imports relativos se resuelven al archivo real; los alias (@/services)
+        // se comparan tal cual con los patrones de capa
+        let source: &str = &n.src.value;
+        let target = resolve_import_path(self.rc.path, source)
+            .map(|resolved| resolved.to_string_lossy().to_lowercase())
+            .unwrap_or_else(|| source.to_lowercase());
+        let layer = match layer_of(&target, self.layers) {
+            Some(layer) => layer,
+            None => return,
+        };
+
+        for specifier in &n.specifiers {
+            let local = match specifier {
+                ImportSpecifier::Named(named) if !named.is_type_only => &named.local,
+                ImportSpecifier::Default(default) => &default.local,
+                _ => continue,
+            };
+            self.by_name.insert(local.sym.to_string(), layer);
+        }
+    }
+}
+
+struct NewVisitor<'a, 'b> {
+    rc: &'a RuleContext<'b>,
+    current_layer: &'a str,
+    imported: HashMap<String, &'a str>,
+    findings: Vec<Finding>,
+}
+
+impl Visit for NewVisitor<'_, '_> {
+    fn visit_new_expr(&mut self, n: &NewExpr) {
+        if let Expr::Ident(ident) = &*n.callee {
+            let name: &str = &ident.sym;
+            match self.imported.get(name) {
+                Some(layer) if *layer != self.current_layer => {
+                    self.findings.push(self.rc.finding(
+                        RULE_ID,
+                        Severity::Error,
+                        n.span,
+                        format!(
+                            "No instancies '{}' (capa '{}') desde '{}'. Recíbelo por inyección de dependencias.",
+                            name, layer, self.current_layer
+                        ),
+                    ));
+                }
+                _ => {}
+            }
+        }
+        n.visit_children_with(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Framework, LinterContext, NoCrossLayerNewRule, RuleSettings};
+    use crate::rules::testing::findings_in;
+
+    const SOURCE: &str = "\
+import { UsersService } from '../services/users.service';
+import { UserDto } from './user.dto';
+
+export class UsersController {
+    private readonly service = new UsersService();
+    private readonly empty = new UserDto();
+    private readonly cache = new Map();
+}
+";
+
+    fn context(framework: Framework) -> LinterContext {
+        let settings = RuleSettings {
+            no_cross_layer_new: Some(NoCrossLayerNewRule {
+                layers: vec!["src/controllers/".to_string(), "src/services/".to_string()],
+            }),
+            ..RuleSettings::default()
+        };
+        LinterContext {
+            framework,
+            ..LinterContext::for_tests(settings)
+        }
+    }
+
+    #[test]
+    fn reports_instances_of_classes_from_other_layers() {
+        let findings = findings_in(
+            RULE_ID,
+            "src/controllers/users.controller.ts",
+            SOURCE,
+            &context(Framework::NestJS),
+        );
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].location.start_line, 5);
+        assert_eq!(
+            findings[0].message,
+            "No instancies 'UsersService' (capa 'src/services/') desde 'src/controllers/'. Recíbelo por inyección de dependencias."
+        );
+    }
+
+    #[test]
+    fn only_applies_with_a_dependency_injection_container() {
+        let path = "src/controllers/users.controller.ts";
+        assert!(findings_in(RULE_ID, path, SOURCE, &context(Framework::Unknown)).is_empty());
+        assert_eq!(
+            findings_in(RULE_ID, path, SOURCE, &context(Framework::Angular)).len(),
+            1
+        );
+    }
+}
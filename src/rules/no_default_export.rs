@@ -0,0 +1,112 @@
+//! Prohíbe `export default` en proyectos que estandarizan exports nombrados
+//!
+//! Los exports nombrados mantienen el mismo nombre en todos los imports y
+//! funcionan mejor con el autocompletado y los renombrados. Los archivos de
+//! entrada de frameworks que exigen un default (páginas, configs) se permiten.
+
+use super::{Finding, RuleContext, Severity};
+use swc_ecma_ast::{ExportSpecifier, ModuleDecl, ModuleExportName, ModuleItem};
+
+pub const RULE_ID: &str = "no-default-export";
+
+/// Archivos donde el framework o la herramienta exige un export default
+pub fn default_allowed_paths() -> Vec<String> {
+    ["pages/", ".config.", ".stories."]
+        .iter()
+        .map(|p| p.to_string())
+        .collect()
+}
+
+/// Reporta cada export default fuera de los archivos permitidos
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    let rule = match &rc.ctx.settings.no_default_export {
+        Some(rule) => rule,
+        None => return Vec::new(),
+    };
+
+    if rc.path_matches(&rule.allow) {
+        return Vec::new();
+    }
+
+    rc.module
+        .body
+        .iter()
+        .filter_map(|item| match item {
+            ModuleItem::ModuleDecl(decl) => default_export_span(decl),
+            _ => None,
+        })
+        .map(|span| {
+            rc.finding(
+                RULE_ID,
+                Severity::Error,
+                span,
+                "No uses export default. Exporta por nombre (export class/function/const ...)."
+                    .to_string(),
+            )
+        })
+        .collect()
+}
+
+/// Span del export si es un default (incluye `export { x as default }`)
+fn default_export_span(decl: &ModuleDecl) -> Option<swc_common::Span> {
+    match decl {
+        ModuleDecl::ExportDefaultDecl(n) => Some(n.span),
+        ModuleDecl::ExportDefaultExpr(n) => Some(n.span),
+        ModuleDecl::ExportNamed(n) if !n.type_only => {
+            let has_default = n.specifiers.iter().any(|specifier| match specifier {
+                ExportSpecifier::Named(named) => {
+                    let exported = named.exported.as_ref().unwrap_or(&named.orig);
+                    matches!(exported, ModuleExportName::Ident(ident) if &*ident.sym == "default")
+                }
+                ExportSpecifier::Default(_) => true,
+                ExportSpecifier::Namespace(_) => false,
+            });
+            has_default.then_some(n.span)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{NoDefaultExportRule, RuleSettings};
+    use crate::rules::testing::check;
+
+    const SOURCE: &str = "\
+export const limit = 10;
+export default function list() {}
+const page = 1;
+export { page as default };
+";
+
+    fn settings() -> RuleSettings {
+        RuleSettings {
+            no_default_export: Some(NoDefaultExportRule {
+                allow: default_allowed_paths(),
+            }),
+            ..RuleSettings::default()
+        }
+    }
+
+    #[test]
+    fn reports_every_default_export() {
+        let findings = check(RULE_ID, "src/orders/list.ts", SOURCE, settings());
+        let lines: Vec<usize> = findings.iter().map(|f| f.location.start_line).collect();
+        assert_eq!(lines, vec![2, 4]);
+        assert!(findings.iter().all(|f| f.severity == Severity::Error));
+    }
+
+    #[test]
+    fn allows_framework_entry_files() {
+        assert!(check(RULE_ID, "src/pages/orders.tsx", SOURCE, settings()).is_empty());
+        assert!(check(RULE_ID, "vite.config.ts", SOURCE, settings()).is_empty());
+        assert!(check(
+            RULE_ID,
+            "src/orders/list.ts",
+            SOURCE,
+            RuleSettings::default()
+        )
+        .is_empty());
+    }
+}
@@ -0,0 +1,96 @@
+//! Imports directos de librerías de logging desde las capas de aplicación y dominio
+//!
+//! El dominio registra eventos a través de un puerto `Logger` inyectado; solo la
+//! infraestructura sabe si detrás hay winston, pino o la consola.
+
+use super::{Finding, RuleContext, Severity};
+use crate::resolver::package_specifier_name;
+use swc_ecma_ast::{ModuleDecl, ModuleItem};
+
+pub const RULE_ID: &str = "no-direct-logger";
+
+/// Paquetes de logging revisados por defecto
+pub fn default_logger_packages() -> Vec<String> {
+    [
+        "winston", "pino", "bunyan", "log4js", "loglevel", "signale", "consola", "npmlog",
+    ]
+    .iter()
+    .map(|p| p.to_string())
+    .collect()
+}
+
+/// Reporta cada import de una librería de logging (incluidos sus subpaths) en las
+/// capas configuradas
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    let rule = match &rc.ctx.settings.no_direct_logger {
+        Some(rule) => rule,
+        None => return Vec::new(),
+    };
+
+    if !rc.path_matches(&rule.paths) {
+        return Vec::new();
+    }
+
+    rc.module
+        .body
+        .iter()
+        .filter_map(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => Some(import),
+            _ => None,
+        })
+        .filter_map(|import| {
+            let package = package_specifier_name(&import.src.value)?;
+            if !rule.packages.iter().any(|p| p == package) {
+                return None;
+            }
+            Some(rc.finding(
+                RULE_ID,
+                Severity::Error,
+                import.span,
+                format!(
+                    "Esta capa no debe depender del logger '{}'. Recibe un puerto Logger inyectado e impleméntalo en infraestructura.",
+                    package
+                ),
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{NoDirectLoggerRule, RuleSettings};
+    use crate::rules::testing::check;
+
+    const SOURCE: &str = "\
+import winston from 'winston';
+import pino from 'pino/browser';
+import { Logger } from '../ports/logger';
+";
+
+    fn settings() -> RuleSettings {
+        RuleSettings {
+            no_direct_logger: Some(NoDirectLoggerRule {
+                paths: vec!["src/domain/".to_string()],
+                packages: default_logger_packages(),
+            }),
+            ..RuleSettings::default()
+        }
+    }
+
+    #[test]
+    fn reports_logging_libraries_and_their_subpaths() {
+        let findings = check(RULE_ID, "src/domain/order.ts", SOURCE, settings());
+        let lines: Vec<usize> = findings.iter().map(|f| f.location.start_line).collect();
+        assert_eq!(lines, vec![1, 2]);
+        assert_eq!(
+            findings[1].message,
+            "Esta capa no debe depender del logger 'pino'. Recibe un puerto Logger inyectado e impleméntalo en infraestructura."
+        );
+    }
+
+    #[test]
+    fn allows_the_infrastructure_layer() {
+        assert!(check(RULE_ID, "src/infrastructure/logger.ts", SOURCE, settings()).is_empty());
+    }
+}
@@ -0,0 +1,99 @@
+//! Imports directos de ORMs desde las capas de aplicación y dominio
+//!
+//! En Clean/Hexagonal los servicios dependen de un puerto de repositorio; solo la
+//! infraestructura conoce TypeORM, Prisma o Mongoose.
+
+use super::{Finding, RuleContext, Severity};
+use crate::resolver::package_specifier_name;
+use swc_ecma_ast::{ModuleDecl, ModuleItem};
+
+pub const RULE_ID: &str = "no-direct-orm";
+
+/// Paquetes de ORM revisados por defecto
+pub fn default_orm_packages() -> Vec<String> {
+    [
+        "typeorm",
+        "@prisma/client",
+        "mongoose",
+        "sequelize",
+        "sequelize-typescript",
+        "@mikro-orm/core",
+        "drizzle-orm",
+    ]
+    .iter()
+    .map(|p| p.to_string())
+    .collect()
+}
+
+/// Reporta cada import de un ORM (incluidos sus subpaths) en las capas configuradas
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    let rule = match &rc.ctx.settings.no_direct_orm {
+        Some(rule) => rule,
+        None => return Vec::new(),
+    };
+
+    if !rc.path_matches(&rule.paths) {
+        return Vec::new();
+    }
+
+    rc.module
+        .body
+        .iter()
+        .filter_map(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => Some(import),
+            _ => None,
+        })
+        .filter_map(|import| {
+            let package = package_specifier_name(&import.src.value)?;
+            if !rule.packages.iter().any(|p| p == package) {
+                return None;
+            }
+            Some(rc.finding(
+                RULE_ID,
+                Severity::Error,
+                import.span,
+                format!(
+                    "Esta capa no debe depender del ORM '{}'. Usa una interfaz de repositorio implementada en infraestructura.",
+                    package
+                ),
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{NoDirectOrmRule, RuleSettings};
+    use crate::rules::testing::check;
+
+    const SOURCE: &str = "\
+import { Repository } from 'typeorm';
+import { PrismaClient } from '@prisma/client/edge';
+import { OrderRepository } from '../ports/order.repository';
+import { Injectable } from '@nestjs/common';
+";
+
+    fn settings() -> RuleSettings {
+        RuleSettings {
+            no_direct_orm: Some(NoDirectOrmRule {
+                paths: vec!["src/application/".to_string()],
+                packages: default_orm_packages(),
+            }),
+            ..RuleSettings::default()
+        }
+    }
+
+    #[test]
+    fn reports_orm_packages_and_their_subpaths() {
+        let findings = check(RULE_ID, "src/application/orders.ts", SOURCE, settings());
+        let lines: Vec<usize> = findings.iter().map(|f| f.location.start_line).collect();
+        assert_eq!(lines, vec![1, 2]);
+        assert!(findings[1].message.contains("del ORM '@prisma/client'"));
+    }
+
+    #[test]
+    fn allows_the_infrastructure_layer() {
+        assert!(check(RULE_ID, "src/infrastructure/orders.ts", SOURCE, settings()).is_empty());
+    }
+}
@@ -0,0 +1,139 @@
+//! Prohíbe lanzar errores genéricos (`throw new Error(...)`) en las capas configuradas
+//!
+//! El dominio debe lanzar errores propios (`DomainError`, `OrderNotFound`...) para que
+//! las capas externas puedan distinguirlos sin parsear mensajes.
+
+use super::{Finding, RuleContext, Severity};
+use swc_ecma_ast::{Callee, Expr, ThrowStmt};
+use swc_ecma_visit::{Visit, VisitWith};
+
+pub const RULE_ID: &str = "no-generic-error";
+
+/// Constructores de error nativos de JavaScript
+const GENERIC_ERRORS: &[&str] = &[
+    "Error",
+    "TypeError",
+    "RangeError",
+    "ReferenceError",
+    "SyntaxError",
+    "EvalError",
+    "URIError",
+    "AggregateError",
+];
+
+/// Advierte sobre cada `throw` de un error nativo dentro de las capas configuradas
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    let rule = match &rc.ctx.settings.no_generic_error {
+        Some(rule) => rule,
+        None => return Vec::new(),
+    };
+
+    if !rc.path_matches(&rule.paths) {
+        return Vec::new();
+    }
+
+    let mut visitor = ThrowVisitor {
+        rc,
+        allow: &rule.allow,
+        findings: Vec::new(),
+    };
+    rc.module.visit_with(&mut visitor);
+    visitor.findings
+}
+
+struct ThrowVisitor<'a, 'b> {
+    rc: &'a RuleContext<'b>,
+    allow: &'a [String],
+    findings: Vec<Finding>,
+}
+
+impl Visit for ThrowVisitor<'_, '_> {
+    fn visit_throw_stmt(&mut self, n: &ThrowStmt) {
+        // `throw new Error(...)` y `throw Error(...)` son equivalentes
+        let callee = match &*n.arg {
+            Expr::New(new_expr) => match &*new_expr.callee {
+                Expr::Ident(ident) => Some(&ident.sym),
+                _ => None,
+            },
+            Expr::Call(call) => match &call.callee {
+                Callee::Expr(expr) => match &**expr {
+                    Expr::Ident(ident) => Some(&ident.sym),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        };
+
+        if let Some(name) = callee {
+            let name: &str = name;
+            if GENERIC_ERRORS.contains(&name) && !self.allow.iter().any(|a| a == name) {
+                self.findings.push(self.rc.finding(
+                    RULE_ID,
+                    Severity::Warning,
+                    n.span,
+                    format!(
+                        "No lances '{}' genérico en esta capa. Usa una clase de error de dominio (ej: class OrderNotFoundError extends DomainError).",
+                        name
+                    ),
+                ));
+            }
+        }
+
+        n.visit_children_with(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{NoGenericErrorRule, RuleSettings};
+    use crate::rules::testing::check;
+
+    const SOURCE: &str = "\
+export function cancel(order: Order) {
+    if (order.shipped) {
+        throw new Error('ya enviado');
+    }
+    if (!order.id) {
+        throw TypeError('sin id');
+    }
+    throw new OrderNotFoundError(order.id);
+}
+";
+
+    fn settings(allow: &[&str]) -> RuleSettings {
+        RuleSettings {
+            no_generic_error: Some(NoGenericErrorRule {
+                paths: vec!["src/domain/".to_string()],
+                allow: allow.iter().map(|name| name.to_string()).collect(),
+            }),
+            ..RuleSettings::default()
+        }
+    }
+
+    #[test]
+    fn reports_native_errors_in_configured_layers() {
+        let findings = check(RULE_ID, "src/domain/order.ts", SOURCE, settings(&[]));
+        let lines: Vec<usize> = findings.iter().map(|f| f.location.start_line).collect();
+        assert_eq!(lines, vec![3, 6]);
+        assert!(findings[0]
+            .message
+            .starts_with("No lances 'Error' genérico"));
+        assert!(findings[1]
+            .message
+            .starts_with("No lances 'TypeError' genérico"));
+    }
+
+    #[test]
+    fn skips_allowed_errors_and_other_layers() {
+        let findings = check(
+            RULE_ID,
+            "src/domain/order.ts",
+            SOURCE,
+            settings(&["TypeError"]),
+        );
+        assert_eq!(findings.len(), 1);
+        assert!(check(RULE_ID, "src/infrastructure/db.ts", SOURCE, settings(&[])).is_empty());
+    }
+}
@@ -0,0 +1,200 @@
+//! Números mágicos en la lógica de las capas configuradas
+//!
+//! Timeouts, límites o códigos de estado escritos como literales sueltos son
+//! difíciles de entender y de cambiar. Se permiten 0, 1 y -1, los índices de
+//! arrays, los inicializadores de `const` y los miembros de enums.
+
+use super::{Finding, RuleContext, Severity};
+use swc_ecma_ast::{
+    ArrowExpr, Class, Expr, Function, Lit, MemberExpr, MemberProp, Number, TsEnumMember, UnaryExpr,
+    UnaryOp, VarDecl, VarDeclKind,
+};
+use swc_ecma_visit::{Visit, VisitWith};
+
+pub const RULE_ID: &str = "no-magic-numbers";
+
+/// Valores que nunca se consideran mágicos
+const ALWAYS_ALLOWED: [f64; 3] = [0.0, 1.0, -1.0];
+
+/// Advierte sobre cada literal numérico usado en la lógica de las capas configuradas
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    let rule = match &rc.ctx.settings.no_magic_numbers {
+        Some(rule) => rule,
+        None => return Vec::new(),
+    };
+
+    if !rc.path_matches(&rule.paths) {
+        return Vec::new();
+    }
+
+    let mut visitor = MagicNumbersVisitor {
+        rc,
+        ignore: &rule.ignore,
+        in_const: false,
+        findings: Vec::new(),
+    };
+    rc.module.visit_with(&mut visitor);
+    visitor.findings
+}
+
+struct MagicNumbersVisitor<'a, 'b> {
+    rc: &'a RuleContext<'b>,
+    ignore: &'a [f64],
+    /// Dentro del inicializador de una declaración `const` (fuera de funciones)
+    in_const: bool,
+    findings: Vec<Finding>,
+}
+
+impl MagicNumbersVisitor<'_, '_> {
+    fn check_number(&mut self, number: &Number, negative: bool) {
+        if self.in_const {
+            return;
+        }
+
+        let value = if negative {
+            -number.value
+        } else {
+            number.value
+        };
+        if ALWAYS_ALLOWED.contains(&value) || self.ignore.contains(&value) {
+            return;
+        }
+
+        let raw = number
+            .raw
+            .as_ref()
+            .map(|raw| raw.to_string())
+            .unwrap_or_else(|| number.value.to_string());
+        let literal = if negative { format!("-{}", raw) } else { raw };
+
+        self.findings.push(self.rc.finding(
+            RULE_ID,
+            Severity::Warning,
+            number.span,
+            format!(
+                "Número mágico {}. Extráelo a una constante con nombre o a la configuración.",
+                literal
+            ),
+        ));
+    }
+
+    /// Las funciones y clases dentro de un `const` vuelven a ser lógica
+    fn visit_outside_const<N: VisitWith<Self>>(&mut self, node: &N) {
+        let in_const = self.in_const;
+        self.in_const = false;
+        node.visit_children_with(self);
+        self.in_const = in_const;
+    }
+}
+
+impl Visit for MagicNumbersVisitor<'_, '_> {
+    fn visit_number(&mut self, n: &Number) {
+        self.check_number(n, false);
+    }
+
+    fn visit_unary_expr(&mut self, n: &UnaryExpr) {
+        // `-3600` se reporta como un único literal negativo
+        match (&n.op, &*n.arg) {
+            (UnaryOp::Minus, Expr::Lit(Lit::Num(number))) => self.check_number(number, true),
+            _ => n.visit_children_with(self),
+        }
+    }
+
+    fn visit_member_expr(&mut self, n: &MemberExpr) {
+        // Los índices de arrays (items[2]) están permitidos
+        n.obj.visit_with(self);
+        if let MemberProp::Computed(computed) = &n.prop {
+            if !matches!(&*computed.expr, Expr::Lit(Lit::Num(_))) {
+                computed.visit_with(self);
+            }
+        }
+    }
+
+    fn visit_var_decl(&mut self, n: &VarDecl) {
+        if n.kind != VarDeclKind::Const {
+            return n.visit_children_with(self);
+        }
+        let in_const = self.in_const;
+        self.in_const = true;
+        n.visit_children_with(self);
+        self.in_const = in_const;
+    }
+
+    fn visit_ts_enum_member(&mut self, _: &TsEnumMember) {}
+
+    fn visit_function(&mut self, n: &Function) {
+        self.visit_outside_const(n);
+    }
+
+    fn visit_arrow_expr(&mut self, n: &ArrowExpr) {
+        self.visit_outside_const(n);
+    }
+
+    fn visit_class(&mut self, n: &Class) {
+        self.visit_outside_const(n);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{NoMagicNumbersRule, RuleSettings};
+    use crate::rules::testing::check;
+
+    const SOURCE: &str = "\
+const TIMEOUT_MS = 3600;
+export function retry(items: number[], attempts: number) {
+    if (attempts > 5 && items[3]) {
+        return -3600;
+    }
+    return items.length * 2 + 1;
+}
+enum Status {
+    Active = 10,
+}
+";
+
+    fn settings(ignore: Vec<f64>) -> RuleSettings {
+        RuleSettings {
+            no_magic_numbers: Some(NoMagicNumbersRule {
+                paths: vec!["src/services/".to_string()],
+                ignore,
+            }),
+            ..RuleSettings::default()
+        }
+    }
+
+    #[test]
+    fn reports_numbers_in_logic() {
+        let findings = check(
+            RULE_ID,
+            "src/services/retry.ts",
+            SOURCE,
+            settings(Vec::new()),
+        );
+        let found: Vec<(usize, &str)> = findings
+            .iter()
+            .map(|f| (f.location.start_line, f.message.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (3, "Número mágico 5. Extráelo a una constante con nombre o a la configuración."),
+                (4, "Número mágico -3600. Extráelo a una constante con nombre o a la configuración."),
+                (6, "Número mágico 2. Extráelo a una constante con nombre o a la configuración."),
+            ]
+        );
+    }
+
+    #[test]
+    fn skips_ignored_numbers_and_other_layers() {
+        let findings = check(
+            RULE_ID,
+            "src/services/retry.ts",
+            SOURCE,
+            settings(vec![2.0, 5.0]),
+        );
+        assert_eq!(findings.len(), 1);
+        assert!(check(RULE_ID, "src/ui/retry.ts", SOURCE, settings(Vec::new())).is_empty());
+    }
+}
@@ -0,0 +1,374 @@
+//! Providers instanciados a mano con `new`
+//!
+//! En NestJS o Angular una clase `@Injectable()` la crea el contenedor de
+//! inyección: `new UsersService()` se salta sus dependencias, su ciclo de vida
+//! (`onModuleInit`, scopes) y los mocks de los tests. A diferencia de
+//! no-cross-layer-new, no depende de las capas: se abre el archivo de donde se
+//! importa la clase (siguiendo los re-exports de los barrels) y se miran sus
+//! decoradores.
+
+use super::dto_location::decorator_name;
+use super::{Finding, RuleContext, Severity};
+use crate::analyzer;
+use crate::circular::resolve_import_path;
+use crate::unused_exports::export_name;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::SystemTime;
+use swc_common::{SourceMap, Span};
+use swc_ecma_ast::{
+    Class, Decl, DefaultDecl, ExportSpecifier, Expr, ImportSpecifier, Module, ModuleDecl,
+    ModuleItem, NewExpr, Stmt,
+};
+use swc_ecma_visit::{Visit, VisitWith};
+
+pub const RULE_ID: &str = "no-manual-di-instantiation";
+
+/// Barrels encadenados que se siguen como máximo al buscar una clase
+const MAX_REEXPORT_DEPTH: usize = 5;
+
+/// Decoradores que registran una clase en el contenedor
+pub fn default_provider_decorators() -> Vec<String> {
+    vec!["Injectable".to_string()]
+}
+
+/// Archivos donde `new` es legítimo: tests y factories
+pub fn default_allowed_paths() -> Vec<String> {
+    ["*.spec.*", "*.test.*", "__tests__/", "*.factory.*"]
+        .iter()
+        .map(|pattern| pattern.to_string())
+        .collect()
+}
+
+/// Reporta cada `new X()` cuya clase lleva un decorador de provider
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    let rule = match &rc.ctx.settings.no_manual_di_instantiation {
+        Some(rule) => rule,
+        None => return Vec::new(),
+    };
+
+    if rc.path_matches(&rule.allow) {
+        return Vec::new();
+    }
+
+    let mut news = NewCollector::default();
+    rc.module.visit_with(&mut news);
+    if news.instantiated.is_empty() {
+        return Vec::new();
+    }
+
+    // Nombre local -> decorador de provider de su clase
+    let mut providers: HashMap<String, String> = HashMap::new();
+    for (local, decorators) in visible_classes(rc.path, rc.module, &news.instantiated) {
+        let provider = decorators
+            .into_iter()
+            .find(|decorator| rule.decorators.contains(decorator));
+        if let Some(provider) = provider {
+            providers.insert(local, provider);
+        }
+    }
+
+    news.instantiated
+        .into_iter()
+        .filter_map(|(name, span)| {
+            let provider = providers.get(&name)?;
+            Some(rc.finding(
+                RULE_ID,
+                Severity::Error,
+                span,
+                format!(
+                    "'{}' es un provider (@{}) y se instancia con new. Recíbelo por constructor para que lo gestione el contenedor de inyección.",
+                    name, provider
+                ),
+            ))
+        })
+        .collect()
+}
+
+/// Clases del módulo que se instancian, con sus decoradores. Solo se abren los
+/// archivos de las clases importadas que aparecen en un `new`.
+fn visible_classes(
+    path: &Path,
+    module: &Module,
+    instantiated: &[(String, Span)],
+) -> Vec<(String, Vec<String>)> {
+    let is_instantiated = |local: &str| instantiated.iter().any(|(name, _)| name == local);
+    let mut classes = Vec::new();
+    for item in &module.body {
+        match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(import)) if !import.type_only => {
+                let target = match resolve_import_path(path, &import.src.value) {
+                    Some(target) => target,
+                    None => continue,
+                };
+                for specifier in &import.specifiers {
+                    let (local, imported) = match specifier {
+                        ImportSpecifier::Named(named) if !named.is_type_only => (
+                            &named.local,
+                            named
+                                .imported
+                                .as_ref()
+                                .map_or_else(|| named.local.sym.to_string(), export_name),
+                        ),
+                        ImportSpecifier::Default(default) => {
+                            (&default.local, "default".to_string())
+                        }
+                        _ => continue,
+                    };
+                    if !is_instantiated(&local.sym) {
+                        continue;
+                    }
+                    if let Some(decorators) = exported_decorators(&target, &imported, 0) {
+                        classes.push((local.sym.to_string(), decorators));
+                    }
+                }
+            }
+            _ => {
+                if let Some((name, class)) = declared_class(item) {
+                    if is_instantiated(name) {
+                        classes.push((name.to_string(), class_decorators(class)));
+                    }
+                }
+            }
+        }
+    }
+    classes
+}
+
+/// Clase declarada en el nivel superior (exportada o no)
+fn declared_class(item: &ModuleItem) -> Option<(&str, &Class)> {
+    match item {
+        ModuleItem::Stmt(Stmt::Decl(Decl::Class(c))) => Some((&*c.ident.sym, &*c.class)),
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => match &export.decl {
+            Decl::Class(c) => Some((&*c.ident.sym, &*c.class)),
+            _ => None,
+        },
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(export)) => match &export.decl {
+            DefaultDecl::Class(c) => c.ident.as_ref().map(|ident| (&*ident.sym, &*c.class)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn class_decorators(class: &Class) -> Vec<String> {
+    class
+        .decorators
+        .iter()
+        .filter_map(decorator_name)
+        .map(str::to_string)
+        .collect()
+}
+
+/// Decoradores de la clase que `path` exporta como `name` ("default" para el export
+/// default), siguiendo los re-exports. `None` si no es una clase o no se encuentra.
+fn exported_decorators(path: &Path, name: &str, depth: usize) -> Option<Vec<String>> {
+    if depth > MAX_REEXPORT_DEPTH {
+        return None;
+    }
+    let classes = module_classes(path)?;
+    if let Some(decorators) = classes.exported.get(name) {
+        return Some(decorators.clone());
+    }
+    if let Some((source, original)) = classes.forwarded.get(name) {
+        return exported_decorators(source, original, depth + 1);
+    }
+    // `export * from` no re-exporta el default
+    if name == "default" {
+        return None;
+    }
+    classes
+        .star
+        .iter()
+        .find_map(|source| exported_decorators(source, name, depth + 1))
+}
+
+/// Clases exportadas por un archivo y los re-exports que hay que seguir
+#[derive(Default)]
+struct ModuleClasses {
+    /// Nombre exportado -> decoradores de la clase
+    exported: HashMap<String, Vec<String>>,
+    /// `export { A as B } from './x'`: B -> (archivo, A)
+    forwarded: HashMap<String, (PathBuf, String)>,
+    /// `export * from './x'`
+    star: Vec<PathBuf>,
+}
+
+/// Clases de un archivo, cacheadas mientras no cambie su fecha de modificación
+/// (en --watch los archivos se reanalizan)
+fn module_classes(path: &Path) -> Option<Arc<ModuleClasses>> {
+    type Cache = Mutex<HashMap<PathBuf, (Option<SystemTime>, Option<Arc<ModuleClasses>>)>>;
+    static CACHE: OnceLock<Cache> = OnceLock::new();
+
+    let modified = fs::metadata(path).and_then(|meta| meta.modified()).ok();
+    let cache = CACHE.get_or_init(Default::default);
+    if let Some((cached_at, classes)) = cache.lock().unwrap().get(path) {
+        if *cached_at == modified {
+            return classes.clone();
+        }
+    }
+
+    // Se parsea sin el lock: otros hilos pueden estar analizando sus archivos
+    let classes = match analyzer::parse_module(&SourceMap::default(), path) {
+        Ok(Some((_, Ok(module)))) => Some(Arc::new(summarize(path, &module))),
+        _ => None,
+    };
+    cache
+        .lock()
+        .unwrap()
+        .insert(path.to_path_buf(), (modified, classes.clone()));
+    classes
+}
+
+fn summarize(path: &Path, module: &Module) -> ModuleClasses {
+    let declared: HashMap<&str, &Class> = module.body.iter().filter_map(declared_class).collect();
+    let mut classes = ModuleClasses::default();
+
+    for item in &module.body {
+        let decl = match item {
+            ModuleItem::ModuleDecl(decl) => decl,
+            ModuleItem::Stmt(_) => continue,
+        };
+        match decl {
+            ModuleDecl::ExportDecl(export) => {
+                if let Decl::Class(c) = &export.decl {
+                    let decorators = class_decorators(&c.class);
+                    classes.exported.insert(c.ident.sym.to_string(), decorators);
+                }
+            }
+            ModuleDecl::ExportDefaultDecl(export) => {
+                if let DefaultDecl::Class(c) = &export.decl {
+                    let decorators = class_decorators(&c.class);
+                    classes.exported.insert("default".to_string(), decorators);
+                }
+            }
+            // `export default UsersService;` de una clase declarada antes
+            ModuleDecl::ExportDefaultExpr(export) => {
+                if let Expr::Ident(ident) = &*export.expr {
+                    if let Some(class) = declared.get(&*ident.sym) {
+                        let decorators = class_decorators(class);
+                        classes.exported.insert("default".to_string(), decorators);
+                    }
+                }
+            }
+            ModuleDecl::ExportNamed(export) if !export.type_only => {
+                let source = match &export.src {
+                    Some(src) => match resolve_import_path(path, &src.value) {
+                        Some(source) => Some(source),
+                        None => continue,
+                    },
+                    None => None,
+                };
+                for specifier in &export.specifiers {
+                    let named = match specifier {
+                        ExportSpecifier::Named(named) if !named.is_type_only => named,
+                        _ => continue,
+                    };
+                    let original = export_name(&named.orig);
+                    let exported = named
+                        .exported
+                        .as_ref()
+                        .map_or_else(|| original.clone(), export_name);
+                    match &source {
+                        Some(source) => {
+                            classes
+                                .forwarded
+                                .insert(exported, (source.clone(), original));
+                        }
+                        // `export { UsersService }` de una clase del propio archivo
+                        None => {
+                            if let Some(class) = declared.get(original.as_str()) {
+                                classes.exported.insert(exported, class_decorators(class));
+                            }
+                        }
+                    }
+                }
+            }
+            ModuleDecl::ExportAll(export) if !export.type_only => {
+                if let Some(source) = resolve_import_path(path, &export.src.value) {
+                    classes.star.push(source);
+                }
+            }
+            _ => {}
+        }
+    }
+    classes
+}
+
+/// `new X()` con un identificador como clase
+#[derive(Default)]
+struct NewCollector {
+    instantiated: Vec<(String, Span)>,
+}
+
+impl Visit for NewCollector {
+    fn visit_new_expr(&mut self, n: &NewExpr) {
+        if let Expr::Ident(ident) = &*n.callee {
+            self.instantiated.push((ident.sym.to_string(), n.span));
+        }
+        n.visit_children_with(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{NoManualDiInstantiationRule, RuleSettings};
+    use crate::rules::testing::{check, temp_project};
+
+    const SOURCE: &str = "\
+import { UsersService, PasswordHasher } from '../users';
+@Injectable()
+class LocalCache {}
+export class OrdersController {
+    private readonly users = new UsersService();
+    private readonly hasher = new PasswordHasher();
+    private readonly cache = new LocalCache();
+}
+";
+
+    fn settings() -> RuleSettings {
+        RuleSettings {
+            no_manual_di_instantiation: Some(NoManualDiInstantiationRule {
+                decorators: default_provider_decorators(),
+                allow: default_allowed_paths(),
+            }),
+            ..RuleSettings::default()
+        }
+    }
+
+    #[test]
+    fn reports_providers_created_with_new() {
+        let root = temp_project(
+            "manual-di",
+            &[
+                (
+                    "src/users/users.service.ts",
+                    "@Injectable()\nexport class UsersService {}\n",
+                ),
+                ("src/users/hasher.ts", "export class Hasher {}\n"),
+                (
+                    "src/users/index.ts",
+                    "export * from './users.service';\nexport { Hasher as PasswordHasher } from './hasher';\n",
+                ),
+                ("src/orders/orders.controller.ts", SOURCE),
+                ("src/orders/orders.factory.ts", SOURCE),
+            ],
+        );
+
+        // Se siguen los re-exports del barrel; Hasher no es un provider
+        let controller = root.join("src/orders/orders.controller.ts");
+        let findings = check(RULE_ID, controller.to_str().unwrap(), SOURCE, settings());
+        let lines: Vec<usize> = findings.iter().map(|f| f.location.start_line).collect();
+        assert_eq!(lines, vec![5, 7]);
+        assert_eq!(
+            findings[0].message,
+            "'UsersService' es un provider (@Injectable) y se instancia con new. Recíbelo por constructor para que lo gestione el contenedor de inyección."
+        );
+
+        let factory = root.join("src/orders/orders.factory.ts");
+        assert!(check(RULE_ID, factory.to_str().unwrap(), SOURCE, settings()).is_empty());
+    }
+}
@@ -0,0 +1,260 @@
+//! Estado mutable a nivel de módulo
+//!
+//! Un `let cache = {}` en el nivel superior de un archivo lo comparten todos los
+//! que importan el módulo: acopla a quien lo modifica con quien lo lee sin que se
+//! vea en ninguna firma, y contamina un test con lo que dejó el anterior. Se
+//! señalan los `let`/`var` del nivel superior y los `const` con un objeto, array,
+//! `Map` o `Set` que el propio archivo modifica (`CACHE.x = 1`, `items.push(x)`).
+//!
+//! Lo segundo es una heurística por nombre: no sigue alias
+//! (`const c = CACHE; c.x = 1`) y una variable local con el mismo nombre que la
+//! constante cuenta como si fuera ella.
+
+use super::{Finding, RuleContext, Severity};
+use std::collections::HashMap;
+use swc_common::Span;
+use swc_ecma_ast::{
+    AssignExpr, CallExpr, Callee, Decl, Expr, MemberProp, ModuleDecl, ModuleItem, Pat, PatOrExpr,
+    Stmt, UnaryExpr, UnaryOp, UpdateExpr, VarDecl, VarDeclKind,
+};
+use swc_ecma_visit::{Visit, VisitWith};
+
+pub const RULE_ID: &str = "no-mutable-module-state";
+
+/// Métodos que modifican un array, un `Map` o un `Set`
+const MUTATING_METHODS: &[&str] = &[
+    "push", "pop", "shift", "unshift", "splice", "sort", "reverse", "fill", "set", "add", "delete",
+    "clear",
+];
+
+/// Constructores de colecciones mutables
+const MUTABLE_COLLECTIONS: &[&str] = &["Map", "Set", "WeakMap", "WeakSet", "Array", "Object"];
+
+/// Advierte sobre cada variable de módulo reasignable o modificada
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    let rule = match &rc.ctx.settings.no_mutable_module_state {
+        Some(rule) => rule,
+        None => return Vec::new(),
+    };
+
+    // Sin `paths` aplica a todo el proyecto
+    if !rule.paths.is_empty() && !rc.path_matches(&rule.paths) {
+        return Vec::new();
+    }
+
+    let mut findings = Vec::new();
+    // Constantes con un valor mutable: nombre -> span de la declaración
+    let mut collections: HashMap<String, Span> = HashMap::new();
+
+    for var in rc.module.body.iter().filter_map(module_var) {
+        for declarator in &var.decls {
+            let binding = match &declarator.name {
+                Pat::Ident(binding) => binding,
+                _ => continue,
+            };
+            let name = binding.id.sym.to_string();
+            if rule.allow.contains(&name) {
+                continue;
+            }
+            let keyword = match var.kind {
+                VarDeclKind::Let => "let",
+                VarDeclKind::Var => "var",
+                VarDeclKind::Const => {
+                    if declarator.init.as_deref().is_some_and(is_mutable_value) {
+                        collections.insert(name, declarator.span);
+                    }
+                    continue;
+                }
+            };
+            findings.push(rc.finding(
+                RULE_ID,
+                Severity::Warning,
+                declarator.span,
+                format!(
+                    "'{}' es estado mutable del módulo ({}): lo comparten todos los que lo importan. Recíbelo como dependencia o encapsúlalo en una clase.",
+                    name, keyword
+                ),
+            ));
+        }
+    }
+
+    if collections.is_empty() {
+        return findings;
+    }
+    let mut mutations = MutationVisitor {
+        names: &collections,
+        mutated: HashMap::new(),
+    };
+    rc.module.visit_with(&mut mutations);
+
+    let mut mutated: Vec<(&String, String)> = mutations.mutated.into_iter().collect();
+    mutated.sort_by_key(|(name, _)| collections[*name].lo);
+    for (name, how) in mutated {
+        findings.push(rc.finding(
+            RULE_ID,
+            Severity::Warning,
+            collections[name],
+            format!(
+                "'{}' es const, pero el módulo lo modifica ({}): es estado mutable compartido. Recíbelo como dependencia o encapsúlalo en una clase.",
+                name, how
+            ),
+        ));
+    }
+    findings
+}
+
+/// Declaración de variables del nivel superior, exportada o no
+fn module_var(item: &ModuleItem) -> Option<&VarDecl> {
+    match item {
+        ModuleItem::Stmt(Stmt::Decl(Decl::Var(var))) => Some(var),
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => match &export.decl {
+            Decl::Var(var) => Some(var),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Objeto o array literal, o una colección creada con `new`
+fn is_mutable_value(expr: &Expr) -> bool {
+    match expr {
+        Expr::Object(_) | Expr::Array(_) => true,
+        Expr::New(new) => {
+            matches!(&*new.callee, Expr::Ident(ident) if MUTABLE_COLLECTIONS.contains(&&*ident.sym))
+        }
+        Expr::Paren(paren) => is_mutable_value(&paren.expr),
+        Expr::TsAs(as_expr) => is_mutable_value(&as_expr.expr),
+        _ => false,
+    }
+}
+
+/// Busca modificaciones de las constantes: la primera de cada una, descrita para el mensaje
+struct MutationVisitor<'a> {
+    names: &'a HashMap<String, Span>,
+    mutated: HashMap<&'a String, String>,
+}
+
+impl<'a> MutationVisitor<'a> {
+    /// Constante al inicio de una cadena de accesos (`CACHE` en `CACHE.users[id]`)
+    fn root(&self, expr: &Expr) -> Option<&'a String> {
+        match expr {
+            Expr::Ident(ident) => self.names.get_key_value(&*ident.sym).map(|(name, _)| name),
+            Expr::Member(member) => self.root(&member.obj),
+            Expr::Paren(paren) => self.root(&paren.expr),
+            _ => None,
+        }
+    }
+
+    /// `CONST.x`, `CONST[i]` o más profundo: no cuenta reasignar la constante, que no compila
+    fn record_member(&mut self, target: &Expr, how: &str) {
+        if let Expr::Member(member) = target {
+            self.record(&member.obj, how);
+        }
+    }
+
+    fn record(&mut self, obj: &Expr, how: &str) {
+        if let Some(name) = self.root(obj) {
+            self.mutated.entry(name).or_insert_with(|| how.to_string());
+        }
+    }
+}
+
+impl Visit for MutationVisitor<'_> {
+    fn visit_assign_expr(&mut self, n: &AssignExpr) {
+        match &n.left {
+            PatOrExpr::Expr(expr) => self.record_member(expr, "asignación"),
+            PatOrExpr::Pat(pat) => {
+                if let Pat::Expr(expr) = &**pat {
+                    self.record_member(expr, "asignación");
+                }
+            }
+        }
+        n.visit_children_with(self);
+    }
+
+    fn visit_update_expr(&mut self, n: &UpdateExpr) {
+        self.record_member(&n.arg, "++/--");
+        n.visit_children_with(self);
+    }
+
+    fn visit_unary_expr(&mut self, n: &UnaryExpr) {
+        if n.op == UnaryOp::Delete {
+            self.record_member(&n.arg, "delete");
+        }
+        n.visit_children_with(self);
+    }
+
+    // `items.push(x)`, `CACHE.users.set(id, user)`
+    fn visit_call_expr(&mut self, n: &CallExpr) {
+        if let Callee::Expr(callee) = &n.callee {
+            if let Expr::Member(member) = &**callee {
+                if let MemberProp::Ident(method) = &member.prop {
+                    if MUTATING_METHODS.contains(&&*method.sym) {
+                        self.record(&member.obj, &format!(".{}()", method.sym));
+                    }
+                }
+            }
+        }
+        n.visit_children_with(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{NoMutableModuleStateRule, RuleSettings};
+    use crate::rules::testing::check;
+
+    const SOURCE: &str = "\
+let counter = 0;
+export var registry = {};
+let instance = null;
+const CACHE = {};
+const items: string[] = [];
+const LIMITS = { max: 10 };
+const seen = new Set<string>();
+export function remember(id: string) {
+    CACHE[id] = true;
+    items.push(id);
+    seen.add(id);
+    return LIMITS.max;
+}
+";
+
+    fn settings(paths: &[&str]) -> RuleSettings {
+        RuleSettings {
+            no_mutable_module_state: Some(NoMutableModuleStateRule {
+                paths: paths.iter().map(|p| p.to_string()).collect(),
+                allow: vec!["instance".to_string()],
+            }),
+            ..RuleSettings::default()
+        }
+    }
+
+    #[test]
+    fn reports_reassignable_and_mutated_module_state() {
+        let findings = check(RULE_ID, "src/app/state.ts", SOURCE, settings(&[]));
+        // `instance` está permitida y LIMITS no se modifica
+        let lines: Vec<usize> = findings.iter().map(|f| f.location.start_line).collect();
+        assert_eq!(lines, vec![1, 2, 4, 5, 7]);
+        assert_eq!(
+            findings[0].message,
+            "'counter' es estado mutable del módulo (let): lo comparten todos los que lo importan. Recíbelo como dependencia o encapsúlalo en una clase."
+        );
+        assert_eq!(
+            findings[3].message,
+            "'items' es const, pero el módulo lo modifica (.push()): es estado mutable compartido. Recíbelo como dependencia o encapsúlalo en una clase."
+        );
+    }
+
+    #[test]
+    fn ignores_files_outside_configured_paths() {
+        assert!(check(
+            RULE_ID,
+            "src/app/state.ts",
+            SOURCE,
+            settings(&["src/domain"])
+        )
+        .is_empty());
+    }
+}
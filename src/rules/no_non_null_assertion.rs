@@ -0,0 +1,99 @@
+//! Aserciones non-null (`valor!`) de TypeScript
+//!
+//! `user!.email` le dice al compilador que el valor nunca es null sin comprobarlo:
+//! si la suposición falla, el error aparece en runtime lejos de su causa. En los
+//! tests suele ser aceptable (el valor lo controla el propio test).
+
+use super::{Finding, RuleContext, Severity};
+use swc_ecma_ast::TsNonNullExpr;
+use swc_ecma_visit::{Visit, VisitWith};
+
+pub const RULE_ID: &str = "no-non-null-assertion";
+
+/// Archivos donde se permiten por defecto: los tests
+pub fn default_allowed_paths() -> Vec<String> {
+    ["*.spec.*", "*.test.*", "__tests__/"]
+        .iter()
+        .map(|pattern| pattern.to_string())
+        .collect()
+}
+
+/// Advierte sobre cada aserción non-null en las capas configuradas
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    let rule = match &rc.ctx.settings.no_non_null_assertion {
+        Some(rule) => rule,
+        None => return Vec::new(),
+    };
+
+    // Sin `paths` aplica a todo el proyecto
+    if (!rule.paths.is_empty() && !rc.path_matches(&rule.paths)) || rc.path_matches(&rule.allow) {
+        return Vec::new();
+    }
+
+    let mut visitor = NonNullVisitor {
+        rc,
+        findings: Vec::new(),
+    };
+    rc.module.visit_with(&mut visitor);
+    visitor.findings
+}
+
+struct NonNullVisitor<'a, 'b> {
+    rc: &'a RuleContext<'b>,
+    findings: Vec<Finding>,
+}
+
+impl Visit for NonNullVisitor<'_, '_> {
+    fn visit_ts_non_null_expr(&mut self, n: &TsNonNullExpr) {
+        self.findings.push(self.rc.finding(
+            RULE_ID,
+            Severity::Warning,
+            n.span,
+            "Aserción non-null (!): si el valor llega a ser null fallará en runtime. Compruébalo (if, ?. o ??) o ajusta el tipo.".to_string(),
+        ));
+        n.visit_children_with(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{NoNonNullAssertionRule, RuleSettings};
+    use crate::rules::testing::check;
+
+    const SOURCE: &str = "\
+export function email(user?: User) {
+    const name = user?.name ?? 'anónimo';
+    return user!.profile!.email;
+}
+";
+
+    fn settings(paths: &[&str]) -> RuleSettings {
+        RuleSettings {
+            no_non_null_assertion: Some(NoNonNullAssertionRule {
+                paths: paths.iter().map(|path| path.to_string()).collect(),
+                allow: default_allowed_paths(),
+            }),
+            ..RuleSettings::default()
+        }
+    }
+
+    #[test]
+    fn reports_each_assertion() {
+        let findings = check(RULE_ID, "src/users/email.ts", SOURCE, settings(&[]));
+        assert_eq!(findings.len(), 2);
+        assert!(findings.iter().all(|f| f.location.start_line == 3));
+    }
+
+    #[test]
+    fn skips_tests_and_files_outside_the_paths() {
+        assert!(check(RULE_ID, "src/users/email.spec.ts", SOURCE, settings(&[])).is_empty());
+        assert!(check(
+            RULE_ID,
+            "src/users/email.ts",
+            SOURCE,
+            settings(&["src/domain/"])
+        )
+        .is_empty());
+    }
+}
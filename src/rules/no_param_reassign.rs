@@ -0,0 +1,267 @@
+//! Funciones que reasignan o modifican sus parámetros
+//!
+//! Cambiar un objeto o array recibido por parámetro modifica también el de quien
+//! llamó a la función, lejos de donde se ve el cambio; reasignar el parámetro oculta
+//! el valor recibido. Se señalan las asignaciones (`param = x`, `param.campo = x`,
+//! `param[i] = x`), los `++`/`--` y los `delete` sobre parámetros.
+//!
+//! Es una heurística sin tipos ni análisis de alias: no ve las mutaciones a través
+//! de otra variable (`const o = param; o.x = 1`) ni las llamadas que mutan
+//! (`param.push(x)`), y una variable local que oculta a un parámetro con el mismo
+//! nombre se toma por el parámetro.
+
+use super::{Finding, RuleContext, Severity};
+use std::collections::HashSet;
+use swc_common::Span;
+use swc_ecma_ast::{
+    ArrowExpr, AssignExpr, AssignPatProp, BindingIdent, Constructor, Expr, Function, Pat,
+    PatOrExpr, TsTypeAnn, UnaryExpr, UnaryOp, UpdateExpr,
+};
+use swc_ecma_visit::{Visit, VisitWith};
+
+pub const RULE_ID: &str = "no-param-reassign";
+
+/// Parámetros que se modifican por convención: acumuladores de `reduce` y los
+/// objetos de petición/respuesta de los middlewares
+pub fn default_ignored_props_for() -> Vec<String> {
+    ["acc", "ctx", "req", "res", "request", "response"]
+        .iter()
+        .map(|name| name.to_string())
+        .collect()
+}
+
+/// Advierte sobre cada reasignación o mutación de un parámetro
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    let rule = match &rc.ctx.settings.no_param_reassign {
+        Some(rule) => rule,
+        None => return Vec::new(),
+    };
+
+    // Sin `paths` aplica a todo el proyecto
+    if !rule.paths.is_empty() && !rc.path_matches(&rule.paths) {
+        return Vec::new();
+    }
+
+    let mut visitor = ParamVisitor {
+        rc,
+        props: rule.props,
+        ignore_props_for: &rule.ignore_props_for,
+        params: Vec::new(),
+        findings: Vec::new(),
+    };
+    rc.module.visit_with(&mut visitor);
+    visitor.findings
+}
+
+struct ParamVisitor<'a, 'b> {
+    rc: &'a RuleContext<'b>,
+    /// Señalar también las asignaciones a propiedades de los parámetros
+    props: bool,
+    ignore_props_for: &'a [String],
+    /// Parámetros de cada función que rodea al nodo actual (las closures ven los
+    /// de las funciones externas)
+    params: Vec<HashSet<String>>,
+    findings: Vec<Finding>,
+}
+
+impl ParamVisitor<'_, '_> {
+    fn is_param(&self, name: &str) -> bool {
+        self.params.iter().any(|scope| scope.contains(name))
+    }
+
+    fn enter<P: VisitWith<ParamNames>>(&mut self, params: &P) {
+        let mut names = ParamNames::default();
+        params.visit_with(&mut names);
+        self.params.push(names.0);
+    }
+
+    fn check_reassign(&mut self, name: &str, span: Span) {
+        if !self.is_param(name) {
+            return;
+        }
+        self.findings.push(self.rc.finding(
+            RULE_ID,
+            Severity::Warning,
+            span,
+            format!(
+                "Reasigna el parámetro '{}'. Usa una variable local para no ocultar el valor recibido.",
+                name
+            ),
+        ));
+    }
+
+    /// Revisa el destino de una asignación, `++`/`--` o `delete`
+    fn check_target(&mut self, target: &Expr, span: Span, reassignable: bool) {
+        match target {
+            Expr::Ident(ident) if reassignable => self.check_reassign(&ident.sym, span),
+            Expr::Member(member) if self.props => {
+                let root = match root_ident(&member.obj) {
+                    Some(root) => root,
+                    None => return,
+                };
+                if self.is_param(root) && !self.ignore_props_for.iter().any(|name| name == root) {
+                    self.findings.push(self.rc.finding(
+                        RULE_ID,
+                        Severity::Warning,
+                        span,
+                        format!(
+                            "Modifica el parámetro '{}': el cambio también lo ve quien llamó a la función. Trabaja sobre una copia ({{ ...obj }}, [...arr]) o retorna el valor nuevo.",
+                            root
+                        ),
+                    ));
+                }
+            }
+            Expr::Paren(paren) => self.check_target(&paren.expr, span, reassignable),
+            _ => {}
+        }
+    }
+}
+
+impl Visit for ParamVisitor<'_, '_> {
+    fn visit_function(&mut self, n: &Function) {
+        self.enter(&n.params);
+        n.body.visit_with(self);
+        self.params.pop();
+    }
+
+    fn visit_arrow_expr(&mut self, n: &ArrowExpr) {
+        self.enter(&n.params);
+        n.body.visit_with(self);
+        self.params.pop();
+    }
+
+    fn visit_constructor(&mut self, n: &Constructor) {
+        self.enter(&n.params);
+        n.body.visit_with(self);
+        self.params.pop();
+    }
+
+    fn visit_assign_expr(&mut self, n: &AssignExpr) {
+        match &n.left {
+            PatOrExpr::Expr(expr) => self.check_target(expr, n.span, true),
+            PatOrExpr::Pat(pat) => match &**pat {
+                Pat::Ident(binding) => self.check_reassign(&binding.id.sym, n.span),
+                Pat::Expr(expr) => self.check_target(expr, n.span, true),
+                _ => {}
+            },
+        }
+        n.right.visit_with(self);
+    }
+
+    fn visit_update_expr(&mut self, n: &UpdateExpr) {
+        self.check_target(&n.arg, n.span, true);
+        n.visit_children_with(self);
+    }
+
+    fn visit_unary_expr(&mut self, n: &UnaryExpr) {
+        // `delete param` no es válido: solo cuentan las propiedades
+        if n.op == UnaryOp::Delete {
+            self.check_target(&n.arg, n.span, false);
+        }
+        n.visit_children_with(self);
+    }
+}
+
+/// Variable al inicio de una cadena de accesos (`user` en `user.address.city`)
+fn root_ident(expr: &Expr) -> Option<&str> {
+    match expr {
+        Expr::Ident(ident) => Some(&*ident.sym),
+        Expr::Member(member) => root_ident(&member.obj),
+        Expr::Paren(paren) => root_ident(&paren.expr),
+        _ => None,
+    }
+}
+
+/// Nombres enlazados por una lista de parámetros, incluidos los desestructurados
+#[derive(Default)]
+struct ParamNames(HashSet<String>);
+
+impl Visit for ParamNames {
+    fn visit_binding_ident(&mut self, n: &BindingIdent) {
+        self.0.insert(n.id.sym.to_string());
+    }
+
+    // `{ limit = 10 }`: la clave es el nombre enlazado
+    fn visit_assign_pat_prop(&mut self, n: &AssignPatProp) {
+        self.0.insert(n.key.sym.to_string());
+    }
+
+    // Los valores por defecto y los decoradores no declaran parámetros
+    fn visit_expr(&mut self, _: &Expr) {}
+
+    // Ni los parámetros de los tipos función (`cb: (x: number) => void`)
+    fn visit_ts_type_ann(&mut self, _: &TsTypeAnn) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{NoParamReassignRule, RuleSettings};
+    use crate::rules::testing::check;
+
+    const SOURCE: &str = "\
+export function apply(order: Order, { limit = 10 }: Options, items: Item[]) {
+    order = normalize(order);
+    order.total = 0;
+    items[0] = order;
+    limit++;
+    delete order.draft;
+    return items.reduce((acc, item) => {
+        acc.count = item.count;
+        return acc;
+    }, {});
+}
+";
+
+    fn settings(props: bool) -> RuleSettings {
+        RuleSettings {
+            no_param_reassign: Some(NoParamReassignRule {
+                paths: Vec::new(),
+                props,
+                ignore_props_for: default_ignored_props_for(),
+            }),
+            ..RuleSettings::default()
+        }
+    }
+
+    fn lines(props: bool) -> Vec<usize> {
+        check(RULE_ID, "src/orders.ts", SOURCE, settings(props))
+            .iter()
+            .map(|f| f.location.start_line)
+            .collect()
+    }
+
+    #[test]
+    fn reports_reassignments_and_mutations() {
+        // El acumulador de reduce se modifica por convención
+        assert_eq!(lines(true), vec![2, 3, 4, 5, 6]);
+
+        let findings = check(RULE_ID, "src/orders.ts", SOURCE, settings(true));
+        assert_eq!(
+            findings[0].message,
+            "Reasigna el parámetro 'order'. Usa una variable local para no ocultar el valor recibido."
+        );
+        assert!(findings[1]
+            .message
+            .starts_with("Modifica el parámetro 'order'"));
+    }
+
+    #[test]
+    fn property_changes_can_be_allowed() {
+        assert_eq!(lines(false), vec![2, 5]);
+    }
+
+    #[test]
+    fn local_variables_are_not_parameters() {
+        let source = "\
+export function total(items: Item[]) {
+    let sum = 0;
+    for (const item of items) {
+        sum += item.price;
+    }
+    return sum;
+}
+";
+        assert!(check(RULE_ID, "src/orders.ts", source, settings(true)).is_empty());
+    }
+}
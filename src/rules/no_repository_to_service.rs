@@ -0,0 +1,99 @@
+//! Repositorios que importan servicios (dependencia invertida)
+//!
+//! El repositorio es infraestructura: implementa una interfaz del dominio y no debe
+//! conocer a los servicios que lo usan. Si lo hace, la dependencia apunta al revés y
+//! suele terminar en un ciclo servicio ↔ repositorio.
+
+use super::{Finding, RuleContext, Severity};
+use crate::circular::resolve_import_path;
+use std::path::Path;
+use swc_ecma_ast::{ModuleDecl, ModuleItem};
+
+pub const RULE_ID: &str = "no-repository-to-service";
+
+/// Reporta los imports de un `*.repository.*` que resuelven a un `*.service.*`
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    if !has_role(rc.path, "repository") || !rc.ctx.is_rule_enabled(RULE_ID, rc.path) {
+        return Vec::new();
+    }
+
+    rc.module
+        .body
+        .iter()
+        .filter_map(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(import)) if !import.type_only => {
+                Some(import)
+            }
+            _ => None,
+        })
+        .filter_map(|import| {
+            let source: &str = &import.src.value;
+            // El rol se confirma con el archivo resuelto, no con el texto del import
+            let target = resolve_import_path(rc.path, source)?;
+            if !has_role(&target, "service") {
+                return None;
+            }
+            Some(rc.finding(
+                RULE_ID,
+                Severity::Error,
+                import.span,
+                format!(
+                    "El repositorio importa el servicio '{}'. Invierte la dependencia: el servicio usa el repositorio a través de una interfaz del dominio, no al revés.",
+                    source
+                ),
+            ))
+        })
+        .collect()
+}
+
+/// `user.repository.ts`, `user.service.js`...
+fn has_role(path: &Path, role: &str) -> bool {
+    path.file_name().is_some_and(|name| {
+        name.to_string_lossy()
+            .to_lowercase()
+            .contains(&format!(".{}.", role))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Framework, LinterContext, RuleSettings};
+    use crate::rules::testing::{findings_in, temp_project};
+
+    const SOURCE: &str = "\
+import { OrdersService } from './orders.service';
+import { Order } from './order.entity';
+import type { OrdersPort } from './orders.service';
+";
+
+    #[test]
+    fn reports_repositories_that_import_services() {
+        let root = temp_project(
+            "repository-to-service",
+            &[
+                ("src/orders/orders.repository.ts", SOURCE),
+                (
+                    "src/orders/orders.service.ts",
+                    "export class OrdersService {}\n",
+                ),
+                ("src/orders/order.entity.ts", "export class Order {}\n"),
+            ],
+        );
+        let repository = root.join("src/orders/orders.repository.ts");
+        let repository = repository.to_str().unwrap();
+        let nestjs = LinterContext {
+            framework: Framework::NestJS,
+            ..LinterContext::for_tests(RuleSettings::default())
+        };
+
+        // Las entidades y los imports de solo tipos están permitidos
+        let findings = findings_in(RULE_ID, repository, SOURCE, &nestjs);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].location.start_line, 1);
+
+        // Sin framework ni patrón en capas la regla no aplica
+        let plain = LinterContext::for_tests(RuleSettings::default());
+        assert!(findings_in(RULE_ID, repository, SOURCE, &plain).is_empty());
+    }
+}
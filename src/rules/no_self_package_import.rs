@@ -0,0 +1,139 @@
+//! Módulos que importan su propio paquete por nombre
+//!
+//! En un monorepo, `import { x } from '@org/pkg/util'` dentro del propio
+//! `@org/pkg` pasa por los `exports` publicados (o por el build en `dist/`) en
+//! lugar del código fuente: el bundler puede duplicar módulos y aparecen ciclos
+//! que el grafo de imports relativos no muestra. El paquete es el `name` del
+//! package.json más cercano al archivo.
+
+use super::{Finding, RuleContext, Severity};
+use crate::resolver::own_package_name;
+use swc_ecma_ast::{ModuleDecl, ModuleItem};
+
+pub const RULE_ID: &str = "no-self-package-import";
+
+/// Archivos donde se permite por defecto: los tests, que prueban la API publicada
+pub fn default_allowed_paths() -> Vec<String> {
+    ["*.spec.*", "*.test.*", "__tests__/"]
+        .iter()
+        .map(|pattern| pattern.to_string())
+        .collect()
+}
+
+/// Reporta los imports y re-exports del nombre del propio paquete o de sus subpaths
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    let rule = match &rc.ctx.settings.no_self_package_import {
+        Some(rule) => rule,
+        None => return Vec::new(),
+    };
+
+    if rc.path_matches(&rule.allow) {
+        return Vec::new();
+    }
+    let package = match rc
+        .path
+        .canonicalize()
+        .ok()
+        .and_then(|path| own_package_name(&path))
+    {
+        Some(package) => package,
+        None => return Vec::new(),
+    };
+
+    rc.module
+        .body
+        .iter()
+        .filter_map(|item| match item {
+            // Los imports de tipos se borran al compilar: no afectan al bundle
+            ModuleItem::ModuleDecl(ModuleDecl::Import(import)) if !import.type_only => {
+                Some((&*import.src.value, import.span))
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportAll(export)) if !export.type_only => {
+                Some((&*export.src.value, export.span))
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(export)) if !export.type_only => {
+                export.src.as_ref().map(|src| (&*src.value, export.span))
+            }
+            _ => None,
+        })
+        .filter(|(source, _)| is_self_import(source, &package))
+        .map(|(source, span)| {
+            rc.finding(
+                RULE_ID,
+                Severity::Error,
+                span,
+                format!(
+                    "'{}' importa el propio paquete '{}' por su nombre. Dentro del paquete usa un import relativo.",
+                    source, package
+                ),
+            )
+        })
+        .collect()
+}
+
+/// El nombre exacto del paquete o uno de sus subpaths (`@org/pkg/util`)
+fn is_self_import(source: &str, package: &str) -> bool {
+    source
+        .strip_prefix(package)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{NoSelfPackageImportRule, RuleSettings};
+    use crate::rules::testing::{check, temp_project};
+
+    const SOURCE: &str = "\
+import { slugify } from '@org/core/util';
+import { Extra } from '@org/core-extra';
+import type { Order } from '@org/core';
+export * from '@org/core';
+import { format } from './format';
+";
+
+    fn settings() -> RuleSettings {
+        RuleSettings {
+            no_self_package_import: Some(NoSelfPackageImportRule {
+                allow: default_allowed_paths(),
+            }),
+            ..RuleSettings::default()
+        }
+    }
+
+    #[test]
+    fn reports_imports_of_the_own_package() {
+        let root = temp_project(
+            "self-package-import",
+            &[
+                (
+                    "packages/core/package.json",
+                    "{ \"name\": \"@org/core\" }\n",
+                ),
+                ("packages/core/src/orders.ts", SOURCE),
+                ("packages/core/src/orders.spec.ts", SOURCE),
+            ],
+        );
+
+        let file = root.join("packages/core/src/orders.ts");
+        let findings = check(RULE_ID, file.to_str().unwrap(), SOURCE, settings());
+        // Otro paquete con el mismo prefijo y los imports de tipos no cuentan
+        let lines: Vec<usize> = findings.iter().map(|f| f.location.start_line).collect();
+        assert_eq!(lines, vec![1, 4]);
+        assert_eq!(
+            findings[0].message,
+            "'@org/core/util' importa el propio paquete '@org/core' por su nombre. Dentro del paquete usa un import relativo."
+        );
+
+        let spec = root.join("packages/core/src/orders.spec.ts");
+        assert!(check(RULE_ID, spec.to_str().unwrap(), SOURCE, settings()).is_empty());
+    }
+
+    #[test]
+    fn matches_the_package_name_and_its_subpaths() {
+        assert!(is_self_import("@org/core", "@org/core"));
+        assert!(is_self_import("@org/core/util", "@org/core"));
+        assert!(!is_self_import("@org/core-extra", "@org/core"));
+        assert!(!is_self_import("./core", "@org/core"));
+    }
+}
@@ -0,0 +1,142 @@
+//! Servicios que importan servicios de otra feature
+//!
+//! En arquitecturas con mediador o bus de eventos, los servicios de features
+//! distintas no se conocen entre sí: se comunican publicando comandos o eventos.
+//! Los servicios de la misma feature pueden colaborar directamente.
+
+use super::feature_isolation::feature_of;
+use super::{Finding, RuleContext, Severity};
+use crate::analyzer::normalize_pattern;
+use crate::circular::resolve_import_path;
+use std::path::Path;
+use swc_ecma_ast::{ModuleDecl, ModuleItem};
+
+pub const RULE_ID: &str = "no-service-to-service";
+
+/// Reporta los imports de un `*.service.*` hacia un servicio de otra feature
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    let rule = match &rc.ctx.settings.no_service_to_service {
+        Some(rule) => rule,
+        None => return Vec::new(),
+    };
+
+    if !is_service(rc.path) || (!rule.paths.is_empty() && !rc.path_matches(&rule.paths)) {
+        return Vec::new();
+    }
+
+    let features_root = rule.features_root.as_deref().map(|root| {
+        let mut root = normalize_pattern(root);
+        if !root.ends_with('/') {
+            root.push('/');
+        }
+        root
+    });
+    let feature = |path: &Path| match &features_root {
+        Some(root) => feature_of(path, root).map(|(feature, _)| feature),
+        // Sin features_root, cada carpeta es una feature
+        None => path
+            .parent()
+            .map(|dir| dir.to_string_lossy().to_lowercase().replace('\\', "/")),
+    };
+
+    let current = match rc.path.canonicalize().ok().and_then(|path| feature(&path)) {
+        Some(current) => current,
+        None => return Vec::new(),
+    };
+
+    rc.module
+        .body
+        .iter()
+        .filter_map(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(import)) if !import.type_only => {
+                Some(import)
+            }
+            _ => None,
+        })
+        .filter_map(|import| {
+            let source: &str = &import.src.value;
+            let target = resolve_import_path(rc.path, source)?.canonicalize().ok()?;
+            if !is_service(&target) || feature(&target)? == current {
+                return None;
+            }
+            Some(rc.finding(
+                RULE_ID,
+                Severity::Error,
+                import.span,
+                format!(
+                    "El servicio importa directamente '{}' de otra feature. Comunícalo a través del mediador o de un evento.",
+                    source
+                ),
+            ))
+        })
+        .collect()
+}
+
+/// `user.service.ts`, `user.service.js`...
+fn is_service(path: &Path) -> bool {
+    path.file_name()
+        .is_some_and(|name| name.to_string_lossy().to_lowercase().contains(".service."))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{NoServiceToServiceRule, RuleSettings};
+    use crate::rules::testing::{check, temp_project};
+
+    const SOURCE: &str = "\
+import { BillingService } from '../billing/billing.service';
+import { PricingService } from './pricing.service';
+import { Invoice } from '../billing/invoice';
+import type { BillingPort } from '../billing/billing.service';
+";
+
+    fn settings(features_root: Option<&str>) -> RuleSettings {
+        RuleSettings {
+            no_service_to_service: Some(NoServiceToServiceRule {
+                paths: Vec::new(),
+                features_root: features_root.map(str::to_string),
+            }),
+            ..RuleSettings::default()
+        }
+    }
+
+    #[test]
+    fn reports_services_of_other_features() {
+        let root = temp_project(
+            "service-to-service",
+            &[
+                ("src/orders/orders.service.ts", SOURCE),
+                (
+                    "src/orders/pricing.service.ts",
+                    "export class PricingService {}\n",
+                ),
+                ("src/orders/orders.controller.ts", SOURCE),
+                (
+                    "src/billing/billing.service.ts",
+                    "export class BillingService {}\n",
+                ),
+                ("src/billing/invoice.ts", "export class Invoice {}\n"),
+            ],
+        );
+        let service = root.join("src/orders/orders.service.ts");
+        let service = service.to_str().unwrap();
+
+        // Misma feature, no servicios e imports de solo tipos están permitidos
+        for settings in [settings(None), settings(Some("src/"))] {
+            let findings = check(RULE_ID, service, SOURCE, settings);
+            assert_eq!(findings.len(), 1);
+            assert_eq!(findings[0].location.start_line, 1);
+        }
+
+        // La regla solo mira los imports de los servicios
+        let controller = root.join("src/orders/orders.controller.ts");
+        assert!(check(
+            RULE_ID,
+            controller.to_str().unwrap(),
+            SOURCE,
+            settings(None)
+        )
+        .is_empty());
+    }
+}
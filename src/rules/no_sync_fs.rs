@@ -0,0 +1,139 @@
+//! Llamadas síncronas de fs en capas que atienden peticiones
+//!
+//! `fs.readFileSync` y compañía bloquean el event loop de Node: dentro de un
+//! controlador o servicio frenan todas las peticiones concurrentes.
+
+use super::{Finding, RuleContext, Severity};
+use swc_ecma_ast::{CallExpr, Callee, Expr, MemberProp};
+use swc_ecma_visit::{Visit, VisitWith};
+
+pub const RULE_ID: &str = "no-sync-fs";
+
+/// Métodos bloqueantes de `fs` revisados por defecto
+pub fn default_sync_methods() -> Vec<String> {
+    [
+        "readFileSync",
+        "writeFileSync",
+        "appendFileSync",
+        "existsSync",
+        "statSync",
+        "lstatSync",
+        "readdirSync",
+        "mkdirSync",
+        "rmSync",
+        "unlinkSync",
+        "copyFileSync",
+        "renameSync",
+        "accessSync",
+        "execSync",
+    ]
+    .iter()
+    .map(|m| m.to_string())
+    .collect()
+}
+
+/// Advierte sobre cada llamada síncrona dentro de las capas configuradas
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    let rule = match &rc.ctx.settings.no_sync_fs {
+        Some(rule) => rule,
+        None => return Vec::new(),
+    };
+
+    if !rc.path_matches(&rule.paths) {
+        return Vec::new();
+    }
+
+    let mut visitor = SyncCallVisitor {
+        rc,
+        methods: &rule.methods,
+        findings: Vec::new(),
+    };
+    rc.module.visit_with(&mut visitor);
+    visitor.findings
+}
+
+struct SyncCallVisitor<'a, 'b> {
+    rc: &'a RuleContext<'b>,
+    methods: &'a [String],
+    findings: Vec<Finding>,
+}
+
+impl Visit for SyncCallVisitor<'_, '_> {
+    fn visit_call_expr(&mut self, n: &CallExpr) {
+        // `fs.readFileSync(...)` o `readFileSync(...)` importado por nombre
+        let name = match &n.callee {
+            Callee::Expr(expr) => match &**expr {
+                Expr::Member(member) => match &member.prop {
+                    MemberProp::Ident(ident) => Some(&ident.sym),
+                    _ => None,
+                },
+                Expr::Ident(ident) => Some(&ident.sym),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        if let Some(name) = name {
+            let name: &str = name;
+            if self.methods.iter().any(|m| m == name) {
+                self.findings.push(self.rc.finding(
+                    RULE_ID,
+                    Severity::Warning,
+                    n.span,
+                    format!(
+                        "'{}' bloquea el event loop. Usa la versión asíncrona (fs/promises) en esta capa.",
+                        name
+                    ),
+                ));
+            }
+        }
+
+        n.visit_children_with(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{NoSyncFsRule, RuleSettings};
+    use crate::rules::testing::check;
+
+    const SOURCE: &str = "\
+import fs from 'fs';
+import { existsSync } from 'fs';
+
+export async function load(path: string) {
+    if (!existsSync(path)) {
+        return null;
+    }
+    const raw = fs.readFileSync(path, 'utf8');
+    return fs.promises.readFile(path, 'utf8').then(() => raw);
+}
+";
+
+    fn settings() -> RuleSettings {
+        RuleSettings {
+            no_sync_fs: Some(NoSyncFsRule {
+                paths: vec!["src/services/".to_string()],
+                methods: default_sync_methods(),
+            }),
+            ..RuleSettings::default()
+        }
+    }
+
+    #[test]
+    fn reports_sync_calls_in_configured_layers() {
+        let findings = check(RULE_ID, "src/services/loader.ts", SOURCE, settings());
+        let lines: Vec<usize> = findings.iter().map(|f| f.location.start_line).collect();
+        assert_eq!(lines, vec![5, 8]);
+        assert_eq!(
+            findings[1].message,
+            "'readFileSync' bloquea el event loop. Usa la versión asíncrona (fs/promises) en esta capa."
+        );
+    }
+
+    #[test]
+    fn ignores_files_outside_the_configured_layers() {
+        assert!(check(RULE_ID, "scripts/build.ts", SOURCE, settings()).is_empty());
+    }
+}
@@ -0,0 +1,322 @@
+//! Funciones y métodos públicos que retornan `any` o `unknown`
+//!
+//! Un `any` en el retorno de la API pública de un módulo se propaga a todo el
+//! código que la usa sin que el compilador avise. Se revisan las funciones
+//! exportadas y los métodos públicos de las clases exportadas: con tipo de retorno
+//! `any`/`unknown` (también dentro de `Promise<...>`) o, sin anotación, cuando
+//! algún `return` devuelve algo que TypeScript tipa como `any` (`JSON.parse(...)`,
+//! `respuesta.json()` o `x as any`). Lo segundo es una heurística: no hay
+//! inferencia de tipos real.
+
+use super::{prop_name_to_string, Finding, RuleContext, Severity};
+use swc_common::{Span, Spanned};
+use swc_ecma_ast::{
+    Accessibility, ArrowExpr, BlockStmtOrExpr, Callee, Class, ClassMember, Decl, DefaultDecl, Expr,
+    Function, MemberProp, MethodKind, ModuleDecl, ModuleItem, Pat, ReturnStmt, TsEntityName,
+    TsKeywordTypeKind, TsType, TsTypeAnn,
+};
+use swc_ecma_visit::{Visit, VisitWith};
+
+pub const RULE_ID: &str = "no-weak-public-return";
+
+/// Extensiones con anotaciones de tipo
+const TYPESCRIPT_EXTENSIONS: &[&str] = &["ts", "tsx", "mts", "cts"];
+
+/// Advierte sobre cada firma pública con un retorno débil
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    let rule = match &rc.ctx.settings.no_weak_public_return {
+        Some(rule) => rule,
+        None => return Vec::new(),
+    };
+
+    let extension = rc.path.extension().and_then(|e| e.to_str()).unwrap_or("");
+    if !TYPESCRIPT_EXTENSIONS.contains(&extension) {
+        return Vec::new();
+    }
+    // Sin `paths` aplica a todo el proyecto
+    if !rule.paths.is_empty() && !rc.path_matches(&rule.paths) {
+        return Vec::new();
+    }
+
+    let mut checker = SignatureChecker {
+        rc,
+        allow_unknown: rule.allow_unknown,
+        findings: Vec::new(),
+    };
+    for item in &rc.module.body {
+        let decl = match item {
+            ModuleItem::ModuleDecl(decl) => decl,
+            ModuleItem::Stmt(_) => continue,
+        };
+        match decl {
+            ModuleDecl::ExportDecl(export) => match &export.decl {
+                Decl::Fn(f) => checker.check_function(&f.ident.sym, f.ident.span, &f.function),
+                Decl::Class(c) => checker.check_class(&c.ident.sym, &c.class),
+                // `export const handler = () => ...` (sin tipo en la variable)
+                Decl::Var(var) => {
+                    for declarator in &var.decls {
+                        let binding = match &declarator.name {
+                            Pat::Ident(binding) if binding.type_ann.is_none() => binding,
+                            _ => continue,
+                        };
+                        match declarator.init.as_deref() {
+                            Some(Expr::Arrow(arrow)) => {
+                                checker.check_arrow(&binding.id.sym, binding.id.span, arrow)
+                            }
+                            Some(Expr::Fn(f)) => checker.check_function(
+                                &binding.id.sym,
+                                binding.id.span,
+                                &f.function,
+                            ),
+                            _ => {}
+                        }
+                    }
+                }
+                _ => {}
+            },
+            ModuleDecl::ExportDefaultDecl(export) => match &export.decl {
+                DefaultDecl::Fn(f) => match &f.ident {
+                    Some(ident) => checker.check_function(&ident.sym, ident.span, &f.function),
+                    None => checker.check_function("default", export.span, &f.function),
+                },
+                DefaultDecl::Class(c) => {
+                    let name = c.ident.as_ref().map_or("default", |ident| &*ident.sym);
+                    checker.check_class(name, &c.class);
+                }
+                DefaultDecl::TsInterfaceDecl(_) => {}
+            },
+            _ => {}
+        }
+    }
+    checker.findings
+}
+
+struct SignatureChecker<'a, 'b> {
+    rc: &'a RuleContext<'b>,
+    allow_unknown: bool,
+    findings: Vec<Finding>,
+}
+
+impl SignatureChecker<'_, '_> {
+    /// Métodos públicos de la clase (ni `private`, ni `protected`, ni `#privados`)
+    fn check_class(&mut self, class_name: &str, class: &Class) {
+        for member in &class.body {
+            let method = match member {
+                ClassMember::Method(method) => method,
+                _ => continue,
+            };
+            let hidden = matches!(
+                method.accessibility,
+                Some(Accessibility::Private) | Some(Accessibility::Protected)
+            );
+            if hidden || method.kind == MethodKind::Setter {
+                continue;
+            }
+            let name = format!("{}.{}", class_name, prop_name_to_string(&method.key));
+            self.check_function(&name, method.key.span(), &method.function);
+        }
+    }
+
+    fn check_function(&mut self, name: &str, name_span: Span, function: &Function) {
+        let inferred = || {
+            let mut returns = ReturnVisitor::default();
+            function.body.visit_with(&mut returns);
+            returns.any_source
+        };
+        self.report(name, name_span, function.return_type.as_deref(), inferred);
+    }
+
+    fn check_arrow(&mut self, name: &str, name_span: Span, arrow: &ArrowExpr) {
+        let inferred = || match &*arrow.body {
+            BlockStmtOrExpr::Expr(expr) => any_source(expr),
+            BlockStmtOrExpr::BlockStmt(body) => {
+                let mut returns = ReturnVisitor::default();
+                body.visit_with(&mut returns);
+                returns.any_source
+            }
+        };
+        self.report(name, name_span, arrow.return_type.as_deref(), inferred);
+    }
+
+    fn report(
+        &mut self,
+        name: &str,
+        name_span: Span,
+        return_type: Option<&TsTypeAnn>,
+        inferred: impl FnOnce() -> Option<&'static str>,
+    ) {
+        let (span, message) = match return_type {
+            Some(annotation) => match weak_type(&annotation.type_ann, self.allow_unknown) {
+                Some(weak) => (
+                    annotation.span,
+                    format!(
+                        "'{}' es parte de la API pública y retorna '{}': quien lo use pierde el tipado. Declara el tipo concreto del resultado.",
+                        name, weak
+                    ),
+                ),
+                None => return,
+            },
+            None => match inferred() {
+                Some(source) => (
+                    name_span,
+                    format!(
+                        "'{}' es parte de la API pública y no anota su retorno: TypeScript infiere 'any' por {}. Declara el tipo de retorno.",
+                        name, source
+                    ),
+                ),
+                None => return,
+            },
+        };
+        self.findings
+            .push(self.rc.finding(RULE_ID, Severity::Warning, span, message));
+    }
+}
+
+/// `any`, `unknown` (salvo con `allow_unknown`) o una `Promise` de alguno de ellos
+fn weak_type(ty: &TsType, allow_unknown: bool) -> Option<String> {
+    match ty {
+        TsType::TsKeywordType(keyword) => match keyword.kind {
+            TsKeywordTypeKind::TsAnyKeyword => Some("any".to_string()),
+            TsKeywordTypeKind::TsUnknownKeyword if !allow_unknown => Some("unknown".to_string()),
+            _ => None,
+        },
+        TsType::TsTypeRef(reference) => {
+            let is_promise = matches!(
+                &reference.type_name,
+                TsEntityName::Ident(ident) if &*ident.sym == "Promise"
+            );
+            let params = reference.type_params.as_ref()?;
+            match params.params.as_slice() {
+                [inner] if is_promise => {
+                    weak_type(inner, allow_unknown).map(|weak| format!("Promise<{}>", weak))
+                }
+                _ => None,
+            }
+        }
+        TsType::TsParenthesizedType(paren) => weak_type(&paren.type_ann, allow_unknown),
+        _ => None,
+    }
+}
+
+/// Expresiones que TypeScript tipa como `any`, descritas para el mensaje
+fn any_source(expr: &Expr) -> Option<&'static str> {
+    match expr {
+        Expr::Await(await_expr) => any_source(&await_expr.arg),
+        Expr::Paren(paren) => any_source(&paren.expr),
+        Expr::TsAs(as_expr) => match &*as_expr.type_ann {
+            TsType::TsKeywordType(keyword) if keyword.kind == TsKeywordTypeKind::TsAnyKeyword => {
+                Some("'as any'")
+            }
+            _ => None,
+        },
+        Expr::Call(call) => {
+            let member = match &call.callee {
+                Callee::Expr(callee) => match &**callee {
+                    Expr::Member(member) => member,
+                    _ => return None,
+                },
+                _ => return None,
+            };
+            let method = match &member.prop {
+                MemberProp::Ident(ident) => &*ident.sym,
+                _ => return None,
+            };
+            match (&*member.obj, method) {
+                (Expr::Ident(obj), "parse") if &*obj.sym == "JSON" => Some("JSON.parse()"),
+                // `respuesta.json()` de fetch; `res.json(datos)` de Express recibe argumentos
+                (_, "json") if call.args.is_empty() => Some(".json()"),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Busca el primer `return` de la función cuyo valor es `any`
+#[derive(Default)]
+struct ReturnVisitor {
+    any_source: Option<&'static str>,
+}
+
+impl Visit for ReturnVisitor {
+    fn visit_return_stmt(&mut self, n: &ReturnStmt) {
+        if self.any_source.is_none() {
+            self.any_source = n.arg.as_deref().and_then(any_source);
+        }
+    }
+
+    // Los `return` de las funciones anidadas no son el retorno de la firma
+    fn visit_function(&mut self, _: &Function) {}
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{NoWeakPublicReturnRule, RuleSettings};
+    use crate::rules::testing::check;
+
+    const SOURCE: &str = "\
+export function parse(raw: string): any {
+    return JSON.parse(raw);
+}
+export async function load(url: string) {
+    const response = await fetch(url);
+    return response.json();
+}
+export const read = (raw: string) => JSON.parse(raw) as Order;
+export class Orders {
+    find(id: string): Promise<unknown> {
+        return this.repo.find(id);
+    }
+    private raw(): any {
+        return null;
+    }
+}
+function internal(): any {
+    return null;
+}
+";
+
+    fn settings(allow_unknown: bool) -> RuleSettings {
+        RuleSettings {
+            no_weak_public_return: Some(NoWeakPublicReturnRule {
+                paths: Vec::new(),
+                allow_unknown,
+            }),
+            ..RuleSettings::default()
+        }
+    }
+
+    #[test]
+    fn reports_weak_returns_of_the_public_api() {
+        let findings = check(RULE_ID, "src/orders.ts", SOURCE, settings(false));
+        let found: Vec<(usize, &str)> = findings
+            .iter()
+            .map(|f| (f.location.start_line, f.message.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (1, "'parse' es parte de la API pública y retorna 'any': quien lo use pierde el tipado. Declara el tipo concreto del resultado."),
+                (4, "'load' es parte de la API pública y no anota su retorno: TypeScript infiere 'any' por .json(). Declara el tipo de retorno."),
+                (10, "'Orders.find' es parte de la API pública y retorna 'Promise<unknown>': quien lo use pierde el tipado. Declara el tipo concreto del resultado."),
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_can_be_allowed() {
+        let findings = check(RULE_ID, "src/orders.ts", SOURCE, settings(true));
+        assert_eq!(findings.len(), 2);
+        // Los archivos JavaScript no tienen anotaciones que revisar
+        assert!(check(
+            RULE_ID,
+            "src/orders.js",
+            "export function f() {}\n",
+            settings(false)
+        )
+        .is_empty());
+    }
+}
@@ -0,0 +1,128 @@
+//! Funciones cuyo cuerpo entero está envuelto en un `if`
+//!
+//! `function f() { if (ok) { ...todo el cuerpo... } }` añade un nivel de
+//! anidamiento a toda la lógica. Invertir la condición y salir antes (guard
+//! clause) deja el camino principal sin indentar.
+
+use super::{Finding, RuleContext, Severity};
+use swc_ecma_ast::{ArrowExpr, BlockStmt, BlockStmtOrExpr, Function, Stmt};
+use swc_ecma_visit::{Visit, VisitWith};
+
+pub const RULE_ID: &str = "prefer-early-return";
+
+/// Advierte sobre las funciones cuyo único statement es un `if` sin `else`
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    if rc.ctx.settings.prefer_early_return_min_statements == 0 {
+        return Vec::new();
+    }
+
+    let mut visitor = EarlyReturnVisitor {
+        rc,
+        findings: Vec::new(),
+    };
+    rc.module.visit_with(&mut visitor);
+    visitor.findings
+}
+
+struct EarlyReturnVisitor<'a, 'b> {
+    rc: &'a RuleContext<'b>,
+    findings: Vec<Finding>,
+}
+
+impl EarlyReturnVisitor<'_, '_> {
+    fn check_body(&mut self, body: &BlockStmt) {
+        let if_stmt = match body.stmts.as_slice() {
+            [Stmt::If(if_stmt)] if if_stmt.alt.is_none() => if_stmt,
+            _ => return,
+        };
+
+        // Un `if` corto que envuelve una o dos líneas no merece la inversión
+        let statements = match &*if_stmt.cons {
+            Stmt::Block(block) => block.stmts.len(),
+            _ => 1,
+        };
+        if statements < self.rc.ctx.settings.prefer_early_return_min_statements {
+            return;
+        }
+
+        self.findings.push(self.rc.finding(
+            RULE_ID,
+            Severity::Warning,
+            if_stmt.span,
+            "Todo el cuerpo de la función está dentro de este if. Invierte la condición y retorna antes (guard clause).".to_string(),
+        ));
+    }
+}
+
+impl Visit for EarlyReturnVisitor<'_, '_> {
+    fn visit_function(&mut self, n: &Function) {
+        if let Some(body) = &n.body {
+            self.check_body(body);
+        }
+        n.visit_children_with(self);
+    }
+
+    fn visit_arrow_expr(&mut self, n: &ArrowExpr) {
+        if let BlockStmtOrExpr::BlockStmt(body) = &*n.body {
+            self.check_body(body);
+        }
+        n.visit_children_with(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RuleSettings;
+    use crate::rules::testing::check;
+
+    const WRAPPED: &str = "\
+export function save(order: Order) {
+    if (order.valid) {
+        validate(order);
+        persist(order);
+        notify(order);
+    }
+}
+";
+
+    fn settings(min: usize) -> RuleSettings {
+        RuleSettings {
+            prefer_early_return_min_statements: min,
+            ..RuleSettings::default()
+        }
+    }
+
+    #[test]
+    fn reports_bodies_wrapped_in_a_single_if() {
+        let findings = check(RULE_ID, "src/save.ts", WRAPPED, settings(3));
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].location.start_line, 2);
+        assert!(check(RULE_ID, "src/save.ts", WRAPPED, settings(4)).is_empty());
+        assert!(check(RULE_ID, "src/save.ts", WRAPPED, settings(0)).is_empty());
+    }
+
+    #[test]
+    fn allows_guard_clauses_and_if_else() {
+        let source = "\
+export const save = (order: Order) => {
+    if (!order.valid) {
+        return;
+    }
+    validate(order);
+    persist(order);
+    notify(order);
+};
+export function label(order: Order) {
+    if (order.valid) {
+        validate(order);
+        persist(order);
+        notify(order);
+    } else {
+        reject(order);
+    }
+}
+";
+        assert!(check(RULE_ID, "src/save.ts", source, settings(3)).is_empty());
+    }
+}
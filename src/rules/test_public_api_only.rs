@@ -0,0 +1,155 @@
+//! Los tests importan el código bajo prueba solo desde su API pública
+//!
+//! Un test que entra a `../src/users/internal/hash` queda atado a la estructura
+//! interna del módulo: cualquier refactor lo rompe aunque el comportamiento
+//! público no cambie. La raíz de un módulo es la carpeta más cercana con `index`.
+
+use super::{Finding, RuleContext, Severity};
+use crate::analyzer;
+use crate::circular::resolve_import_path;
+use std::path::Path;
+use swc_ecma_ast::{ModuleDecl, ModuleItem};
+
+pub const RULE_ID: &str = "test-public-api-only";
+
+/// Archivos de test por defecto
+pub fn default_test_patterns() -> Vec<String> {
+    ["*.spec.*", "*.test.*", "__tests__/"]
+        .iter()
+        .map(|pattern| pattern.to_string())
+        .collect()
+}
+
+/// Reporta los imports de un test que resuelven a un archivo interno (no el
+/// `index`) de otro módulo
+pub fn check(rc: &RuleContext) -> Vec<Finding> {
+    let rule = match &rc.ctx.settings.test_public_api_only {
+        Some(rule) => rule,
+        None => return Vec::new(),
+    };
+
+    if !rc.path_matches(&rule.tests) {
+        return Vec::new();
+    }
+    let test_path = match rc.path.canonicalize() {
+        Ok(path) => path,
+        Err(_) => return Vec::new(),
+    };
+
+    rc.module
+        .body
+        .iter()
+        .filter_map(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => Some(import),
+            _ => None,
+        })
+        .filter_map(|import| {
+            let source = &*import.src.value;
+            let resolved = resolve_import_path(rc.path, source)?.canonicalize().ok()?;
+            let module_root = module_root(&resolved, &test_path)?;
+            if is_index(&resolved, module_root) || is_allowed(&resolved, &rule.allow) {
+                return None;
+            }
+            Some(rc.finding(
+                RULE_ID,
+                Severity::Warning,
+                import.span,
+                format!(
+                    "El test importa '{}', un archivo interno del módulo '{}'. Importa desde su API pública (index) o agrégalo a 'allow'.",
+                    source,
+                    module_root
+                        .file_name()
+                        .unwrap_or_default()
+                        .to_string_lossy()
+                ),
+            ))
+        })
+        .collect()
+}
+
+/// Carpeta más cercana al archivo importado que tiene `index` y no contiene al
+/// test: los tests ubicados dentro del módulo pueden probar sus internals
+fn module_root<'a>(resolved: &'a Path, test_path: &Path) -> Option<&'a Path> {
+    resolved
+        .ancestors()
+        .skip(1)
+        .take_while(|dir| !test_path.starts_with(dir))
+        .find(|dir| has_index(dir))
+}
+
+fn has_index(dir: &Path) -> bool {
+    ["index.ts", "index.tsx", "index.js", "index.jsx"]
+        .iter()
+        .any(|index| dir.join(index).is_file())
+}
+
+fn is_index(resolved: &Path, module_root: &Path) -> bool {
+    resolved.parent() == Some(module_root)
+        && resolved.file_stem().is_some_and(|stem| stem == "index")
+}
+
+/// Escape para internals que los tests pueden importar (ej: helpers de fixtures)
+fn is_allowed(resolved: &Path, allow: &[String]) -> bool {
+    let resolved = resolved.to_string_lossy().to_lowercase();
+    allow
+        .iter()
+        .any(|pattern| analyzer::matches_pattern(&resolved, &analyzer::normalize_pattern(pattern)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{RuleSettings, TestPublicApiOnlyRule};
+    use crate::rules::testing::{check, temp_project};
+
+    const E2E: &str = "\
+import { UsersService } from '../src/users';
+import { hash } from '../src/users/internal/hash';
+import { UsersService as Direct } from '../src/users/users.service';
+import { buildUser } from '../src/testing/fixtures';
+";
+
+    fn settings() -> RuleSettings {
+        RuleSettings {
+            test_public_api_only: Some(TestPublicApiOnlyRule {
+                tests: default_test_patterns(),
+                allow: vec!["src/testing/".to_string()],
+            }),
+            ..RuleSettings::default()
+        }
+    }
+
+    #[test]
+    fn reports_tests_that_import_module_internals() {
+        let unit = "import { hash } from './internal/hash';\n";
+        let root = temp_project(
+            "test-public-api",
+            &[
+                ("src/users/index.ts", "export * from './users.service';\n"),
+                (
+                    "src/users/users.service.ts",
+                    "export class UsersService {}\n",
+                ),
+                ("src/users/internal/hash.ts", "export const hash = 1;\n"),
+                ("src/users/users.service.spec.ts", unit),
+                ("src/testing/index.ts", "export * from './fixtures';\n"),
+                ("src/testing/fixtures.ts", "export const buildUser = 1;\n"),
+                ("test/users.e2e.spec.ts", E2E),
+            ],
+        );
+
+        let e2e = root.join("test/users.e2e.spec.ts");
+        let findings = check(RULE_ID, e2e.to_str().unwrap(), E2E, settings());
+        // El index y los internals de 'allow' están permitidos
+        let lines: Vec<usize> = findings.iter().map(|f| f.location.start_line).collect();
+        assert_eq!(lines, vec![2, 3]);
+        assert_eq!(
+            findings[0].message,
+            "El test importa '../src/users/internal/hash', un archivo interno del módulo 'users'. Importa desde su API pública (index) o agrégalo a 'allow'."
+        );
+
+        // Un test dentro del módulo puede probar sus internals
+        let spec = root.join("src/users/users.service.spec.ts");
+        assert!(check(RULE_ID, spec.to_str().unwrap(), unit, settings()).is_empty());
+    }
+}
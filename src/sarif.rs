@@ -0,0 +1,115 @@
+//! Exportación de las violaciones en SARIF 2.1.0 (--format sarif)
+//!
+//! Es el formato que GitHub code scanning y la mayoría de los IDE importan. Igual
+//! que las anotaciones de GitHub, con --compare-to solo se exportan las
+//! violaciones nuevas.
+
+use crate::compare::ReportEntry;
+use crate::rules::{self, Severity};
+use serde_json::{json, Value};
+use std::collections::BTreeSet;
+use std::io::{self, Write};
+
+const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Escribe el documento SARIF en stdout
+pub fn print_sarif(entries: &[ReportEntry]) -> io::Result<()> {
+    write_sarif(&mut io::stdout().lock(), entries)
+}
+
+/// Escribe un documento SARIF con una única ejecución
+pub fn write_sarif(out: &mut impl Write, entries: &[ReportEntry]) -> io::Result<()> {
+    serde_json::to_writer_pretty(&mut *out, &document(entries))?;
+    writeln!(out)?;
+    out.flush()
+}
+
+fn document(entries: &[ReportEntry]) -> Value {
+    // Solo se describen las reglas que aparecen en los resultados
+    let rule_ids: BTreeSet<&str> = entries.iter().map(|entry| entry.rule_id.as_str()).collect();
+    let rules: Vec<Value> = rule_ids.into_iter().map(rule).collect();
+    let results: Vec<Value> = entries.iter().map(result).collect();
+
+    json!({
+        "$schema": SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "architect-linter",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": "https://github.com/sergiogswv/architect-linter",
+                    "rules": rules,
+                }
+            },
+            "results": results,
+        }]
+    })
+}
+
+fn rule(id: &str) -> Value {
+    match rules::RULES.iter().find(|meta| meta.id == id) {
+        Some(meta) => json!({
+            "id": id,
+            "shortDescription": { "text": meta.description },
+            "help": { "text": meta.help },
+        }),
+        None => json!({ "id": id }),
+    }
+}
+
+fn result(entry: &ReportEntry) -> Value {
+    let level = match entry.severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+    };
+    let mut location = json!({
+        "artifactLocation": { "uri": entry.file }
+    });
+    // Los ciclos y las carpetas no señalan una línea: el resultado queda a nivel de archivo
+    if entry.line > 0 {
+        let mut region = json!({ "startLine": entry.line });
+        if entry.column > 0 {
+            region["startColumn"] = json!(entry.column);
+        }
+        if entry.end_line > 0 {
+            region["endLine"] = json!(entry.end_line);
+        }
+        if entry.end_column > 0 {
+            region["endColumn"] = json!(entry.end_column);
+        }
+        location["region"] = region;
+    }
+
+    json!({
+        "ruleId": entry.rule_id,
+        "level": level,
+        "message": { "text": entry.message },
+        "partialFingerprints": { "architectLinter/v1": entry.fingerprint },
+        "locations": [{ "physicalLocation": location }],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn results_point_at_the_file_and_describe_their_rule() {
+        let mut entry = ReportEntry::from_unclassified("src/helpers/hash.ts");
+        entry.line = 3;
+        entry.column = 7;
+        let document = document(&[entry]);
+
+        let run = &document["runs"][0];
+        assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "require-layer");
+        let result = &run["results"][0];
+        assert_eq!(result["ruleId"], "require-layer");
+        assert_eq!(result["level"], "error");
+        let location = &result["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "src/helpers/hash.ts");
+        assert_eq!(location["region"]["startLine"], 3);
+        assert_eq!(location["region"]["startColumn"], 7);
+        assert!(location["region"].get("endLine").is_none());
+    }
+}
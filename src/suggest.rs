@@ -0,0 +1,203 @@
+//! Sugerencia de forbidden_imports a partir del código existente (--suggest-rules)
+//!
+//! Para adoptar el linter en un proyecto que ya existe, recorre el grafo de
+//! dependencias buscando imports que van de una capa interna a una externa según
+//! el patrón de arquitectura, y propone una regla por cada par de carpetas con el
+//! número de imports que la violan hoy. No usa la IA: solo el grafo.
+
+use crate::analyzer::{matches_pattern, normalize_pattern};
+use crate::circular::CircularDependencyAnalyzer;
+use crate::config::{ArchPattern, ForbiddenRule};
+use std::collections::HashMap;
+
+/// Regla propuesta con los imports del proyecto que la violan
+pub struct LayerCrossing {
+    pub rule: ForbiddenRule,
+    /// Imports (archivo → archivo) que cruzan de `from` a `to`
+    pub matches: usize,
+    /// Primer import encontrado, como ejemplo
+    pub example: (String, String),
+}
+
+/// Nombres de carpeta de cada capa del patrón, de la más interna a la más externa
+pub fn pattern_layers(pattern: &ArchPattern) -> &'static [&'static [&'static str]] {
+    match pattern {
+        ArchPattern::Hexagonal => &[
+            &["domain"],
+            &["application", "ports"],
+            &["infrastructure", "adapters"],
+        ],
+        ArchPattern::Clean => &[
+            &["domain", "entities"],
+            &["application", "use-cases", "usecases"],
+            &["presentation", "controllers", "interfaces"],
+            &["infrastructure", "frameworks"],
+        ],
+        ArchPattern::MVC => &[
+            &["models", "entities"],
+            &["services"],
+            &["controllers"],
+            &["views"],
+        ],
+        ArchPattern::Ninguno => &[],
+    }
+}
+
+/// Capa de un módulo y la carpeta que la representa (ej: "src/domain/")
+fn layer_of(module: &str, layers: &[&[&str]]) -> Option<(usize, String)> {
+    let segments: Vec<&str> = module.split('/').collect();
+    // El último segmento es el nombre del archivo
+    let folders = &segments[..segments.len().saturating_sub(1)];
+    folders.iter().enumerate().find_map(|(depth, segment)| {
+        let layer = layers.iter().position(|names| names.contains(segment))?;
+        Some((layer, format!("{}/", folders[..=depth].join("/"))))
+    })
+}
+
+/// Propone una regla por cada par de carpetas (interna → externa) con imports que
+/// cruzan en ese sentido. Omite los pares que `existing` ya prohíbe. Ordenadas de
+/// más a menos imports.
+pub fn suggest_forbidden_rules(
+    graph: &CircularDependencyAnalyzer,
+    pattern: &ArchPattern,
+    existing: &[ForbiddenRule],
+) -> Vec<LayerCrossing> {
+    let layers = pattern_layers(pattern);
+    let mut crossings: HashMap<(String, String), LayerCrossing> = HashMap::new();
+
+    let mut edges: Vec<(&str, &str)> = graph.edges().into_iter().collect();
+    edges.sort_unstable();
+    for (from, to) in edges {
+        let (from_layer, from_dir) = match layer_of(from, layers) {
+            Some(layer) => layer,
+            None => continue,
+        };
+        let (to_layer, to_dir) = match layer_of(to, layers) {
+            Some(layer) => layer,
+            None => continue,
+        };
+        if to_layer <= from_layer || is_covered(from, to, existing) {
+            continue;
+        }
+
+        crossings
+            .entry((from_dir.clone(), to_dir.clone()))
+            .or_insert_with(|| LayerCrossing {
+                rule: ForbiddenRule {
+                    from: format!("{}**", from_dir),
+                    to: format!("{}**", to_dir),
+                },
+                matches: 0,
+                example: (from.to_string(), to.to_string()),
+            })
+            .matches += 1;
+    }
+
+    let mut suggestions: Vec<LayerCrossing> = crossings.into_values().collect();
+    suggestions.sort_by(|a, b| {
+        b.matches
+            .cmp(&a.matches)
+            .then_with(|| a.rule.from.cmp(&b.rule.from))
+            .then_with(|| a.rule.to.cmp(&b.rule.to))
+    });
+    suggestions
+}
+
+/// El import ya lo prohíbe una regla de architect.json
+fn is_covered(from: &str, to: &str, existing: &[ForbiddenRule]) -> bool {
+    existing.iter().any(|rule| {
+        matches_pattern(from, &normalize_pattern(&rule.from))
+            && matches_pattern(to, &normalize_pattern(&rule.to))
+    })
+}
+
+/// Imprime las reglas sugeridas y el fragmento listo para architect.json
+pub fn print_suggestions(suggestions: &[LayerCrossing], pattern: &ArchPattern) {
+    if suggestions.is_empty() {
+        println!(
+            "\n✅ Ningún import cruza de una capa interna a una externa ({:?}).",
+            pattern
+        );
+        return;
+    }
+
+    println!(
+        "\n💡 REGLAS SUGERIDAS PARA {:?} ({})\n",
+        pattern,
+        suggestions.len()
+    );
+    for suggestion in suggestions {
+        println!(
+            "  {:>4} imports  {} → {}",
+            suggestion.matches, suggestion.rule.from, suggestion.rule.to
+        );
+        println!(
+            "                ej: {} → {}",
+            suggestion.example.0, suggestion.example.1
+        );
+    }
+
+    let rules: Vec<&ForbiddenRule> = suggestions.iter().map(|s| &s.rule).collect();
+    println!("\n📋 Fragmento para \"forbidden_imports\" en architect.json:\n");
+    match serde_json::to_string_pretty(&rules) {
+        Ok(json) => println!("{}", json),
+        Err(e) => eprintln!("❌ No se pudo generar el fragmento: {}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::testing::temp_project;
+    use std::fs;
+    use swc_common::SourceMap;
+
+    #[test]
+    fn an_inward_to_outward_import_becomes_a_rule() {
+        let root = temp_project(
+            "suggest-rules",
+            &[
+                (
+                    "src/domain/user.ts",
+                    "import { db } from '../infrastructure/db';\nexport const user = db;\n",
+                ),
+                (
+                    "src/domain/order.ts",
+                    "import { db } from '../infrastructure/db';\nexport const order = db;\n",
+                ),
+                // Hacia adentro: no es un cruce
+                (
+                    "src/application/users.ts",
+                    "import { user } from '../domain/user';\nexport const users = [user];\n",
+                ),
+                ("src/infrastructure/db.ts", "export const db = {};\n"),
+            ],
+        );
+        let files: Vec<_> = [
+            "src/domain/user.ts",
+            "src/domain/order.ts",
+            "src/application/users.ts",
+            "src/infrastructure/db.ts",
+        ]
+        .iter()
+        .map(|path| root.join(path))
+        .collect();
+        let mut graph = CircularDependencyAnalyzer::new(&root);
+        graph.build_graph(&files, &SourceMap::default());
+
+        let suggestions = suggest_forbidden_rules(&graph, &ArchPattern::Hexagonal, &[]);
+
+        assert_eq!(suggestions.len(), 1);
+        let crossing = &suggestions[0];
+        assert_eq!(crossing.rule.from, "src/domain/**");
+        assert_eq!(crossing.rule.to, "src/infrastructure/**");
+        assert_eq!(crossing.matches, 2);
+        assert_eq!(crossing.example.1, "src/infrastructure/db.ts");
+
+        // Una regla que ya lo prohíbe no se vuelve a sugerir
+        let existing = [crossing.rule.clone()];
+        assert!(suggest_forbidden_rules(&graph, &ArchPattern::Hexagonal, &existing).is_empty());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
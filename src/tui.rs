@@ -0,0 +1,404 @@
+//! Explorador interactivo de resultados (--tui)
+//!
+//! Lista los hallazgos agrupados por archivo en un árbol navegable, con un panel
+//! de detalle (fragmento de código y sugerencia de la regla) y filtros por regla
+//! y severidad.
+
+use crate::rules::{self, Finding, Severity};
+use miette::{IntoDiagnostic, Result};
+use ratatui::backend::CrosstermBackend;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::crossterm::execute;
+use ratatui::crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::{Frame, Terminal};
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// Líneas de contexto alrededor de la línea señalada en el panel de detalle
+const SNIPPET_CONTEXT: usize = 3;
+
+/// Hallazgo de un archivo tal como se acumula durante el análisis
+pub struct CollectedFinding {
+    pub path: PathBuf,
+    /// Ruta a mostrar (relativa salvo con --absolute-paths)
+    pub display: String,
+    pub finding: Finding,
+}
+
+/// Fila visible del árbol
+enum Row {
+    File { display: String, count: usize },
+    Finding(usize),
+}
+
+/// Estado del explorador: hallazgos, filtros activos y selección
+struct BrowserState {
+    entries: Vec<CollectedFinding>,
+    collapsed: HashSet<String>,
+    rule_filter: Option<&'static str>,
+    severity_filter: Option<Severity>,
+    selected: usize,
+}
+
+impl BrowserState {
+    fn new(mut entries: Vec<CollectedFinding>) -> Self {
+        entries.sort_by(|a, b| {
+            a.display.cmp(&b.display).then(
+                a.finding
+                    .location
+                    .start_line
+                    .cmp(&b.finding.location.start_line),
+            )
+        });
+        Self {
+            entries,
+            collapsed: HashSet::new(),
+            rule_filter: None,
+            severity_filter: None,
+            selected: 0,
+        }
+    }
+
+    fn is_visible(&self, entry: &CollectedFinding) -> bool {
+        self.rule_filter
+            .is_none_or(|rule| entry.finding.rule_id == rule)
+            && self
+                .severity_filter
+                .is_none_or(|severity| entry.finding.severity == severity)
+    }
+
+    /// Filas del árbol: cada archivo seguido de sus hallazgos (si no está plegado)
+    fn rows(&self) -> Vec<Row> {
+        let mut rows = Vec::new();
+        let mut current: Option<&str> = None;
+
+        for (index, entry) in self.entries.iter().enumerate() {
+            if !self.is_visible(entry) {
+                continue;
+            }
+            if current != Some(entry.display.as_str()) {
+                current = Some(entry.display.as_str());
+                let count = self
+                    .entries
+                    .iter()
+                    .filter(|e| e.display == entry.display && self.is_visible(e))
+                    .count();
+                rows.push(Row::File {
+                    display: entry.display.clone(),
+                    count,
+                });
+            }
+            if !self.collapsed.contains(&entry.display) {
+                rows.push(Row::Finding(index));
+            }
+        }
+
+        rows
+    }
+
+    fn rule_ids(&self) -> Vec<&'static str> {
+        let mut ids: Vec<&'static str> = self.entries.iter().map(|e| e.finding.rule_id).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    }
+
+    /// Todas las reglas -> cada regla presente -> todas las reglas
+    fn cycle_rule_filter(&mut self) {
+        let ids = self.rule_ids();
+        self.rule_filter = match self.rule_filter {
+            None => ids.first().copied(),
+            Some(current) => ids
+                .iter()
+                .position(|id| *id == current)
+                .and_then(|i| ids.get(i + 1).copied()),
+        };
+        self.selected = 0;
+    }
+
+    fn cycle_severity_filter(&mut self) {
+        self.severity_filter = match self.severity_filter {
+            None => Some(Severity::Error),
+            Some(Severity::Error) => Some(Severity::Warning),
+            Some(Severity::Warning) => None,
+        };
+        self.selected = 0;
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        let len = self.rows().len();
+        if len == 0 {
+            self.selected = 0;
+            return;
+        }
+        let next = self.selected as isize + delta;
+        self.selected = next.clamp(0, len as isize - 1) as usize;
+    }
+
+    /// Pliega o despliega el archivo de la fila seleccionada
+    fn toggle_selected(&mut self) {
+        let display = match self.rows().get(self.selected) {
+            Some(Row::File { display, .. }) => display.clone(),
+            Some(Row::Finding(index)) => self.entries[*index].display.clone(),
+            None => return,
+        };
+        if !self.collapsed.remove(&display) {
+            self.collapsed.insert(display.clone());
+        }
+
+        // Mantener la selección sobre la fila del archivo
+        if let Some(position) = self
+            .rows()
+            .iter()
+            .position(|row| matches!(row, Row::File { display: d, .. } if *d == display))
+        {
+            self.selected = position;
+        }
+    }
+}
+
+/// Abre el explorador con los hallazgos recolectados hasta que el usuario sale
+pub fn browse(findings: Vec<CollectedFinding>) -> Result<()> {
+    if findings.is_empty() {
+        return Ok(());
+    }
+
+    let mut state = BrowserState::new(findings);
+
+    let _terminal = TerminalGuard::enter()?;
+    run_loop(&mut state)
+}
+
+/// Modo raw y pantalla alternativa mientras dura el explorador. Al soltarse restaura
+/// la terminal aunque el bucle haya fallado o entrado en pánico
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn enter() -> Result<Self> {
+        enable_raw_mode().into_diagnostic()?;
+        // Desde aquí cualquier error sale por `drop`, que desactiva el modo raw
+        let guard = TerminalGuard;
+        execute!(io::stdout(), EnterAlternateScreen).into_diagnostic()?;
+        Ok(guard)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        // Cada paso por separado: si uno falla se intenta igual el otro
+        let _ = disable_raw_mode();
+        let _ = execute!(io::stdout(), LeaveAlternateScreen);
+    }
+}
+
+fn run_loop(state: &mut BrowserState) -> Result<()> {
+    let mut terminal = Terminal::new(CrosstermBackend::new(io::stdout())).into_diagnostic()?;
+
+    loop {
+        terminal
+            .draw(|frame| render(frame, state))
+            .into_diagnostic()?;
+
+        let key = match event::read().into_diagnostic()? {
+            Event::Key(key) if key.kind == KeyEventKind::Press => key,
+            _ => continue,
+        };
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+            KeyCode::Down | KeyCode::Char('j') => state.move_selection(1),
+            KeyCode::Up | KeyCode::Char('k') => state.move_selection(-1),
+            KeyCode::PageDown => state.move_selection(10),
+            KeyCode::PageUp => state.move_selection(-10),
+            KeyCode::Enter | KeyCode::Char(' ') => state.toggle_selected(),
+            KeyCode::Char('r') => state.cycle_rule_filter(),
+            KeyCode::Char('s') => state.cycle_severity_filter(),
+            _ => {}
+        }
+    }
+}
+
+fn render(frame: &mut Frame, state: &BrowserState) {
+    let [main, footer] =
+        Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).areas(frame.area());
+    let [tree, detail] =
+        Layout::horizontal([Constraint::Percentage(45), Constraint::Percentage(55)]).areas(main);
+
+    let rows = state.rows();
+    let items: Vec<ListItem> = rows
+        .iter()
+        .map(|row| match row {
+            Row::File { display, count } => {
+                let marker = if state.collapsed.contains(display) {
+                    "▸"
+                } else {
+                    "▾"
+                };
+                ListItem::new(format!("{} {} ({})", marker, display, count))
+                    .style(Style::default().add_modifier(Modifier::BOLD))
+            }
+            Row::Finding(index) => {
+                let finding = &state.entries[*index].finding;
+                let (icon, color) = severity_style(finding.severity);
+                ListItem::new(format!(
+                    "   {} L{} [{}] {}",
+                    icon, finding.location.start_line, finding.rule_id, finding.message
+                ))
+                .style(Style::default().fg(color))
+            }
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(" Hallazgos "))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+    let mut list_state = ListState::default().with_selected(Some(state.selected));
+    frame.render_stateful_widget(list, tree, &mut list_state);
+
+    let detail_lines = match rows.get(state.selected) {
+        Some(Row::Finding(index)) => finding_detail(&state.entries[*index]),
+        Some(Row::File { display, count }) => vec![
+            Line::from(display.clone()),
+            Line::from(format!("{} hallazgo(s) con los filtros actuales", count)),
+        ],
+        None => vec![Line::from("Sin hallazgos con los filtros actuales")],
+    };
+    let detail_pane = Paragraph::new(detail_lines)
+        .block(Block::default().borders(Borders::ALL).title(" Detalle "))
+        .wrap(Wrap { trim: false });
+    frame.render_widget(detail_pane, detail);
+
+    let severity = match state.severity_filter {
+        None => "todas",
+        Some(Severity::Error) => "error",
+        Some(Severity::Warning) => "warning",
+    };
+    let help = format!(
+        "↑/↓ navegar · Enter plegar · r regla: {} · s severidad: {} · q salir",
+        state.rule_filter.unwrap_or("todas"),
+        severity
+    );
+    frame.render_widget(Paragraph::new(help), footer);
+}
+
+fn severity_style(severity: Severity) -> (&'static str, Color) {
+    match severity {
+        Severity::Error => ("✖", Color::Red),
+        Severity::Warning => ("⚠", Color::Yellow),
+    }
+}
+
+/// Regla, mensaje, sugerencia y fragmento de código del hallazgo seleccionado
+fn finding_detail(entry: &CollectedFinding) -> Vec<Line<'static>> {
+    let finding = &entry.finding;
+    let (icon, color) = severity_style(finding.severity);
+
+    let mut lines = vec![
+        Line::styled(
+            format!("{} {}", icon, finding.rule_id),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        ),
+        Line::from(format!("{}:{}", entry.display, finding.location.start_line)),
+        Line::from(""),
+        Line::from(finding.message.clone()),
+    ];
+
+    if let Some(help) = rules::help_for(finding.rule_id) {
+        lines.push(Line::from(""));
+        lines.push(Line::styled(
+            format!("💡 {}", help),
+            Style::default().fg(Color::Cyan),
+        ));
+    }
+
+    if let Ok(source) = fs::read_to_string(&entry.path) {
+        lines.push(Line::from(""));
+        let target = finding.location.start_line;
+        let first = target.saturating_sub(SNIPPET_CONTEXT).max(1);
+        for (number, text) in source
+            .lines()
+            .enumerate()
+            .map(|(i, text)| (i + 1, text))
+            .skip(first - 1)
+            .take(SNIPPET_CONTEXT * 2 + 1)
+        {
+            let marker = if number == target { ">" } else { " " };
+            let line = Line::from(format!("{} {:>4} │ {}", marker, number, text));
+            lines.push(if number == target {
+                line.style(Style::default().add_modifier(Modifier::BOLD))
+            } else {
+                line
+            });
+        }
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::Location;
+
+    fn collected(display: &str, rule_id: &'static str, line: usize) -> CollectedFinding {
+        let location = Location {
+            start_line: line,
+            start_col: 1,
+            end_line: line,
+            end_col: 1,
+        };
+        let message = format!("{} en la línea {}", rule_id, line);
+        CollectedFinding {
+            path: PathBuf::from(display),
+            display: display.to_string(),
+            finding: Finding::error(rule_id, location, message.clone(), miette::miette!(message)),
+        }
+    }
+
+    #[test]
+    fn the_browser_starts_with_findings_grouped_by_file() {
+        let mut state = BrowserState::new(vec![
+            collected("src/b.ts", "max-lines", 9),
+            collected("src/a.ts", "max-imports", 1),
+            collected("src/b.ts", "forbidden-imports", 2),
+        ]);
+
+        // Cada archivo seguido de sus hallazgos, ordenados por línea
+        let rows: Vec<String> = state
+            .rows()
+            .iter()
+            .map(|row| match row {
+                Row::File { display, count } => format!("{} ({})", display, count),
+                Row::Finding(index) => state.entries[*index].finding.rule_id.to_string(),
+            })
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                "src/a.ts (1)",
+                "max-imports",
+                "src/b.ts (2)",
+                "forbidden-imports",
+                "max-lines",
+            ]
+        );
+        assert_eq!(state.selected, 0);
+        assert_eq!(
+            state.rule_ids(),
+            vec!["forbidden-imports", "max-imports", "max-lines"]
+        );
+
+        // El filtro por regla deja solo sus hallazgos
+        state.cycle_rule_filter();
+        assert_eq!(state.rule_filter, Some("forbidden-imports"));
+        assert_eq!(state.rows().len(), 2);
+    }
+}
@@ -1,5 +1,5 @@
 use crate::ai::{AISuggestionResponse, SuggestedRule};
-use crate::config::AIConfig;
+use crate::config::{AIConfig, AIProvider};
 use console::style;
 use dialoguer::{theme::ColorfulTheme, Input, MultiSelect, Select};
 use miette::{IntoDiagnostic, Result};
@@ -55,10 +55,12 @@ pub fn print_banner() {
 
 /// Solicita al usuario una o más configuraciones de IA
 pub fn ask_ai_configs() -> Result<Vec<AIConfig>> {
-    let mut configs = Vec::new();
+    // Primero se piden los datos de conexión de cada modelo y después se consultan
+    // los modelos disponibles de todos los proveedores a la vez
+    let mut pending: Vec<(String, AIProvider, String, String)> = Vec::new();
 
     loop {
-        println!("🤖 CONFIGURACIÓN DE LA IA (#{})", configs.len() + 1);
+        println!("🤖 CONFIGURACIÓN DE LA IA (#{})", pending.len() + 1);
         println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
 
         // Pedir un nombre para esta configuración
@@ -67,45 +69,19 @@ pub fn ask_ai_configs() -> Result<Vec<AIConfig>> {
             .interact_text()
             .into_diagnostic()?;
 
-        let providers = vec![
-            "Claude (Anthropic)",
-            "Gemini (Google)",
-            "OpenAI",
-            "Groq",
-            "Ollama (Local)",
-            "Kimi (Moonshot)",
-            "DeepSeek",
-        ];
+        // El proveedor se guarda en .architect.ai.json y decide a qué API consulta
+        // `consultar_ia`: no se deduce de la URL
+        let providers: Vec<&str> = AIProvider::ALL.iter().map(AIProvider::label).collect();
         let selection = Select::with_theme(&ColorfulTheme::default())
             .with_prompt("Selecciona un proveedor de IA")
             .items(&providers)
             .default(0)
             .interact()
             .into_diagnostic()?;
-
-        let provider = match selection {
-            0 => crate::config::AIProvider::Claude,
-            1 => crate::config::AIProvider::Gemini,
-            2 => crate::config::AIProvider::OpenAI,
-            3 => crate::config::AIProvider::Groq,
-            4 => crate::config::AIProvider::Ollama,
-            5 => crate::config::AIProvider::Kimi,
-            6 => crate::config::AIProvider::DeepSeek,
-            _ => unreachable!(),
-        };
+        let provider = AIProvider::ALL[selection].clone();
 
         // URLs base según el proveedor (Hardcoded)
-        let default_url = match provider {
-            crate::config::AIProvider::Claude => "https://api.anthropic.com".to_string(),
-            crate::config::AIProvider::Gemini => {
-                "https://generativelanguage.googleapis.com".to_string()
-            }
-            crate::config::AIProvider::OpenAI => "https://api.openai.com/v1".to_string(),
-            crate::config::AIProvider::Groq => "https://api.groq.com/openai/v1".to_string(),
-            crate::config::AIProvider::Ollama => "http://localhost:11434/v1".to_string(),
-            crate::config::AIProvider::Kimi => "https://api.moonshot.ai/v1".to_string(),
-            crate::config::AIProvider::DeepSeek => "https://api.deepseek.com".to_string(),
-        };
+        let default_url = provider.default_api_url().to_string();
 
         // Verificar si existen variables de entorno
         let env_url = env::var(format!("{}_BASE_URL", provider.as_str().to_uppercase())).ok();
@@ -122,54 +98,76 @@ pub fn ask_ai_configs() -> Result<Vec<AIConfig>> {
             Input::with_theme(&ColorfulTheme::default())
                 .with_prompt("API Key (opcional para Ollama)")
                 .allow_empty(true)
-                .default(env_key.unwrap_or_else(|| String::new()))
+                .default(env_key.unwrap_or_default())
                 .interact_text()
                 .into_diagnostic()?
         } else {
             Input::with_theme(&ColorfulTheme::default())
                 .with_prompt(format!("API Key para {}", provider.as_str()))
-                .default(env_key.unwrap_or_else(|| String::new()))
+                .default(env_key.unwrap_or_default())
                 .interact_text()
                 .into_diagnostic()?
         };
 
-        // Obtener modelos dinámicamente usando los curls
-        println!(
-            "🔍 Conectando con {} para obtener modelos...",
-            provider.as_str()
-        );
-        let model: String =
-            match crate::ai::obtener_modelos_disponibles(&provider, &api_url, &api_key) {
-                Ok(mut models) if !models.is_empty() => {
-                    models.sort();
-                    let selection = Select::with_theme(&ColorfulTheme::default())
-                        .with_prompt("Selecciona el modelo")
-                        .items(&models)
-                        .default(0)
-                        .interact()
-                        .into_diagnostic()?;
-                    models[selection].clone()
-                }
-                Err(e) => {
-                    println!(
-                        "⚠️  No se pudieron obtener los modelos automáticamente: {}",
-                        e
-                    );
-                    Input::with_theme(&ColorfulTheme::default())
+        pending.push((name, provider, api_url, api_key));
+
+        let add_another = dialoguer::Confirm::with_theme(&ColorfulTheme::default())
+            .with_prompt("¿Deseas agregar otro modelo de IA?")
+            .default(false)
+            .interact()
+            .into_diagnostic()?;
+
+        if !add_another {
+            break;
+        }
+    }
+
+    // Obtener modelos dinámicamente usando los curls
+    let names: Vec<&str> = pending.iter().map(|(_, p, _, _)| p.as_str()).collect();
+    println!(
+        "🔍 Conectando con {} para obtener modelos...",
+        names.join(", ")
+    );
+    let requests: Vec<(AIProvider, String, String)> = pending
+        .iter()
+        .map(|(_, provider, api_url, api_key)| (provider.clone(), api_url.clone(), api_key.clone()))
+        .collect();
+    let available = crate::ai::obtener_modelos_de_proveedores(&requests);
+
+    let mut configs = Vec::new();
+    for ((name, provider, api_url, api_key), models) in pending.into_iter().zip(available) {
+        println!("\n🤖 Modelo para '{}' ({})", name, provider.as_str());
+        let model: String = match models {
+            Ok(mut models) if !models.is_empty() => {
+                models.sort();
+                let selection = Select::with_theme(&ColorfulTheme::default())
+                    .with_prompt("Selecciona el modelo")
+                    .items(&models)
+                    .default(0)
+                    .interact()
+                    .into_diagnostic()?;
+                models[selection].clone()
+            }
+            Err(e) => {
+                println!(
+                    "⚠️  No se pudieron obtener los modelos automáticamente: {}",
+                    e
+                );
+                Input::with_theme(&ColorfulTheme::default())
                     .with_prompt(
                         "Ingresa el nombre del modelo manualmente (ej: claude-3-5-sonnet-20241022)",
                     )
                     .interact_text()
                     .into_diagnostic()?
-                }
-                _ => {
-                    println!("⚠️  La lista de modelos está vacía.");
-                    Input::with_theme(&ColorfulTheme::default())
-                        .with_prompt("Ingresa el nombre del modelo manualmente")
-                        .interact_text()
-                        .into_diagnostic()?
-                }
-            };
+            }
+            _ => {
+                println!("⚠️  La lista de modelos está vacía.");
+                Input::with_theme(&ColorfulTheme::default())
+                    .with_prompt("Ingresa el nombre del modelo manualmente")
+                    .interact_text()
+                    .into_diagnostic()?
+            }
+        };
 
         configs.push(crate::config::AIConfig {
             name,
@@ -177,27 +175,22 @@ pub fn ask_ai_configs() -> Result<Vec<AIConfig>> {
             api_url,
             api_key,
             model,
+            max_concurrent_requests: None,
+            temperature: None,
+            max_tokens: None,
         });
 
         println!("✅ Configuración añadida.");
-
-        let add_another = dialoguer::Confirm::with_theme(&ColorfulTheme::default())
-            .with_prompt("¿Deseas agregar otro modelo de IA?")
-            .default(false)
-            .interact()
-            .into_diagnostic()?;
-
-        if !add_another {
-            break;
-        }
     }
 
     Ok(configs)
 }
 
 /// Permite al usuario elegir qué reglas de las sugeridas por la IA desea aplicar.
+/// Con `min_confidence` (--ai-min-confidence) solo vienen marcadas las reglas que lo alcanzan.
 pub fn ask_user_to_confirm_rules(
     suggestions: AISuggestionResponse,
+    min_confidence: Option<f32>,
 ) -> Result<(Vec<SuggestedRule>, usize)> {
     println!("\n🤖 El Arquitecto Virtual ha analizado tu proyecto.");
     println!(
@@ -205,11 +198,7 @@ pub fn ask_user_to_confirm_rules(
         suggestions.pattern
     );
 
-    let max_lines: usize = Input::with_theme(&ColorfulTheme::default())
-        .with_prompt("Límite máximo de líneas por función sugerido")
-        .default(suggestions.suggested_max_lines)
-        .interact_text()
-        .into_diagnostic()?;
+    let max_lines = ask_max_lines(suggestions.suggested_max_lines)?;
 
     println!("Deseas aplicar las siguientes reglas de importación?\n");
 
@@ -217,11 +206,21 @@ pub fn ask_user_to_confirm_rules(
     let items: Vec<String> = suggestions
         .rules
         .iter()
-        .map(|r| format!("{} -> {} \n   └─ Razón: {}", r.from, r.to, r.reason))
+        .map(|r| match r.confidence {
+            Some(confidence) => format!(
+                "{} -> {} (confianza {:.2}) \n   └─ Razón: {}",
+                r.from, r.to, confidence, r.reason
+            ),
+            None => format!("{} -> {} \n   └─ Razón: {}", r.from, r.to, r.reason),
+        })
         .collect();
 
-    // Por defecto, todas las reglas están marcadas (true)
-    let defaults = vec![true; items.len()];
+    // Por defecto están marcadas todas las reglas que alcanzan el umbral
+    let defaults: Vec<bool> = suggestions
+        .rules
+        .iter()
+        .map(|r| r.meets_confidence(min_confidence))
+        .collect();
 
     let selections = MultiSelect::with_theme(&ColorfulTheme::default())
         .with_prompt("Usa [Espacio] para marcar/desmarcar y [Enter] para confirmar")
@@ -239,6 +238,15 @@ pub fn ask_user_to_confirm_rules(
     Ok((selected_rules, max_lines))
 }
 
+/// Pide el límite de líneas por función partiendo de un valor sugerido
+pub fn ask_max_lines(default: usize) -> Result<usize> {
+    Input::with_theme(&ColorfulTheme::default())
+        .with_prompt("Límite máximo de líneas por función sugerido")
+        .default(default)
+        .interact_text()
+        .into_diagnostic()
+}
+
 pub fn get_interactive_path() -> Result<PathBuf> {
     let current_dir = env::current_dir().into_diagnostic()?;
     let search_dir = current_dir.parent().unwrap_or(&current_dir);
@@ -0,0 +1,145 @@
+//! Archivos fuera de todas las capas (require-layer)
+//!
+//! En un proyecto Hexagonal o Clean cada archivo debería vivir en una capa: uno que
+//! no cae en ninguna queda fuera de las reglas entre capas y suele estar mal
+//! ubicado. Las capas son `layer_paths` o, si está vacío, las carpetas
+//! convencionales del patrón (las mismas que usa --suggest-rules).
+
+use crate::analyzer;
+use crate::config::{ArchPattern, RuleSettings};
+use crate::suggest;
+use std::path::{Path, PathBuf};
+
+pub const RULE_ID: &str = "require-layer";
+
+/// Extensiones revisadas: el resto de lenguajes no sigue estas convenciones de carpetas
+const CHECKED_EXTENSIONS: &[&str] = &["ts", "tsx"];
+
+/// Archivos que no pertenecen a una capa por naturaleza: tests, declaraciones y
+/// puntos de entrada
+pub fn default_exempt_paths() -> Vec<String> {
+    ["*.spec.*", "*.test.*", "*.d.ts", "/main.ts", "/index.ts"]
+        .iter()
+        .map(|pattern| pattern.to_string())
+        .collect()
+}
+
+/// La regla solo tiene sentido en los patrones organizados por capas
+pub fn applies_to(pattern: &ArchPattern) -> bool {
+    matches!(pattern, ArchPattern::Hexagonal | ArchPattern::Clean)
+}
+
+/// Patrones de las carpetas de capa: los configurados o los del patrón
+pub fn layer_patterns(settings: &RuleSettings, pattern: &ArchPattern) -> Vec<String> {
+    if !settings.layer_paths.is_empty() {
+        return settings.layer_paths.clone();
+    }
+    suggest::pattern_layers(pattern)
+        .iter()
+        .flat_map(|names| names.iter())
+        .map(|name| format!("/{}/", name))
+        .collect()
+}
+
+/// Rutas relativas (ordenadas) de los archivos que no están en ninguna capa
+pub fn find_unclassified_files(
+    files: &[PathBuf],
+    project_root: &Path,
+    settings: &RuleSettings,
+    pattern: &ArchPattern,
+) -> Vec<String> {
+    let layers: Vec<String> = layer_patterns(settings, pattern)
+        .iter()
+        .map(|layer| analyzer::normalize_pattern(layer))
+        .collect();
+    let exempt: Vec<String> = settings
+        .require_layer_exempt
+        .iter()
+        .map(|pattern| analyzer::normalize_pattern(pattern))
+        .collect();
+
+    let mut unclassified: Vec<String> = files
+        .iter()
+        .filter(|file| {
+            let extension = file.extension().and_then(|e| e.to_str()).unwrap_or("");
+            CHECKED_EXTENSIONS.contains(&extension)
+        })
+        .map(|file| {
+            file.strip_prefix(project_root)
+                .unwrap_or(file)
+                .to_string_lossy()
+                .replace('\\', "/")
+        })
+        .filter(|file| {
+            // La barra inicial permite patrones como "/domain/" en la raíz del proyecto
+            let path = format!("/{}", file);
+            let matches_any = |patterns: &[String]| {
+                patterns
+                    .iter()
+                    .any(|pattern| analyzer::matches_pattern(&path, pattern))
+            };
+            !matches_any(&layers) && !matches_any(&exempt)
+        })
+        .collect();
+    unclassified.sort();
+    unclassified
+}
+
+/// Imprime los archivos sin capa y las capas que se buscaron
+pub fn print_unclassified_report(unclassified: &[String], layers: &[String]) {
+    if unclassified.is_empty() {
+        return;
+    }
+
+    println!(
+        "\n🧭 ARCHIVOS SIN CLASIFICAR EN NINGUNA CAPA ({})\n",
+        unclassified.len()
+    );
+    for file in unclassified {
+        println!("  {}", file);
+    }
+    println!("\n   Capas: {}", layers.join(", "));
+    println!("💡 Mueve cada archivo a su capa o agrega su carpeta a 'layer_paths' (o a 'require_layer_exempt').");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::testing::temp_project;
+    use std::fs;
+
+    #[test]
+    fn files_outside_every_layer_are_unclassified() {
+        let paths = [
+            "src/domain/user.ts",
+            "src/application/create-user.ts",
+            "src/infrastructure/db.ts",
+            "src/helpers/hash.ts",
+            "src/helpers/hash.spec.ts",
+            "src/main.ts",
+            "src/types/env.d.ts",
+            "scripts/seed.js",
+        ];
+        let files: Vec<(&str, &str)> = paths.iter().map(|path| (*path, "")).collect();
+        let root = temp_project("require-layer", &files);
+        let files: Vec<PathBuf> = paths.iter().map(|path| root.join(path)).collect();
+
+        let settings = RuleSettings::default();
+        assert_eq!(
+            find_unclassified_files(&files, &root, &settings, &ArchPattern::Hexagonal),
+            vec!["src/helpers/hash.ts"]
+        );
+
+        // Con `layer_paths` solo cuentan las capas configuradas
+        let settings = RuleSettings {
+            layer_paths: vec!["src/domain/".to_string(), "src/helpers/".to_string()],
+            ..RuleSettings::default()
+        };
+        assert_eq!(
+            find_unclassified_files(&files, &root, &settings, &ArchPattern::Hexagonal),
+            vec!["src/application/create-user.ts", "src/infrastructure/db.ts"]
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
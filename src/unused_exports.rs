@@ -0,0 +1,432 @@
+//! Exports sin uso en el proyecto (no-unused-exports)
+//!
+//! A diferencia de las reglas por archivo, necesita todo el proyecto: se recorren
+//! los imports de cada archivo registrando qué nombres pide de cada módulo y se
+//! reportan los exports que nadie importa. Los puntos de entrada (package.json,
+//! `main`/`index` de la raíz o de src/, archivos `*.config.*` y los configurados) y
+//! los tests no se reportan: sus exports los consume algo fuera del grafo.
+//!
+//! El análisis es conservador: `import * as`, `export * from`, `import()` y
+//! `require()` marcan como usados todos los exports del módulo, y un re-export
+//! (`export { x } from`) cuenta como uso del original.
+
+use crate::analyzer;
+use crate::circular::resolve_import_path;
+use crate::config::LinterContext;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use swc_common::{SourceMap, Span};
+use swc_ecma_ast::{
+    CallExpr, Callee, Decl, ExportSpecifier, Expr, ImportSpecifier, Lit, Module, ModuleDecl,
+    ModuleExportName, ModuleItem, Pat, TsModuleName, TsModuleRef,
+};
+use swc_ecma_visit::{Visit, VisitWith};
+
+pub const RULE_ID: &str = "no-unused-exports";
+
+/// Nombres de archivo (sin extensión) que se consideran puntos de entrada en la
+/// raíz del proyecto y en src/
+const ENTRY_STEMS: [&str; 4] = ["main", "index", "server", "app"];
+
+/// Patrones de puntos de entrada que siempre aplican (configs de herramientas)
+const DEFAULT_ENTRY_PATTERNS: [&str; 1] = ["*.config."];
+
+/// Export que ningún otro módulo del proyecto importa
+#[derive(Debug, Clone)]
+pub struct UnusedExport {
+    /// Ruta relativa a la raíz del proyecto
+    pub file: String,
+    pub name: String,
+    pub line: usize,
+}
+
+/// Qué se usa de un módulo
+#[derive(Default)]
+struct Usage {
+    names: HashSet<String>,
+    /// Un import de namespace, `export *` o import dinámico: se usa todo
+    all: bool,
+}
+
+/// Exports declarados por un archivo: nombre y span de la declaración
+struct FileExports {
+    path: PathBuf,
+    exports: Vec<(String, Span)>,
+}
+
+/// Analiza los archivos del proyecto y devuelve los exports sin uso, ordenados por
+/// archivo y línea. Sin `no_unused_exports` en architect.json no hace nada.
+pub fn find_unused_exports(
+    files: &[PathBuf],
+    project_root: &Path,
+    cm: &SourceMap,
+    ctx: &LinterContext,
+) -> Vec<UnusedExport> {
+    let rule = match &ctx.settings.no_unused_exports {
+        Some(rule) => rule,
+        None => return Vec::new(),
+    };
+
+    let mut usages: HashMap<PathBuf, Usage> = HashMap::new();
+    let mut declared = Vec::new();
+
+    for file_path in files {
+        // Los archivos que no son JS/TS o no parsean ya se reportan en la fase de reglas
+        let module = match analyzer::parse_module(cm, file_path) {
+            Ok(Some((_, Ok(module)))) => module,
+            _ => continue,
+        };
+
+        let mut collector = ImportCollector {
+            file_path,
+            usages: &mut usages,
+        };
+        collector.collect(&module);
+
+        declared.push(FileExports {
+            path: file_path.clone(),
+            exports: exported_names(&module),
+        });
+    }
+
+    let entry_points = entry_points(project_root);
+    let mut excluded: Vec<String> = DEFAULT_ENTRY_PATTERNS
+        .iter()
+        .map(|pattern| pattern.to_string())
+        .chain(rule.entry_points.iter().cloned())
+        .collect();
+    excluded.extend(rule.tests.iter().cloned());
+
+    let mut unused = Vec::new();
+    for file in declared {
+        let relative = relative_path(&file.path, project_root);
+        let key = canonical(&file.path);
+        if entry_points.contains(&key)
+            || matches_any(&relative, &excluded)
+            || !ctx.is_rule_enabled(RULE_ID, &file.path)
+        {
+            continue;
+        }
+
+        let usage = usages.get(&key);
+        if usage.is_some_and(|usage| usage.all) {
+            continue;
+        }
+        for (name, span) in file.exports {
+            if usage.is_some_and(|usage| usage.names.contains(&name)) {
+                continue;
+            }
+            unused.push(UnusedExport {
+                file: relative.clone(),
+                name,
+                line: cm.lookup_char_pos(span.lo).line,
+            });
+        }
+    }
+
+    unused.sort_by(|a, b| a.file.cmp(&b.file).then(a.line.cmp(&b.line)));
+    unused
+}
+
+/// Imprime los exports sin uso agrupados por archivo
+pub fn print_unused_exports_report(unused: &[UnusedExport]) {
+    if unused.is_empty() {
+        println!("✅ No se encontraron exports sin uso.");
+        return;
+    }
+
+    println!("\n🧹 EXPORTS SIN USO ({})\n", unused.len());
+    let mut current_file = "";
+    for export in unused {
+        if export.file != current_file {
+            println!("  📄 {}", export.file);
+            current_file = &export.file;
+        }
+        println!("     {}: '{}'", export.line, export.name);
+    }
+    println!("\n💡 Elimina los exports que nadie usa o quita el `export` si solo se usan dentro del archivo.");
+}
+
+/// Registra qué nombres importa cada archivo de los módulos que resuelve
+struct ImportCollector<'a> {
+    file_path: &'a Path,
+    usages: &'a mut HashMap<PathBuf, Usage>,
+}
+
+impl ImportCollector<'_> {
+    fn collect(&mut self, module: &Module) {
+        for item in &module.body {
+            let decl = match item {
+                ModuleItem::ModuleDecl(decl) => decl,
+                _ => continue,
+            };
+            match decl {
+                ModuleDecl::Import(import) => {
+                    let mut names = Vec::new();
+                    for specifier in &import.specifiers {
+                        match specifier {
+                            ImportSpecifier::Named(named) => names.push(match &named.imported {
+                                Some(imported) => export_name(imported),
+                                None => named.local.sym.to_string(),
+                            }),
+                            ImportSpecifier::Default(_) => names.push("default".to_string()),
+                            ImportSpecifier::Namespace(_) => {
+                                self.use_all(&import.src.value);
+                            }
+                        }
+                    }
+                    self.use_names(&import.src.value, names);
+                }
+                ModuleDecl::ExportNamed(export) => {
+                    if let Some(src) = &export.src {
+                        let mut names = Vec::new();
+                        for specifier in &export.specifiers {
+                            match specifier {
+                                ExportSpecifier::Named(named) => {
+                                    names.push(export_name(&named.orig))
+                                }
+                                ExportSpecifier::Default(_) => names.push("default".to_string()),
+                                ExportSpecifier::Namespace(_) => self.use_all(&src.value),
+                            }
+                        }
+                        self.use_names(&src.value, names);
+                    }
+                }
+                ModuleDecl::ExportAll(export) => self.use_all(&export.src.value),
+                ModuleDecl::TsImportEquals(import) => {
+                    if let TsModuleRef::TsExternalModuleRef(external) = &import.module_ref {
+                        self.use_all(&external.expr.value);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        module.visit_with(self);
+    }
+
+    fn usage(&mut self, source: &str) -> Option<&mut Usage> {
+        let resolved = resolve_import_path(self.file_path, source)?;
+        Some(self.usages.entry(canonical(&resolved)).or_default())
+    }
+
+    fn use_names(&mut self, source: &str, names: Vec<String>) {
+        if names.is_empty() {
+            return;
+        }
+        if let Some(usage) = self.usage(source) {
+            usage.names.extend(names);
+        }
+    }
+
+    fn use_all(&mut self, source: &str) {
+        if let Some(usage) = self.usage(source) {
+            usage.all = true;
+        }
+    }
+}
+
+/// `import('./x')` y `require('./x')` con especificador literal
+impl Visit for ImportCollector<'_> {
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        let is_dynamic = match &call.callee {
+            Callee::Import(_) => true,
+            Callee::Expr(callee) => {
+                matches!(&**callee, Expr::Ident(ident) if &*ident.sym == "require")
+            }
+            _ => false,
+        };
+        if is_dynamic {
+            if let Some(Expr::Lit(Lit::Str(source))) = call.args.first().map(|arg| &*arg.expr) {
+                self.use_all(&source.value);
+            }
+        }
+        call.visit_children_with(self);
+    }
+}
+
+/// Nombres exportados por el propio archivo (los re-exports no se reportan: su
+/// uso se atribuye al módulo original)
+fn exported_names(module: &Module) -> Vec<(String, Span)> {
+    let mut exports = Vec::new();
+
+    for item in &module.body {
+        let decl = match item {
+            ModuleItem::ModuleDecl(decl) => decl,
+            _ => continue,
+        };
+        match decl {
+            ModuleDecl::ExportDecl(export) => {
+                for name in declared_names(&export.decl) {
+                    exports.push((name, export.span));
+                }
+            }
+            ModuleDecl::ExportDefaultDecl(export) => {
+                exports.push(("default".to_string(), export.span))
+            }
+            ModuleDecl::ExportDefaultExpr(export) => {
+                exports.push(("default".to_string(), export.span))
+            }
+            ModuleDecl::ExportNamed(export) if export.src.is_none() => {
+                for specifier in &export.specifiers {
+                    if let ExportSpecifier::Named(named) = specifier {
+                        let exported = named.exported.as_ref().unwrap_or(&named.orig);
+                        exports.push((export_name(exported), export.span));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    exports
+}
+
+/// Identificadores que introduce una declaración exportada. Las desestructuraciones
+/// (`export const { a, b } = x`) se omiten.
+fn declared_names(decl: &Decl) -> Vec<String> {
+    match decl {
+        Decl::Fn(function) => vec![function.ident.sym.to_string()],
+        Decl::Class(class) => vec![class.ident.sym.to_string()],
+        Decl::Var(var) => var
+            .decls
+            .iter()
+            .filter_map(|declarator| match &declarator.name {
+                Pat::Ident(binding) => Some(binding.id.sym.to_string()),
+                _ => None,
+            })
+            .collect(),
+        Decl::TsInterface(interface) => vec![interface.id.sym.to_string()],
+        Decl::TsTypeAlias(alias) => vec![alias.id.sym.to_string()],
+        Decl::TsEnum(ts_enum) => vec![ts_enum.id.sym.to_string()],
+        Decl::TsModule(ts_module) => match &ts_module.id {
+            TsModuleName::Ident(ident) => vec![ident.sym.to_string()],
+            TsModuleName::Str(_) => Vec::new(),
+        },
+        _ => Vec::new(),
+    }
+}
+
+pub(crate) fn export_name(name: &ModuleExportName) -> String {
+    match name {
+        ModuleExportName::Ident(ident) => ident.sym.to_string(),
+        ModuleExportName::Str(s) => s.value.to_string(),
+    }
+}
+
+/// Puntos de entrada inferidos: los archivos de `main`, `module`, `bin` y `exports`
+/// del package.json y los `main`/`index`/`server`/`app` de la raíz y de src/
+fn entry_points(project_root: &Path) -> HashSet<PathBuf> {
+    let mut entries = HashSet::new();
+
+    if let Some(package) = fs::read_to_string(project_root.join("package.json"))
+        .ok()
+        .and_then(|content| serde_json::from_str::<Value>(&content).ok())
+    {
+        let mut targets = Vec::new();
+        for field in ["main", "module", "bin", "exports"] {
+            if let Some(value) = package.get(field) {
+                collect_strings(value, &mut targets);
+            }
+        }
+        for target in targets {
+            // `dist/index.js` suele compilarse desde `src/index.ts`
+            let target = Path::new(target.trim_start_matches("./"));
+            let source = Path::new("src").join(target.iter().skip(1).collect::<PathBuf>());
+            for candidate in [target.to_path_buf(), source] {
+                if let Some(file) = crate::circular::resolve_candidate(
+                    &project_root.join(candidate.with_extension("")),
+                ) {
+                    entries.insert(canonical(&file));
+                }
+            }
+        }
+    }
+
+    for dir in [project_root.to_path_buf(), project_root.join("src")] {
+        for stem in ENTRY_STEMS {
+            if let Some(file) = crate::circular::resolve_candidate(&dir.join(stem)) {
+                entries.insert(canonical(&file));
+            }
+        }
+    }
+
+    entries
+}
+
+/// Cadenas de un valor de package.json (`bin` y `exports` pueden ser objetos anidados)
+fn collect_strings<'a>(value: &'a Value, out: &mut Vec<&'a str>) {
+    match value {
+        Value::String(s) => out.push(s),
+        Value::Array(items) => items.iter().for_each(|item| collect_strings(item, out)),
+        Value::Object(map) => map.values().for_each(|item| collect_strings(item, out)),
+        _ => {}
+    }
+}
+
+fn matches_any(relative: &str, patterns: &[String]) -> bool {
+    patterns
+        .iter()
+        .any(|pattern| analyzer::matches_pattern(relative, &analyzer::normalize_pattern(pattern)))
+}
+
+fn relative_path(path: &Path, project_root: &Path) -> String {
+    path.strip_prefix(project_root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// Clave estable de un archivo: los imports con `..` y las rutas de la lista de
+/// archivos deben coincidir
+fn canonical(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{NoUnusedExportsRule, RuleSettings};
+    use crate::rules::test_public_api_only::default_test_patterns;
+    use crate::rules::testing::temp_project;
+
+    #[test]
+    fn only_the_export_nobody_imports_is_reported() {
+        let root = temp_project(
+            "unused-exports",
+            &[
+                ("src/main.ts", "import { used } from './lib';\nused();\n"),
+                (
+                    "src/lib.ts",
+                    "export const used = () => 1;\nexport const unused = () => 2;\nexport const tested = () => 3;\n",
+                ),
+                (
+                    "src/lib.spec.ts",
+                    "import { tested } from './lib';\nexport const helper = tested;\n",
+                ),
+            ],
+        );
+        let files: Vec<PathBuf> = ["src/main.ts", "src/lib.ts", "src/lib.spec.ts"]
+            .iter()
+            .map(|path| root.join(path))
+            .collect();
+        let ctx = LinterContext::for_tests(RuleSettings {
+            no_unused_exports: Some(NoUnusedExportsRule {
+                entry_points: Vec::new(),
+                tests: default_test_patterns(),
+            }),
+            ..RuleSettings::default()
+        });
+
+        let unused = find_unused_exports(&files, &root, &SourceMap::default(), &ctx);
+
+        // El entry point y el export del test no se reportan; lo que importa el test sí cuenta como uso
+        let found: Vec<(&str, &str, usize)> = unused
+            .iter()
+            .map(|export| (export.file.as_str(), export.name.as_str(), export.line))
+            .collect();
+        assert_eq!(found, vec![("src/lib.ts", "unused", 2)]);
+    }
+}